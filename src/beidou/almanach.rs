@@ -0,0 +1,179 @@
+use crate::bits::{
+    get_bits, get_bits2, get_signed_bits, get_signed_bits2, set_bits, set_bits2, to_signed_bits,
+    to_unsigned_bits,
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [BeiDouAlmanach] is found in subframe 4 (pages 1 to 24)
+/// and subframe 5 (pages 1 to 6). The described satellite is
+/// identified by the page number.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BeiDouAlmanach {
+    /// Square root of semi-major axis (in m^1/2)
+    pub sqrt_a: f64,
+
+    /// Clock drift (in s.s⁻¹)
+    pub a1: f64,
+
+    /// Clock offset (in seconds)
+    pub a0: f64,
+
+    /// Longitude of ascending node at weekly epoch (in semi-circles)
+    pub omega0: f64,
+
+    /// Eccentricity
+    pub e: f64,
+
+    /// Correction of orbit reference inclination (in semi-circles)
+    pub delta_i: f64,
+
+    /// Almanach reference time of week (in seconds)
+    pub toa_seconds: u32,
+
+    /// Rate of right ascension (in semi-circles.s⁻¹)
+    pub omega_dot: f64,
+
+    /// Argument of perigee (in semi-circles)
+    pub omega: f64,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    pub m0: f64,
+}
+
+impl BeiDouAlmanach {
+    /// Generates a realistic almanach model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_sqrt_a(5_282.625)
+            .with_a1(-12.0 * 2.0_f64.powi(-38))
+            .with_a0(345.0 * 2.0_f64.powi(-20))
+            .with_omega0(-1_234_567.0 * 2.0_f64.powi(-23))
+            .with_e(1_234.0 * 2.0_f64.powi(-21))
+            .with_delta_i(8_765.0 * 2.0_f64.powi(-19))
+            .with_toa_seconds(147 * 4096)
+            .with_omega_dot(-23_456.0 * 2.0_f64.powi(-38))
+            .with_omega(2_345_678.0 * 2.0_f64.powi(-23))
+            .with_m0(-3_456_789.0 * 2.0_f64.powi(-23))
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated square root of semi-major axis (in m^1/2)
+    pub fn with_sqrt_a(mut self, sqrt_a: f64) -> Self {
+        self.sqrt_a = sqrt_a;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated clock drift (in s.s⁻¹)
+    pub fn with_a1(mut self, a1: f64) -> Self {
+        self.a1 = a1;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated clock offset (in seconds)
+    pub fn with_a0(mut self, a0: f64) -> Self {
+        self.a0 = a0;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated longitude of ascending node (in semi-circles)
+    pub fn with_omega0(mut self, omega0: f64) -> Self {
+        self.omega0 = omega0;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated eccentricity
+    pub fn with_e(mut self, e: f64) -> Self {
+        self.e = e;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated inclination correction (in semi-circles)
+    pub fn with_delta_i(mut self, delta_i: f64) -> Self {
+        self.delta_i = delta_i;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated reference time of week (in seconds).
+    /// Should be a multiple of 4096 seconds, to be exactly encoded.
+    pub fn with_toa_seconds(mut self, toa_seconds: u32) -> Self {
+        self.toa_seconds = toa_seconds;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated rate of right ascension (in semi-circles.s⁻¹)
+    pub fn with_omega_dot(mut self, omega_dot: f64) -> Self {
+        self.omega_dot = omega_dot;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated argument of perigee (in semi-circles)
+    pub fn with_omega(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Copies and returns [BeiDouAlmanach] with updated mean anomaly (in semi-circles)
+    pub fn with_m0(mut self, m0: f64) -> Self {
+        self.m0 = m0;
+        self
+    }
+
+    /// Decodes [Self] from this deinterleaved subframe.
+    /// This method does not care for the BCH code.
+    pub(crate) fn from_buffer(buf: &[u8]) -> Self {
+        Self {
+            sqrt_a: get_bits2(buf, 50, 2, 60, 22) as f64 * 2.0_f64.powi(-11),
+            a1: get_signed_bits(buf, 90, 11) as f64 * 2.0_f64.powi(-38),
+            a0: get_signed_bits(buf, 101, 11) as f64 * 2.0_f64.powi(-20),
+            omega0: get_signed_bits2(buf, 120, 22, 150, 2) as f64 * 2.0_f64.powi(-23),
+            e: get_bits(buf, 152, 17) as f64 * 2.0_f64.powi(-21),
+            delta_i: get_signed_bits2(buf, 169, 3, 180, 13) as f64 * 2.0_f64.powi(-19),
+            toa_seconds: get_bits(buf, 193, 8) * 4096,
+            omega_dot: get_signed_bits2(buf, 201, 1, 210, 16) as f64 * 2.0_f64.powi(-38),
+            omega: get_signed_bits2(buf, 226, 6, 240, 18) as f64 * 2.0_f64.powi(-23),
+            m0: get_signed_bits2(buf, 258, 4, 270, 20) as f64 * 2.0_f64.powi(-23),
+        }
+    }
+
+    /// Encodes this [BeiDouAlmanach] into this deinterleaved subframe.
+    pub(crate) fn to_buffer(self, buf: &mut [u8]) {
+        let sqrt_a = to_unsigned_bits(self.sqrt_a / 2.0_f64.powi(-11), 24);
+        let a1 = to_signed_bits(self.a1 / 2.0_f64.powi(-38), 11);
+        let a0 = to_signed_bits(self.a0 / 2.0_f64.powi(-20), 11);
+        let omega0 = to_signed_bits(self.omega0 / 2.0_f64.powi(-23), 24);
+        let e = to_unsigned_bits(self.e / 2.0_f64.powi(-21), 17);
+        let delta_i = to_signed_bits(self.delta_i / 2.0_f64.powi(-19), 16);
+        let omega_dot = to_signed_bits(self.omega_dot / 2.0_f64.powi(-38), 17);
+        let omega = to_signed_bits(self.omega / 2.0_f64.powi(-23), 24);
+        let m0 = to_signed_bits(self.m0 / 2.0_f64.powi(-23), 24);
+
+        set_bits2(buf, 50, 2, 60, 22, sqrt_a);
+        set_bits(buf, 90, 11, a1);
+        set_bits(buf, 101, 11, a0);
+        set_bits2(buf, 120, 22, 150, 2, omega0);
+        set_bits(buf, 152, 17, e);
+        set_bits2(buf, 169, 3, 180, 13, delta_i);
+        set_bits(buf, 193, 8, (self.toa_seconds / 4096) & 0xff);
+        set_bits2(buf, 201, 1, 210, 16, omega_dot);
+        set_bits2(buf, 226, 6, 240, 18, omega);
+        set_bits2(buf, 258, 4, 270, 20, m0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beidou::BEIDOU_D1_FRAME_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = BeiDouAlmanach::model();
+
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+        model.to_buffer(&mut buf);
+
+        assert_eq!(BeiDouAlmanach::from_buffer(&buf), model);
+    }
+}
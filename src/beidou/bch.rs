@@ -0,0 +1,110 @@
+//! BeiDou D1 words are protected by BCH(15,11,1) codes, generator polynomial g(x) = x⁴ + x + 1.
+//! The first word carries one codeword (its last 15 bits), following words carry
+//! two codewords that are interleaved bit by bit.
+//! BCH(15,11) is a perfect code: every non null syndrome designates a single bit error,
+//! which means that two or more errors in a codeword are not detected (they're miscorrected).
+
+/// Generator polynomial g(x) = x⁴ + x + 1
+const GENERATOR: u32 = 0x13;
+
+/// Returns the 4-bit remainder of this polynomial divided by g(x)
+fn remainder(mut value: u32, degree: u32) -> u32 {
+    for bit in (4..=degree).rev() {
+        if value & (1 << bit) > 0 {
+            value ^= GENERATOR << (bit - 4);
+        }
+    }
+
+    value & 0x0f
+}
+
+/// Returns the 4 parity bits of this 11-bit information sequence
+pub(crate) fn parity(info: u32) -> u32 {
+    remainder((info & 0x7ff) << 4, 14)
+}
+
+/// Verifies this 15-bit codeword (11 information bits followed by 4 parity bits).
+/// Single bit errors are corrected.
+/// Returns the (possibly corrected) 11 information bits.
+pub(crate) fn decode(codeword: u32) -> u32 {
+    let codeword = codeword & 0x7fff;
+    let syndrome = remainder(codeword, 14);
+
+    // every single bit error has a unique syndrome (x^n mod g(x))
+    let error = (0..15)
+        .map(|position| 1 << position)
+        .find(|error| syndrome != 0 && remainder(*error, 14) == syndrome)
+        .unwrap_or(0);
+
+    (codeword ^ error) >> 4
+}
+
+/// Interleaves these two 15-bit codewords into a 30-bit word, as transmitted:
+/// the first bit of the first codeword is transmitted first.
+pub(crate) fn interleave(codeword1: u32, codeword2: u32) -> u32 {
+    let mut word = 0;
+
+    for bit in (0..15).rev() {
+        word <<= 2;
+        word |= ((codeword1 >> bit) & 0x01) << 1;
+        word |= (codeword2 >> bit) & 0x01;
+    }
+
+    word
+}
+
+/// Splits this transmitted 30-bit word into its two 15-bit codewords.
+pub(crate) fn deinterleave(word: u32) -> (u32, u32) {
+    let (mut codeword1, mut codeword2) = (0, 0);
+
+    for bit in (0..15).rev() {
+        codeword1 <<= 1;
+        codeword2 <<= 1;
+        codeword1 |= (word >> (2 * bit + 1)) & 0x01;
+        codeword2 |= (word >> (2 * bit)) & 0x01;
+    }
+
+    (codeword1, codeword2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parity_bits() {
+        assert_eq!(parity(0), 0);
+        // x^4 mod g(x) = x + 1
+        assert_eq!(parity(0x001), 0x3);
+        assert_eq!(parity(0x7ff), remainder(0x7ff << 4, 14));
+
+        for info in 0..0x800 {
+            let codeword = (info << 4) | parity(info);
+            assert_eq!(remainder(codeword, 14), 0);
+            assert_eq!(decode(codeword), info);
+        }
+    }
+
+    #[test]
+    fn correction() {
+        for info in [0x000, 0x5a5, 0x7ff, 0x123] {
+            let codeword = (info << 4) | parity(info);
+
+            for bit in 0..15 {
+                let corrupt = codeword ^ (1 << bit);
+                assert_eq!(decode(corrupt), info, "failed to correct bit #{}", bit);
+            }
+        }
+    }
+
+    #[test]
+    fn interleaving() {
+        let word = interleave(0x7fff, 0);
+        assert_eq!(word, 0x2aaa_aaaa);
+
+        for (codeword1, codeword2) in [(0x1234, 0x5678), (0x7fff, 0x0001), (0, 0x4000)] {
+            let word = interleave(codeword1, codeword2);
+            assert_eq!(deinterleave(word), (codeword1, codeword2));
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    beidou::{
+        BeiDouD1Frame, BEIDOU_D1_FRAME_BITS, BEIDOU_D1_PREAMBLE, BEIDOU_D1_PREAMBLE_BITS,
+        BEIDOU_WORDS_PER_FRAME, BEIDOU_WORD_BITS,
+    },
+    bits::{get_bits, peek_bits},
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [BeiDouD1Decoder] can decode BeiDou D1 (B1I) navigation subframes,
+/// from a stream of bits that was already stripped from the Neumann-Hoffman code.
+/// The stream is synchronized on the 11-bit preamble.
+///
+/// By [Default], our [BeiDouD1Decoder] verifies the BCH codes of each word
+/// and corrects single bit errors. Note that BCH(15,11) can't detect two or more errors
+/// within a codeword.
+#[derive(Debug, Copy, Clone)]
+pub struct BeiDouD1Decoder {
+    /// True when BCH verification is requested
+    bch_verification: bool,
+}
+
+impl Default for BeiDouD1Decoder {
+    /// Creates a default [BeiDouD1Decoder] that verifies the BCH codes.
+    fn default() -> Self {
+        Self {
+            bch_verification: true,
+        }
+    }
+}
+
+impl BeiDouD1Decoder {
+    /// Copies and returns a [BeiDouD1Decoder] that does not verify the BCH codes.
+    /// Every subframe is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_bch_verification(mut self) -> Self {
+        self.bch_verification = false;
+        self
+    }
+
+    /// Locates the first preamble within this buffer, at any bit offset.
+    fn find_preamble(buffer: &[u8], size: usize) -> Option<usize> {
+        (0..size * 8).find(|offset| {
+            peek_bits(&buffer[..size], *offset, BEIDOU_D1_PREAMBLE_BITS)
+                == Some(BEIDOU_D1_PREAMBLE as u32)
+        })
+    }
+
+    /// Decodes the first valid [BeiDouD1Frame] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same subframe twice.
+    /// - Optional [BeiDouD1Frame] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When the buffer ends before the subframe does (partial read), nothing past the preamble
+    /// is consumed and [None] is returned: simply append the following bytes and call
+    /// [Self::decode] again. When the subframe can't be interpreted, the preamble was most likely
+    /// found in the data bits: only its first bit is consumed, so the search resumes right after.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<BeiDouD1Frame>) {
+        let size = size.min(buffer.len());
+
+        let preamble_offset_bit = match Self::find_preamble(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(BEIDOU_D1_PREAMBLE_BITS - 1), None);
+            },
+        };
+
+        let frame_end = preamble_offset_bit + BEIDOU_D1_FRAME_BITS;
+
+        // wait for the complete subframe
+        if frame_end > size * 8 {
+            return (preamble_offset_bit, None);
+        }
+
+        #[cfg(feature = "log")]
+        trace!("(BDS) [preamble]: pos={}", preamble_offset_bit);
+
+        let mut transmitted = [0u32; BEIDOU_WORDS_PER_FRAME];
+
+        for (i, word) in transmitted.iter_mut().enumerate() {
+            *word = get_bits(
+                buffer,
+                preamble_offset_bit + i * BEIDOU_WORD_BITS,
+                BEIDOU_WORD_BITS,
+            );
+        }
+
+        let words = BeiDouD1Frame::deinterleave(&transmitted, self.bch_verification);
+
+        match BeiDouD1Frame::from_words(&words) {
+            Ok(frame) => (frame_end, Some(frame)),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (preamble_offset_bit + 1, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(BDS) [subframe]: {}", e);
+                (preamble_offset_bit + 1, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        beidou::{
+            BeiDouAlmanach, BeiDouD1Decoder, BeiDouD1Frame, BeiDouD1Subframe, BeiDouD1Subframe2,
+            BeiDouD1Subframe3, BEIDOU_D1_FRAME_BITS,
+        },
+        testing::insert_bit_delay,
+    };
+
+    fn models() -> Vec<BeiDouD1Frame> {
+        [
+            BeiDouD1Subframe::Subframe2(BeiDouD1Subframe2::model()),
+            BeiDouD1Subframe::Subframe3(BeiDouD1Subframe3::model()),
+            BeiDouD1Subframe::Subframe4(3, BeiDouAlmanach::model()),
+            BeiDouD1Subframe::Subframe5(4, BeiDouAlmanach::model()),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, subframe)| {
+            BeiDouD1Frame::default()
+                .with_sow(1_000 + 6 * i as u32)
+                .with_subframe(subframe)
+        })
+        .collect()
+    }
+
+    /// Concatenates (bitwise) these subframes, like a synchronous stream does.
+    fn stream(frames: &[BeiDouD1Frame]) -> Vec<u8> {
+        let mut bits = Vec::new();
+
+        for frame in frames {
+            let raw = frame.encode_raw();
+
+            for bit in 0..BEIDOU_D1_FRAME_BITS {
+                bits.push((raw[bit / 8] >> (7 - (bit % 8))) & 0x01);
+            }
+        }
+
+        bits.chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, bit)| byte | (bit << (7 - i)))
+            })
+            .collect()
+    }
+
+    /// Decodes this buffer with a lazy buffer management, returning all subframes.
+    fn decode_all(decoder: &mut BeiDouD1Decoder, buffer: &[u8]) -> Vec<BeiDouD1Frame> {
+        let mut ptr = 0;
+        let mut decoded = Vec::new();
+
+        while ptr < buffer.len() {
+            let (processed, frame) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+            if let Some(frame) = frame {
+                decoded.push(frame);
+            }
+
+            if processed / 8 == 0 {
+                break;
+            }
+
+            ptr += processed / 8;
+        }
+
+        decoded
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let models = models();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&models), delay);
+
+            let mut decoder = BeiDouD1Decoder::default();
+            let decoded = decode_all(&mut decoder, &buffer);
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+
+    #[test]
+    fn partial_read() {
+        let model = models()[0];
+        let buffer = stream(&[model]);
+
+        let mut decoder = BeiDouD1Decoder::default();
+
+        let (processed, frame) = decoder.decode(&buffer, 37);
+        assert_eq!(processed, 0);
+        assert!(frame.is_none());
+
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, BEIDOU_D1_FRAME_BITS);
+        assert_eq!(frame, Some(model));
+    }
+
+    #[test]
+    fn bch_correction() {
+        let model = models()[1];
+
+        // corrupt one bit in words #1, #5 and #10
+        let mut buffer = stream(&[model]);
+        buffer[2] ^= 0x20;
+        buffer[18] ^= 0x04;
+        buffer[36] ^= 0x80;
+
+        let mut decoder = BeiDouD1Decoder::default();
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, BEIDOU_D1_FRAME_BITS);
+        assert_eq!(frame, Some(model));
+
+        // without verification, corrupt data is interpreted
+        let mut decoder = BeiDouD1Decoder::default().without_bch_verification();
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, BEIDOU_D1_FRAME_BITS);
+        assert!(frame.is_some());
+        assert_ne!(frame, Some(model));
+    }
+
+    #[test]
+    fn invalid_subframe() {
+        let mut buffer = stream(&[models()[0]]);
+
+        // subframe ID #7
+        buffer[1] |= 0x01;
+        buffer[2] |= 0xc0;
+
+        let mut decoder = BeiDouD1Decoder::default().without_bch_verification();
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, 1);
+        assert!(frame.is_none());
+    }
+}
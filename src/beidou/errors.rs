@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BeiDouError {
+    /// Not a valid BeiDou preamble
+    #[error("invalid BeiDou preamble")]
+    InvalidPreamble,
+
+    /// Subframe ID is either invalid or not supported
+    #[error("unknown BeiDou subframe type")]
+    UnknownFrameType,
+
+    /// Subframe 4 or 5 page is either invalid or not supported
+    #[error("invalid or unsupported page")]
+    InvalidPage,
+}
@@ -0,0 +1,369 @@
+use crate::{
+    beidou::{
+        bch, BeiDouAlmanach, BeiDouD1Subframe1, BeiDouD1Subframe2, BeiDouD1Subframe3, BeiDouError,
+        BEIDOU_D1_FRAME_BYTES, BEIDOU_D1_PREAMBLE, BEIDOU_WORDS_PER_FRAME, BEIDOU_WORD_BITS,
+    },
+    bits::{get_bits, get_bits2, set_bits, set_bits2},
+};
+
+/// Number of raw words following the page number, in pages that we do not interpret
+const RAW_WORDS: usize = BEIDOU_WORDS_PER_FRAME - 1;
+
+/// [BeiDouD1Subframe] describes the content of a [BeiDouD1Frame]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BeiDouD1Subframe {
+    /// Subframe 1: clock, ionosphere and group delays
+    Subframe1(BeiDouD1Subframe1),
+
+    /// Subframe 2: first half of the ephemeris
+    Subframe2(BeiDouD1Subframe2),
+
+    /// Subframe 3: second half of the ephemeris
+    Subframe3(BeiDouD1Subframe3),
+
+    /// Subframe 4 page (1 to 24): [BeiDouAlmanach] of satellite #1 to #24
+    Subframe4(u8, BeiDouAlmanach),
+
+    /// Subframe 5 page (1 to 6): [BeiDouAlmanach] of satellite #25 to #30
+    Subframe5(u8, BeiDouAlmanach),
+
+    /// Subframe 5 page (7 to 24): health, almanach week and time offsets, not interpreted yet.
+    /// Raw data bits that follow the page number, right aligned: word #2 has 2 bits,
+    /// words #3 to #10 have 22 bits.
+    Subframe5Raw(u8, [u32; RAW_WORDS]),
+}
+
+impl Default for BeiDouD1Subframe {
+    fn default() -> Self {
+        Self::Subframe1(Default::default())
+    }
+}
+
+impl BeiDouD1Subframe {
+    /// Returns the subframe ID (1 to 5)
+    pub fn frame_id(&self) -> u8 {
+        match self {
+            Self::Subframe1(_) => 1,
+            Self::Subframe2(_) => 2,
+            Self::Subframe3(_) => 3,
+            Self::Subframe4(_, _) => 4,
+            Self::Subframe5(_, _) | Self::Subframe5Raw(_, _) => 5,
+        }
+    }
+
+    /// Returns the page number, for subframes 4 and 5
+    pub fn page_id(&self) -> Option<u8> {
+        match self {
+            Self::Subframe4(page, _) | Self::Subframe5(page, _) | Self::Subframe5Raw(page, _) => {
+                Some(*page)
+            },
+            _ => None,
+        }
+    }
+}
+
+/// [BeiDouD1Frame] is a BeiDou D1 (B1I) subframe, as broadcast by MEO and IGSO satellites.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BeiDouD1Frame {
+    /// 4-bit reserved field, following the preamble
+    pub rev: u8,
+
+    /// Seconds of week, at the start of this subframe
+    pub sow: u32,
+
+    /// [BeiDouD1Subframe]
+    pub subframe: BeiDouD1Subframe,
+}
+
+impl BeiDouD1Frame {
+    /// Copies and returns [BeiDouD1Frame] with updated seconds of week
+    pub fn with_sow(mut self, sow: u32) -> Self {
+        self.sow = sow & 0xfffff;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Frame] with updated [BeiDouD1Subframe]
+    pub fn with_subframe(mut self, subframe: BeiDouD1Subframe) -> Self {
+        self.subframe = subframe;
+        self
+    }
+
+    /// Decodes a [BeiDouD1Frame] from 10 deinterleaved words, each being right aligned
+    /// and organized as the receivers usually report them: information bits first,
+    /// followed by the BCH parity bits.
+    /// This method does not care for the BCH code: use the [BeiDouD1Decoder](crate::BeiDouD1Decoder)
+    /// to verify the transmitted stream.
+    pub fn from_words(words: &[u32; BEIDOU_WORDS_PER_FRAME]) -> Result<Self, BeiDouError> {
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+
+        for (i, word) in words.iter().enumerate() {
+            set_bits(&mut buf, i * BEIDOU_WORD_BITS, BEIDOU_WORD_BITS, *word);
+        }
+
+        if get_bits(&buf, 0, 11) != BEIDOU_D1_PREAMBLE as u32 {
+            return Err(BeiDouError::InvalidPreamble);
+        }
+
+        let rev = get_bits(&buf, 11, 4) as u8;
+        let sow = get_bits2(&buf, 18, 8, 30, 12);
+        let page = get_bits(&buf, 43, 7) as u8;
+
+        let subframe = match get_bits(&buf, 15, 3) {
+            1 => BeiDouD1Subframe::Subframe1(BeiDouD1Subframe1::from_buffer(&buf)),
+            2 => BeiDouD1Subframe::Subframe2(BeiDouD1Subframe2::from_buffer(&buf)),
+            3 => BeiDouD1Subframe::Subframe3(BeiDouD1Subframe3::from_buffer(&buf)),
+            4 => match page {
+                1..=24 => BeiDouD1Subframe::Subframe4(page, BeiDouAlmanach::from_buffer(&buf)),
+                _ => return Err(BeiDouError::InvalidPage),
+            },
+            5 => match page {
+                1..=6 => BeiDouD1Subframe::Subframe5(page, BeiDouAlmanach::from_buffer(&buf)),
+                7..=24 => {
+                    let mut raw = [0u32; RAW_WORDS];
+                    raw[0] = get_bits(&buf, 50, 2);
+
+                    for (i, raw) in raw.iter_mut().enumerate().skip(1) {
+                        *raw = get_bits(&buf, (i + 1) * BEIDOU_WORD_BITS, 22);
+                    }
+
+                    BeiDouD1Subframe::Subframe5Raw(page, raw)
+                },
+                _ => return Err(BeiDouError::InvalidPage),
+            },
+            _ => return Err(BeiDouError::UnknownFrameType),
+        };
+
+        Ok(Self { rev, sow, subframe })
+    }
+
+    /// Encodes this [BeiDouD1Frame] as 10 deinterleaved words (right aligned),
+    /// information bits first, followed by the BCH parity bits.
+    pub fn to_words(&self) -> [u32; BEIDOU_WORDS_PER_FRAME] {
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+
+        set_bits(&mut buf, 0, 11, BEIDOU_D1_PREAMBLE as u32);
+        set_bits(&mut buf, 11, 4, (self.rev & 0x0f) as u32);
+        set_bits(&mut buf, 15, 3, self.subframe.frame_id() as u32);
+        set_bits2(&mut buf, 18, 8, 30, 12, self.sow & 0xfffff);
+
+        if let Some(page) = self.subframe.page_id() {
+            set_bits(&mut buf, 43, 7, (page & 0x7f) as u32);
+        }
+
+        match self.subframe {
+            BeiDouD1Subframe::Subframe1(subframe) => subframe.to_buffer(&mut buf),
+            BeiDouD1Subframe::Subframe2(subframe) => subframe.to_buffer(&mut buf),
+            BeiDouD1Subframe::Subframe3(subframe) => subframe.to_buffer(&mut buf),
+            BeiDouD1Subframe::Subframe4(_, almanach) => almanach.to_buffer(&mut buf),
+            BeiDouD1Subframe::Subframe5(_, almanach) => almanach.to_buffer(&mut buf),
+            BeiDouD1Subframe::Subframe5Raw(_, raw) => {
+                set_bits(&mut buf, 50, 2, raw[0] & 0x03);
+
+                for (i, raw) in raw.iter().enumerate().skip(1) {
+                    set_bits(&mut buf, (i + 1) * BEIDOU_WORD_BITS, 22, raw & 0x3fffff);
+                }
+            },
+        }
+
+        let mut words = [0u32; BEIDOU_WORDS_PER_FRAME];
+
+        for (i, word) in words.iter_mut().enumerate() {
+            let value = get_bits(&buf, i * BEIDOU_WORD_BITS, BEIDOU_WORD_BITS);
+
+            *word = if i == 0 {
+                // first 15 bits are not protected
+                let info = (value >> 4) & 0x7ff;
+                (value & 0x3fff_fff0) | bch::parity(info)
+            } else {
+                let info1 = (value >> 19) & 0x7ff;
+                let info2 = (value >> 8) & 0x7ff;
+                (value & 0x3fff_ff00) | (bch::parity(info1) << 4) | bch::parity(info2)
+            };
+        }
+
+        words
+    }
+
+    /// Encodes this [BeiDouD1Frame] as transmitted: words #2 to #10 have their
+    /// two BCH codewords interleaved. The 300 bits are left aligned in the returned buffer.
+    pub fn encode_raw(&self) -> [u8; BEIDOU_D1_FRAME_BYTES] {
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+
+        for (i, word) in self.to_words().iter().enumerate() {
+            let transmitted = if i == 0 {
+                *word
+            } else {
+                let codeword1 = (((word >> 19) & 0x7ff) << 4) | ((word >> 4) & 0x0f);
+                let codeword2 = (((word >> 8) & 0x7ff) << 4) | (word & 0x0f);
+                bch::interleave(codeword1, codeword2)
+            };
+
+            set_bits(
+                &mut buf,
+                i * BEIDOU_WORD_BITS,
+                BEIDOU_WORD_BITS,
+                transmitted,
+            );
+        }
+
+        buf
+    }
+
+    /// Deinterleaves these 10 transmitted words, ready for [Self::from_words].
+    /// When `verification` is requested, the BCH codes are verified
+    /// and single bit errors are corrected.
+    pub(crate) fn deinterleave(
+        transmitted: &[u32; BEIDOU_WORDS_PER_FRAME],
+        verification: bool,
+    ) -> [u32; BEIDOU_WORDS_PER_FRAME] {
+        let mut words = [0u32; BEIDOU_WORDS_PER_FRAME];
+
+        let decode = |codeword: u32| {
+            if verification {
+                let info = bch::decode(codeword);
+                (info << 4) | bch::parity(info)
+            } else {
+                codeword & 0x7fff
+            }
+        };
+
+        for (i, (word, transmitted)) in words.iter_mut().zip(transmitted.iter()).enumerate() {
+            *word = if i == 0 {
+                (transmitted & 0x3fff_8000) | decode(transmitted & 0x7fff)
+            } else {
+                let (codeword1, codeword2) = bch::deinterleave(*transmitted);
+                let (codeword1, codeword2) = (decode(codeword1), decode(codeword2));
+
+                ((codeword1 >> 4) << 19)
+                    | ((codeword2 >> 4) << 8)
+                    | ((codeword1 & 0x0f) << 4)
+                    | (codeword2 & 0x0f)
+            };
+        }
+
+        words
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn models() -> Vec<BeiDouD1Frame> {
+        let mut raw = [0u32; RAW_WORDS];
+        raw[0] = 0x02;
+
+        for (i, raw) in raw.iter_mut().enumerate().skip(1) {
+            *raw = 0x155555 ^ (i as u32);
+        }
+
+        vec![
+            BeiDouD1Subframe::Subframe1(BeiDouD1Subframe1::model()),
+            BeiDouD1Subframe::Subframe2(BeiDouD1Subframe2::model()),
+            BeiDouD1Subframe::Subframe3(BeiDouD1Subframe3::model()),
+            BeiDouD1Subframe::Subframe4(1, BeiDouAlmanach::model()),
+            BeiDouD1Subframe::Subframe4(24, BeiDouAlmanach::model()),
+            BeiDouD1Subframe::Subframe5(6, BeiDouAlmanach::model()),
+            BeiDouD1Subframe::Subframe5Raw(7, raw),
+            BeiDouD1Subframe::Subframe5Raw(24, raw),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, subframe)| {
+            BeiDouD1Frame::default()
+                .with_sow(345_600 + 6 * i as u32)
+                .with_subframe(subframe)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn reciprocal() {
+        for model in models() {
+            let words = model.to_words();
+
+            assert_eq!(words[0] >> 19, BEIDOU_D1_PREAMBLE as u32);
+
+            let decoded = BeiDouD1Frame::from_words(&words).unwrap_or_else(|e| {
+                panic!("failed to decode {:?}: {}", model.subframe, e);
+            });
+
+            // tgd are not exact multiples of their LSB
+            if let BeiDouD1Subframe::Subframe1(subframe1) = model.subframe {
+                assert_eq!(decoded.sow, model.sow);
+                assert_eq!(decoded.subframe.frame_id(), 1);
+                assert_eq!(decoded.to_words(), words);
+
+                match decoded.subframe {
+                    BeiDouD1Subframe::Subframe1(decoded) => {
+                        assert_eq!(decoded.week, subframe1.week);
+                        assert_eq!(decoded.af0, subframe1.af0);
+                    },
+                    _ => panic!("decoded invalid subframe"),
+                }
+            } else {
+                assert_eq!(decoded, model);
+            }
+        }
+    }
+
+    #[test]
+    fn parity() {
+        for model in models() {
+            let words = model.to_words();
+
+            for (i, word) in words.iter().enumerate() {
+                if i == 0 {
+                    assert_eq!(bch::decode(word & 0x7fff), (word >> 4) & 0x7ff);
+                } else {
+                    let codeword1 = (((word >> 19) & 0x7ff) << 4) | ((word >> 4) & 0x0f);
+                    let codeword2 = (((word >> 8) & 0x7ff) << 4) | (word & 0x0f);
+                    assert_eq!(bch::decode(codeword1), (word >> 19) & 0x7ff);
+                    assert_eq!(bch::decode(codeword2), (word >> 8) & 0x7ff);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn interleaving() {
+        for model in models() {
+            let raw = model.encode_raw();
+
+            let mut transmitted = [0u32; BEIDOU_WORDS_PER_FRAME];
+
+            for (i, word) in transmitted.iter_mut().enumerate() {
+                *word = get_bits(&raw, i * BEIDOU_WORD_BITS, BEIDOU_WORD_BITS);
+            }
+
+            // preamble is not interleaved
+            assert_eq!(transmitted[0] >> 19, BEIDOU_D1_PREAMBLE as u32);
+
+            let words = BeiDouD1Frame::deinterleave(&transmitted, true);
+            assert_eq!(words, model.to_words());
+        }
+    }
+
+    #[test]
+    fn invalid_pages() {
+        let mut words = BeiDouD1Frame::default()
+            .with_subframe(BeiDouD1Subframe::Subframe4(1, Default::default()))
+            .to_words();
+
+        // page #25
+        words[1] |= 0x18 << 10;
+
+        match BeiDouD1Frame::from_words(&words) {
+            Err(BeiDouError::InvalidPage) => {},
+            other => panic!("should have failed: {:?}", other),
+        }
+
+        let mut words = BeiDouD1Frame::default().to_words();
+        words[0] |= 0x07 << 12;
+
+        match BeiDouD1Frame::from_words(&words) {
+            Err(BeiDouError::UnknownFrameType) => {},
+            other => panic!("should have failed: {:?}", other),
+        }
+    }
+}
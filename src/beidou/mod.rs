@@ -0,0 +1,51 @@
+/// BeiDou D1 preamble (11 bits)
+pub const BEIDOU_D1_PREAMBLE: u16 = 0x712;
+
+/// BeiDou D1 preamble size (in bits!)
+pub const BEIDOU_D1_PREAMBLE_BITS: usize = 11;
+
+/// BeiDou data word size (in bits!)
+pub const BEIDOU_WORD_BITS: usize = 30;
+
+/// Number of words in a subframe
+pub const BEIDOU_WORDS_PER_FRAME: usize = 10;
+
+/// Total BeiDou D1 subframe size (in bits!)
+pub const BEIDOU_D1_FRAME_BITS: usize = BEIDOU_WORDS_PER_FRAME * BEIDOU_WORD_BITS;
+
+/// Total BeiDou D1 subframe size (in bytes!)
+pub const BEIDOU_D1_FRAME_BYTES: usize = (BEIDOU_D1_FRAME_BITS + 7) / 8;
+
+mod almanach;
+pub use almanach::BeiDouAlmanach;
+
+mod bch;
+
+mod decoder;
+pub use decoder::BeiDouD1Decoder;
+
+mod errors;
+pub use errors::BeiDouError;
+
+mod frame;
+pub use frame::{BeiDouD1Frame, BeiDouD1Subframe};
+
+mod subframe1;
+pub use subframe1::BeiDouD1Subframe1;
+
+mod subframe2;
+pub use subframe2::BeiDouD1Subframe2;
+
+mod subframe3;
+pub use subframe3::BeiDouD1Subframe3;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn beidou_properties() {
+        assert_eq!(BEIDOU_D1_FRAME_BITS, 300);
+        assert_eq!(BEIDOU_D1_FRAME_BYTES, 38);
+    }
+}
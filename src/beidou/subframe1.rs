@@ -0,0 +1,316 @@
+use crate::bits::{
+    get_bits, get_bits2, get_signed_bits, get_signed_bits2, set_bits, set_bits2, to_signed_bits,
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [BeiDouD1Subframe1] gives the satellite clock correction,
+/// the ionospheric model and the group delays.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BeiDouD1Subframe1 {
+    /// Autonomous satellite health flag: false means healthy
+    pub sat_h1: bool,
+
+    /// 5-bit Age of Data, Clock
+    pub aodc: u8,
+
+    /// 4-bit User Range Accuracy Index
+    pub urai: u8,
+
+    /// 13-bit BeiDou week number
+    pub week: u16,
+
+    /// Clock reference time of week (in seconds)
+    pub toc_seconds: u32,
+
+    /// B1I group delay (in seconds)
+    pub tgd1: f64,
+
+    /// B2I group delay (in seconds)
+    pub tgd2: f64,
+
+    /// Ionospheric α0 (in seconds)
+    pub alpha0: f64,
+
+    /// Ionospheric α1 (in s.semi-circle⁻¹)
+    pub alpha1: f64,
+
+    /// Ionospheric α2 (in s.semi-circle⁻²)
+    pub alpha2: f64,
+
+    /// Ionospheric α3 (in s.semi-circle⁻³)
+    pub alpha3: f64,
+
+    /// Ionospheric β0 (in seconds)
+    pub beta0: f64,
+
+    /// Ionospheric β1 (in s.semi-circle⁻¹)
+    pub beta1: f64,
+
+    /// Ionospheric β2 (in s.semi-circle⁻²)
+    pub beta2: f64,
+
+    /// Ionospheric β3 (in s.semi-circle⁻³)
+    pub beta3: f64,
+
+    /// Clock offset (in seconds)
+    pub af0: f64,
+
+    /// Clock drift (in s.s⁻¹)
+    pub af1: f64,
+
+    /// Clock drift rate (in s.s⁻²)
+    pub af2: f64,
+
+    /// 5-bit Age of Data, Ephemeris
+    pub aode: u8,
+}
+
+impl BeiDouD1Subframe1 {
+    /// Generates a realistic subframe model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_aodc(1)
+            .with_urai(2)
+            .with_week(1_027)
+            .with_toc_seconds(345_600)
+            .with_tgd1(-2.1E-9)
+            .with_tgd2(5.0E-10)
+            .with_alpha0(4.0 * 2.0_f64.powi(-30))
+            .with_alpha1(-3.0 * 2.0_f64.powi(-27))
+            .with_alpha2(2.0_f64.powi(-24))
+            .with_alpha3(-2.0_f64.powi(-24))
+            .with_beta0(10.0 * 2.0_f64.powi(11))
+            .with_beta1(-5.0 * 2.0_f64.powi(14))
+            .with_beta2(-2.0 * 2.0_f64.powi(16))
+            .with_beta3(3.0 * 2.0_f64.powi(16))
+            .with_af0(-1_234_567.0 * 2.0_f64.powi(-33))
+            .with_af1(123_456.0 * 2.0_f64.powi(-50))
+            .with_af2(-12.0 * 2.0_f64.powi(-66))
+            .with_aode(3)
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated autonomous health flag
+    pub fn with_sat_h1(mut self, unhealthy: bool) -> Self {
+        self.sat_h1 = unhealthy;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated 5-bit Age of Data, Clock
+    pub fn with_aodc(mut self, aodc: u8) -> Self {
+        self.aodc = aodc & 0x1f;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated 4-bit User Range Accuracy Index
+    pub fn with_urai(mut self, urai: u8) -> Self {
+        self.urai = urai & 0x0f;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated 13-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0x1fff;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated clock reference time (in seconds).
+    /// Should be a multiple of 8 seconds, to be exactly encoded.
+    pub fn with_toc_seconds(mut self, toc_seconds: u32) -> Self {
+        self.toc_seconds = toc_seconds;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated B1I group delay (in seconds)
+    pub fn with_tgd1(mut self, tgd1: f64) -> Self {
+        self.tgd1 = tgd1;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated B2I group delay (in seconds)
+    pub fn with_tgd2(mut self, tgd2: f64) -> Self {
+        self.tgd2 = tgd2;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric α0
+    pub fn with_alpha0(mut self, alpha0: f64) -> Self {
+        self.alpha0 = alpha0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric α1
+    pub fn with_alpha1(mut self, alpha1: f64) -> Self {
+        self.alpha1 = alpha1;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric α2
+    pub fn with_alpha2(mut self, alpha2: f64) -> Self {
+        self.alpha2 = alpha2;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric α3
+    pub fn with_alpha3(mut self, alpha3: f64) -> Self {
+        self.alpha3 = alpha3;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric β0
+    pub fn with_beta0(mut self, beta0: f64) -> Self {
+        self.beta0 = beta0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric β1
+    pub fn with_beta1(mut self, beta1: f64) -> Self {
+        self.beta1 = beta1;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric β2
+    pub fn with_beta2(mut self, beta2: f64) -> Self {
+        self.beta2 = beta2;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated ionospheric β3
+    pub fn with_beta3(mut self, beta3: f64) -> Self {
+        self.beta3 = beta3;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated clock offset (in seconds)
+    pub fn with_af0(mut self, af0: f64) -> Self {
+        self.af0 = af0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated clock drift (in s.s⁻¹)
+    pub fn with_af1(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated clock drift rate (in s.s⁻²)
+    pub fn with_af2(mut self, af2: f64) -> Self {
+        self.af2 = af2;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe1] with updated 5-bit Age of Data, Ephemeris
+    pub fn with_aode(mut self, aode: u8) -> Self {
+        self.aode = aode & 0x1f;
+        self
+    }
+
+    /// Decodes [Self] from this deinterleaved subframe.
+    /// This method does not care for the BCH code.
+    pub(crate) fn from_buffer(buf: &[u8]) -> Self {
+        Self {
+            sat_h1: get_bits(buf, 42, 1) > 0,
+            aodc: get_bits(buf, 43, 5) as u8,
+            urai: get_bits(buf, 48, 4) as u8,
+            week: get_bits(buf, 60, 13) as u16,
+            toc_seconds: get_bits2(buf, 73, 9, 90, 8) * 8,
+            tgd1: get_signed_bits(buf, 98, 10) as f64 * 0.1E-9,
+            tgd2: get_signed_bits2(buf, 108, 4, 120, 6) as f64 * 0.1E-9,
+            alpha0: get_signed_bits(buf, 126, 8) as f64 * 2.0_f64.powi(-30),
+            alpha1: get_signed_bits(buf, 134, 8) as f64 * 2.0_f64.powi(-27),
+            alpha2: get_signed_bits(buf, 150, 8) as f64 * 2.0_f64.powi(-24),
+            alpha3: get_signed_bits(buf, 158, 8) as f64 * 2.0_f64.powi(-24),
+            beta0: get_signed_bits2(buf, 166, 6, 180, 2) as f64 * 2.0_f64.powi(11),
+            beta1: get_signed_bits(buf, 182, 8) as f64 * 2.0_f64.powi(14),
+            beta2: get_signed_bits(buf, 190, 8) as f64 * 2.0_f64.powi(16),
+            beta3: get_signed_bits2(buf, 198, 4, 210, 4) as f64 * 2.0_f64.powi(16),
+            af2: get_signed_bits(buf, 214, 11) as f64 * 2.0_f64.powi(-66),
+            af0: get_signed_bits2(buf, 225, 7, 240, 17) as f64 * 2.0_f64.powi(-33),
+            af1: get_signed_bits2(buf, 257, 5, 270, 17) as f64 * 2.0_f64.powi(-50),
+            aode: get_bits(buf, 287, 5) as u8,
+        }
+    }
+
+    /// Encodes this [BeiDouD1Subframe1] into this deinterleaved subframe.
+    pub(crate) fn to_buffer(self, buf: &mut [u8]) {
+        set_bits(buf, 42, 1, self.sat_h1 as u32);
+        set_bits(buf, 43, 5, self.aodc as u32);
+        set_bits(buf, 48, 4, self.urai as u32);
+        set_bits(buf, 60, 13, self.week as u32);
+        set_bits2(buf, 73, 9, 90, 8, (self.toc_seconds / 8) & 0x1ffff);
+
+        set_bits(buf, 98, 10, to_signed_bits(self.tgd1 / 0.1E-9, 10));
+        set_bits2(buf, 108, 4, 120, 6, to_signed_bits(self.tgd2 / 0.1E-9, 10));
+
+        let alpha0 = to_signed_bits(self.alpha0 / 2.0_f64.powi(-30), 8);
+        let alpha1 = to_signed_bits(self.alpha1 / 2.0_f64.powi(-27), 8);
+        let alpha2 = to_signed_bits(self.alpha2 / 2.0_f64.powi(-24), 8);
+        let alpha3 = to_signed_bits(self.alpha3 / 2.0_f64.powi(-24), 8);
+
+        set_bits(buf, 126, 8, alpha0);
+        set_bits(buf, 134, 8, alpha1);
+        set_bits(buf, 150, 8, alpha2);
+        set_bits(buf, 158, 8, alpha3);
+
+        let beta0 = to_signed_bits(self.beta0 / 2.0_f64.powi(11), 8);
+        let beta1 = to_signed_bits(self.beta1 / 2.0_f64.powi(14), 8);
+        let beta2 = to_signed_bits(self.beta2 / 2.0_f64.powi(16), 8);
+        let beta3 = to_signed_bits(self.beta3 / 2.0_f64.powi(16), 8);
+
+        set_bits2(buf, 166, 6, 180, 2, beta0);
+        set_bits(buf, 182, 8, beta1);
+        set_bits(buf, 190, 8, beta2);
+        set_bits2(buf, 198, 4, 210, 4, beta3);
+
+        let af2 = to_signed_bits(self.af2 / 2.0_f64.powi(-66), 11);
+        let af0 = to_signed_bits(self.af0 / 2.0_f64.powi(-33), 24);
+        let af1 = to_signed_bits(self.af1 / 2.0_f64.powi(-50), 22);
+
+        set_bits(buf, 214, 11, af2);
+        set_bits2(buf, 225, 7, 240, 17, af0);
+        set_bits2(buf, 257, 5, 270, 17, af1);
+
+        set_bits(buf, 287, 5, self.aode as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beidou::BEIDOU_D1_FRAME_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = BeiDouD1Subframe1::model().with_sat_h1(true);
+
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+        model.to_buffer(&mut buf);
+
+        let decoded = BeiDouD1Subframe1::from_buffer(&buf);
+
+        assert_eq!(decoded.sat_h1, model.sat_h1);
+        assert_eq!(decoded.aodc, model.aodc);
+        assert_eq!(decoded.urai, model.urai);
+        assert_eq!(decoded.week, model.week);
+        assert_eq!(decoded.toc_seconds, model.toc_seconds);
+        assert_eq!(decoded.aode, model.aode);
+
+        assert!((decoded.tgd1 - model.tgd1).abs() < 1E-12);
+        assert!((decoded.tgd2 - model.tgd2).abs() < 1E-12);
+
+        assert_eq!(decoded.alpha0, model.alpha0);
+        assert_eq!(decoded.alpha1, model.alpha1);
+        assert_eq!(decoded.alpha2, model.alpha2);
+        assert_eq!(decoded.alpha3, model.alpha3);
+        assert_eq!(decoded.beta0, model.beta0);
+        assert_eq!(decoded.beta1, model.beta1);
+        assert_eq!(decoded.beta2, model.beta2);
+        assert_eq!(decoded.beta3, model.beta3);
+
+        assert_eq!(decoded.af0, model.af0);
+        assert_eq!(decoded.af1, model.af1);
+        assert_eq!(decoded.af2, model.af2);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::bits::{
+    get_bits, get_bits2, get_signed_bits, get_signed_bits2, set_bits, set_bits2, to_signed_bits,
+    to_unsigned_bits,
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [BeiDouD1Subframe2] gives the first half of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BeiDouD1Subframe2 {
+    /// Mean motion difference (in semi-circles.s⁻¹)
+    pub delta_n: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the argument of latitude (in radians)
+    pub cuc: f64,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    pub m0: f64,
+
+    /// Eccentricity
+    pub e: f64,
+
+    /// Amplitude of the sine harmonic correction term to the argument of latitude (in radians)
+    pub cus: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the orbit radius (in meters)
+    pub crc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the orbit radius (in meters)
+    pub crs: f64,
+
+    /// Square root of semi-major axis (in m^1/2)
+    pub sqrt_a: f64,
+
+    /// 2 MSBs of the 17-bit ephemeris reference time.
+    /// The LSBs are found in [BeiDouD1Subframe3].
+    pub toe_msb: u8,
+}
+
+impl BeiDouD1Subframe2 {
+    /// Generates a realistic subframe model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_delta_n(12_345.0 * 2.0_f64.powi(-43))
+            .with_cuc(-1_234.0 * 2.0_f64.powi(-31))
+            .with_m0(-456_789_012.0 * 2.0_f64.powi(-31))
+            .with_e(34_567_890.0 * 2.0_f64.powi(-33))
+            .with_cus(4_321.0 * 2.0_f64.powi(-31))
+            .with_crc(12_345.0 * 2.0_f64.powi(-6))
+            .with_crs(-6_789.0 * 2.0_f64.powi(-6))
+            .with_sqrt_a(6_493.5)
+            .with_toe_msb(1)
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated mean motion difference (in semi-circles.s⁻¹)
+    pub fn with_delta_n(mut self, delta_n: f64) -> Self {
+        self.delta_n = delta_n;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated Cuc (in radians)
+    pub fn with_cuc(mut self, cuc: f64) -> Self {
+        self.cuc = cuc;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated mean anomaly (in semi-circles)
+    pub fn with_m0(mut self, m0: f64) -> Self {
+        self.m0 = m0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated eccentricity
+    pub fn with_e(mut self, e: f64) -> Self {
+        self.e = e;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated Cus (in radians)
+    pub fn with_cus(mut self, cus: f64) -> Self {
+        self.cus = cus;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated Crc (in meters)
+    pub fn with_crc(mut self, crc: f64) -> Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated Crs (in meters)
+    pub fn with_crs(mut self, crs: f64) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated square root of semi-major axis (in m^1/2)
+    pub fn with_sqrt_a(mut self, sqrt_a: f64) -> Self {
+        self.sqrt_a = sqrt_a;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe2] with updated 2 MSBs of the ephemeris reference time
+    pub fn with_toe_msb(mut self, toe_msb: u8) -> Self {
+        self.toe_msb = toe_msb & 0x03;
+        self
+    }
+
+    /// Decodes [Self] from this deinterleaved subframe.
+    /// This method does not care for the BCH code.
+    pub(crate) fn from_buffer(buf: &[u8]) -> Self {
+        Self {
+            delta_n: get_signed_bits2(buf, 42, 10, 60, 6) as f64 * 2.0_f64.powi(-43),
+            cuc: get_signed_bits2(buf, 66, 16, 90, 2) as f64 * 2.0_f64.powi(-31),
+            m0: get_signed_bits2(buf, 92, 20, 120, 12) as f64 * 2.0_f64.powi(-31),
+            e: get_bits2(buf, 132, 10, 150, 22) as f64 * 2.0_f64.powi(-33),
+            cus: get_signed_bits(buf, 180, 18) as f64 * 2.0_f64.powi(-31),
+            crc: get_signed_bits2(buf, 198, 4, 210, 14) as f64 * 2.0_f64.powi(-6),
+            crs: get_signed_bits2(buf, 224, 8, 240, 10) as f64 * 2.0_f64.powi(-6),
+            sqrt_a: get_bits2(buf, 250, 12, 270, 20) as f64 * 2.0_f64.powi(-19),
+            toe_msb: get_bits(buf, 290, 2) as u8,
+        }
+    }
+
+    /// Encodes this [BeiDouD1Subframe2] into this deinterleaved subframe.
+    pub(crate) fn to_buffer(self, buf: &mut [u8]) {
+        let delta_n = to_signed_bits(self.delta_n / 2.0_f64.powi(-43), 16);
+        let cuc = to_signed_bits(self.cuc / 2.0_f64.powi(-31), 18);
+        let m0 = to_signed_bits(self.m0 / 2.0_f64.powi(-31), 32);
+        let e = to_unsigned_bits(self.e / 2.0_f64.powi(-33), 32);
+        let cus = to_signed_bits(self.cus / 2.0_f64.powi(-31), 18);
+        let crc = to_signed_bits(self.crc / 2.0_f64.powi(-6), 18);
+        let crs = to_signed_bits(self.crs / 2.0_f64.powi(-6), 18);
+        let sqrt_a = to_unsigned_bits(self.sqrt_a / 2.0_f64.powi(-19), 32);
+
+        set_bits2(buf, 42, 10, 60, 6, delta_n);
+        set_bits2(buf, 66, 16, 90, 2, cuc);
+        set_bits2(buf, 92, 20, 120, 12, m0);
+        set_bits2(buf, 132, 10, 150, 22, e);
+        set_bits(buf, 180, 18, cus);
+        set_bits2(buf, 198, 4, 210, 14, crc);
+        set_bits2(buf, 224, 8, 240, 10, crs);
+        set_bits2(buf, 250, 12, 270, 20, sqrt_a);
+        set_bits(buf, 290, 2, self.toe_msb as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beidou::BEIDOU_D1_FRAME_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = BeiDouD1Subframe2::model();
+
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+        model.to_buffer(&mut buf);
+
+        assert_eq!(BeiDouD1Subframe2::from_buffer(&buf), model);
+    }
+}
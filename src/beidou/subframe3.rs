@@ -0,0 +1,164 @@
+use crate::{
+    beidou::BeiDouD1Subframe2,
+    bits::{get_bits2, get_signed_bits2, set_bits2, to_signed_bits},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [BeiDouD1Subframe3] gives the second half of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct BeiDouD1Subframe3 {
+    /// 15 LSBs of the 17-bit ephemeris reference time.
+    /// The MSBs are found in [BeiDouD1Subframe2].
+    pub toe_lsb: u16,
+
+    /// Inclination angle at reference time (in semi-circles)
+    pub i0: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the angle of inclination (in radians)
+    pub cic: f64,
+
+    /// Rate of right ascension (in semi-circles.s⁻¹)
+    pub omega_dot: f64,
+
+    /// Amplitude of the sine harmonic correction term to the angle of inclination (in radians)
+    pub cis: f64,
+
+    /// Rate of inclination angle (in semi-circles.s⁻¹)
+    pub idot: f64,
+
+    /// Longitude of ascending node at weekly epoch (in semi-circles)
+    pub omega0: f64,
+
+    /// Argument of perigee (in semi-circles)
+    pub omega: f64,
+}
+
+impl BeiDouD1Subframe3 {
+    /// Generates a realistic subframe model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_toe_lsb(0x2a2a)
+            .with_i0(654_321_987.0 * 2.0_f64.powi(-31))
+            .with_cic(-321.0 * 2.0_f64.powi(-31))
+            .with_omega_dot(-4_567_890.0 * 2.0_f64.powi(-43))
+            .with_cis(987.0 * 2.0_f64.powi(-31))
+            .with_idot(-1_234.0 * 2.0_f64.powi(-43))
+            .with_omega0(-1_987_654_321.0 * 2.0_f64.powi(-31))
+            .with_omega(1_234_567_890.0 * 2.0_f64.powi(-31))
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated 15 LSBs of the ephemeris reference time
+    pub fn with_toe_lsb(mut self, toe_lsb: u16) -> Self {
+        self.toe_lsb = toe_lsb & 0x7fff;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated inclination angle (in semi-circles)
+    pub fn with_i0(mut self, i0: f64) -> Self {
+        self.i0 = i0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated Cic (in radians)
+    pub fn with_cic(mut self, cic: f64) -> Self {
+        self.cic = cic;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated rate of right ascension (in semi-circles.s⁻¹)
+    pub fn with_omega_dot(mut self, omega_dot: f64) -> Self {
+        self.omega_dot = omega_dot;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated Cis (in radians)
+    pub fn with_cis(mut self, cis: f64) -> Self {
+        self.cis = cis;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated rate of inclination angle (in semi-circles.s⁻¹)
+    pub fn with_idot(mut self, idot: f64) -> Self {
+        self.idot = idot;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated longitude of ascending node (in semi-circles)
+    pub fn with_omega0(mut self, omega0: f64) -> Self {
+        self.omega0 = omega0;
+        self
+    }
+
+    /// Copies and returns [BeiDouD1Subframe3] with updated argument of perigee (in semi-circles)
+    pub fn with_omega(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Returns the ephemeris reference time (in seconds),
+    /// combining this [BeiDouD1Subframe3] and the previous [BeiDouD1Subframe2].
+    pub fn toe_seconds(&self, subframe2: &BeiDouD1Subframe2) -> u32 {
+        (((subframe2.toe_msb as u32) << 15) | self.toe_lsb as u32) * 8
+    }
+
+    /// Decodes [Self] from this deinterleaved subframe.
+    /// This method does not care for the BCH code.
+    pub(crate) fn from_buffer(buf: &[u8]) -> Self {
+        Self {
+            toe_lsb: get_bits2(buf, 42, 10, 60, 5) as u16,
+            i0: get_signed_bits2(buf, 65, 17, 90, 15) as f64 * 2.0_f64.powi(-31),
+            cic: get_signed_bits2(buf, 105, 7, 120, 11) as f64 * 2.0_f64.powi(-31),
+            omega_dot: get_signed_bits2(buf, 131, 11, 150, 13) as f64 * 2.0_f64.powi(-43),
+            cis: get_signed_bits2(buf, 163, 9, 180, 9) as f64 * 2.0_f64.powi(-31),
+            idot: get_signed_bits2(buf, 189, 13, 210, 1) as f64 * 2.0_f64.powi(-43),
+            omega0: get_signed_bits2(buf, 211, 21, 240, 11) as f64 * 2.0_f64.powi(-31),
+            omega: get_signed_bits2(buf, 251, 11, 270, 21) as f64 * 2.0_f64.powi(-31),
+        }
+    }
+
+    /// Encodes this [BeiDouD1Subframe3] into this deinterleaved subframe.
+    pub(crate) fn to_buffer(self, buf: &mut [u8]) {
+        let i0 = to_signed_bits(self.i0 / 2.0_f64.powi(-31), 32);
+        let cic = to_signed_bits(self.cic / 2.0_f64.powi(-31), 18);
+        let omega_dot = to_signed_bits(self.omega_dot / 2.0_f64.powi(-43), 24);
+        let cis = to_signed_bits(self.cis / 2.0_f64.powi(-31), 18);
+        let idot = to_signed_bits(self.idot / 2.0_f64.powi(-43), 14);
+        let omega0 = to_signed_bits(self.omega0 / 2.0_f64.powi(-31), 32);
+        let omega = to_signed_bits(self.omega / 2.0_f64.powi(-31), 32);
+
+        set_bits2(buf, 42, 10, 60, 5, self.toe_lsb as u32);
+        set_bits2(buf, 65, 17, 90, 15, i0);
+        set_bits2(buf, 105, 7, 120, 11, cic);
+        set_bits2(buf, 131, 11, 150, 13, omega_dot);
+        set_bits2(buf, 163, 9, 180, 9, cis);
+        set_bits2(buf, 189, 13, 210, 1, idot);
+        set_bits2(buf, 211, 21, 240, 11, omega0);
+        set_bits2(buf, 251, 11, 270, 21, omega);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::beidou::BEIDOU_D1_FRAME_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = BeiDouD1Subframe3::model();
+
+        let mut buf = [0u8; BEIDOU_D1_FRAME_BYTES];
+        model.to_buffer(&mut buf);
+
+        assert_eq!(BeiDouD1Subframe3::from_buffer(&buf), model);
+    }
+
+    #[test]
+    fn toe() {
+        let subframe2 = BeiDouD1Subframe2::default().with_toe_msb(0x01);
+        let subframe3 = BeiDouD1Subframe3::default().with_toe_lsb(0x0010);
+        assert_eq!(subframe3.toe_seconds(&subframe2), ((1 << 15) + 16) * 8);
+    }
+}
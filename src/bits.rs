@@ -0,0 +1,514 @@
+//! Bit fields helpers, for protocols that are not organized in 30-bit GPS words.
+
+#[cfg(all(
+    not(feature = "std"),
+    any(
+        feature = "gps",
+        feature = "beidou",
+        feature = "galileo",
+        feature = "sbas"
+    )
+))]
+use num_traits::float::FloatCore;
+
+/// [BitIter] iterates over each bit of a read-only [u8] buffer, MSB first
+/// (unless [BitIter::with_lsb_first] is used), starting at any bit offset. It neither allocates nor modifies the buffer,
+/// which makes it suited for sub-byte synchronization.
+///
+/// ```
+/// use gnss_protos::BitIter;
+///
+/// let buffer = [0x8B, 0x80];
+///
+/// // 0x8B preamble, delayed by one bit
+/// let bits = BitIter::new(&buffer, 1).collect::<Vec<_>>();
+/// assert_eq!(bits.len(), 15);
+/// assert_eq!(bits[..4], [false, false, false, true]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitIter<'a> {
+    /// Read-only buffer
+    buffer: &'a [u8],
+
+    /// Next bit position
+    pos: usize,
+
+    /// True when bits are transmitted LSB first within each byte
+    lsb_first: bool,
+}
+
+impl<'a> BitIter<'a> {
+    /// Creates a new [BitIter] starting at bit position `offset` of this buffer.
+    /// The [BitIter] is simply empty when `offset` lies past the end of the buffer.
+    pub fn new(buffer: &'a [u8], offset: usize) -> Self {
+        Self {
+            buffer,
+            pos: offset,
+            lsb_first: false,
+        }
+    }
+
+    /// Copies and returns [BitIter] iterating over each byte LSB first,
+    /// for streams that were delivered with reversed bit order.
+    ///
+    /// ```
+    /// use gnss_protos::BitIter;
+    ///
+    /// // 0x8B preamble, bit reversed
+    /// let bits = BitIter::new(&[0xD1], 0).with_lsb_first().collect::<Vec<_>>();
+    /// assert_eq!(bits, [true, false, false, false, true, false, true, true]);
+    /// ```
+    pub fn with_lsb_first(mut self) -> Self {
+        self.lsb_first = true;
+        self
+    }
+}
+
+impl<'a> Iterator for BitIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        let byte = self.buffer.get(self.pos / 8)?;
+        let shift = if self.lsb_first {
+            self.pos % 8
+        } else {
+            7 - (self.pos % 8)
+        };
+
+        let bit = (byte >> shift) & 0x01 > 0;
+        self.pos += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.buffer.len() * 8).saturating_sub(self.pos);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a> ExactSizeIterator for BitIter<'a> {}
+
+/// [BitReader] reads consecutive bit fields (up to 32 bits each) of a read-only [u8] buffer,
+/// MSB first, starting at any bit offset. It keeps track of the position, so fields
+/// are simply read in order of appearance, without any mask or shift.
+///
+/// ```
+/// use gnss_protos::BitReader;
+///
+/// let buffer = [0x8B, 0x5A, 0xC3];
+/// let mut reader = BitReader::new(&buffer, 0);
+///
+/// assert_eq!(reader.read_u32(8), Some(0x8B));
+/// assert_eq!(reader.read_i32(4), Some(5));
+/// reader.skip(4);
+/// assert_eq!(reader.read_i32(4), Some(-4));
+/// assert_eq!(reader.position(), 20);
+///
+/// // not enough data
+/// assert_eq!(reader.read_u32(5), None);
+/// assert_eq!(reader.position(), 20);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    /// Read-only buffer
+    buffer: &'a [u8],
+
+    /// Position of the next bit to read
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a new [BitReader] over this read-only [u8] buffer,
+    /// starting at this bit offset.
+    pub fn new(buffer: &'a [u8], offset: usize) -> Self {
+        Self {
+            buffer,
+            position: offset,
+        }
+    }
+
+    /// Returns the position (in bits) of the next field to read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Skips the following `nbits` bits (reserved fields, parity bits..).
+    pub fn skip(&mut self, nbits: usize) {
+        self.position += nbits;
+    }
+
+    /// Reads the following `nbits`-bit (up to 32) unsigned field.
+    /// Returns [None] when the buffer is too short (or `nbits` exceeds 32),
+    /// in which case nothing is consumed.
+    pub fn read_u32(&mut self, nbits: usize) -> Option<u32> {
+        let value = peek_bits(self.buffer, self.position, nbits)?;
+        self.position += nbits;
+        Some(value)
+    }
+
+    /// Reads the following `nbits`-bit (up to 32) two's complement field.
+    /// Returns [None] when the buffer is too short (or `nbits` exceeds 32),
+    /// in which case nothing is consumed.
+    pub fn read_i32(&mut self, nbits: usize) -> Option<i32> {
+        if nbits == 0 {
+            return Some(0);
+        }
+
+        let value = self.read_u32(nbits)?;
+        let shift = 32 - nbits as u32;
+        Some(((value << shift) as i32) >> shift)
+    }
+}
+
+/// [BitWriter] writes consecutive bit fields (up to 32 bits each) into a [u8] buffer,
+/// MSB first, starting at any bit offset. It is the counterpart of [BitReader]:
+/// values are truncated to the field size, so signed values are written in two's complement
+/// without any mask or shift.
+///
+/// ```
+/// use gnss_protos::{BitReader, BitWriter};
+///
+/// let mut buffer = [0u8; 3];
+/// let mut writer = BitWriter::new(&mut buffer, 0);
+///
+/// writer.write_u32(0x8B, 8);
+/// writer.write_i32(-3, 4);
+/// writer.skip(4);
+/// assert_eq!(writer.position(), 16);
+///
+/// // does not fit
+/// assert!(writer.write_u32(0, 9).is_none());
+///
+/// assert_eq!(buffer, [0x8B, 0xD0, 0x00]);
+/// assert_eq!(BitReader::new(&buffer, 8).read_i32(4), Some(-3));
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    /// Destination buffer
+    buffer: &'a mut [u8],
+
+    /// Position of the next bit to write
+    position: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a new [BitWriter] into this [u8] buffer, starting at this bit offset.
+    pub fn new(buffer: &'a mut [u8], offset: usize) -> Self {
+        Self {
+            buffer,
+            position: offset,
+        }
+    }
+
+    /// Returns the position (in bits) of the next field to write.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Skips the following `nbits` bits, which are left untouched.
+    pub fn skip(&mut self, nbits: usize) {
+        self.position += nbits;
+    }
+
+    /// Writes the `nbits` (up to 32) least significant bits of this value.
+    /// Returns [None] when the field does not fit in the buffer (or `nbits` exceeds 32),
+    /// in which case nothing is written.
+    pub fn write_u32(&mut self, value: u32, nbits: usize) -> Option<()> {
+        if nbits > 32 || self.position + nbits > self.buffer.len() * 8 {
+            return None;
+        }
+
+        set_bits(self.buffer, self.position, nbits, value);
+        self.position += nbits;
+        Some(())
+    }
+
+    /// Writes this value as a `nbits`-bit (up to 32) two's complement field.
+    /// Returns [None] when the field does not fit in the buffer (or `nbits` exceeds 32),
+    /// in which case nothing is written.
+    pub fn write_i32(&mut self, value: i32, nbits: usize) -> Option<()> {
+        self.write_u32(value as u32, nbits)
+    }
+}
+
+/// Reads the `nbits`-bit (up to 32) field at bit position `offset` of this buffer, MSB first,
+/// without consuming anything. Returns [None] when the buffer is too short (or `nbits` exceeds 32).
+///
+/// ```
+/// use gnss_protos::peek_bits;
+///
+/// let buffer = [0x8B, 0x5A];
+///
+/// assert_eq!(peek_bits(&buffer, 4, 8), Some(0xB5));
+/// assert_eq!(peek_bits(&buffer, 12, 5), None);
+/// ```
+pub fn peek_bits(buffer: &[u8], offset: usize, nbits: usize) -> Option<u32> {
+    if nbits > 32 || offset + nbits > buffer.len() * 8 {
+        return None;
+    }
+
+    Some(get_bits(buffer, offset, nbits))
+}
+
+/// Reads the `len`-bit (up to 32) field at bit position `pos` of this buffer, MSB first.
+pub(crate) fn get_bits(buffer: &[u8], pos: usize, len: usize) -> u32 {
+    let mut value = 0u32;
+
+    for bit in pos..pos + len {
+        value <<= 1;
+        value |= ((buffer[bit / 8] >> (7 - (bit % 8))) & 0x01) as u32;
+    }
+
+    value
+}
+
+/// Reads the 32-bit big endian word starting at any bit position of this buffer (MSB first).
+/// Bits past the end of the buffer are read as zeros, so the last word of a frame
+/// may be read without padding.
+#[cfg(feature = "gps")]
+pub(crate) fn read_word_be(buffer: &[u8], bit_offset: usize) -> u32 {
+    let (byte_index, bit_index) = (bit_offset / 8, bit_offset % 8);
+
+    let mut bytes = [0u8; 5];
+
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = buffer.get(byte_index + i).copied().unwrap_or(0);
+    }
+
+    let msb = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+    if bit_index > 0 {
+        (msb << bit_index) | (bytes[4] >> (8 - bit_index)) as u32
+    } else {
+        msb
+    }
+}
+
+/// Reads the `len`-bit (up to 32) two's complement field at bit position `pos` of this buffer.
+#[cfg(any(
+    test,
+    feature = "gps",
+    feature = "beidou",
+    feature = "galileo",
+    feature = "sbas"
+))]
+pub(crate) fn get_signed_bits(buffer: &[u8], pos: usize, len: usize) -> i32 {
+    let shift = 32 - len as u32;
+    ((get_bits(buffer, pos, len) << shift) as i32) >> shift
+}
+
+/// Reads a field that is split in two parts (MSB first), because it spans two words.
+#[cfg(any(test, feature = "beidou"))]
+pub(crate) fn get_bits2(buffer: &[u8], pos1: usize, len1: usize, pos2: usize, len2: usize) -> u32 {
+    (get_bits(buffer, pos1, len1) << len2) | get_bits(buffer, pos2, len2)
+}
+
+/// Reads a two's complement field that is split in two parts (MSB first).
+#[cfg(any(test, feature = "beidou"))]
+pub(crate) fn get_signed_bits2(
+    buffer: &[u8],
+    pos1: usize,
+    len1: usize,
+    pos2: usize,
+    len2: usize,
+) -> i32 {
+    let shift = 32 - (len1 + len2) as u32;
+    ((get_bits2(buffer, pos1, len1, pos2, len2) << shift) as i32) >> shift
+}
+
+/// Writes the `len`-bit (up to 32) field at bit position `pos` of this buffer, MSB first.
+pub(crate) fn set_bits(buffer: &mut [u8], pos: usize, len: usize, value: u32) {
+    for (i, bit) in (pos..pos + len).enumerate() {
+        let mask = 0x80 >> (bit % 8);
+
+        if (value >> (len - 1 - i)) & 0x01 > 0 {
+            buffer[bit / 8] |= mask;
+        } else {
+            buffer[bit / 8] &= !mask;
+        }
+    }
+}
+
+/// Writes a field that is split in two parts (MSB first), because it spans two words.
+#[cfg(any(test, feature = "beidou"))]
+pub(crate) fn set_bits2(
+    buffer: &mut [u8],
+    pos1: usize,
+    len1: usize,
+    pos2: usize,
+    len2: usize,
+    value: u32,
+) {
+    set_bits(buffer, pos1, len1, value >> len2);
+    set_bits(buffer, pos2, len2, value);
+}
+
+/// Rounds and saturates this scaled value to a `len`-bit two's complement field.
+#[cfg(any(
+    test,
+    feature = "gps",
+    feature = "beidou",
+    feature = "galileo",
+    feature = "sbas"
+))]
+pub(crate) fn to_signed_bits(value: f64, len: usize) -> u32 {
+    let max = (1i64 << (len - 1)) - 1;
+    let value = (value.round() as i64).clamp(-max - 1, max);
+    (value as u32) & ((1u64 << len) - 1) as u32
+}
+
+/// Rounds and saturates this scaled value to a `len`-bit unsigned field.
+#[cfg(any(test, feature = "beidou", feature = "galileo"))]
+pub(crate) fn to_unsigned_bits(value: f64, len: usize) -> u32 {
+    let max = (1u64 << len) - 1;
+    (value.round().max(0.0) as u64).min(max) as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bit_fields() {
+        let mut buffer = [0u8; 38];
+
+        set_bits(&mut buffer, 0, 11, 0x712);
+        assert_eq!(buffer[0], 0xE2);
+        assert_eq!(buffer[1], 0x40);
+        assert_eq!(get_bits(&buffer, 0, 11), 0x712);
+
+        set_bits2(&mut buffer, 42, 10, 60, 6, 0xfffe);
+        assert_eq!(get_bits2(&buffer, 42, 10, 60, 6), 0xfffe);
+        assert_eq!(get_signed_bits2(&buffer, 42, 10, 60, 6), -2);
+
+        set_bits(&mut buffer, 98, 10, to_signed_bits(-3.0, 10));
+        assert_eq!(get_signed_bits(&buffer, 98, 10), -3);
+
+        // saturation
+        assert_eq!(to_signed_bits(600.0, 10), 0x1ff);
+        assert_eq!(to_signed_bits(-600.0, 10), 0x200);
+        assert_eq!(to_unsigned_bits(-1.0, 8), 0);
+        assert_eq!(to_unsigned_bits(300.0, 8), 0xff);
+    }
+
+    #[test]
+    fn bit_reader() {
+        let mut buffer = [0u8; 8];
+
+        set_bits(&mut buffer, 3, 11, 0x712);
+        set_bits(&mut buffer, 14, 7, to_signed_bits(-5.0, 7));
+        set_bits(&mut buffer, 24, 32, 0x8BAD_F00D);
+
+        let mut reader = BitReader::new(&buffer, 3);
+        assert_eq!(reader.position(), 3);
+
+        assert_eq!(reader.read_u32(11), Some(0x712));
+        assert_eq!(reader.read_i32(7), Some(-5));
+        assert_eq!(reader.position(), 21);
+
+        reader.skip(3);
+        assert_eq!(reader.read_u32(32), Some(0x8BAD_F00D));
+        assert_eq!(reader.position(), 56);
+
+        // positive and extreme two's complement values
+        let mut reader = BitReader::new(&buffer, 24);
+        assert_eq!(reader.read_i32(4), Some(-8));
+        assert_eq!(reader.read_i32(4), Some(-5));
+        assert_eq!(reader.read_i32(4), Some(-6));
+        assert_eq!(reader.read_i32(4), Some(-3));
+        assert_eq!(reader.read_i32(0), Some(0));
+        assert_eq!(reader.read_i32(16), Some(-4083));
+
+        let mut reader = BitReader::new(&buffer, 24);
+        assert_eq!(reader.read_i32(32), Some(0x8BAD_F00D_u32 as i32));
+
+        // exhausted: nothing is consumed
+        assert_eq!(reader.read_u32(8), Some(0));
+        assert_eq!(reader.read_u32(1), None);
+        assert_eq!(reader.read_i32(1), None);
+        assert_eq!(reader.position(), 64);
+
+        let mut reader = BitReader::new(&buffer, 0);
+        assert_eq!(reader.read_u32(33), None);
+        assert_eq!(reader.read_i32(33), None);
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    fn bit_writer() {
+        let mut buffer = [0xffu8; 8];
+        let mut writer = BitWriter::new(&mut buffer, 3);
+
+        assert_eq!(writer.write_u32(0x712, 11), Some(()));
+        assert_eq!(writer.write_i32(-5, 7), Some(()));
+        writer.skip(3);
+        assert_eq!(writer.write_u32(0x8BAD_F00D, 32), Some(()));
+
+        // truncation
+        assert_eq!(writer.write_u32(0x1234, 4), Some(()));
+        assert_eq!(writer.write_i32(-1, 3), Some(()));
+        assert_eq!(writer.position(), 63);
+
+        // does not fit: nothing is written
+        assert_eq!(writer.write_u32(0, 2), None);
+        assert_eq!(writer.write_u32(0, 33), None);
+        assert_eq!(writer.position(), 63);
+
+        let mut reader = BitReader::new(&buffer, 0);
+        assert_eq!(reader.read_u32(3), Some(0x7));
+        assert_eq!(reader.read_u32(11), Some(0x712));
+        assert_eq!(reader.read_i32(7), Some(-5));
+        assert_eq!(reader.read_u32(3), Some(0x7));
+        assert_eq!(reader.read_u32(32), Some(0x8BAD_F00D));
+        assert_eq!(reader.read_u32(4), Some(0x4));
+        assert_eq!(reader.read_i32(3), Some(-1));
+        assert_eq!(reader.read_u32(1), Some(1));
+    }
+
+    #[test]
+    fn bit_iterator() {
+        let buffer = [0x8B, 0x5A, 0xC3];
+
+        let pattern = "100010110101101011000011"
+            .chars()
+            .map(|c| c == '1')
+            .collect::<Vec<_>>();
+
+        for offset in 0..16 {
+            let iter = BitIter::new(&buffer, offset);
+            assert_eq!(
+                iter.len(),
+                24 - offset,
+                "invalid size for offset {}",
+                offset
+            );
+
+            let bits = iter.collect::<Vec<_>>();
+            assert_eq!(bits, pattern[offset..], "failed for offset {}", offset);
+        }
+
+        assert_eq!(BitIter::new(&buffer, 24).next(), None);
+        assert_eq!(BitIter::new(&buffer, 100).len(), 0);
+    }
+
+    #[test]
+    fn bits_peeking() {
+        let buffer = [0x8B, 0x5A, 0xC3, 0x01, 0xFF];
+
+        assert_eq!(peek_bits(&buffer, 0, 8), Some(0x8B));
+        assert_eq!(peek_bits(&buffer, 6, 4), Some(0xD));
+        assert_eq!(peek_bits(&buffer, 7, 10), Some(0x2B5));
+        assert_eq!(peek_bits(&buffer, 3, 32), Some(0x5AD6180F));
+        assert_eq!(peek_bits(&buffer, 8, 32), Some(0x5AC301FF));
+        assert_eq!(peek_bits(&buffer, 39, 1), Some(1));
+        assert_eq!(peek_bits(&buffer, 12, 0), Some(0));
+
+        // not enough data
+        assert_eq!(peek_bits(&buffer, 9, 32), None);
+        assert_eq!(peek_bits(&buffer, 40, 1), None);
+        assert_eq!(peek_bits(&buffer, 0, 33), None);
+
+        // nothing was consumed
+        assert_eq!(buffer, [0x8B, 0x5A, 0xC3, 0x01, 0xFF]);
+    }
+}
@@ -0,0 +1,80 @@
+//! CRC-24Q, as used by the RTCM3 framing and the Galileo (I/NAV, F/NAV) messages.
+
+/// CRC-24Q generator polynomial
+const CRC24Q_POLYNOMIAL: u32 = 0x1864CFB;
+
+/// Computes the CRC-24Q (generator polynomial 0x1864CFB, null initial value)
+/// of the first `nbits` of this buffer, MSB first.
+/// `nbits` does not have to be a multiple of 8, which allows verifying messages that are not aligned to [u8].
+///
+/// ```
+/// use gnss_protos::crc24q;
+///
+/// assert_eq!(crc24q(b"123456789", 72), 0xCDE703);
+/// ```
+///
+/// ## Panics
+/// When `nbits` exceeds the buffer size (in bits).
+pub fn crc24q(bits: &[u8], nbits: usize) -> u32 {
+    assert!(
+        nbits <= bits.len() * 8,
+        "crc24q: {} bits requested from a {}-bit buffer",
+        nbits,
+        bits.len() * 8
+    );
+
+    let mut crc = 0u32;
+
+    for bit in 0..nbits {
+        let input = ((bits[bit / 8] >> (7 - (bit % 8))) & 0x01) as u32;
+
+        crc ^= input << 23;
+        crc <<= 1;
+
+        if crc & 0x1000000 > 0 {
+            crc ^= CRC24Q_POLYNOMIAL;
+        }
+    }
+
+    crc & 0xffffff
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_answers() {
+        assert_eq!(crc24q(&[], 0), 0);
+
+        // standard check value
+        assert_eq!(crc24q(b"123456789", 72), 0xCDE703);
+
+        // RTCM3 message 1005 (header and payload), followed by its CRC
+        let rtcm = [
+            0xD3, 0x00, 0x13, 0x3E, 0xD7, 0xD3, 0x02, 0x02, 0x98, 0x0E, 0xDE, 0xEF, 0x34, 0xB4,
+            0xBD, 0x62, 0xAC, 0x09, 0x41, 0x98, 0x6F, 0x33, 0x36, 0x0B, 0x98,
+        ];
+
+        assert_eq!(crc24q(&rtcm, 22 * 8), 0x360B98);
+
+        // the CRC of a message followed by its CRC is null
+        assert_eq!(crc24q(&rtcm, rtcm.len() * 8), 0);
+    }
+
+    #[test]
+    fn bit_granularity() {
+        // leading zeros do not modify the CRC
+        assert_eq!(crc24q(&[0x00, 0x80], 9), crc24q(&[0x80], 1));
+
+        // trailing bits are ignored
+        assert_eq!(crc24q(&[0xA5, 0xFF], 8), crc24q(&[0xA5, 0x00], 8));
+        assert_ne!(crc24q(&[0xA5, 0xFF], 9), crc24q(&[0xA5, 0x00], 9));
+    }
+
+    #[test]
+    #[should_panic(expected = "crc24q: 17 bits requested from a 16-bit buffer")]
+    fn buffer_too_short() {
+        crc24q(&[0xA5, 0xFF], 17);
+    }
+}
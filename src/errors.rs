@@ -1,9 +1,33 @@
 #[cfg(feature = "gps")]
 use crate::gps::GpsError;
 
+#[cfg(feature = "glonass")]
+use crate::glonass::GlonassError;
+
+#[cfg(feature = "beidou")]
+use crate::beidou::BeiDouError;
+
+#[cfg(feature = "galileo")]
+use crate::galileo::GalileoError;
+
+#[cfg(feature = "sbas")]
+use crate::sbas::SbasError;
+
 /// GNSS-Proto error
 #[derive(Debug)]
 pub enum Error {
     #[cfg(feature = "gps")]
     Gps(GpsError),
+
+    #[cfg(feature = "glonass")]
+    Glonass(GlonassError),
+
+    #[cfg(feature = "beidou")]
+    BeiDou(BeiDouError),
+
+    #[cfg(feature = "galileo")]
+    Galileo(GalileoError),
+
+    #[cfg(feature = "sbas")]
+    Sbas(SbasError),
 }
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GalileoError {
+    /// Not a valid even/odd page pair
+    #[error("invalid even/odd page pair")]
+    InvalidPage,
+
+    /// Alert pages are not supported
+    #[error("alert pages are not supported")]
+    AlertPage,
+
+    /// Buffer is too small to encode a complete page
+    #[error("buffer too small for this Galileo page")]
+    WouldNotFit,
+}
@@ -0,0 +1,214 @@
+use crate::{
+    bits::get_bits,
+    galileo::{
+        GalFNavFrame, GalileoMessage, GALILEO_FNAV_PAGE_BITS, GALILEO_FNAV_PAGE_BYTES,
+        GALILEO_FNAV_SYNC_BITS, GALILEO_FNAV_SYNC_PATTERN,
+    },
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [GalileoFnavDecoder] can decode Galileo F/NAV (E5a-I) pages, from a stream of
+/// pages that were already deinterleaved and Viterbi decoded: each 244-bit page
+/// is preceded by the 12-bit synchronization pattern and terminated by its 6 tail bits.
+///
+/// The stream is synchronized on the synchronization pattern, followed by a page of known type
+/// that ends with null tail bits. By [Default], our [GalileoFnavDecoder] also verifies the CRC-24Q,
+/// which makes the synchronization much more robust.
+#[derive(Debug, Copy, Clone)]
+pub struct GalileoFnavDecoder {
+    /// True when CRC verification is requested
+    crc_verification: bool,
+}
+
+impl Default for GalileoFnavDecoder {
+    /// Creates a default [GalileoFnavDecoder] that verifies the CRC.
+    fn default() -> Self {
+        Self {
+            crc_verification: true,
+        }
+    }
+}
+
+impl GalileoFnavDecoder {
+    /// Copies and returns a [GalileoFnavDecoder] that does not verify the CRC.
+    /// Every page is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_crc_verification(mut self) -> Self {
+        self.crc_verification = false;
+        self
+    }
+
+    /// Locates the first synchronization pattern that is followed by a page,
+    /// within this buffer, at any bit offset.
+    fn find_page(&self, buffer: &[u8], size: usize) -> Option<usize> {
+        let total_bits = size * 8;
+
+        if total_bits < Self::line_bits() {
+            return None;
+        }
+
+        (0..=total_bits - Self::line_bits()).find(|offset| {
+            let page_offset = offset + GALILEO_FNAV_SYNC_BITS;
+
+            get_bits(buffer, *offset, GALILEO_FNAV_SYNC_BITS) == GALILEO_FNAV_SYNC_PATTERN as u32
+                && GalFNavFrame::is_page(buffer, page_offset)
+                && (!self.crc_verification || GalFNavFrame::crc_ok(buffer, page_offset))
+        })
+    }
+
+    /// Returns the number of bits between two consecutive pages of a synchronous stream
+    pub const fn line_bits() -> usize {
+        GALILEO_FNAV_SYNC_BITS + GALILEO_FNAV_PAGE_BITS
+    }
+
+    /// Decodes the first valid [GalileoMessage] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same page twice.
+    /// - Optional [GalileoMessage] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When no complete page was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next page: simply append the following bytes
+    /// and call [Self::decode] again.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<GalileoMessage>) {
+        let size = size.min(buffer.len());
+
+        let offset_bit = match self.find_page(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(Self::line_bits() - 1), None);
+            },
+        };
+
+        #[cfg(feature = "log")]
+        trace!("(GAL) [fnav]: pos={}", offset_bit);
+
+        let page_offset_bit = offset_bit + GALILEO_FNAV_SYNC_BITS;
+        let mut page = [0u8; GALILEO_FNAV_PAGE_BYTES];
+
+        for (i, byte) in page.iter_mut().enumerate() {
+            let nbits = (GALILEO_FNAV_PAGE_BITS - i * 8).min(8);
+            *byte = (get_bits(buffer, page_offset_bit + i * 8, nbits) << (8 - nbits)) as u8;
+        }
+
+        let end = page_offset_bit + GALILEO_FNAV_PAGE_BITS;
+
+        match GalFNavFrame::decode_page(&page) {
+            Ok(fnav) => (end, Some(GalileoMessage::FNAV(fnav))),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(GAL) [fnav]: {}", e);
+                (end, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        bits::{get_bits, set_bits},
+        galileo::{
+            GalFNavFrame, GalFNavPage1, GalFNavPage2, GalFNavPage3, GalFNavPage4,
+            GalileoFnavDecoder, GalileoMessage, GALILEO_FNAV_PAGE_BITS, GALILEO_FNAV_SYNC_BITS,
+            GALILEO_FNAV_SYNC_PATTERN,
+        },
+        testing::insert_bit_delay,
+    };
+
+    fn models() -> Vec<GalFNavFrame> {
+        vec![
+            GalFNavFrame::Page1(GalFNavPage1::model()),
+            GalFNavFrame::Page2(GalFNavPage2::model()),
+            GalFNavFrame::Page3(GalFNavPage3::model()),
+            GalFNavFrame::Page4(GalFNavPage4::model()),
+        ]
+    }
+
+    /// Concatenates (bitwise) these pages, each preceded by the synchronization pattern,
+    /// like a synchronous stream does.
+    fn stream(models: &[GalFNavFrame]) -> Vec<u8> {
+        let line_bits = GalileoFnavDecoder::line_bits();
+        let total_bits = models.len() * line_bits;
+        let mut buffer = vec![0u8; (total_bits + 7) / 8];
+
+        for (i, model) in models.iter().enumerate() {
+            let page = model.encode_page();
+
+            set_bits(
+                &mut buffer,
+                i * line_bits,
+                GALILEO_FNAV_SYNC_BITS,
+                GALILEO_FNAV_SYNC_PATTERN as u32,
+            );
+
+            for bit in (0..GALILEO_FNAV_PAGE_BITS).step_by(4) {
+                let offset = i * line_bits + GALILEO_FNAV_SYNC_BITS + bit;
+                set_bits(&mut buffer, offset, 4, get_bits(&page, bit, 4));
+            }
+        }
+
+        buffer
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let models = models();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&models), delay);
+
+            let mut decoder = GalileoFnavDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, message) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(GalileoMessage::FNAV(fnav)) = message {
+                    decoded.push(fnav);
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+
+    #[test]
+    fn crc_rejection() {
+        let model = GalFNavFrame::Page1(GalFNavPage1::model());
+        let mut buffer = stream(&[model]);
+
+        let mut decoder = GalileoFnavDecoder::default();
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GalileoFnavDecoder::line_bits());
+        assert_eq!(message, Some(GalileoMessage::FNAV(model)));
+
+        // corrupt af0
+        buffer[6] ^= 0x10;
+
+        let (_, message) = decoder.decode(&buffer, buffer.len());
+        assert!(message.is_none());
+
+        let mut decoder = GalileoFnavDecoder::default().without_crc_verification();
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GalileoFnavDecoder::line_bits());
+        assert!(message.is_some());
+        assert_ne!(message, Some(GalileoMessage::FNAV(model)));
+    }
+}
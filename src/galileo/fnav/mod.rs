@@ -0,0 +1,337 @@
+use crate::{
+    bits::{get_bits, set_bits},
+    crc24q, encoding_fits,
+    galileo::GalileoError,
+    GstGpsParameters, UtcParameters,
+};
+
+/// F/NAV synchronization pattern, preceding each page
+pub const GALILEO_FNAV_SYNC_PATTERN: u16 = 0xB70;
+
+/// F/NAV synchronization pattern size (in bits!)
+pub const GALILEO_FNAV_SYNC_BITS: usize = 12;
+
+/// F/NAV page size (in bits!), CRC and tail bits included
+pub const GALILEO_FNAV_PAGE_BITS: usize = 244;
+
+/// F/NAV page size (in bytes!), once padded
+pub const GALILEO_FNAV_PAGE_BYTES: usize = (GALILEO_FNAV_PAGE_BITS + 7) / 8;
+
+/// Page type and navigation data size (in bits!), protected by the CRC
+const DATA_BITS: usize = 214;
+
+/// Page type and navigation data size (in bytes!), once padded
+const DATA_BYTES: usize = (DATA_BITS + 7) / 8;
+
+/// CRC size (in bits!)
+const CRC_BITS: usize = 24;
+
+/// Number of tail bits, ending each page
+const TAIL_BITS: usize = 6;
+
+mod decoder;
+pub use decoder::GalileoFnavDecoder;
+
+mod page1;
+pub use page1::GalFNavPage1;
+
+mod page2;
+pub use page2::GalFNavPage2;
+
+mod page3;
+pub use page3::GalFNavPage3;
+
+mod page4;
+pub use page4::GalFNavPage4;
+
+/// [GalFNavFrame] is the content of an F/NAV page, broadcast on E5a-I.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GalFNavFrame {
+    /// Page type 1: [GalFNavPage1] (clock, ionosphere, group delay and health)
+    Page1(GalFNavPage1),
+
+    /// Page type 2: [GalFNavPage2] (ephemeris)
+    Page2(GalFNavPage2),
+
+    /// Page type 3: [GalFNavPage3] (ephemeris)
+    Page3(GalFNavPage3),
+
+    /// Page type 4: [GalFNavPage4] (ephemeris, GST-UTC and GST-GPS conversion)
+    Page4(GalFNavPage4),
+
+    /// Other page types (almanach), not interpreted yet: page type and the 214 bits
+    /// of page type and navigation data, left aligned.
+    Raw(u8, [u8; DATA_BYTES]),
+}
+
+impl Default for GalFNavFrame {
+    fn default() -> Self {
+        Self::Page1(Default::default())
+    }
+}
+
+impl GalFNavFrame {
+    /// Returns the 6-bit page type
+    pub fn page_type(&self) -> u8 {
+        match self {
+            Self::Page1(_) => 1,
+            Self::Page2(_) => 2,
+            Self::Page3(_) => 3,
+            Self::Page4(_) => 4,
+            Self::Raw(page_type, _) => *page_type,
+        }
+    }
+
+    /// Returns the 10-bit Issue of Data (navigation batch), for page types 1 to 4
+    pub fn iod_nav(&self) -> Option<u16> {
+        match self {
+            Self::Page1(page) => Some(page.iod_nav),
+            Self::Page2(page) => Some(page.iod_nav),
+            Self::Page3(page) => Some(page.iod_nav),
+            Self::Page4(page) => Some(page.iod_nav),
+            Self::Raw(_, _) => None,
+        }
+    }
+
+    /// Returns the time of week (in seconds), for page types 1 to 4
+    pub fn tow(&self) -> Option<u32> {
+        match self {
+            Self::Page1(page) => Some(page.tow),
+            Self::Page2(page) => Some(page.tow),
+            Self::Page3(page) => Some(page.tow),
+            Self::Page4(page) => Some(page.tow),
+            Self::Raw(_, _) => None,
+        }
+    }
+
+    /// Returns the GST-UTC conversion [UtcParameters], for page type 4
+    pub fn utc_parameters(&self) -> Option<UtcParameters> {
+        match self {
+            Self::Page4(page) => Some(page.utc_parameters()),
+            _ => None,
+        }
+    }
+
+    /// Returns the GST-GPS conversion [GstGpsParameters], for page type 4
+    pub fn gst_gps_parameters(&self) -> Option<GstGpsParameters> {
+        match self {
+            Self::Page4(page) => Some(page.gst_gps_parameters()),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this page's CRC is correct
+    pub(crate) fn crc_ok(page: &[u8], offset: usize) -> bool {
+        let mut data = [0u8; DATA_BYTES];
+
+        for bit in (0..DATA_BITS).step_by(16) {
+            let nbits = (DATA_BITS - bit).min(16);
+            set_bits(&mut data, bit, nbits, get_bits(page, offset + bit, nbits));
+        }
+
+        crc24q(&data, DATA_BITS) == get_bits(page, offset + DATA_BITS, CRC_BITS)
+    }
+
+    /// Returns true if this buffer looks like an F/NAV page at this bit offset:
+    /// known page type and null tail bits.
+    pub(crate) fn is_page(buffer: &[u8], offset: usize) -> bool {
+        let page_type = get_bits(buffer, offset, 6);
+        let tail = get_bits(
+            buffer,
+            offset + GALILEO_FNAV_PAGE_BITS - TAIL_BITS,
+            TAIL_BITS,
+        );
+
+        (1..=6).contains(&page_type) && tail == 0
+    }
+
+    /// Decodes a [GalFNavFrame] from this 244-bit page (left aligned),
+    /// after deinterleaving and Viterbi decoding.
+    /// This method does not care for the CRC.
+    pub fn decode_page(page: &[u8]) -> Result<Self, GalileoError> {
+        if page.len() < GALILEO_FNAV_PAGE_BYTES {
+            return Err(GalileoError::InvalidPage);
+        }
+
+        Ok(match get_bits(page, 0, 6) {
+            1 => Self::Page1(GalFNavPage1::from_page(page)),
+            2 => Self::Page2(GalFNavPage2::from_page(page)),
+            3 => Self::Page3(GalFNavPage3::from_page(page)),
+            4 => Self::Page4(GalFNavPage4::from_page(page)),
+            page_type => {
+                let mut data = [0u8; DATA_BYTES];
+                data.copy_from_slice(&page[..DATA_BYTES]);
+
+                // clear the CRC MSBs
+                set_bits(&mut data, DATA_BITS, DATA_BYTES * 8 - DATA_BITS, 0);
+
+                Self::Raw(page_type as u8, data)
+            },
+        })
+    }
+
+    /// Encodes this [GalFNavFrame] as a 244-bit page (left aligned),
+    /// CRC and tail bits included.
+    pub fn encode_page(&self) -> [u8; GALILEO_FNAV_PAGE_BYTES] {
+        let mut page = [0u8; GALILEO_FNAV_PAGE_BYTES];
+
+        match self {
+            Self::Page1(data) => data.to_page(&mut page),
+            Self::Page2(data) => data.to_page(&mut page),
+            Self::Page3(data) => data.to_page(&mut page),
+            Self::Page4(data) => data.to_page(&mut page),
+            Self::Raw(page_type, data) => {
+                page[..DATA_BYTES].copy_from_slice(data);
+                set_bits(&mut page, 0, 6, (*page_type & 0x3f) as u32);
+            },
+        }
+
+        // raw data may have trailing bits
+        set_bits(&mut page, DATA_BITS, DATA_BYTES * 8 - DATA_BITS, 0);
+
+        let crc = crc24q(&page, DATA_BITS);
+        set_bits(&mut page, DATA_BITS, CRC_BITS, crc);
+
+        page
+    }
+
+    /// Encodes this [GalFNavFrame] as a page (see [Self::encode_page])
+    /// into mutable [u8] buffer directly.
+    ///
+    /// ## Output
+    /// - [GalileoError::WouldNotFit] if buffer can't accept the page entirely
+    /// - total number of bits that were encoded ([GALILEO_FNAV_PAGE_BITS])
+    pub fn encode_bits(&self, dest: &mut [u8]) -> Result<usize, GalileoError> {
+        if !encoding_fits(dest.len(), GALILEO_FNAV_PAGE_BITS) {
+            return Err(GalileoError::WouldNotFit);
+        }
+
+        dest[..GALILEO_FNAV_PAGE_BYTES].copy_from_slice(&self.encode_page());
+
+        Ok(GALILEO_FNAV_PAGE_BITS)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::GST_GPS_WEEK_OFFSET;
+
+    fn models() -> Vec<GalFNavFrame> {
+        let mut raw = [0u8; DATA_BYTES];
+        raw[0] = 0x14;
+        raw[13] = 0xAB;
+        raw[26] = 0xC0;
+
+        vec![
+            GalFNavFrame::Page1(GalFNavPage1::model()),
+            GalFNavFrame::Page2(GalFNavPage2::model()),
+            GalFNavFrame::Page3(GalFNavPage3::model()),
+            GalFNavFrame::Page4(GalFNavPage4::model()),
+            GalFNavFrame::Raw(5, raw),
+        ]
+    }
+
+    #[test]
+    fn fnav_properties() {
+        assert_eq!(GALILEO_FNAV_PAGE_BYTES, 31);
+        assert_eq!(DATA_BITS + CRC_BITS + TAIL_BITS, GALILEO_FNAV_PAGE_BITS);
+    }
+
+    #[test]
+    fn page_reciprocal() {
+        for model in models() {
+            let page = model.encode_page();
+
+            assert!(GalFNavFrame::is_page(&page, 0));
+            assert!(GalFNavFrame::crc_ok(&page, 0));
+
+            let decoded = GalFNavFrame::decode_page(&page).unwrap_or_else(|e| {
+                panic!("failed to decode page #{}: {}", model.page_type(), e);
+            });
+
+            assert_eq!(decoded, model);
+        }
+    }
+
+    #[test]
+    fn encode_bits() {
+        let fnav = GalFNavFrame::Page1(GalFNavPage1::model());
+        let expected = fnav.encode_page();
+
+        let mut buf = [0u8; GALILEO_FNAV_PAGE_BYTES];
+        assert_eq!(fnav.encode_bits(&mut buf).unwrap(), GALILEO_FNAV_PAGE_BITS);
+        assert_eq!(buf, expected);
+
+        let mut buf = [0u8; GALILEO_FNAV_PAGE_BYTES - 1];
+        assert!(matches!(
+            fnav.encode_bits(&mut buf),
+            Err(GalileoError::WouldNotFit)
+        ));
+    }
+
+    #[test]
+    fn crc() {
+        let mut page = GalFNavFrame::Page2(GalFNavPage2::model()).encode_page();
+        assert!(GalFNavFrame::crc_ok(&page, 0));
+
+        page[10] ^= 0x01;
+        assert!(!GalFNavFrame::crc_ok(&page, 0));
+    }
+
+    #[test]
+    fn accessors() {
+        let page3 = GalFNavPage3::model();
+        let fnav = GalFNavFrame::Page3(page3);
+
+        assert_eq!(fnav.page_type(), 3);
+        assert_eq!(fnav.iod_nav(), Some(page3.iod_nav));
+        assert_eq!(fnav.tow(), Some(page3.tow));
+
+        let fnav = GalFNavFrame::Raw(6, [0; DATA_BYTES]);
+        assert_eq!(fnav.page_type(), 6);
+        assert_eq!(fnav.iod_nav(), None);
+        assert_eq!(fnav.utc_parameters(), None);
+        assert_eq!(fnav.gst_gps_parameters(), None);
+    }
+
+    #[test]
+    fn time_parameters() {
+        let page4 = GalFNavPage4::model();
+        let page = GalFNavFrame::Page4(page4).encode_page();
+
+        let decoded = GalFNavFrame::decode_page(&page).unwrap();
+
+        let ggto = decoded.gst_gps_parameters().unwrap();
+        assert_eq!(ggto.a0g, page4.a0g);
+        assert_eq!(ggto.a1g, page4.a1g);
+        assert_eq!(ggto.t0g_seconds, page4.t0g_seconds);
+        assert_eq!(ggto.wn0g, page4.week0g);
+
+        // GST week that matches the 6-bit reference week
+        let (week, tow) = (1024 + page4.week0g as u32, page4.t0g_seconds as f64 + 100.0);
+        let offset = page4.a0g + page4.a1g * 100.0;
+
+        let (gps_week, gps_tow) = ggto.gst_to_gps(week, tow);
+        assert_eq!(gps_week, week + GST_GPS_WEEK_OFFSET);
+        assert!((gps_tow - (tow - offset)).abs() < 1e-9);
+
+        let utc = decoded.utc_parameters().unwrap();
+        assert_eq!(utc.a0, page4.a0);
+        assert_eq!(utc.a1, page4.a1);
+        assert_eq!(utc.tot_seconds, page4.tot_seconds);
+        assert_eq!(utc.wnt, page4.week_ot);
+        assert_eq!(utc.delta_t_ls, page4.delta_t_ls as i16);
+
+        // GST week that matches the 8-bit reference week
+        let (week, tow) = (
+            1024 + page4.week_ot as u32,
+            page4.tot_seconds as f64 + 100.0,
+        );
+        let offset = page4.delta_t_ls as f64 + page4.a0 + page4.a1 * 100.0;
+
+        let (utc_week, utc_tow) = utc.to_utc(week, tow);
+        assert_eq!(utc_week, week);
+        assert!((utc_tow - (tow - offset)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,266 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits, to_unsigned_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalFNavPage1] gives the satellite clock correction, the ionospheric model, the group delay, the signal health and the time of week.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalFNavPage1 {
+    /// 6-bit satellite ID
+    pub sv_id: u8,
+
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Clock reference time of week (in seconds)
+    pub toc_seconds: u32,
+
+    /// Clock offset (in seconds)
+    pub af0: f64,
+
+    /// Clock drift (in s.s⁻¹)
+    pub af1: f64,
+
+    /// Clock drift rate (in s.s⁻²)
+    pub af2: f64,
+
+    /// Signal In Space Accuracy index (E1, E5a), 255 means no accuracy prediction available
+    pub sisa: u8,
+
+    /// Effective ionisation level 1st order parameter (in sfu)
+    pub ai0: f64,
+
+    /// Effective ionisation level 2nd order parameter (in sfu.degree⁻¹)
+    pub ai1: f64,
+
+    /// Effective ionisation level 3rd order parameter (in sfu.degree⁻²)
+    pub ai2: f64,
+
+    /// 5-bit ionospheric disturbance flags, for regions 1 (MSB) to 5
+    pub region_flags: u8,
+
+    /// E1-E5a Broadcast Group Delay (in seconds)
+    pub bgd_e1_e5a: f64,
+
+    /// 2-bit E5a signal health status
+    pub e5a_hs: u8,
+
+    /// 12-bit Galileo week number
+    pub week: u16,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+
+    /// E5a data validity status: true when working without guarantee
+    pub e5a_dvs: bool,
+}
+
+impl GalFNavPage1 {
+    /// Generates a realistic page model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_sv_id(11)
+            .with_iod_nav(0x155)
+            .with_toc_seconds(43_200)
+            .with_af0(-123_456_789.0 * 2.0_f64.powi(-34))
+            .with_af1(-12_345.0 * 2.0_f64.powi(-46))
+            .with_af2(3.0 * 2.0_f64.powi(-59))
+            .with_sisa(107)
+            .with_ai0(123.25)
+            .with_ai1(-123.0 * 2.0_f64.powi(-8))
+            .with_ai2(1_234.0 * 2.0_f64.powi(-15))
+            .with_region_flags(0x05)
+            .with_bgd_e1_e5a(-12.0 * 2.0_f64.powi(-32))
+            .with_e5a_hs(1)
+            .with_week(1_234)
+            .with_tow(345_600)
+            .with_e5a_dvs(true)
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated 6-bit satellite ID
+    pub fn with_sv_id(mut self, sv_id: u8) -> Self {
+        self.sv_id = sv_id & 0x3f;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated clock reference time (in seconds).
+    /// Should be a multiple of 60 seconds, to be exactly encoded
+    pub fn with_toc_seconds(mut self, toc_seconds: u32) -> Self {
+        self.toc_seconds = toc_seconds;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated clock offset (in seconds)
+    pub fn with_af0(mut self, af0: f64) -> Self {
+        self.af0 = af0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated clock drift (in s.s⁻¹)
+    pub fn with_af1(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated clock drift rate (in s.s⁻²)
+    pub fn with_af2(mut self, af2: f64) -> Self {
+        self.af2 = af2;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated SISA index
+    pub fn with_sisa(mut self, sisa: u8) -> Self {
+        self.sisa = sisa;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated ai0 (in sfu)
+    pub fn with_ai0(mut self, ai0: f64) -> Self {
+        self.ai0 = ai0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated ai1 (in sfu.degree⁻¹)
+    pub fn with_ai1(mut self, ai1: f64) -> Self {
+        self.ai1 = ai1;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated ai2 (in sfu.degree⁻²)
+    pub fn with_ai2(mut self, ai2: f64) -> Self {
+        self.ai2 = ai2;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated 5-bit ionospheric disturbance flags
+    pub fn with_region_flags(mut self, region_flags: u8) -> Self {
+        self.region_flags = region_flags & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated E1-E5a group delay (in seconds)
+    pub fn with_bgd_e1_e5a(mut self, bgd_e1_e5a: f64) -> Self {
+        self.bgd_e1_e5a = bgd_e1_e5a;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated 2-bit E5a signal health status
+    pub fn with_e5a_hs(mut self, e5a_hs: u8) -> Self {
+        self.e5a_hs = e5a_hs & 0x3;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated 12-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0xfff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage1] with updated E5a data validity status
+    pub fn with_e5a_dvs(mut self, e5a_dvs: bool) -> Self {
+        self.e5a_dvs = e5a_dvs;
+        self
+    }
+
+    /// Decodes [Self] from this 244-bit page.
+    pub(crate) fn from_page(page: &[u8]) -> Self {
+        Self {
+            sv_id: get_bits(page, 6, 6) as u8,
+            iod_nav: get_bits(page, 12, 10) as u16,
+            toc_seconds: get_bits(page, 22, 14) * 60,
+            af0: get_signed_bits(page, 36, 31) as f64 * 2.0_f64.powi(-34),
+            af1: get_signed_bits(page, 67, 21) as f64 * 2.0_f64.powi(-46),
+            af2: get_signed_bits(page, 88, 6) as f64 * 2.0_f64.powi(-59),
+            sisa: get_bits(page, 94, 8) as u8,
+            ai0: get_bits(page, 102, 11) as f64 * 2.0_f64.powi(-2),
+            ai1: get_signed_bits(page, 113, 11) as f64 * 2.0_f64.powi(-8),
+            ai2: get_signed_bits(page, 124, 14) as f64 * 2.0_f64.powi(-15),
+            region_flags: get_bits(page, 138, 5) as u8,
+            bgd_e1_e5a: get_signed_bits(page, 143, 10) as f64 * 2.0_f64.powi(-32),
+            e5a_hs: get_bits(page, 153, 2) as u8,
+            week: get_bits(page, 155, 12) as u16,
+            tow: get_bits(page, 167, 20),
+            e5a_dvs: get_bits(page, 187, 1) > 0,
+        }
+    }
+
+    /// Encodes this [GalFNavPage1] into this 244-bit page (page type included).
+    pub(crate) fn to_page(self, page: &mut [u8]) {
+        set_bits(page, 0, 6, 1);
+        set_bits(page, 6, 6, self.sv_id as u32);
+        set_bits(page, 12, 10, self.iod_nav as u32);
+        set_bits(page, 22, 14, (self.toc_seconds / 60) & 0x3fff);
+        set_bits(
+            page,
+            36,
+            31,
+            to_signed_bits(self.af0 / 2.0_f64.powi(-34), 31),
+        );
+        set_bits(
+            page,
+            67,
+            21,
+            to_signed_bits(self.af1 / 2.0_f64.powi(-46), 21),
+        );
+        set_bits(page, 88, 6, to_signed_bits(self.af2 / 2.0_f64.powi(-59), 6));
+        set_bits(page, 94, 8, self.sisa as u32);
+        set_bits(
+            page,
+            102,
+            11,
+            to_unsigned_bits(self.ai0 / 2.0_f64.powi(-2), 11),
+        );
+        set_bits(
+            page,
+            113,
+            11,
+            to_signed_bits(self.ai1 / 2.0_f64.powi(-8), 11),
+        );
+        set_bits(
+            page,
+            124,
+            14,
+            to_signed_bits(self.ai2 / 2.0_f64.powi(-15), 14),
+        );
+        set_bits(page, 138, 5, self.region_flags as u32);
+        set_bits(
+            page,
+            143,
+            10,
+            to_signed_bits(self.bgd_e1_e5a / 2.0_f64.powi(-32), 10),
+        );
+        set_bits(page, 153, 2, self.e5a_hs as u32);
+        set_bits(page, 155, 12, self.week as u32);
+        set_bits(page, 167, 20, self.tow);
+        set_bits(page, 187, 1, self.e5a_dvs as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalFNavPage1::model();
+
+        let mut page = [0u8; 31];
+        model.to_page(&mut page);
+
+        assert_eq!(get_bits(&page, 0, 6), 1);
+        assert_eq!(GalFNavPage1::from_page(&page), model);
+    }
+}
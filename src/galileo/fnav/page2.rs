@@ -0,0 +1,181 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits, to_unsigned_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalFNavPage2] gives the first part of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalFNavPage2 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    pub m0: f64,
+
+    /// Rate of right ascension (in semi-circles.s⁻¹)
+    pub omega_dot: f64,
+
+    /// Eccentricity
+    pub e: f64,
+
+    /// Square root of semi-major axis (in m^1/2)
+    pub sqrt_a: f64,
+
+    /// Longitude of ascending node at weekly epoch (in semi-circles)
+    pub omega0: f64,
+
+    /// Rate of inclination angle (in semi-circles.s⁻¹)
+    pub idot: f64,
+
+    /// 12-bit Galileo week number
+    pub week: u16,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+}
+
+impl GalFNavPage2 {
+    /// Generates a realistic page model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_m0(-1_234_567_890.0 * 2.0_f64.powi(-31))
+            .with_omega_dot(-4_567_890.0 * 2.0_f64.powi(-43))
+            .with_e(1_234_567.0 * 2.0_f64.powi(-33))
+            .with_sqrt_a(5_440.612_304_687_5)
+            .with_omega0(-987_654_321.0 * 2.0_f64.powi(-31))
+            .with_idot(-1_234.0 * 2.0_f64.powi(-43))
+            .with_week(1_234)
+            .with_tow(345_610)
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated mean anomaly (in semi-circles)
+    pub fn with_m0(mut self, m0: f64) -> Self {
+        self.m0 = m0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated rate of right ascension (in semi-circles.s⁻¹)
+    pub fn with_omega_dot(mut self, omega_dot: f64) -> Self {
+        self.omega_dot = omega_dot;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated eccentricity
+    pub fn with_e(mut self, e: f64) -> Self {
+        self.e = e;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated square root of semi-major axis (in m^1/2)
+    pub fn with_sqrt_a(mut self, sqrt_a: f64) -> Self {
+        self.sqrt_a = sqrt_a;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated longitude of ascending node (in semi-circles)
+    pub fn with_omega0(mut self, omega0: f64) -> Self {
+        self.omega0 = omega0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated rate of inclination angle (in semi-circles.s⁻¹)
+    pub fn with_idot(mut self, idot: f64) -> Self {
+        self.idot = idot;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated 12-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0xfff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage2] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Decodes [Self] from this 244-bit page.
+    pub(crate) fn from_page(page: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(page, 6, 10) as u16,
+            m0: get_signed_bits(page, 16, 32) as f64 * 2.0_f64.powi(-31),
+            omega_dot: get_signed_bits(page, 48, 24) as f64 * 2.0_f64.powi(-43),
+            e: get_bits(page, 72, 32) as f64 * 2.0_f64.powi(-33),
+            sqrt_a: get_bits(page, 104, 32) as f64 * 2.0_f64.powi(-19),
+            omega0: get_signed_bits(page, 136, 32) as f64 * 2.0_f64.powi(-31),
+            idot: get_signed_bits(page, 168, 14) as f64 * 2.0_f64.powi(-43),
+            week: get_bits(page, 182, 12) as u16,
+            tow: get_bits(page, 194, 20),
+        }
+    }
+
+    /// Encodes this [GalFNavPage2] into this 244-bit page (page type included).
+    pub(crate) fn to_page(self, page: &mut [u8]) {
+        set_bits(page, 0, 6, 2);
+        set_bits(page, 6, 10, self.iod_nav as u32);
+        set_bits(
+            page,
+            16,
+            32,
+            to_signed_bits(self.m0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            page,
+            48,
+            24,
+            to_signed_bits(self.omega_dot / 2.0_f64.powi(-43), 24),
+        );
+        set_bits(
+            page,
+            72,
+            32,
+            to_unsigned_bits(self.e / 2.0_f64.powi(-33), 32),
+        );
+        set_bits(
+            page,
+            104,
+            32,
+            to_unsigned_bits(self.sqrt_a / 2.0_f64.powi(-19), 32),
+        );
+        set_bits(
+            page,
+            136,
+            32,
+            to_signed_bits(self.omega0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            page,
+            168,
+            14,
+            to_signed_bits(self.idot / 2.0_f64.powi(-43), 14),
+        );
+        set_bits(page, 182, 12, self.week as u32);
+        set_bits(page, 194, 20, self.tow);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalFNavPage2::model();
+
+        let mut page = [0u8; 31];
+        model.to_page(&mut page);
+
+        assert_eq!(get_bits(&page, 0, 6), 2);
+        assert_eq!(GalFNavPage2::from_page(&page), model);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalFNavPage3] gives the second part of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalFNavPage3 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Inclination angle at reference time (in semi-circles)
+    pub i0: f64,
+
+    /// Argument of perigee (in semi-circles)
+    pub omega: f64,
+
+    /// Mean motion difference (in semi-circles.s⁻¹)
+    pub delta_n: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the argument of latitude (in radians)
+    pub cuc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the argument of latitude (in radians)
+    pub cus: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the orbit radius (in meters)
+    pub crc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the orbit radius (in meters)
+    pub crs: f64,
+
+    /// Ephemeris reference time of week (in seconds)
+    pub toe_seconds: u32,
+
+    /// 12-bit Galileo week number
+    pub week: u16,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+}
+
+impl GalFNavPage3 {
+    /// Generates a realistic page model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_i0(654_321_987.0 * 2.0_f64.powi(-31))
+            .with_omega(1_357_924_680.0 * 2.0_f64.powi(-31))
+            .with_delta_n(12_345.0 * 2.0_f64.powi(-43))
+            .with_cuc(-1_234.0 * 2.0_f64.powi(-29))
+            .with_cus(4_321.0 * 2.0_f64.powi(-29))
+            .with_crc(6_789.0 * 2.0_f64.powi(-5))
+            .with_crs(-987.0 * 2.0_f64.powi(-5))
+            .with_toe_seconds(43_200)
+            .with_week(1_234)
+            .with_tow(345_620)
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated inclination angle (in semi-circles)
+    pub fn with_i0(mut self, i0: f64) -> Self {
+        self.i0 = i0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated argument of perigee (in semi-circles)
+    pub fn with_omega(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated mean motion difference (in semi-circles.s⁻¹)
+    pub fn with_delta_n(mut self, delta_n: f64) -> Self {
+        self.delta_n = delta_n;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated Cuc (in radians)
+    pub fn with_cuc(mut self, cuc: f64) -> Self {
+        self.cuc = cuc;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated Cus (in radians)
+    pub fn with_cus(mut self, cus: f64) -> Self {
+        self.cus = cus;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated Crc (in meters)
+    pub fn with_crc(mut self, crc: f64) -> Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated Crs (in meters)
+    pub fn with_crs(mut self, crs: f64) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated ephemeris reference time (in seconds).
+    /// Should be a multiple of 60 seconds, to be exactly encoded
+    pub fn with_toe_seconds(mut self, toe_seconds: u32) -> Self {
+        self.toe_seconds = toe_seconds;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated 12-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0xfff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage3] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Decodes [Self] from this 244-bit page.
+    pub(crate) fn from_page(page: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(page, 6, 10) as u16,
+            i0: get_signed_bits(page, 16, 32) as f64 * 2.0_f64.powi(-31),
+            omega: get_signed_bits(page, 48, 32) as f64 * 2.0_f64.powi(-31),
+            delta_n: get_signed_bits(page, 80, 16) as f64 * 2.0_f64.powi(-43),
+            cuc: get_signed_bits(page, 96, 16) as f64 * 2.0_f64.powi(-29),
+            cus: get_signed_bits(page, 112, 16) as f64 * 2.0_f64.powi(-29),
+            crc: get_signed_bits(page, 128, 16) as f64 * 2.0_f64.powi(-5),
+            crs: get_signed_bits(page, 144, 16) as f64 * 2.0_f64.powi(-5),
+            toe_seconds: get_bits(page, 160, 14) * 60,
+            week: get_bits(page, 174, 12) as u16,
+            tow: get_bits(page, 186, 20),
+        }
+    }
+
+    /// Encodes this [GalFNavPage3] into this 244-bit page (page type included).
+    pub(crate) fn to_page(self, page: &mut [u8]) {
+        set_bits(page, 0, 6, 3);
+        set_bits(page, 6, 10, self.iod_nav as u32);
+        set_bits(
+            page,
+            16,
+            32,
+            to_signed_bits(self.i0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            page,
+            48,
+            32,
+            to_signed_bits(self.omega / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            page,
+            80,
+            16,
+            to_signed_bits(self.delta_n / 2.0_f64.powi(-43), 16),
+        );
+        set_bits(
+            page,
+            96,
+            16,
+            to_signed_bits(self.cuc / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            page,
+            112,
+            16,
+            to_signed_bits(self.cus / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            page,
+            128,
+            16,
+            to_signed_bits(self.crc / 2.0_f64.powi(-5), 16),
+        );
+        set_bits(
+            page,
+            144,
+            16,
+            to_signed_bits(self.crs / 2.0_f64.powi(-5), 16),
+        );
+        set_bits(page, 160, 14, (self.toe_seconds / 60) & 0x3fff);
+        set_bits(page, 174, 12, self.week as u32);
+        set_bits(page, 186, 20, self.tow);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalFNavPage3::model();
+
+        let mut page = [0u8; 31];
+        model.to_page(&mut page);
+
+        assert_eq!(get_bits(&page, 0, 6), 3);
+        assert_eq!(GalFNavPage3::from_page(&page), model);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::{
+    bits::{get_bits, get_signed_bits, set_bits, to_signed_bits},
+    GstGpsParameters, UtcParameters,
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalFNavPage4] gives the last part of the ephemeris, the GST-UTC and GST-GPS conversion parameters.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalFNavPage4 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Amplitude of the cosine harmonic correction term to the angle of inclination (in radians)
+    pub cic: f64,
+
+    /// Amplitude of the sine harmonic correction term to the angle of inclination (in radians)
+    pub cis: f64,
+
+    /// Constant term of the GST-UTC offset polynomial (in seconds)
+    pub a0: f64,
+
+    /// 1st order term of the GST-UTC offset polynomial (in s.s⁻¹)
+    pub a1: f64,
+
+    /// Leap second count before leap second adjustment
+    pub delta_t_ls: i8,
+
+    /// UTC data reference time of week (in seconds)
+    pub tot_seconds: u32,
+
+    /// UTC data reference week number (modulo 256)
+    pub week_ot: u8,
+
+    /// Week number of leap second adjustment (modulo 256)
+    pub week_lsf: u8,
+
+    /// Day number (1 to 7) at the end of which the leap second adjustment becomes effective
+    pub day_number: u8,
+
+    /// Leap second count after leap second adjustment
+    pub delta_t_lsf: i8,
+
+    /// GST-GPS data reference time of week (in seconds)
+    pub t0g_seconds: u32,
+
+    /// Constant term of the GST-GPS offset polynomial (in seconds)
+    pub a0g: f64,
+
+    /// 1st order term of the GST-GPS offset polynomial (in s.s⁻¹)
+    pub a1g: f64,
+
+    /// GST-GPS data reference week number (modulo 64)
+    pub week0g: u8,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+}
+
+impl GalFNavPage4 {
+    /// Generates a realistic page model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_cic(-321.0 * 2.0_f64.powi(-29))
+            .with_cis(987.0 * 2.0_f64.powi(-29))
+            .with_a0(-12_345.0 * 2.0_f64.powi(-30))
+            .with_a1(-3_456.0 * 2.0_f64.powi(-50))
+            .with_delta_t_ls(18)
+            .with_tot_seconds(86_400)
+            .with_week_ot(0x12)
+            .with_week_lsf(0x34)
+            .with_day_number(7)
+            .with_delta_t_lsf(19)
+            .with_t0g_seconds(7_200)
+            .with_a0g(-1_234.0 * 2.0_f64.powi(-35))
+            .with_a1g(56.0 * 2.0_f64.powi(-51))
+            .with_week0g(0x2a)
+            .with_tow(345_630)
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated Cic (in radians)
+    pub fn with_cic(mut self, cic: f64) -> Self {
+        self.cic = cic;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated Cis (in radians)
+    pub fn with_cis(mut self, cis: f64) -> Self {
+        self.cis = cis;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-UTC constant term (in seconds)
+    pub fn with_a0(mut self, a0: f64) -> Self {
+        self.a0 = a0;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-UTC 1st order term (in s.s⁻¹)
+    pub fn with_a1(mut self, a1: f64) -> Self {
+        self.a1 = a1;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated leap second count before adjustment
+    pub fn with_delta_t_ls(mut self, delta_t_ls: i8) -> Self {
+        self.delta_t_ls = delta_t_ls;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated UTC data reference time (in seconds).
+    /// Should be a multiple of 3600 seconds, to be exactly encoded
+    pub fn with_tot_seconds(mut self, tot_seconds: u32) -> Self {
+        self.tot_seconds = tot_seconds;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated UTC data reference week number
+    pub fn with_week_ot(mut self, week_ot: u8) -> Self {
+        self.week_ot = week_ot;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated week number of leap second adjustment
+    pub fn with_week_lsf(mut self, week_lsf: u8) -> Self {
+        self.week_lsf = week_lsf;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated day number of leap second adjustment
+    pub fn with_day_number(mut self, day_number: u8) -> Self {
+        self.day_number = day_number & 0x7;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated leap second count after adjustment
+    pub fn with_delta_t_lsf(mut self, delta_t_lsf: i8) -> Self {
+        self.delta_t_lsf = delta_t_lsf;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-GPS data reference time (in seconds).
+    /// Should be a multiple of 3600 seconds, to be exactly encoded
+    pub fn with_t0g_seconds(mut self, t0g_seconds: u32) -> Self {
+        self.t0g_seconds = t0g_seconds;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-GPS constant term (in seconds)
+    pub fn with_a0g(mut self, a0g: f64) -> Self {
+        self.a0g = a0g;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-GPS 1st order term (in s.s⁻¹)
+    pub fn with_a1g(mut self, a1g: f64) -> Self {
+        self.a1g = a1g;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated GST-GPS data reference week number
+    pub fn with_week0g(mut self, week0g: u8) -> Self {
+        self.week0g = week0g & 0x3f;
+        self
+    }
+
+    /// Copies and returns [GalFNavPage4] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Returns the GST-UTC conversion [UtcParameters] broadcast by this page.
+    pub fn utc_parameters(&self) -> UtcParameters {
+        UtcParameters::default()
+            .with_a0_seconds(self.a0)
+            .with_a1_seconds_s(self.a1)
+            .with_tot_seconds(self.tot_seconds)
+            .with_wnt(self.week_ot)
+            .with_leap_seconds(self.delta_t_ls as i16)
+    }
+
+    /// Returns the GST-GPS conversion [GstGpsParameters] broadcast by this page.
+    pub fn gst_gps_parameters(&self) -> GstGpsParameters {
+        GstGpsParameters::default()
+            .with_a0g_seconds(self.a0g)
+            .with_a1g_seconds_s(self.a1g)
+            .with_t0g_seconds(self.t0g_seconds)
+            .with_wn0g(self.week0g)
+    }
+
+    /// Decodes [Self] from this 244-bit page.
+    pub(crate) fn from_page(page: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(page, 6, 10) as u16,
+            cic: get_signed_bits(page, 16, 16) as f64 * 2.0_f64.powi(-29),
+            cis: get_signed_bits(page, 32, 16) as f64 * 2.0_f64.powi(-29),
+            a0: get_signed_bits(page, 48, 32) as f64 * 2.0_f64.powi(-30),
+            a1: get_signed_bits(page, 80, 24) as f64 * 2.0_f64.powi(-50),
+            delta_t_ls: get_signed_bits(page, 104, 8) as i8,
+            tot_seconds: get_bits(page, 112, 8) * 3600,
+            week_ot: get_bits(page, 120, 8) as u8,
+            week_lsf: get_bits(page, 128, 8) as u8,
+            day_number: get_bits(page, 136, 3) as u8,
+            delta_t_lsf: get_signed_bits(page, 139, 8) as i8,
+            t0g_seconds: get_bits(page, 147, 8) * 3600,
+            a0g: get_signed_bits(page, 155, 16) as f64 * 2.0_f64.powi(-35),
+            a1g: get_signed_bits(page, 171, 12) as f64 * 2.0_f64.powi(-51),
+            week0g: get_bits(page, 183, 6) as u8,
+            tow: get_bits(page, 189, 20),
+        }
+    }
+
+    /// Encodes this [GalFNavPage4] into this 244-bit page (page type included).
+    pub(crate) fn to_page(self, page: &mut [u8]) {
+        set_bits(page, 0, 6, 4);
+        set_bits(page, 6, 10, self.iod_nav as u32);
+        set_bits(
+            page,
+            16,
+            16,
+            to_signed_bits(self.cic / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            page,
+            32,
+            16,
+            to_signed_bits(self.cis / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            page,
+            48,
+            32,
+            to_signed_bits(self.a0 / 2.0_f64.powi(-30), 32),
+        );
+        set_bits(
+            page,
+            80,
+            24,
+            to_signed_bits(self.a1 / 2.0_f64.powi(-50), 24),
+        );
+        set_bits(page, 104, 8, (self.delta_t_ls as u32) & 0xff);
+        set_bits(page, 112, 8, (self.tot_seconds / 3600) & 0xff);
+        set_bits(page, 120, 8, self.week_ot as u32);
+        set_bits(page, 128, 8, self.week_lsf as u32);
+        set_bits(page, 136, 3, self.day_number as u32);
+        set_bits(page, 139, 8, (self.delta_t_lsf as u32) & 0xff);
+        set_bits(page, 147, 8, (self.t0g_seconds / 3600) & 0xff);
+        set_bits(
+            page,
+            155,
+            16,
+            to_signed_bits(self.a0g / 2.0_f64.powi(-35), 16),
+        );
+        set_bits(
+            page,
+            171,
+            12,
+            to_signed_bits(self.a1g / 2.0_f64.powi(-51), 12),
+        );
+        set_bits(page, 183, 6, self.week0g as u32);
+        set_bits(page, 189, 20, self.tow);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalFNavPage4::model();
+
+        let mut page = [0u8; 31];
+        model.to_page(&mut page);
+
+        assert_eq!(get_bits(&page, 0, 6), 4);
+        assert_eq!(GalFNavPage4::from_page(&page), model);
+    }
+}
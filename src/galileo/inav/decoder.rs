@@ -0,0 +1,194 @@
+use crate::{
+    bits::get_bits,
+    galileo::{GalINAV, GalileoMessage, GALILEO_INAV_PAGE_BITS, GALILEO_INAV_PAGE_BYTES},
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [GalileoInavDecoder] can decode Galileo I/NAV nominal pages, from a stream of
+/// page parts that were already deinterleaved and Viterbi decoded (sync patterns removed):
+/// each 120-bit page part is terminated by its 6 tail bits.
+///
+/// The stream is synchronized on the even/odd page pair: an even nominal page part
+/// followed by an odd nominal page part, both ending with null tail bits.
+/// By [Default], our [GalileoInavDecoder] also verifies the CRC-24Q.
+#[derive(Debug, Copy, Clone)]
+pub struct GalileoInavDecoder {
+    /// True when CRC verification is requested
+    crc_verification: bool,
+}
+
+impl Default for GalileoInavDecoder {
+    /// Creates a default [GalileoInavDecoder] that verifies the CRC.
+    fn default() -> Self {
+        Self {
+            crc_verification: true,
+        }
+    }
+}
+
+impl GalileoInavDecoder {
+    /// Copies and returns a [GalileoInavDecoder] that does not verify the CRC.
+    /// Every page is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_crc_verification(mut self) -> Self {
+        self.crc_verification = false;
+        self
+    }
+
+    /// Locates the first even/odd page pair within this buffer, at any bit offset.
+    fn find_page(&self, buffer: &[u8], size: usize) -> Option<usize> {
+        let total_bits = size * 8;
+
+        if total_bits < GALILEO_INAV_PAGE_BITS {
+            return None;
+        }
+
+        (0..=total_bits - GALILEO_INAV_PAGE_BITS).find(|offset| {
+            GalINAV::is_page(buffer, *offset)
+                && (!self.crc_verification || GalINAV::crc_ok(buffer, *offset))
+        })
+    }
+
+    /// Decodes the first valid [GalileoMessage] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same page twice.
+    /// - Optional [GalileoMessage] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When no complete page pair was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next page: simply append the following bytes
+    /// and call [Self::decode] again.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<GalileoMessage>) {
+        let size = size.min(buffer.len());
+
+        let offset_bit = match self.find_page(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(GALILEO_INAV_PAGE_BITS - 1), None);
+            },
+        };
+
+        #[cfg(feature = "log")]
+        trace!("(GAL) [inav]: pos={}", offset_bit);
+
+        let mut page = [0u8; GALILEO_INAV_PAGE_BYTES];
+
+        for (i, byte) in page.iter_mut().enumerate() {
+            *byte = get_bits(buffer, offset_bit + i * 8, 8) as u8;
+        }
+
+        let end = offset_bit + GALILEO_INAV_PAGE_BITS;
+
+        match GalINAV::decode_page(&page) {
+            Ok(inav) => (end, Some(GalileoMessage::INAV(inav))),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(GAL) [inav]: {}", e);
+                (end, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        galileo::{
+            GalINAV, GalINAVWord1, GalINAVWord2, GalINAVWord3, GalINAVWord4, GalINAVWord5,
+            GalINAVWord6, GalileoInavDecoder, GalileoMessage, GALILEO_INAV_PAGE_BITS,
+        },
+        testing::insert_bit_delay,
+    };
+
+    fn models() -> Vec<GalINAV> {
+        vec![
+            GalINAV::Word2(GalINAVWord2::model()),
+            GalINAV::Word4(GalINAVWord4::model()),
+            GalINAV::Word6(GalINAVWord6::model()),
+            GalINAV::Word1(GalINAVWord1::model()),
+            GalINAV::Word3(GalINAVWord3::model()),
+            GalINAV::Word5(GalINAVWord5::model()),
+        ]
+    }
+
+    /// Concatenates these nominal pages, like a stream of page parts does.
+    fn stream(models: &[GalINAV]) -> Vec<u8> {
+        models
+            .iter()
+            .flat_map(|model| model.encode_page())
+            .collect()
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let models = models();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&models), delay);
+
+            let mut decoder = GalileoInavDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, message) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(GalileoMessage::INAV(inav)) = message {
+                    decoded.push(inav);
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+
+    #[test]
+    fn partial_read() {
+        let model = GalINAV::Word4(GalINAVWord4::model());
+        let buffer = stream(&[model]);
+
+        let mut decoder = GalileoInavDecoder::default();
+
+        let (processed, message) = decoder.decode(&buffer, 29);
+        assert_eq!(processed, 0);
+        assert!(message.is_none());
+
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GALILEO_INAV_PAGE_BITS);
+        assert_eq!(message, Some(GalileoMessage::INAV(model)));
+    }
+
+    #[test]
+    fn crc_rejection() {
+        let model = GalINAV::Word1(GalINAVWord1::model());
+        let mut buffer = stream(&[model]);
+
+        // corrupt m0
+        buffer[5] ^= 0x08;
+
+        let mut decoder = GalileoInavDecoder::default();
+        let (_, message) = decoder.decode(&buffer, buffer.len());
+        assert!(message.is_none());
+
+        let mut decoder = GalileoInavDecoder::default().without_crc_verification();
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GALILEO_INAV_PAGE_BITS);
+        assert!(message.is_some());
+        assert_ne!(message, Some(GalileoMessage::INAV(model)));
+    }
+}
@@ -0,0 +1,513 @@
+use crate::{
+    bits::{get_bits, set_bits},
+    crc24q, encoding_fits,
+    galileo::GalileoError,
+    UtcParameters,
+};
+
+/// I/NAV page part size (in bits!), tail bits included
+pub const GALILEO_INAV_PAGE_PART_BITS: usize = 120;
+
+/// I/NAV nominal page size (in bits!): even and odd page parts
+pub const GALILEO_INAV_PAGE_BITS: usize = 2 * GALILEO_INAV_PAGE_PART_BITS;
+
+/// I/NAV nominal page size (in bytes!)
+pub const GALILEO_INAV_PAGE_BYTES: usize = GALILEO_INAV_PAGE_BITS / 8;
+
+/// I/NAV word size (in bits!)
+pub const GALILEO_INAV_WORD_BITS: usize = 128;
+
+/// I/NAV word size (in bytes!)
+pub const GALILEO_INAV_WORD_BYTES: usize = GALILEO_INAV_WORD_BITS / 8;
+
+/// Number of word bits carried by the even page part
+const DATA_K_BITS: usize = 112;
+
+/// Number of word bits carried by the odd page part
+const DATA_J_BITS: usize = GALILEO_INAV_WORD_BITS - DATA_K_BITS;
+
+/// Number of tail bits, ending each page part
+const TAIL_BITS: usize = 6;
+
+/// Number of even page part bits protected by the CRC
+const EVEN_CRC_BITS: usize = 114;
+
+/// Number of odd page part bits protected by the CRC, which follows them
+const ODD_CRC_BITS: usize = 82;
+
+/// CRC size (in bits!)
+const CRC_BITS: usize = 24;
+
+mod decoder;
+pub use decoder::GalileoInavDecoder;
+
+mod word1;
+pub use word1::GalINAVWord1;
+
+mod word2;
+pub use word2::GalINAVWord2;
+
+mod word3;
+pub use word3::GalINAVWord3;
+
+mod word4;
+pub use word4::GalINAVWord4;
+
+mod word5;
+pub use word5::GalINAVWord5;
+
+mod word6;
+pub use word6::GalINAVWord6;
+
+/// [GalINAV] is the 128-bit word carried by an I/NAV nominal page.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GalINAV {
+    /// Word type 1: [GalINAVWord1] (ephemeris)
+    Word1(GalINAVWord1),
+
+    /// Word type 2: [GalINAVWord2] (ephemeris)
+    Word2(GalINAVWord2),
+
+    /// Word type 3: [GalINAVWord3] (ephemeris and SISA)
+    Word3(GalINAVWord3),
+
+    /// Word type 4: [GalINAVWord4] (clock correction)
+    Word4(GalINAVWord4),
+
+    /// Word type 5: [GalINAVWord5] (ionosphere, group delays and health)
+    Word5(GalINAVWord5),
+
+    /// Word type 6: [GalINAVWord6] (GST-UTC conversion)
+    Word6(GalINAVWord6),
+
+    /// Other word types, not interpreted yet: word type and complete word
+    Raw(u8, [u8; GALILEO_INAV_WORD_BYTES]),
+}
+
+impl Default for GalINAV {
+    fn default() -> Self {
+        Self::Word1(Default::default())
+    }
+}
+
+impl GalINAV {
+    /// Returns the 6-bit word type
+    pub fn word_type(&self) -> u8 {
+        match self {
+            Self::Word1(_) => 1,
+            Self::Word2(_) => 2,
+            Self::Word3(_) => 3,
+            Self::Word4(_) => 4,
+            Self::Word5(_) => 5,
+            Self::Word6(_) => 6,
+            Self::Raw(word_type, _) => *word_type,
+        }
+    }
+
+    /// Returns the 10-bit Issue of Data (navigation batch), for word types 1 to 4
+    pub fn iod_nav(&self) -> Option<u16> {
+        match self {
+            Self::Word1(word) => Some(word.iod_nav),
+            Self::Word2(word) => Some(word.iod_nav),
+            Self::Word3(word) => Some(word.iod_nav),
+            Self::Word4(word) => Some(word.iod_nav),
+            _ => None,
+        }
+    }
+
+    /// Returns the ephemeris reference time of week (in seconds), for word type 1
+    pub fn toe_seconds(&self) -> Option<u32> {
+        match self {
+            Self::Word1(word) => Some(word.toe_seconds),
+            _ => None,
+        }
+    }
+
+    /// Returns the square root of semi-major axis (in m^1/2), for word type 1
+    pub fn sqrt_a(&self) -> Option<f64> {
+        match self {
+            Self::Word1(word) => Some(word.sqrt_a),
+            _ => None,
+        }
+    }
+
+    /// Returns the eccentricity, for word type 1
+    pub fn e(&self) -> Option<f64> {
+        match self {
+            Self::Word1(word) => Some(word.e),
+            _ => None,
+        }
+    }
+
+    /// Returns the Signal In Space Accuracy index, for word type 3
+    pub fn sisa(&self) -> Option<u8> {
+        match self {
+            Self::Word3(word) => Some(word.sisa),
+            _ => None,
+        }
+    }
+
+    /// Returns the GST-UTC conversion [UtcParameters], for word type 6
+    pub fn utc_parameters(&self) -> Option<UtcParameters> {
+        match self {
+            Self::Word6(word) => Some(word.utc_parameters()),
+            _ => None,
+        }
+    }
+
+    /// Interprets this 128-bit word. Word types that we do not interpret
+    /// are returned as [GalINAV::Raw].
+    pub fn from_word(word: &[u8; GALILEO_INAV_WORD_BYTES]) -> Self {
+        match get_bits(word, 0, 6) {
+            1 => Self::Word1(GalINAVWord1::from_word(word)),
+            2 => Self::Word2(GalINAVWord2::from_word(word)),
+            3 => Self::Word3(GalINAVWord3::from_word(word)),
+            4 => Self::Word4(GalINAVWord4::from_word(word)),
+            5 => Self::Word5(GalINAVWord5::from_word(word)),
+            6 => Self::Word6(GalINAVWord6::from_word(word)),
+            word_type => Self::Raw(word_type as u8, *word),
+        }
+    }
+
+    /// Encodes this [GalINAV] as a 128-bit word.
+    pub fn to_word(&self) -> [u8; GALILEO_INAV_WORD_BYTES] {
+        let mut word = [0u8; GALILEO_INAV_WORD_BYTES];
+
+        match self {
+            Self::Word1(data) => data.to_word(&mut word),
+            Self::Word2(data) => data.to_word(&mut word),
+            Self::Word3(data) => data.to_word(&mut word),
+            Self::Word4(data) => data.to_word(&mut word),
+            Self::Word5(data) => data.to_word(&mut word),
+            Self::Word6(data) => data.to_word(&mut word),
+            Self::Raw(word_type, data) => {
+                word = *data;
+                set_bits(&mut word, 0, 6, (*word_type & 0x3f) as u32);
+            },
+        }
+
+        word
+    }
+
+    /// Decodes a [GalINAV] from this nominal page: even page part followed by the odd page part,
+    /// both 120-bit long (tail bits included) after deinterleaving and Viterbi decoding.
+    /// This method does not care for the CRC.
+    pub fn decode_page(page: &[u8]) -> Result<Self, GalileoError> {
+        if page.len() < GALILEO_INAV_PAGE_BYTES {
+            return Err(GalileoError::InvalidPage);
+        }
+
+        let odd = GALILEO_INAV_PAGE_PART_BITS;
+
+        if get_bits(page, 0, 1) != 0 || get_bits(page, odd, 1) != 1 {
+            return Err(GalileoError::InvalidPage);
+        }
+
+        if get_bits(page, 1, 1) != 0 || get_bits(page, odd + 1, 1) != 0 {
+            return Err(GalileoError::AlertPage);
+        }
+
+        let mut word = [0u8; GALILEO_INAV_WORD_BYTES];
+
+        for i in (0..DATA_K_BITS).step_by(16) {
+            set_bits(&mut word, i, 16, get_bits(page, 2 + i, 16));
+        }
+
+        set_bits(
+            &mut word,
+            DATA_K_BITS,
+            DATA_J_BITS,
+            get_bits(page, odd + 2, DATA_J_BITS),
+        );
+
+        Ok(Self::from_word(&word))
+    }
+
+    /// Encodes this [GalINAV] as a nominal page: even page part followed by the odd page part,
+    /// both 120-bit long (tail bits included), CRC included. Reserved and SAR fields are left empty.
+    pub fn encode_page(&self) -> [u8; GALILEO_INAV_PAGE_BYTES] {
+        let word = self.to_word();
+        let mut page = [0u8; GALILEO_INAV_PAGE_BYTES];
+
+        let odd = GALILEO_INAV_PAGE_PART_BITS;
+
+        for i in (0..DATA_K_BITS).step_by(16) {
+            set_bits(&mut page, 2 + i, 16, get_bits(&word, i, 16));
+        }
+
+        set_bits(&mut page, odd, 1, 1);
+
+        set_bits(
+            &mut page,
+            odd + 2,
+            DATA_J_BITS,
+            get_bits(&word, DATA_K_BITS, DATA_J_BITS),
+        );
+
+        let crc = Self::crc(&page, 0);
+        set_bits(&mut page, odd + ODD_CRC_BITS, CRC_BITS, crc);
+
+        page
+    }
+
+    /// Encodes this [GalINAV] as a nominal page (see [Self::encode_page])
+    /// into mutable [u8] buffer directly.
+    ///
+    /// ## Output
+    /// - [GalileoError::WouldNotFit] if buffer can't accept the page entirely
+    /// - total number of bits that were encoded ([GALILEO_INAV_PAGE_BITS])
+    pub fn encode_bits(&self, dest: &mut [u8]) -> Result<usize, GalileoError> {
+        if !encoding_fits(dest.len(), GALILEO_INAV_PAGE_BITS) {
+            return Err(GalileoError::WouldNotFit);
+        }
+
+        dest[..GALILEO_INAV_PAGE_BYTES].copy_from_slice(&self.encode_page());
+
+        Ok(GALILEO_INAV_PAGE_BITS)
+    }
+
+    /// Computes the CRC-24Q of the nominal page found at this bit offset:
+    /// it protects the even page part and the beginning of the odd page part.
+    fn crc(buffer: &[u8], offset: usize) -> u32 {
+        let odd = offset + GALILEO_INAV_PAGE_PART_BITS;
+        let mut data = [0u8; (EVEN_CRC_BITS + ODD_CRC_BITS + 7) / 8];
+
+        for bit in (0..EVEN_CRC_BITS).step_by(16) {
+            let nbits = (EVEN_CRC_BITS - bit).min(16);
+            set_bits(&mut data, bit, nbits, get_bits(buffer, offset + bit, nbits));
+        }
+
+        for bit in (0..ODD_CRC_BITS).step_by(16) {
+            let nbits = (ODD_CRC_BITS - bit).min(16);
+            let value = get_bits(buffer, odd + bit, nbits);
+            set_bits(&mut data, EVEN_CRC_BITS + bit, nbits, value);
+        }
+
+        crc24q(&data, EVEN_CRC_BITS + ODD_CRC_BITS)
+    }
+
+    /// Returns true if the CRC of the nominal page found at this bit offset is correct
+    pub(crate) fn crc_ok(buffer: &[u8], offset: usize) -> bool {
+        let odd = offset + GALILEO_INAV_PAGE_PART_BITS;
+        Self::crc(buffer, offset) == get_bits(buffer, odd + ODD_CRC_BITS, CRC_BITS)
+    }
+
+    /// Returns true if this buffer looks like a nominal page at this bit offset:
+    /// even then odd page parts, both terminated by their (null) tail bits.
+    pub(crate) fn is_page(buffer: &[u8], offset: usize) -> bool {
+        let odd = offset + GALILEO_INAV_PAGE_PART_BITS;
+        let even_tail = odd - TAIL_BITS;
+        let odd_tail = odd + GALILEO_INAV_PAGE_PART_BITS - TAIL_BITS;
+
+        get_bits(buffer, offset, 2) == 0
+            && get_bits(buffer, odd, 2) == 0x02
+            && get_bits(buffer, even_tail, TAIL_BITS) == 0
+            && get_bits(buffer, odd_tail, TAIL_BITS) == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn models() -> Vec<GalINAV> {
+        let mut raw = [0u8; GALILEO_INAV_WORD_BYTES];
+        raw[0] = 0x28;
+        raw[15] = 0xAB;
+
+        vec![
+            GalINAV::Word1(GalINAVWord1::model()),
+            GalINAV::Word2(GalINAVWord2::model()),
+            GalINAV::Word3(GalINAVWord3::model()),
+            GalINAV::Word4(GalINAVWord4::model()),
+            GalINAV::Word5(GalINAVWord5::model()),
+            GalINAV::Word6(GalINAVWord6::model()),
+            GalINAV::Raw(10, raw),
+        ]
+    }
+
+    #[test]
+    fn galileo_properties() {
+        assert_eq!(GALILEO_INAV_PAGE_BITS, 240);
+        assert_eq!(GALILEO_INAV_PAGE_BYTES, 30);
+        assert_eq!(GALILEO_INAV_WORD_BYTES, 16);
+    }
+
+    #[test]
+    fn word_reciprocal() {
+        for model in models() {
+            let word = model.to_word();
+            assert_eq!(get_bits(&word, 0, 6), model.word_type() as u32);
+            assert_eq!(GalINAV::from_word(&word), model);
+        }
+    }
+
+    #[test]
+    fn page_reciprocal() {
+        for model in models() {
+            let page = model.encode_page();
+
+            assert!(GalINAV::is_page(&page, 0));
+            assert!(GalINAV::crc_ok(&page, 0));
+
+            let decoded = GalINAV::decode_page(&page).unwrap_or_else(|e| {
+                panic!("failed to decode word #{}: {}", model.word_type(), e);
+            });
+
+            assert_eq!(decoded, model);
+        }
+    }
+
+    #[test]
+    fn encode_bits() {
+        let inav = GalINAV::Word4(GalINAVWord4::model());
+        let expected = inav.encode_page();
+
+        let mut buf = [0u8; GALILEO_INAV_PAGE_BYTES];
+        assert_eq!(inav.encode_bits(&mut buf).unwrap(), GALILEO_INAV_PAGE_BITS);
+        assert_eq!(buf, expected);
+
+        let mut buf = [0u8; GALILEO_INAV_PAGE_BYTES - 1];
+        assert!(matches!(
+            inav.encode_bits(&mut buf),
+            Err(GalileoError::WouldNotFit)
+        ));
+    }
+
+    #[test]
+    fn crc() {
+        let mut page = GalINAV::Word4(GalINAVWord4::model()).encode_page();
+        assert!(GalINAV::crc_ok(&page, 0));
+
+        // even page part
+        page[7] ^= 0x04;
+        assert!(!GalINAV::crc_ok(&page, 0));
+        page[7] ^= 0x04;
+
+        // odd page part (data_j)
+        page[16] ^= 0x01;
+        assert!(!GalINAV::crc_ok(&page, 0));
+        page[16] ^= 0x01;
+
+        // odd page part (SAR)
+        page[24] ^= 0x80;
+        assert!(!GalINAV::crc_ok(&page, 0));
+        page[24] ^= 0x80;
+
+        // tail bits are not protected
+        page[14] ^= 0x01;
+        assert!(GalINAV::crc_ok(&page, 0));
+    }
+
+    #[test]
+    fn reference_page() {
+        // word type 6 with non empty OSNMA, SAR and SSP fields,
+        // assembled and CRC'd independently of this library
+        let page = [
+            0x06, 0xFF, 0xFF, 0xFF, 0xFD, 0xFF, 0xFF, 0xF4, 0x12, 0x60, 0x2C, 0x89, 0xE2, 0x4A,
+            0x80, 0x8C, 0x3C, 0x29, 0x56, 0xB0, 0xCF, 0x25, 0xAA, 0xBC, 0xDE, 0x37, 0x50, 0x10,
+            0x57, 0x00,
+        ];
+
+        assert!(GalINAV::is_page(&page, 0));
+        assert!(GalINAV::crc_ok(&page, 0));
+        assert_eq!(GalINAV::crc(&page, 0), 0xDD4041);
+
+        let expected = GalINAVWord6::default()
+            .with_a0(-3.0 * 2.0_f64.powi(-30))
+            .with_a1(-12.0 * 2.0_f64.powi(-50))
+            .with_delta_t_ls(18)
+            .with_tot_seconds(345_600)
+            .with_week_ot(0x2C)
+            .with_week_lsf(0x89)
+            .with_day_number(7)
+            .with_delta_t_lsf(18)
+            .with_tow(345_630);
+
+        let decoded = GalINAV::decode_page(&page).unwrap();
+        assert_eq!(decoded, GalINAV::Word6(expected));
+
+        // OSNMA, SAR and SSP fields are not encoded
+        let mut encoded = decoded.encode_page();
+        assert!(GalINAV::crc_ok(&encoded, 0));
+
+        set_bits(&mut encoded, GALILEO_INAV_PAGE_PART_BITS + 18, 24, 0xA55AC3);
+        set_bits(&mut encoded, GALILEO_INAV_PAGE_PART_BITS + 42, 16, 0x3C96);
+        set_bits(&mut encoded, GALILEO_INAV_PAGE_PART_BITS + 58, 22, 0x2ABCDE);
+        set_bits(&mut encoded, GALILEO_INAV_PAGE_PART_BITS + 82, 24, 0xDD4041);
+        set_bits(&mut encoded, GALILEO_INAV_PAGE_PART_BITS + 106, 8, 0x5C);
+        assert_eq!(encoded, page);
+    }
+
+    #[test]
+    fn accessors() {
+        let word1 = GalINAVWord1::model();
+        let inav = GalINAV::Word1(word1);
+
+        assert_eq!(inav.iod_nav(), Some(word1.iod_nav));
+        assert_eq!(inav.toe_seconds(), Some(word1.toe_seconds));
+        assert_eq!(inav.sqrt_a(), Some(word1.sqrt_a));
+        assert_eq!(inav.e(), Some(word1.e));
+        assert_eq!(inav.sisa(), None);
+
+        let word3 = GalINAVWord3::model();
+        let inav = GalINAV::Word3(word3);
+
+        assert_eq!(inav.iod_nav(), Some(word3.iod_nav));
+        assert_eq!(inav.sisa(), Some(word3.sisa));
+        assert_eq!(inav.toe_seconds(), None);
+
+        assert_eq!(GalINAV::Word6(GalINAVWord6::model()).iod_nav(), None);
+    }
+
+    #[test]
+    fn utc_parameters() {
+        let word6 = GalINAVWord6::model();
+        let page = GalINAV::Word6(word6).encode_page();
+
+        let decoded = GalINAV::decode_page(&page).unwrap();
+        let params = decoded.utc_parameters().unwrap();
+
+        assert_eq!(params.a0, word6.a0);
+        assert_eq!(params.a1, word6.a1);
+        assert_eq!(params.tot_seconds, word6.tot_seconds);
+        assert_eq!(params.wnt, word6.week_ot);
+        assert_eq!(params.delta_t_ls, word6.delta_t_ls as i16);
+
+        // GST week that matches the 8-bit reference week
+        let (week, tow) = (
+            1024 + word6.week_ot as u32,
+            word6.tot_seconds as f64 + 100.0,
+        );
+        let offset = word6.delta_t_ls as f64 + word6.a0 + word6.a1 * 100.0;
+
+        let (utc_week, utc_tow) = params.to_utc(week, tow);
+        assert_eq!(utc_week, week);
+        assert!((utc_tow - (tow - offset)).abs() < 1e-9);
+
+        assert_eq!(GalINAV::Word1(GalINAVWord1::model()).utc_parameters(), None);
+    }
+
+    #[test]
+    fn invalid_pages() {
+        let mut page = GalINAV::Word1(GalINAVWord1::model()).encode_page();
+
+        // odd page first
+        page[0] |= 0x80;
+
+        match GalINAV::decode_page(&page) {
+            Err(GalileoError::InvalidPage) => {},
+            other => panic!("should have failed: {:?}", other),
+        }
+
+        let mut page = GalINAV::Word1(GalINAVWord1::model()).encode_page();
+
+        // alert page
+        page[0] |= 0x40;
+
+        match GalINAV::decode_page(&page) {
+            Err(GalileoError::AlertPage) => {},
+            other => panic!("should have failed: {:?}", other),
+        }
+    }
+}
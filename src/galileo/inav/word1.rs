@@ -0,0 +1,119 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits, to_unsigned_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord1] gives the first part of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord1 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Ephemeris reference time of week (in seconds)
+    pub toe_seconds: u32,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    pub m0: f64,
+
+    /// Eccentricity
+    pub e: f64,
+
+    /// Square root of semi-major axis (in m^1/2)
+    pub sqrt_a: f64,
+}
+
+impl GalINAVWord1 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_toe_seconds(43_200)
+            .with_m0(-1_234_567_890.0 * 2.0_f64.powi(-31))
+            .with_e(1_234_567.0 * 2.0_f64.powi(-33))
+            .with_sqrt_a(5_440.612_304_687_5)
+    }
+
+    /// Copies and returns [GalINAVWord1] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord1] with updated ephemeris reference time (in seconds).
+    /// Should be a multiple of 60 seconds, to be exactly encoded
+    pub fn with_toe_seconds(mut self, toe_seconds: u32) -> Self {
+        self.toe_seconds = toe_seconds;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord1] with updated mean anomaly (in semi-circles)
+    pub fn with_m0(mut self, m0: f64) -> Self {
+        self.m0 = m0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord1] with updated eccentricity
+    pub fn with_e(mut self, e: f64) -> Self {
+        self.e = e;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord1] with updated square root of semi-major axis (in m^1/2)
+    pub fn with_sqrt_a(mut self, sqrt_a: f64) -> Self {
+        self.sqrt_a = sqrt_a;
+        self
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(word, 6, 10) as u16,
+            toe_seconds: get_bits(word, 16, 14) * 60,
+            m0: get_signed_bits(word, 30, 32) as f64 * 2.0_f64.powi(-31),
+            e: get_bits(word, 62, 32) as f64 * 2.0_f64.powi(-33),
+            sqrt_a: get_bits(word, 94, 32) as f64 * 2.0_f64.powi(-19),
+        }
+    }
+
+    /// Encodes this [GalINAVWord1] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 1);
+        set_bits(word, 6, 10, self.iod_nav as u32);
+        set_bits(word, 16, 14, (self.toe_seconds / 60) & 0x3fff);
+        set_bits(
+            word,
+            30,
+            32,
+            to_signed_bits(self.m0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            word,
+            62,
+            32,
+            to_unsigned_bits(self.e / 2.0_f64.powi(-33), 32),
+        );
+        set_bits(
+            word,
+            94,
+            32,
+            to_unsigned_bits(self.sqrt_a / 2.0_f64.powi(-19), 32),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord1::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 1);
+        assert_eq!(GalINAVWord1::from_word(&word), model);
+    }
+}
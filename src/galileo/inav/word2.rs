@@ -0,0 +1,123 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord2] gives the orbital plane parameters.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord2 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Longitude of ascending node at weekly epoch (in semi-circles)
+    pub omega0: f64,
+
+    /// Inclination angle at reference time (in semi-circles)
+    pub i0: f64,
+
+    /// Argument of perigee (in semi-circles)
+    pub omega: f64,
+
+    /// Rate of inclination angle (in semi-circles.s⁻¹)
+    pub idot: f64,
+}
+
+impl GalINAVWord2 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_omega0(-987_654_321.0 * 2.0_f64.powi(-31))
+            .with_i0(654_321_987.0 * 2.0_f64.powi(-31))
+            .with_omega(1_357_924_680.0 * 2.0_f64.powi(-31))
+            .with_idot(-1_234.0 * 2.0_f64.powi(-43))
+    }
+
+    /// Copies and returns [GalINAVWord2] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord2] with updated longitude of ascending node (in semi-circles)
+    pub fn with_omega0(mut self, omega0: f64) -> Self {
+        self.omega0 = omega0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord2] with updated inclination angle (in semi-circles)
+    pub fn with_i0(mut self, i0: f64) -> Self {
+        self.i0 = i0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord2] with updated argument of perigee (in semi-circles)
+    pub fn with_omega(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord2] with updated rate of inclination angle (in semi-circles.s⁻¹)
+    pub fn with_idot(mut self, idot: f64) -> Self {
+        self.idot = idot;
+        self
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(word, 6, 10) as u16,
+            omega0: get_signed_bits(word, 16, 32) as f64 * 2.0_f64.powi(-31),
+            i0: get_signed_bits(word, 48, 32) as f64 * 2.0_f64.powi(-31),
+            omega: get_signed_bits(word, 80, 32) as f64 * 2.0_f64.powi(-31),
+            idot: get_signed_bits(word, 112, 14) as f64 * 2.0_f64.powi(-43),
+        }
+    }
+
+    /// Encodes this [GalINAVWord2] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 2);
+        set_bits(word, 6, 10, self.iod_nav as u32);
+        set_bits(
+            word,
+            16,
+            32,
+            to_signed_bits(self.omega0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            word,
+            48,
+            32,
+            to_signed_bits(self.i0 / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            word,
+            80,
+            32,
+            to_signed_bits(self.omega / 2.0_f64.powi(-31), 32),
+        );
+        set_bits(
+            word,
+            112,
+            14,
+            to_signed_bits(self.idot / 2.0_f64.powi(-43), 14),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord2::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 2);
+        assert_eq!(GalINAVWord2::from_word(&word), model);
+    }
+}
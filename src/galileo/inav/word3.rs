@@ -0,0 +1,169 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord3] gives the orbit corrections and the Signal In Space Accuracy.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord3 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// Rate of right ascension (in semi-circles.s⁻¹)
+    pub omega_dot: f64,
+
+    /// Mean motion difference (in semi-circles.s⁻¹)
+    pub delta_n: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the argument of latitude (in radians)
+    pub cuc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the argument of latitude (in radians)
+    pub cus: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the orbit radius (in meters)
+    pub crc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the orbit radius (in meters)
+    pub crs: f64,
+
+    /// Signal In Space Accuracy index (255 means no accuracy prediction available)
+    pub sisa: u8,
+}
+
+impl GalINAVWord3 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_omega_dot(-4_567_890.0 * 2.0_f64.powi(-43))
+            .with_delta_n(12_345.0 * 2.0_f64.powi(-43))
+            .with_cuc(-1_234.0 * 2.0_f64.powi(-29))
+            .with_cus(4_321.0 * 2.0_f64.powi(-29))
+            .with_crc(6_789.0 * 2.0_f64.powi(-5))
+            .with_crs(-987.0 * 2.0_f64.powi(-5))
+            .with_sisa(107)
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated rate of right ascension (in semi-circles.s⁻¹)
+    pub fn with_omega_dot(mut self, omega_dot: f64) -> Self {
+        self.omega_dot = omega_dot;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated mean motion difference (in semi-circles.s⁻¹)
+    pub fn with_delta_n(mut self, delta_n: f64) -> Self {
+        self.delta_n = delta_n;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated Cuc (in radians)
+    pub fn with_cuc(mut self, cuc: f64) -> Self {
+        self.cuc = cuc;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated Cus (in radians)
+    pub fn with_cus(mut self, cus: f64) -> Self {
+        self.cus = cus;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated Crc (in meters)
+    pub fn with_crc(mut self, crc: f64) -> Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated Crs (in meters)
+    pub fn with_crs(mut self, crs: f64) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord3] with updated SISA index
+    pub fn with_sisa(mut self, sisa: u8) -> Self {
+        self.sisa = sisa;
+        self
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(word, 6, 10) as u16,
+            omega_dot: get_signed_bits(word, 16, 24) as f64 * 2.0_f64.powi(-43),
+            delta_n: get_signed_bits(word, 40, 16) as f64 * 2.0_f64.powi(-43),
+            cuc: get_signed_bits(word, 56, 16) as f64 * 2.0_f64.powi(-29),
+            cus: get_signed_bits(word, 72, 16) as f64 * 2.0_f64.powi(-29),
+            crc: get_signed_bits(word, 88, 16) as f64 * 2.0_f64.powi(-5),
+            crs: get_signed_bits(word, 104, 16) as f64 * 2.0_f64.powi(-5),
+            sisa: get_bits(word, 120, 8) as u8,
+        }
+    }
+
+    /// Encodes this [GalINAVWord3] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 3);
+        set_bits(word, 6, 10, self.iod_nav as u32);
+        set_bits(
+            word,
+            16,
+            24,
+            to_signed_bits(self.omega_dot / 2.0_f64.powi(-43), 24),
+        );
+        set_bits(
+            word,
+            40,
+            16,
+            to_signed_bits(self.delta_n / 2.0_f64.powi(-43), 16),
+        );
+        set_bits(
+            word,
+            56,
+            16,
+            to_signed_bits(self.cuc / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            word,
+            72,
+            16,
+            to_signed_bits(self.cus / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            word,
+            88,
+            16,
+            to_signed_bits(self.crc / 2.0_f64.powi(-5), 16),
+        );
+        set_bits(
+            word,
+            104,
+            16,
+            to_signed_bits(self.crs / 2.0_f64.powi(-5), 16),
+        );
+        set_bits(word, 120, 8, self.sisa as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord3::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 3);
+        assert_eq!(GalINAVWord3::from_word(&word), model);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord4] gives the satellite clock correction.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord4 {
+    /// 10-bit Issue of Data (navigation batch)
+    pub iod_nav: u16,
+
+    /// 6-bit satellite ID
+    pub sv_id: u8,
+
+    /// Amplitude of the cosine harmonic correction term to the angle of inclination (in radians)
+    pub cic: f64,
+
+    /// Amplitude of the sine harmonic correction term to the angle of inclination (in radians)
+    pub cis: f64,
+
+    /// Clock reference time of week (in seconds)
+    pub toc_seconds: u32,
+
+    /// Clock offset (in seconds)
+    pub af0: f64,
+
+    /// Clock drift (in s.s⁻¹)
+    pub af1: f64,
+
+    /// Clock drift rate (in s.s⁻²)
+    pub af2: f64,
+}
+
+impl GalINAVWord4 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_iod_nav(0x155)
+            .with_sv_id(11)
+            .with_cic(-321.0 * 2.0_f64.powi(-29))
+            .with_cis(987.0 * 2.0_f64.powi(-29))
+            .with_toc_seconds(43_200)
+            .with_af0(-123_456_789.0 * 2.0_f64.powi(-34))
+            .with_af1(-12_345.0 * 2.0_f64.powi(-46))
+            .with_af2(3.0 * 2.0_f64.powi(-59))
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated 10-bit issue of data
+    pub fn with_iod_nav(mut self, iod_nav: u16) -> Self {
+        self.iod_nav = iod_nav & 0x3ff;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated 6-bit satellite ID
+    pub fn with_sv_id(mut self, sv_id: u8) -> Self {
+        self.sv_id = sv_id & 0x3f;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated Cic (in radians)
+    pub fn with_cic(mut self, cic: f64) -> Self {
+        self.cic = cic;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated Cis (in radians)
+    pub fn with_cis(mut self, cis: f64) -> Self {
+        self.cis = cis;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated clock reference time (in seconds).
+    /// Should be a multiple of 60 seconds, to be exactly encoded
+    pub fn with_toc_seconds(mut self, toc_seconds: u32) -> Self {
+        self.toc_seconds = toc_seconds;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated clock offset (in seconds)
+    pub fn with_af0(mut self, af0: f64) -> Self {
+        self.af0 = af0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated clock drift (in s.s⁻¹)
+    pub fn with_af1(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord4] with updated clock drift rate (in s.s⁻²)
+    pub fn with_af2(mut self, af2: f64) -> Self {
+        self.af2 = af2;
+        self
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            iod_nav: get_bits(word, 6, 10) as u16,
+            sv_id: get_bits(word, 16, 6) as u8,
+            cic: get_signed_bits(word, 22, 16) as f64 * 2.0_f64.powi(-29),
+            cis: get_signed_bits(word, 38, 16) as f64 * 2.0_f64.powi(-29),
+            toc_seconds: get_bits(word, 54, 14) * 60,
+            af0: get_signed_bits(word, 68, 31) as f64 * 2.0_f64.powi(-34),
+            af1: get_signed_bits(word, 99, 21) as f64 * 2.0_f64.powi(-46),
+            af2: get_signed_bits(word, 120, 6) as f64 * 2.0_f64.powi(-59),
+        }
+    }
+
+    /// Encodes this [GalINAVWord4] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 4);
+        set_bits(word, 6, 10, self.iod_nav as u32);
+        set_bits(word, 16, 6, self.sv_id as u32);
+        set_bits(
+            word,
+            22,
+            16,
+            to_signed_bits(self.cic / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(
+            word,
+            38,
+            16,
+            to_signed_bits(self.cis / 2.0_f64.powi(-29), 16),
+        );
+        set_bits(word, 54, 14, (self.toc_seconds / 60) & 0x3fff);
+        set_bits(
+            word,
+            68,
+            31,
+            to_signed_bits(self.af0 / 2.0_f64.powi(-34), 31),
+        );
+        set_bits(
+            word,
+            99,
+            21,
+            to_signed_bits(self.af1 / 2.0_f64.powi(-46), 21),
+        );
+        set_bits(
+            word,
+            120,
+            6,
+            to_signed_bits(self.af2 / 2.0_f64.powi(-59), 6),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord4::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 4);
+        assert_eq!(GalINAVWord4::from_word(&word), model);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits, to_unsigned_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord5] gives the ionospheric model, the group delays, the signal health and the time of week.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord5 {
+    /// Effective ionisation level 1st order parameter (in sfu)
+    pub ai0: f64,
+
+    /// Effective ionisation level 2nd order parameter (in sfu.degree⁻¹)
+    pub ai1: f64,
+
+    /// Effective ionisation level 3rd order parameter (in sfu.degree⁻²)
+    pub ai2: f64,
+
+    /// 5-bit ionospheric disturbance flags, for regions 1 (MSB) to 5
+    pub region_flags: u8,
+
+    /// E1-E5a Broadcast Group Delay (in seconds)
+    pub bgd_e1_e5a: f64,
+
+    /// E1-E5b Broadcast Group Delay (in seconds)
+    pub bgd_e1_e5b: f64,
+
+    /// 2-bit E5b signal health status
+    pub e5b_hs: u8,
+
+    /// 2-bit E1-B signal health status
+    pub e1b_hs: u8,
+
+    /// E5b data validity status: true when working without guarantee
+    pub e5b_dvs: bool,
+
+    /// E1-B data validity status: true when working without guarantee
+    pub e1b_dvs: bool,
+
+    /// 12-bit Galileo week number
+    pub week: u16,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+}
+
+impl GalINAVWord5 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_ai0(123.25)
+            .with_ai1(-123.0 * 2.0_f64.powi(-8))
+            .with_ai2(1_234.0 * 2.0_f64.powi(-15))
+            .with_region_flags(0x05)
+            .with_bgd_e1_e5a(-12.0 * 2.0_f64.powi(-32))
+            .with_bgd_e1_e5b(13.0 * 2.0_f64.powi(-32))
+            .with_e5b_hs(1)
+            .with_e1b_hs(2)
+            .with_e5b_dvs(true)
+            .with_e1b_dvs(false)
+            .with_week(1_234)
+            .with_tow(345_600)
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated ai0 (in sfu)
+    pub fn with_ai0(mut self, ai0: f64) -> Self {
+        self.ai0 = ai0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated ai1 (in sfu.degree⁻¹)
+    pub fn with_ai1(mut self, ai1: f64) -> Self {
+        self.ai1 = ai1;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated ai2 (in sfu.degree⁻²)
+    pub fn with_ai2(mut self, ai2: f64) -> Self {
+        self.ai2 = ai2;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated 5-bit ionospheric disturbance flags
+    pub fn with_region_flags(mut self, region_flags: u8) -> Self {
+        self.region_flags = region_flags & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated E1-E5a group delay (in seconds)
+    pub fn with_bgd_e1_e5a(mut self, bgd_e1_e5a: f64) -> Self {
+        self.bgd_e1_e5a = bgd_e1_e5a;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated E1-E5b group delay (in seconds)
+    pub fn with_bgd_e1_e5b(mut self, bgd_e1_e5b: f64) -> Self {
+        self.bgd_e1_e5b = bgd_e1_e5b;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated 2-bit E5b signal health status
+    pub fn with_e5b_hs(mut self, e5b_hs: u8) -> Self {
+        self.e5b_hs = e5b_hs & 0x3;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated 2-bit E1-B signal health status
+    pub fn with_e1b_hs(mut self, e1b_hs: u8) -> Self {
+        self.e1b_hs = e1b_hs & 0x3;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated E5b data validity status
+    pub fn with_e5b_dvs(mut self, e5b_dvs: bool) -> Self {
+        self.e5b_dvs = e5b_dvs;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated E1-B data validity status
+    pub fn with_e1b_dvs(mut self, e1b_dvs: bool) -> Self {
+        self.e1b_dvs = e1b_dvs;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated 12-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0xfff;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord5] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            ai0: get_bits(word, 6, 11) as f64 * 2.0_f64.powi(-2),
+            ai1: get_signed_bits(word, 17, 11) as f64 * 2.0_f64.powi(-8),
+            ai2: get_signed_bits(word, 28, 14) as f64 * 2.0_f64.powi(-15),
+            region_flags: get_bits(word, 42, 5) as u8,
+            bgd_e1_e5a: get_signed_bits(word, 47, 10) as f64 * 2.0_f64.powi(-32),
+            bgd_e1_e5b: get_signed_bits(word, 57, 10) as f64 * 2.0_f64.powi(-32),
+            e5b_hs: get_bits(word, 67, 2) as u8,
+            e1b_hs: get_bits(word, 69, 2) as u8,
+            e5b_dvs: get_bits(word, 71, 1) > 0,
+            e1b_dvs: get_bits(word, 72, 1) > 0,
+            week: get_bits(word, 73, 12) as u16,
+            tow: get_bits(word, 85, 20),
+        }
+    }
+
+    /// Encodes this [GalINAVWord5] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 5);
+        set_bits(
+            word,
+            6,
+            11,
+            to_unsigned_bits(self.ai0 / 2.0_f64.powi(-2), 11),
+        );
+        set_bits(
+            word,
+            17,
+            11,
+            to_signed_bits(self.ai1 / 2.0_f64.powi(-8), 11),
+        );
+        set_bits(
+            word,
+            28,
+            14,
+            to_signed_bits(self.ai2 / 2.0_f64.powi(-15), 14),
+        );
+        set_bits(word, 42, 5, self.region_flags as u32);
+        set_bits(
+            word,
+            47,
+            10,
+            to_signed_bits(self.bgd_e1_e5a / 2.0_f64.powi(-32), 10),
+        );
+        set_bits(
+            word,
+            57,
+            10,
+            to_signed_bits(self.bgd_e1_e5b / 2.0_f64.powi(-32), 10),
+        );
+        set_bits(word, 67, 2, self.e5b_hs as u32);
+        set_bits(word, 69, 2, self.e1b_hs as u32);
+        set_bits(word, 71, 1, self.e5b_dvs as u32);
+        set_bits(word, 72, 1, self.e1b_dvs as u32);
+        set_bits(word, 73, 12, self.week as u32);
+        set_bits(word, 85, 20, self.tow);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord5::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 5);
+        assert_eq!(GalINAVWord5::from_word(&word), model);
+    }
+}
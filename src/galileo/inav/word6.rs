@@ -0,0 +1,170 @@
+use crate::{
+    bits::{get_bits, get_signed_bits, set_bits, to_signed_bits},
+    UtcParameters,
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GalINAVWord6] gives the GST-UTC conversion parameters.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GalINAVWord6 {
+    /// Constant term of the polynomial describing the offset (in seconds)
+    pub a0: f64,
+
+    /// 1st order term of the polynomial describing the offset (in s.s⁻¹)
+    pub a1: f64,
+
+    /// Leap second count before leap second adjustment
+    pub delta_t_ls: i8,
+
+    /// UTC data reference time of week (in seconds)
+    pub tot_seconds: u32,
+
+    /// UTC data reference week number (modulo 256)
+    pub week_ot: u8,
+
+    /// Week number of leap second adjustment (modulo 256)
+    pub week_lsf: u8,
+
+    /// Day number (1 to 7) at the end of which the leap second adjustment becomes effective
+    pub day_number: u8,
+
+    /// Leap second count after leap second adjustment
+    pub delta_t_lsf: i8,
+
+    /// Time of week (in seconds)
+    pub tow: u32,
+}
+
+impl GalINAVWord6 {
+    /// Generates a realistic word model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_a0(-12_345.0 * 2.0_f64.powi(-30))
+            .with_a1(-3_456.0 * 2.0_f64.powi(-50))
+            .with_delta_t_ls(18)
+            .with_tot_seconds(86_400)
+            .with_week_ot(0x12)
+            .with_week_lsf(0x34)
+            .with_day_number(7)
+            .with_delta_t_lsf(19)
+            .with_tow(345_600)
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated constant term (in seconds)
+    pub fn with_a0(mut self, a0: f64) -> Self {
+        self.a0 = a0;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated 1st order term (in s.s⁻¹)
+    pub fn with_a1(mut self, a1: f64) -> Self {
+        self.a1 = a1;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated leap second count before adjustment
+    pub fn with_delta_t_ls(mut self, delta_t_ls: i8) -> Self {
+        self.delta_t_ls = delta_t_ls;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated UTC data reference time (in seconds).
+    /// Should be a multiple of 3600 seconds, to be exactly encoded
+    pub fn with_tot_seconds(mut self, tot_seconds: u32) -> Self {
+        self.tot_seconds = tot_seconds;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated UTC data reference week number
+    pub fn with_week_ot(mut self, week_ot: u8) -> Self {
+        self.week_ot = week_ot;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated week number of leap second adjustment
+    pub fn with_week_lsf(mut self, week_lsf: u8) -> Self {
+        self.week_lsf = week_lsf;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated day number of leap second adjustment
+    pub fn with_day_number(mut self, day_number: u8) -> Self {
+        self.day_number = day_number & 0x7;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated leap second count after adjustment
+    pub fn with_delta_t_lsf(mut self, delta_t_lsf: i8) -> Self {
+        self.delta_t_lsf = delta_t_lsf;
+        self
+    }
+
+    /// Copies and returns [GalINAVWord6] with updated time of week (in seconds)
+    pub fn with_tow(mut self, tow: u32) -> Self {
+        self.tow = tow & 0xfffff;
+        self
+    }
+
+    /// Returns the GST-UTC conversion [UtcParameters] broadcast by this word.
+    pub fn utc_parameters(&self) -> UtcParameters {
+        UtcParameters::default()
+            .with_a0_seconds(self.a0)
+            .with_a1_seconds_s(self.a1)
+            .with_tot_seconds(self.tot_seconds)
+            .with_wnt(self.week_ot)
+            .with_leap_seconds(self.delta_t_ls as i16)
+    }
+
+    /// Decodes [Self] from this 128-bit word.
+    pub(crate) fn from_word(word: &[u8]) -> Self {
+        Self {
+            a0: get_signed_bits(word, 6, 32) as f64 * 2.0_f64.powi(-30),
+            a1: get_signed_bits(word, 38, 24) as f64 * 2.0_f64.powi(-50),
+            delta_t_ls: get_signed_bits(word, 62, 8) as i8,
+            tot_seconds: get_bits(word, 70, 8) * 3600,
+            week_ot: get_bits(word, 78, 8) as u8,
+            week_lsf: get_bits(word, 86, 8) as u8,
+            day_number: get_bits(word, 94, 3) as u8,
+            delta_t_lsf: get_signed_bits(word, 97, 8) as i8,
+            tow: get_bits(word, 105, 20),
+        }
+    }
+
+    /// Encodes this [GalINAVWord6] into this 128-bit word (word type included).
+    pub(crate) fn to_word(self, word: &mut [u8]) {
+        set_bits(word, 0, 6, 6);
+        set_bits(word, 6, 32, to_signed_bits(self.a0 / 2.0_f64.powi(-30), 32));
+        set_bits(
+            word,
+            38,
+            24,
+            to_signed_bits(self.a1 / 2.0_f64.powi(-50), 24),
+        );
+        set_bits(word, 62, 8, (self.delta_t_ls as u32) & 0xff);
+        set_bits(word, 70, 8, (self.tot_seconds / 3600) & 0xff);
+        set_bits(word, 78, 8, self.week_ot as u32);
+        set_bits(word, 86, 8, self.week_lsf as u32);
+        set_bits(word, 94, 3, self.day_number as u32);
+        set_bits(word, 97, 8, (self.delta_t_lsf as u32) & 0xff);
+        set_bits(word, 105, 20, self.tow);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reciprocal() {
+        let model = GalINAVWord6::model();
+
+        let mut word = [0u8; 16];
+        model.to_word(&mut word);
+
+        assert_eq!(get_bits(&word, 0, 6), 6);
+        assert_eq!(GalINAVWord6::from_word(&word), model);
+    }
+}
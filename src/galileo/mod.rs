@@ -0,0 +1,38 @@
+mod errors;
+pub use errors::GalileoError;
+
+mod fnav;
+pub use fnav::{
+    GalFNavFrame, GalFNavPage1, GalFNavPage2, GalFNavPage3, GalFNavPage4, GalileoFnavDecoder,
+    GALILEO_FNAV_PAGE_BITS, GALILEO_FNAV_PAGE_BYTES, GALILEO_FNAV_SYNC_BITS,
+    GALILEO_FNAV_SYNC_PATTERN,
+};
+
+mod inav;
+pub use inav::{
+    GalINAV, GalINAVWord1, GalINAVWord2, GalINAVWord3, GalINAVWord4, GalINAVWord5, GalINAVWord6,
+    GalileoInavDecoder, GALILEO_INAV_PAGE_BITS, GALILEO_INAV_PAGE_BYTES,
+    GALILEO_INAV_PAGE_PART_BITS, GALILEO_INAV_WORD_BITS, GALILEO_INAV_WORD_BYTES,
+};
+
+/// [GalileoMessage] is any supported Galileo navigation message
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum GalileoMessage {
+    /// I/NAV message (E1-B and E5b-I)
+    INAV(GalINAV),
+
+    /// F/NAV message (E5a-I)
+    FNAV(GalFNavFrame),
+}
+
+impl GalileoMessage {
+    /// Encodes this [GalileoMessage] into mutable [u8] buffer directly,
+    /// see [GalINAV::encode_bits] and [GalFNavFrame::encode_bits].
+    pub fn encode_bits(&self, dest: &mut [u8]) -> Result<usize, GalileoError> {
+        match self {
+            Self::INAV(inav) => inav.encode_bits(dest),
+            Self::FNAV(fnav) => fnav.encode_bits(dest),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::glonass::{field, set_field, sign_magnitude, to_sign_magnitude};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GlonassAlmanach1] is the first half of the almanach of one satellite,
+/// transmitted in strings 6, 8, 10, 12 and 14.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassAlmanach1 {
+    /// Cn health flag: asserted means healthy
+    pub cn: bool,
+
+    /// 2-bit satellite type (0: GLONASS, 1: GLONASS-M)
+    pub mn: u8,
+
+    /// 5-bit orbital slot number of the described satellite
+    pub slot: u8,
+
+    /// Coarse satellite clock offset to GLONASS time (in seconds)
+    pub tau: f64,
+
+    /// Longitude of the first ascending node within the day (in semicircles)
+    pub lambda: f64,
+
+    /// Correction to the mean inclination of 63° (in semicircles)
+    pub delta_i: f64,
+
+    /// Eccentricity (dimensionless)
+    pub eccentricity: f64,
+}
+
+impl GlonassAlmanach1 {
+    /// Generates a realistic almanach model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_cn(true)
+            .with_mn(1)
+            .with_slot(5)
+            .with_tau_seconds(-12.0 * 2.0_f64.powi(-18))
+            .with_lambda_semicircles(-0.25)
+            .with_delta_inclination_semicircles(3.0 * 2.0_f64.powi(-12))
+            .with_eccentricity(400.0 * 2.0_f64.powi(-20))
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated Cn (healthy) flag
+    pub fn with_cn(mut self, cn: bool) -> Self {
+        self.cn = cn;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated 2-bit satellite type
+    pub fn with_mn(mut self, mn: u8) -> Self {
+        self.mn = mn & 0x03;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated 5-bit orbital slot number
+    pub fn with_slot(mut self, slot: u8) -> Self {
+        self.slot = slot & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated clock offset (in seconds)
+    pub fn with_tau_seconds(mut self, tau_seconds: f64) -> Self {
+        self.tau = tau_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated longitude of ascending node (in semicircles)
+    pub fn with_lambda_semicircles(mut self, lambda: f64) -> Self {
+        self.lambda = lambda;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated correction to inclination (in semicircles)
+    pub fn with_delta_inclination_semicircles(mut self, delta_i: f64) -> Self {
+        self.delta_i = delta_i;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach1] with updated eccentricity
+    pub fn with_eccentricity(mut self, e: f64) -> Self {
+        self.eccentricity = e;
+        self
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        Self {
+            cn: field(raw, 80, 80) > 0,
+            mn: field(raw, 79, 78) as u8,
+            slot: field(raw, 77, 73) as u8,
+            tau: sign_magnitude(field(raw, 72, 63), 10) as f64 * 2.0_f64.powi(-18),
+            lambda: sign_magnitude(field(raw, 62, 42), 21) as f64 * 2.0_f64.powi(-20),
+            delta_i: sign_magnitude(field(raw, 41, 24), 18) as f64 * 2.0_f64.powi(-20),
+            eccentricity: field(raw, 23, 9) as f64 * 2.0_f64.powi(-20),
+        }
+    }
+
+    /// Encodes this [GlonassAlmanach1] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        let tau = (self.tau * 2.0_f64.powi(18)).round() as i64;
+        let lambda = (self.lambda * 2.0_f64.powi(20)).round() as i64;
+        let delta_i = (self.delta_i * 2.0_f64.powi(20)).round() as i64;
+        let e = (self.eccentricity * 2.0_f64.powi(20)).round() as u32;
+
+        set_field(raw, 80, 80, self.cn as u32);
+        set_field(raw, 79, 78, self.mn as u32);
+        set_field(raw, 77, 73, self.slot as u32);
+        set_field(raw, 72, 63, to_sign_magnitude(tau, 10));
+        set_field(raw, 62, 42, to_sign_magnitude(lambda, 21));
+        set_field(raw, 41, 24, to_sign_magnitude(delta_i, 18));
+        set_field(raw, 23, 9, e);
+    }
+}
+
+/// [GlonassAlmanach2] is the second half of the almanach of one satellite,
+/// transmitted in strings 7, 9, 11, 13 and 15.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassAlmanach2 {
+    /// Argument of perigee (in semicircles)
+    pub omega: f64,
+
+    /// Time of the first ascending node passage within the day (in seconds)
+    pub t_lambda: f64,
+
+    /// Correction to the mean draconian period of 43200 s (in seconds per orbit)
+    pub delta_t: f64,
+
+    /// Rate of change of the draconian period (in seconds per orbit²)
+    pub delta_t_dot: f64,
+
+    /// 5-bit carrier frequency channel number
+    pub channel: u8,
+
+    /// ln health flag: asserted means unhealthy
+    pub ln: bool,
+}
+
+impl GlonassAlmanach2 {
+    /// Generates a realistic almanach model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_omega_semicircles(0.125)
+            .with_t_lambda_seconds(33_000.5)
+            .with_delta_t_seconds(-2_655.5)
+            .with_delta_t_dot_seconds(3.0 * 2.0_f64.powi(-14))
+            .with_channel(1)
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with updated argument of perigee (in semicircles)
+    pub fn with_omega_semicircles(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with updated time of ascending node passage (in seconds)
+    pub fn with_t_lambda_seconds(mut self, t_lambda: f64) -> Self {
+        self.t_lambda = t_lambda;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with updated draconian period correction (in seconds)
+    pub fn with_delta_t_seconds(mut self, delta_t: f64) -> Self {
+        self.delta_t = delta_t;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with updated draconian period rate (in seconds)
+    pub fn with_delta_t_dot_seconds(mut self, delta_t_dot: f64) -> Self {
+        self.delta_t_dot = delta_t_dot;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with updated 5-bit frequency channel number
+    pub fn with_channel(mut self, channel: u8) -> Self {
+        self.channel = channel & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with ln (unhealthy) flag asserted
+    pub fn with_ln(mut self) -> Self {
+        self.ln = true;
+        self
+    }
+
+    /// Copies and returns [GlonassAlmanach2] with ln (unhealthy) flag deasserted
+    pub fn without_ln(mut self) -> Self {
+        self.ln = false;
+        self
+    }
+
+    /// Returns the signed frequency channel number (-7 to +24).
+    /// The channel is transmitted on 5 bits, values 25 to 31 standing for -7 to -1.
+    pub fn frequency_channel(&self) -> i8 {
+        if self.channel > 24 {
+            self.channel as i8 - 32
+        } else {
+            self.channel as i8
+        }
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        Self {
+            omega: sign_magnitude(field(raw, 80, 65), 16) as f64 * 2.0_f64.powi(-15),
+            t_lambda: field(raw, 64, 44) as f64 * 2.0_f64.powi(-5),
+            delta_t: sign_magnitude(field(raw, 43, 22), 22) as f64 * 2.0_f64.powi(-9),
+            delta_t_dot: sign_magnitude(field(raw, 21, 15), 7) as f64 * 2.0_f64.powi(-14),
+            channel: field(raw, 14, 10) as u8,
+            ln: field(raw, 9, 9) > 0,
+        }
+    }
+
+    /// Encodes this [GlonassAlmanach2] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        let omega = (self.omega * 2.0_f64.powi(15)).round() as i64;
+        let t_lambda = (self.t_lambda * 2.0_f64.powi(5)).round() as u32;
+        let delta_t = (self.delta_t * 2.0_f64.powi(9)).round() as i64;
+        let delta_t_dot = (self.delta_t_dot * 2.0_f64.powi(14)).round() as i64;
+
+        set_field(raw, 80, 65, to_sign_magnitude(omega, 16));
+        set_field(raw, 64, 44, t_lambda);
+        set_field(raw, 43, 22, to_sign_magnitude(delta_t, 22));
+        set_field(raw, 21, 15, to_sign_magnitude(delta_t_dot, 7));
+        set_field(raw, 14, 10, self.channel as u32);
+        set_field(raw, 9, 9, self.ln as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GlonassAlmanach1, GlonassAlmanach2};
+
+    #[test]
+    fn encoding() {
+        for almanach in [
+            GlonassAlmanach1::model(),
+            GlonassAlmanach1::model()
+                .with_cn(false)
+                .with_slot(24)
+                .with_tau_seconds(511.0 * 2.0_f64.powi(-18))
+                .with_lambda_semicircles(0.875)
+                .with_delta_inclination_semicircles(-5.0 * 2.0_f64.powi(-20))
+                .with_eccentricity(31_000.0 * 2.0_f64.powi(-20)),
+        ] {
+            let mut raw = 0;
+            almanach.to_raw(&mut raw);
+            assert_eq!(GlonassAlmanach1::from_raw(raw), almanach);
+        }
+
+        for almanach in [
+            GlonassAlmanach2::model(),
+            GlonassAlmanach2::model()
+                .with_omega_semicircles(-0.5)
+                .with_t_lambda_seconds(44_000.0)
+                .with_delta_t_seconds(3.5)
+                .with_delta_t_dot_seconds(-63.0 * 2.0_f64.powi(-14))
+                .with_channel(30)
+                .with_ln(),
+        ] {
+            let mut raw = 0;
+            almanach.to_raw(&mut raw);
+            assert_eq!(GlonassAlmanach2::from_raw(raw), almanach);
+        }
+    }
+
+    #[test]
+    fn frequency_channel() {
+        for (channel, expected) in [(0, 0), (1, 1), (24, 24), (25, -7), (31, -1)] {
+            let almanach = GlonassAlmanach2::default().with_channel(channel);
+            assert_eq!(almanach.frequency_channel(), expected);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::glonass::{
+    hamming, GlonassString, GLONASS_LINE_BITS, GLONASS_STRING_BITS, GLONASS_TIME_MARK,
+    GLONASS_TIME_MARK_BITS,
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [GlonassDecoder] can decode GLONASS (L1OF/L2OF) navigation strings,
+/// from a stream of bits that was already stripped from the relative (meander) code.
+/// The stream is synchronized on the time mark, that ends each 2 second line:
+/// the string that follows a time mark is decoded.
+///
+/// By [Default], our [GlonassDecoder] verifies the Hamming code of each string,
+/// corrects single bit errors and rejects strings that can't be corrected.
+#[derive(Debug, Copy, Clone)]
+pub struct GlonassDecoder {
+    /// True when Hamming code verification is requested
+    hamming_verification: bool,
+}
+
+impl Default for GlonassDecoder {
+    /// Creates a default [GlonassDecoder] that verifies the Hamming code.
+    fn default() -> Self {
+        Self {
+            hamming_verification: true,
+        }
+    }
+}
+
+impl GlonassDecoder {
+    /// Copies and returns a [GlonassDecoder] that does not verify the Hamming code.
+    /// Every string is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_hamming_verification(mut self) -> Self {
+        self.hamming_verification = false;
+        self
+    }
+
+    /// Reads `nbits` (up to 128) starting at bit `offset` of this buffer, MSB first.
+    fn read_bits(buffer: &[u8], offset: usize, nbits: usize) -> u128 {
+        let mut value = 0u128;
+
+        for bit in offset..offset + nbits {
+            value <<= 1;
+            value |= ((buffer[bit / 8] >> (7 - (bit % 8))) & 0x01) as u128;
+        }
+
+        value
+    }
+
+    /// Locates the first time mark within this buffer, at any bit offset.
+    fn find_time_mark(buffer: &[u8], size: usize) -> Option<usize> {
+        let total_bits = size * 8;
+
+        if total_bits < GLONASS_TIME_MARK_BITS {
+            return None;
+        }
+
+        (0..=total_bits - GLONASS_TIME_MARK_BITS).find(|offset| {
+            Self::read_bits(buffer, *offset, GLONASS_TIME_MARK_BITS) == GLONASS_TIME_MARK as u128
+        })
+    }
+
+    /// Decodes the first valid [GlonassString] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same string twice.
+    /// - Optional [GlonassString] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When the buffer ends before the string does (partial read), nothing past the time mark
+    /// is consumed and [None] is returned: simply append the following bytes and call
+    /// [Self::decode] again. When no time mark was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next time mark.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<GlonassString>) {
+        let size = size.min(buffer.len());
+
+        let mark_offset_bit = match Self::find_time_mark(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(GLONASS_TIME_MARK_BITS - 1), None);
+            },
+        };
+
+        let string_offset_bit = mark_offset_bit + GLONASS_TIME_MARK_BITS;
+        let string_end = string_offset_bit + GLONASS_STRING_BITS;
+
+        // wait for the complete string
+        if string_end > size * 8 {
+            return (mark_offset_bit, None);
+        }
+
+        #[cfg(feature = "log")]
+        trace!("(GLONASS) [time mark]: pos={}", mark_offset_bit);
+
+        let raw = Self::read_bits(buffer, string_offset_bit, GLONASS_STRING_BITS);
+
+        let raw = if self.hamming_verification {
+            match hamming::decode(raw) {
+                Ok(raw) => raw,
+                #[cfg(not(feature = "log"))]
+                Err(_) => {
+                    return (string_end, None);
+                },
+                #[cfg(feature = "log")]
+                Err(e) => {
+                    error!("(GLONASS) [hamming]: {}", e);
+                    return (string_end, None);
+                },
+            }
+        } else {
+            raw
+        };
+
+        match GlonassString::from_raw(raw) {
+            Ok(string) => (string_end, Some(string)),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (string_end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(GLONASS) [string]: {}", e);
+                (string_end, None)
+            },
+        }
+    }
+
+    /// Returns the number of bits between two consecutive strings of a synchronous stream
+    pub const fn line_bits() -> usize {
+        GLONASS_LINE_BITS
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        glonass::{GlonassDecoder, GlonassString, GLONASS_LINE_BITS, GLONASS_TIME_MARK},
+        testing::insert_bit_delay,
+    };
+
+    /// Concatenates (bitwise) a time mark then the lines of these strings,
+    /// like a synchronous stream does.
+    fn stream(strings: &[GlonassString]) -> Vec<u8> {
+        let mut bits = Vec::new();
+
+        for bit in (0..30).rev() {
+            bits.push(((GLONASS_TIME_MARK >> bit) & 0x01) as u8);
+        }
+
+        for string in strings {
+            let line = string.encode_line();
+
+            for bit in 0..GLONASS_LINE_BITS {
+                bits.push((line[bit / 8] >> (7 - (bit % 8))) & 0x01);
+            }
+        }
+
+        bits.chunks(8)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, bit)| byte | (bit << (7 - i)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let strings = (1..=15).map(GlonassString::model).collect::<Vec<_>>();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&strings), delay);
+
+            let mut decoder = GlonassDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, string) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(string) = string {
+                    decoded.push(string.string_number());
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(
+                decoded,
+                (1..=15).collect::<Vec<_>>(),
+                "failed with {} bit delay",
+                delay
+            );
+        }
+    }
+
+    #[test]
+    fn partial_read() {
+        let buffer = stream(&[GlonassString::model(1)]);
+
+        let mut decoder = GlonassDecoder::default();
+
+        // string ends at bit 115
+        let (processed, string) = decoder.decode(&buffer, 14);
+        assert_eq!(processed, 0);
+        assert!(string.is_none());
+
+        let (processed, string) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, 30 + 85);
+        assert_eq!(string, Some(GlonassString::model(1)));
+    }
+
+    #[test]
+    fn hamming_correction() {
+        let string = GlonassString::model(3);
+
+        // corrupt one data bit of the string
+        let mut buffer = stream(&[string]);
+        buffer[8] ^= 0x10;
+
+        let mut decoder = GlonassDecoder::default();
+        let (_, decoded) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(decoded, Some(string));
+
+        // corrupt a second bit: can't be corrected
+        buffer[9] ^= 0x01;
+
+        let (processed, decoded) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, 30 + 85);
+        assert!(decoded.is_none());
+
+        // unless verification is disabled
+        let mut decoder = GlonassDecoder::default().without_hamming_verification();
+        let (_, decoded) = decoder.decode(&buffer, buffer.len());
+        assert!(decoded.is_some());
+        assert_ne!(decoded, Some(string));
+    }
+
+    #[test]
+    fn no_time_mark() {
+        let buffer = [0u8; 32];
+        let mut decoder = GlonassDecoder::default();
+
+        let (processed, string) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, 32 * 8 - 29);
+        assert!(string.is_none());
+    }
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum GlonassError {
+    /// String number is either invalid or not supported
+    #[error("unknown GLONASS string number")]
+    UnknownStringNumber,
+
+    /// Hamming code verification failed and the string could not be corrected
+    #[error("invalid hamming code")]
+    Hamming,
+
+    /// Size is too small to encode a correct string
+    #[error("buffer to small for this GLONASS string")]
+    WouldNotFit,
+}
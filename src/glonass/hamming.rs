@@ -0,0 +1,164 @@
+//! GLONASS strings are protected by a Hamming code (KX): 7 check bits
+//! (β1 to β7, string bits 1 to 7) that cover the 77 data bits (string bits 9 to 85),
+//! and one overall parity bit (β8, string bit 8).
+//! This allows correcting any single bit error and detecting double errors.
+
+use crate::glonass::{GlonassError, GLONASS_STRING_BITS};
+
+/// First data bit number
+const FIRST_DATA_BIT: u32 = 9;
+
+/// Number of Hamming check bits (overall parity bit excluded)
+const CHECK_BITS: u32 = 7;
+
+/// Returns the Hamming position of this data bit (9 to 85):
+/// its rank when skipping all powers of two.
+fn position(data_bit: u32) -> u32 {
+    let mut position = 2u32;
+    let mut bit = FIRST_DATA_BIT - 1;
+
+    while bit < data_bit {
+        position += 1;
+
+        if !position.is_power_of_two() {
+            bit += 1;
+        }
+    }
+
+    position
+}
+
+/// Returns the data bit number described by this Hamming position, if any
+fn data_bit(position: u32) -> Option<u32> {
+    if position < 3 || position.is_power_of_two() {
+        return None;
+    }
+
+    let data_bit = position + FIRST_DATA_BIT - 2 - (31 - position.leading_zeros());
+
+    if data_bit as usize > GLONASS_STRING_BITS {
+        None
+    } else {
+        Some(data_bit)
+    }
+}
+
+/// Returns the 7-bit syndrome (c1 to c7) computed over data bits,
+/// and the state of the overall parity check (cΣ).
+fn syndrome(raw: u128) -> (u32, bool) {
+    let mut syndrome = 0;
+
+    for data_bit in FIRST_DATA_BIT..=GLONASS_STRING_BITS as u32 {
+        if raw & (1 << (data_bit - 1)) > 0 {
+            syndrome ^= position(data_bit);
+        }
+    }
+
+    // check bits
+    syndrome ^= (raw & ((1 << CHECK_BITS) - 1)) as u32;
+
+    let overall = raw.count_ones() % 2 == 1;
+
+    (syndrome, overall)
+}
+
+/// Calculates and inserts the 8-bit Hamming code (string bits 1 to 8)
+/// into this raw string.
+pub(crate) fn encode(raw: u128) -> u128 {
+    let mut raw = raw & !0xff;
+
+    let (syndrome, _) = syndrome(raw);
+    raw |= syndrome as u128;
+
+    if raw.count_ones() % 2 == 1 {
+        raw |= 1 << CHECK_BITS;
+    }
+
+    raw
+}
+
+/// Verifies the Hamming code of this raw string.
+/// Single bit errors are corrected, when the error affects a data bit.
+/// Returns the (possibly corrected) raw string.
+pub(crate) fn decode(raw: u128) -> Result<u128, GlonassError> {
+    let (syndrome, overall) = syndrome(raw);
+
+    match (syndrome, overall) {
+        (0, false) => Ok(raw),
+        // single error in one of the check bits
+        (0, true) => Ok(raw),
+        (syndrome, true) if syndrome.is_power_of_two() => Ok(raw),
+        // single error in one of the data bits
+        (syndrome, true) => {
+            let data_bit = data_bit(syndrome).ok_or(GlonassError::Hamming)?;
+            Ok(raw ^ (1 << (data_bit - 1)))
+        },
+        // double error
+        (_, false) => Err(GlonassError::Hamming),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        assert_eq!(position(9), 3);
+        assert_eq!(position(10), 5);
+        assert_eq!(position(12), 7);
+        assert_eq!(position(13), 9);
+        assert_eq!(position(35), 33);
+        assert_eq!(position(65), 63);
+        assert_eq!(position(66), 65);
+        assert_eq!(position(85), 84);
+
+        for bit in FIRST_DATA_BIT..=GLONASS_STRING_BITS as u32 {
+            assert_eq!(data_bit(position(bit)), Some(bit));
+        }
+
+        assert_eq!(data_bit(64), None);
+        assert_eq!(data_bit(85), None);
+    }
+
+    #[test]
+    fn check_bit_coverage() {
+        // β1 covers these data bits, per the ICD
+        let beta1 = [
+            9, 10, 12, 13, 15, 17, 19, 20, 22, 24, 26, 28, 30, 32, 34, 35, 37, 39, 41, 43, 45, 47,
+            49, 51, 53, 55, 57, 59, 61, 63, 65, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84,
+        ];
+
+        for bit in FIRST_DATA_BIT..=GLONASS_STRING_BITS as u32 {
+            assert_eq!(position(bit) & 0x01 > 0, beta1.contains(&bit));
+            // β6 covers 35 to 65, β7 covers 66 to 85
+            assert_eq!(position(bit) & 0x20 > 0, (35..=65).contains(&bit));
+            assert_eq!(position(bit) & 0x40 > 0, (66..=85).contains(&bit));
+        }
+    }
+
+    #[test]
+    fn correction() {
+        let raw = encode(0x0a5a_5a5a_5a5a_5a5a_5a5a_5a00);
+        assert_eq!(decode(raw).unwrap(), raw);
+
+        for bit in 1..=GLONASS_STRING_BITS as u32 {
+            let corrupt = raw ^ (1 << (bit - 1));
+
+            let decoded =
+                decode(corrupt).unwrap_or_else(|e| panic!("failed to correct bit #{}: {}", bit, e));
+
+            if bit > 8 {
+                assert_eq!(decoded, raw, "failed to correct bit #{}", bit);
+            } else {
+                // check bit errors do not affect the data
+                assert_eq!(decoded & !0xff, raw & !0xff);
+            }
+        }
+
+        for (bit1, bit2) in [(9, 10), (20, 85), (3, 50)] {
+            let corrupt = raw ^ (1 << (bit1 - 1)) ^ (1 << (bit2 - 1));
+            assert!(decode(corrupt).is_err(), "double error not detected");
+        }
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// GLONASS string size (in bits!), including the idle bit and the 8-bit Hamming code
+pub const GLONASS_STRING_BITS: usize = 85;
+
+/// GLONASS string size (in bytes!), once padded to [u8]
+pub const GLONASS_STRING_BYTES: usize = (GLONASS_STRING_BITS + 7) / 8;
+
+/// GLONASS time mark (shortened pseudo random sequence), transmitted
+/// at the end of each string, that we use to synchronize the stream.
+pub const GLONASS_TIME_MARK: u32 = 0x3E37_5096;
+
+/// GLONASS time mark size (in bits!)
+pub const GLONASS_TIME_MARK_BITS: usize = 30;
+
+/// GLONASS line size (in bits!): one string followed by one time mark,
+/// transmitted every 2 seconds.
+pub const GLONASS_LINE_BITS: usize = GLONASS_STRING_BITS + GLONASS_TIME_MARK_BITS;
+
+/// GLONASS line size (in bytes!), once padded to [u8]
+pub const GLONASS_LINE_BYTES: usize = (GLONASS_LINE_BITS + 7) / 8;
+
+mod almanach;
+pub use almanach::{GlonassAlmanach1, GlonassAlmanach2};
+
+mod decoder;
+pub use decoder::GlonassDecoder;
+
+mod errors;
+pub use errors::GlonassError;
+
+mod hamming;
+
+mod string;
+pub use string::GlonassString;
+
+mod string1;
+pub use string1::GlonassString1;
+
+mod string2;
+pub use string2::GlonassString2;
+
+mod string3;
+pub use string3::GlonassString3;
+
+mod string4;
+pub use string4::GlonassString4;
+
+mod string5;
+pub use string5::GlonassString5;
+
+/// Extracts the field spanning string bits `msb` down to `lsb` (included),
+/// bits being numbered from 85 (first transmitted) to 1 (last transmitted), like the ICD does.
+pub(crate) fn field(raw: u128, msb: u32, lsb: u32) -> u32 {
+    let mask = (1u128 << (msb - lsb + 1)) - 1;
+    ((raw >> (lsb - 1)) & mask) as u32
+}
+
+/// Encodes `value` in the field spanning string bits `msb` down to `lsb` (included).
+pub(crate) fn set_field(raw: &mut u128, msb: u32, lsb: u32, value: u32) {
+    let mask = (1u128 << (msb - lsb + 1)) - 1;
+    *raw &= !(mask << (lsb - 1));
+    *raw |= ((value as u128) & mask) << (lsb - 1);
+}
+
+/// Interprets this `bits`-bit field, which GLONASS encodes as sign and magnitude
+/// (the MSB is the sign bit).
+pub(crate) fn sign_magnitude(value: u32, bits: u32) -> i64 {
+    let magnitude = (value & ((1 << (bits - 1)) - 1)) as i64;
+
+    if value & (1 << (bits - 1)) > 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Encodes this signed value as `bits`-bit sign and magnitude field.
+/// The magnitude saturates to the field capacity.
+pub(crate) fn to_sign_magnitude(value: i64, bits: u32) -> u32 {
+    let max = (1u64 << (bits - 1)) - 1;
+    let magnitude = value.unsigned_abs().min(max) as u32;
+
+    if value < 0 {
+        magnitude | (1 << (bits - 1))
+    } else {
+        magnitude
+    }
+}
+
+/// Decodes the position, velocity and acceleration of strings 1 to 3,
+/// along one axis: (position in km, velocity in km/s, acceleration in km/s²).
+pub(crate) fn axis(raw: u128) -> (f64, f64, f64) {
+    let velocity = sign_magnitude(field(raw, 64, 41), 24) as f64 * 2.0_f64.powi(-20);
+    let acceleration = sign_magnitude(field(raw, 40, 36), 5) as f64 * 2.0_f64.powi(-30);
+    let position = sign_magnitude(field(raw, 35, 9), 27) as f64 * 2.0_f64.powi(-11);
+    (position, velocity, acceleration)
+}
+
+/// Encodes the position (km), velocity (km/s) and acceleration (km/s²)
+/// of strings 1 to 3, along one axis.
+pub(crate) fn set_axis(raw: &mut u128, position: f64, velocity: f64, acceleration: f64) {
+    let velocity = (velocity * 2.0_f64.powi(20)).round() as i64;
+    let acceleration = (acceleration * 2.0_f64.powi(30)).round() as i64;
+    let position = (position * 2.0_f64.powi(11)).round() as i64;
+
+    set_field(raw, 64, 41, to_sign_magnitude(velocity, 24));
+    set_field(raw, 40, 36, to_sign_magnitude(acceleration, 5));
+    set_field(raw, 35, 9, to_sign_magnitude(position, 27));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn glonass_properties() {
+        assert_eq!(GLONASS_STRING_BYTES, 11);
+        assert_eq!(GLONASS_LINE_BITS, 115);
+        assert_eq!(GLONASS_LINE_BYTES, 15);
+    }
+
+    #[test]
+    fn fields() {
+        let mut raw = 0u128;
+        set_field(&mut raw, 84, 81, 0x5);
+        set_field(&mut raw, 35, 9, 0x7ff_ffff);
+        assert_eq!(field(raw, 84, 81), 0x5);
+        assert_eq!(field(raw, 35, 9), 0x7ff_ffff);
+        assert_eq!(field(raw, 8, 1), 0);
+        assert_eq!(field(raw, 85, 85), 0);
+    }
+
+    #[test]
+    fn sign_magnitude() {
+        for (value, bits, encoded) in [
+            (0, 5, 0x00),
+            (1, 5, 0x01),
+            (-1, 5, 0x11),
+            (15, 5, 0x0f),
+            (-15, 5, 0x1f),
+            (-100, 24, 0x80_0064),
+        ] {
+            assert_eq!(to_sign_magnitude(value, bits), encoded);
+            assert_eq!(super::sign_magnitude(encoded, bits), value);
+        }
+
+        // saturates
+        assert_eq!(to_sign_magnitude(16, 5), 0x0f);
+        assert_eq!(to_sign_magnitude(-16, 5), 0x1f);
+    }
+}
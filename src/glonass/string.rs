@@ -0,0 +1,220 @@
+use crate::glonass::{
+    field, hamming, set_field, GlonassAlmanach1, GlonassAlmanach2, GlonassError, GlonassString1,
+    GlonassString2, GlonassString3, GlonassString4, GlonassString5, GLONASS_LINE_BITS,
+    GLONASS_LINE_BYTES, GLONASS_STRING_BITS, GLONASS_STRING_BYTES, GLONASS_TIME_MARK,
+    GLONASS_TIME_MARK_BITS,
+};
+
+/// GLONASS interpreted navigation string.
+/// Strings 1 to 5 describe the transmitting satellite (immediate data),
+/// strings 6 to 15 describe the almanach of the constellation (non-immediate data).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GlonassString {
+    /// X coordinate and time of frame start
+    String1(GlonassString1),
+
+    /// Y coordinate, health and ephemeris reference time
+    String2(GlonassString2),
+
+    /// Z coordinate and frequency deviation
+    String3(GlonassString3),
+
+    /// Satellite clock and identification
+    String4(GlonassString4),
+
+    /// Time scale corrections
+    String5(GlonassString5),
+
+    /// First half of a satellite almanach, along with its string number (6, 8, 10, 12 or 14)
+    Almanach1(u8, GlonassAlmanach1),
+
+    /// Second half of a satellite almanach, along with its string number (7, 9, 11, 13 or 15)
+    Almanach2(u8, GlonassAlmanach2),
+}
+
+impl Default for GlonassString {
+    /// Builds a default [GlonassString::String1]
+    fn default() -> Self {
+        Self::String1(Default::default())
+    }
+}
+
+impl GlonassString {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model(string_number: u8) -> Self {
+        match string_number {
+            1 => Self::String1(GlonassString1::model()),
+            2 => Self::String2(GlonassString2::model()),
+            3 => Self::String3(GlonassString3::model()),
+            4 => Self::String4(GlonassString4::model()),
+            5 => Self::String5(GlonassString5::model()),
+            n if n % 2 == 0 => Self::Almanach1(n, GlonassAlmanach1::model()),
+            n => Self::Almanach2(n, GlonassAlmanach2::model()),
+        }
+    }
+
+    /// Returns the string number (1 to 15) of this [GlonassString]
+    pub fn string_number(&self) -> u8 {
+        match self {
+            Self::String1(_) => 1,
+            Self::String2(_) => 2,
+            Self::String3(_) => 3,
+            Self::String4(_) => 4,
+            Self::String5(_) => 5,
+            Self::Almanach1(number, _) => *number,
+            Self::Almanach2(number, _) => *number,
+        }
+    }
+
+    /// Returns total number of bytes needed to encode this [GlonassString] to binary
+    /// aligned to [u8]
+    pub const fn encoding_size() -> usize {
+        GLONASS_STRING_BYTES
+    }
+
+    /// Returns exact number of bits needed to encode this [GlonassString]
+    pub const fn encoding_bits() -> usize {
+        GLONASS_STRING_BITS
+    }
+
+    /// Interprets this raw string, bit 85 (first transmitted) being the MSB.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Result<Self, GlonassError> {
+        match field(raw, 84, 81) {
+            1 => Ok(Self::String1(GlonassString1::from_raw(raw))),
+            2 => Ok(Self::String2(GlonassString2::from_raw(raw))),
+            3 => Ok(Self::String3(GlonassString3::from_raw(raw))),
+            4 => Ok(Self::String4(GlonassString4::from_raw(raw))),
+            5 => Ok(Self::String5(GlonassString5::from_raw(raw))),
+            n @ (6 | 8 | 10 | 12 | 14) => {
+                Ok(Self::Almanach1(n as u8, GlonassAlmanach1::from_raw(raw)))
+            },
+            n @ (7 | 9 | 11 | 13 | 15) => {
+                Ok(Self::Almanach2(n as u8, GlonassAlmanach2::from_raw(raw)))
+            },
+            _ => Err(GlonassError::UnknownStringNumber),
+        }
+    }
+
+    /// Encodes this [GlonassString] as raw string, with Hamming code.
+    pub(crate) fn to_raw(self) -> u128 {
+        let mut raw = 0;
+
+        set_field(&mut raw, 84, 81, self.string_number() as u32);
+
+        match self {
+            Self::String1(string) => string.to_raw(&mut raw),
+            Self::String2(string) => string.to_raw(&mut raw),
+            Self::String3(string) => string.to_raw(&mut raw),
+            Self::String4(string) => string.to_raw(&mut raw),
+            Self::String5(string) => string.to_raw(&mut raw),
+            Self::Almanach1(_, almanach) => almanach.to_raw(&mut raw),
+            Self::Almanach2(_, almanach) => almanach.to_raw(&mut raw),
+        }
+
+        hamming::encode(raw)
+    }
+
+    /// Decodes a [GlonassString] from its 85 bits, left aligned in 11 bytes
+    /// (the 3 LSB of the final byte are padding). This is how most receivers
+    /// report GLONASS strings. The Hamming code is verified and single bit errors
+    /// are corrected. When working with a raw stream of bits, you will prefer
+    /// working with the [GlonassDecoder](crate::GlonassDecoder).
+    pub fn decode(bytes: &[u8; GLONASS_STRING_BYTES]) -> Result<Self, GlonassError> {
+        let mut value = [0; 16];
+        value[16 - GLONASS_STRING_BYTES..].copy_from_slice(bytes);
+
+        let raw = u128::from_be_bytes(value) >> (GLONASS_STRING_BYTES * 8 - GLONASS_STRING_BITS);
+        let raw = hamming::decode(raw)?;
+
+        Self::from_raw(raw)
+    }
+
+    /// Encodes this [GlonassString] as 85 bits, left aligned in 11 bytes,
+    /// Hamming code included. The 3 LSB of the final byte are padding bits, set to zeros.
+    pub fn encode_raw(&self) -> [u8; GLONASS_STRING_BYTES] {
+        let raw = self.to_raw() << (GLONASS_STRING_BYTES * 8 - GLONASS_STRING_BITS);
+
+        let mut encoded = [0; GLONASS_STRING_BYTES];
+        encoded.copy_from_slice(&raw.to_be_bytes()[16 - GLONASS_STRING_BYTES..]);
+        encoded
+    }
+
+    /// Encodes this [GlonassString] as a complete 115-bit line: the string
+    /// followed by the time mark, as transmitted every 2 seconds.
+    /// The 5 LSB of the final byte are padding bits, set to zeros.
+    pub fn encode_line(&self) -> [u8; GLONASS_LINE_BYTES] {
+        let mut raw = self.to_raw() << GLONASS_TIME_MARK_BITS;
+        raw |= GLONASS_TIME_MARK as u128;
+        raw <<= GLONASS_LINE_BYTES * 8 - GLONASS_LINE_BITS;
+
+        let mut encoded = [0; GLONASS_LINE_BYTES];
+        encoded.copy_from_slice(&raw.to_be_bytes()[16 - GLONASS_LINE_BYTES..]);
+        encoded
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::glonass::{GlonassError, GlonassString, GLONASS_TIME_MARK};
+
+    #[test]
+    fn reciprocal() {
+        for string_number in 1..=15 {
+            let string = GlonassString::model(string_number);
+            assert_eq!(string.string_number(), string_number);
+
+            let encoded = string.encode_raw();
+            assert_eq!(encoded[0] & 0x80, 0, "idle bit should be zero");
+            assert_eq!(encoded[0] >> 3, string_number);
+            assert_eq!(encoded[10] & 0x07, 0, "padding should be zero");
+
+            let decoded = GlonassString::decode(&encoded).unwrap_or_else(|e| {
+                panic!("failed to decode string #{}: {}", string_number, e);
+            });
+
+            match (decoded, string) {
+                (GlonassString::String4(decoded), GlonassString::String4(string)) => {
+                    assert_eq!(decoded.slot, string.slot);
+                    assert!((decoded.tau - string.tau).abs() < 1e-9);
+                },
+                (decoded, string) => assert_eq!(decoded, string),
+            }
+        }
+    }
+
+    #[test]
+    fn line() {
+        let string = GlonassString::model(2);
+        let encoded = string.encode_raw();
+        let line = string.encode_line();
+
+        assert_eq!(line[..10], encoded[..10]);
+
+        let mark = u32::from_be_bytes([line[10], line[11], line[12], line[13]]);
+        let mark = (((mark as u64) << 8 | line[14] as u64) >> 5) & 0x3fff_ffff;
+        assert_eq!(mark as u32, GLONASS_TIME_MARK);
+    }
+
+    #[test]
+    fn unknown_string_number() {
+        let mut encoded = GlonassString::model(1).encode_raw();
+
+        // string number #0, then fix the hamming code
+        encoded[0] &= 0x87;
+        let raw = u128::from_be_bytes({
+            let mut bytes = [0; 16];
+            bytes[5..].copy_from_slice(&encoded);
+            bytes
+        }) >> 3;
+
+        let raw = super::hamming::encode(raw) << 3;
+        encoded.copy_from_slice(&raw.to_be_bytes()[5..]);
+
+        assert!(matches!(
+            GlonassString::decode(&encoded),
+            Err(GlonassError::UnknownStringNumber)
+        ));
+    }
+}
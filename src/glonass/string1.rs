@@ -0,0 +1,116 @@
+use crate::glonass::{axis, field, set_axis, set_field};
+
+/// [GlonassString1] gives the X coordinate of the satellite (PZ-90),
+/// and the time of frame start.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassString1 {
+    /// 2-bit P1 flag: time interval between two adjacent tb values
+    pub p1: u8,
+
+    /// Time of frame start, within current day (in seconds)
+    pub tk_seconds: u32,
+
+    /// X coordinate (in km)
+    pub x: f64,
+
+    /// X velocity (in km.s⁻¹)
+    pub dx: f64,
+
+    /// X acceleration, due to lunar and solar gravitation (in km.s⁻²)
+    pub ddx: f64,
+}
+
+impl GlonassString1 {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_p1(1)
+            .with_tk_seconds(12 * 3600 + 34 * 60 + 30)
+            .with_x_km(-14_523.287_109_375)
+            .with_dx_km_s(2_460_123.0 * 2.0_f64.powi(-20))
+            .with_ddx_km_s2(-2.0 * 2.0_f64.powi(-30))
+    }
+
+    /// Copies and returns [GlonassString1] with updated 2-bit P1 flag
+    pub fn with_p1(mut self, p1: u8) -> Self {
+        self.p1 = p1 & 0x03;
+        self
+    }
+
+    /// Copies and returns [GlonassString1] with updated time of frame start (in seconds of day).
+    /// Should be a multiple of 30 seconds, to be exactly encoded.
+    pub fn with_tk_seconds(mut self, tk_seconds: u32) -> Self {
+        self.tk_seconds = tk_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassString1] with updated X coordinate (in km)
+    pub fn with_x_km(mut self, x_km: f64) -> Self {
+        self.x = x_km;
+        self
+    }
+
+    /// Copies and returns [GlonassString1] with updated X velocity (in km.s⁻¹)
+    pub fn with_dx_km_s(mut self, dx_km_s: f64) -> Self {
+        self.dx = dx_km_s;
+        self
+    }
+
+    /// Copies and returns [GlonassString1] with updated X acceleration (in km.s⁻²)
+    pub fn with_ddx_km_s2(mut self, ddx_km_s2: f64) -> Self {
+        self.ddx = ddx_km_s2;
+        self
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        let hours = field(raw, 76, 72);
+        let minutes = field(raw, 71, 66);
+        let half_minute = field(raw, 65, 65);
+
+        let (x, dx, ddx) = axis(raw);
+
+        Self {
+            p1: field(raw, 78, 77) as u8,
+            tk_seconds: hours * 3600 + minutes * 60 + half_minute * 30,
+            x,
+            dx,
+            ddx,
+        }
+    }
+
+    /// Encodes this [GlonassString1] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        set_field(raw, 78, 77, self.p1 as u32);
+        set_field(raw, 76, 72, self.tk_seconds / 3600);
+        set_field(raw, 71, 66, (self.tk_seconds % 3600) / 60);
+        set_field(raw, 65, 65, (self.tk_seconds % 60) / 30);
+        set_axis(raw, self.x, self.dx, self.ddx);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GlonassString1;
+
+    #[test]
+    fn encoding() {
+        for string1 in [
+            GlonassString1::model(),
+            GlonassString1::model()
+                .with_p1(3)
+                .with_tk_seconds(23 * 3600 + 59 * 60)
+                .with_x_km(20_000.0)
+                .with_dx_km_s(-3.5)
+                .with_ddx_km_s2(7.0 * 2.0_f64.powi(-30)),
+        ] {
+            let mut raw = 0;
+            string1.to_raw(&mut raw);
+
+            let decoded = GlonassString1::from_raw(raw);
+            assert_eq!(decoded, string1);
+        }
+    }
+}
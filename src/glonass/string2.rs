@@ -0,0 +1,129 @@
+use crate::glonass::{axis, field, set_axis, set_field};
+
+/// [GlonassString2] gives the Y coordinate of the satellite (PZ-90),
+/// the satellite health and the ephemeris reference time.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassString2 {
+    /// 3-bit Bn health flag. Only the MSB is meaningful: asserted means unhealthy.
+    pub health: u8,
+
+    /// P2 flag: parity of tb (asserted when odd)
+    pub p2: bool,
+
+    /// Ephemeris reference time, within current day (in minutes)
+    pub tb_minutes: u16,
+
+    /// Y coordinate (in km)
+    pub y: f64,
+
+    /// Y velocity (in km.s⁻¹)
+    pub dy: f64,
+
+    /// Y acceleration, due to lunar and solar gravitation (in km.s⁻²)
+    pub ddy: f64,
+}
+
+impl GlonassString2 {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_health(0)
+            .with_p2(true)
+            .with_tb_minutes(765)
+            .with_y_km(8_765.5)
+            .with_dy_km_s(-1.125)
+            .with_ddy_km_s2(3.0 * 2.0_f64.powi(-30))
+    }
+
+    /// Copies and returns [GlonassString2] with updated 3-bit Bn health flag
+    pub fn with_health(mut self, health: u8) -> Self {
+        self.health = health & 0x07;
+        self
+    }
+
+    /// Copies and returns [GlonassString2] with updated P2 flag
+    pub fn with_p2(mut self, p2: bool) -> Self {
+        self.p2 = p2;
+        self
+    }
+
+    /// Copies and returns [GlonassString2] with updated reference time (in minutes of day).
+    /// Should be a multiple of 15 minutes, to be exactly encoded.
+    pub fn with_tb_minutes(mut self, tb_minutes: u16) -> Self {
+        self.tb_minutes = tb_minutes;
+        self
+    }
+
+    /// Copies and returns [GlonassString2] with updated Y coordinate (in km)
+    pub fn with_y_km(mut self, y_km: f64) -> Self {
+        self.y = y_km;
+        self
+    }
+
+    /// Copies and returns [GlonassString2] with updated Y velocity (in km.s⁻¹)
+    pub fn with_dy_km_s(mut self, dy_km_s: f64) -> Self {
+        self.dy = dy_km_s;
+        self
+    }
+
+    /// Copies and returns [GlonassString2] with updated Y acceleration (in km.s⁻²)
+    pub fn with_ddy_km_s2(mut self, ddy_km_s2: f64) -> Self {
+        self.ddy = ddy_km_s2;
+        self
+    }
+
+    /// Returns true if this satellite is declared healthy
+    pub fn is_healthy(&self) -> bool {
+        self.health & 0x04 == 0
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        let (y, dy, ddy) = axis(raw);
+
+        Self {
+            health: field(raw, 80, 78) as u8,
+            p2: field(raw, 77, 77) > 0,
+            tb_minutes: (field(raw, 76, 70) * 15) as u16,
+            y,
+            dy,
+            ddy,
+        }
+    }
+
+    /// Encodes this [GlonassString2] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        set_field(raw, 80, 78, self.health as u32);
+        set_field(raw, 77, 77, self.p2 as u32);
+        set_field(raw, 76, 70, (self.tb_minutes / 15) as u32);
+        set_axis(raw, self.y, self.dy, self.ddy);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GlonassString2;
+
+    #[test]
+    fn encoding() {
+        for string2 in [
+            GlonassString2::model(),
+            GlonassString2::model()
+                .with_health(0x04)
+                .with_p2(false)
+                .with_tb_minutes(1425)
+                .with_y_km(-25_000.0)
+                .with_dy_km_s(0.5)
+                .with_ddy_km_s2(-15.0 * 2.0_f64.powi(-30)),
+        ] {
+            let mut raw = 0;
+            string2.to_raw(&mut raw);
+
+            let decoded = GlonassString2::from_raw(raw);
+            assert_eq!(decoded, string2);
+            assert_eq!(decoded.is_healthy(), string2.health == 0);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::glonass::{axis, field, set_axis, set_field, sign_magnitude, to_sign_magnitude};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GlonassString3] gives the Z coordinate of the satellite (PZ-90),
+/// and the relative deviation of the carrier frequency.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassString3 {
+    /// P3 flag: asserted when 5 almanach satellites are transmitted in this frame
+    pub p3: bool,
+
+    /// Relative deviation of the carrier frequency, from its nominal value (dimensionless)
+    pub gamma: f64,
+
+    /// 2-bit P flag: GLONASS-M operation mode
+    pub p: u8,
+
+    /// ln health flag: asserted means unhealthy
+    pub ln: bool,
+
+    /// Z coordinate (in km)
+    pub z: f64,
+
+    /// Z velocity (in km.s⁻¹)
+    pub dz: f64,
+
+    /// Z acceleration, due to lunar and solar gravitation (in km.s⁻²)
+    pub ddz: f64,
+}
+
+impl GlonassString3 {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_p3(true)
+            .with_gamma(-3.0 * 2.0_f64.powi(-40))
+            .with_p(2)
+            .with_z_km(18_123.75)
+            .with_dz_km_s(0.25)
+            .with_ddz_km_s2(-(2.0_f64.powi(-30)))
+    }
+
+    /// Copies and returns [GlonassString3] with updated P3 flag
+    pub fn with_p3(mut self, p3: bool) -> Self {
+        self.p3 = p3;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with updated relative frequency deviation
+    pub fn with_gamma(mut self, gamma: f64) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with updated 2-bit P flag
+    pub fn with_p(mut self, p: u8) -> Self {
+        self.p = p & 0x03;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with ln (unhealthy) flag asserted
+    pub fn with_ln(mut self) -> Self {
+        self.ln = true;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with ln (unhealthy) flag deasserted
+    pub fn without_ln(mut self) -> Self {
+        self.ln = false;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with updated Z coordinate (in km)
+    pub fn with_z_km(mut self, z_km: f64) -> Self {
+        self.z = z_km;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with updated Z velocity (in km.s⁻¹)
+    pub fn with_dz_km_s(mut self, dz_km_s: f64) -> Self {
+        self.dz = dz_km_s;
+        self
+    }
+
+    /// Copies and returns [GlonassString3] with updated Z acceleration (in km.s⁻²)
+    pub fn with_ddz_km_s2(mut self, ddz_km_s2: f64) -> Self {
+        self.ddz = ddz_km_s2;
+        self
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        let (z, dz, ddz) = axis(raw);
+
+        Self {
+            p3: field(raw, 80, 80) > 0,
+            gamma: sign_magnitude(field(raw, 79, 69), 11) as f64 * 2.0_f64.powi(-40),
+            p: field(raw, 67, 66) as u8,
+            ln: field(raw, 65, 65) > 0,
+            z,
+            dz,
+            ddz,
+        }
+    }
+
+    /// Encodes this [GlonassString3] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        let gamma = (self.gamma * 2.0_f64.powi(40)).round() as i64;
+
+        set_field(raw, 80, 80, self.p3 as u32);
+        set_field(raw, 79, 69, to_sign_magnitude(gamma, 11));
+        set_field(raw, 67, 66, self.p as u32);
+        set_field(raw, 65, 65, self.ln as u32);
+        set_axis(raw, self.z, self.dz, self.ddz);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GlonassString3;
+
+    #[test]
+    fn encoding() {
+        for string3 in [
+            GlonassString3::model(),
+            GlonassString3::model()
+                .with_p3(false)
+                .with_gamma(1023.0 * 2.0_f64.powi(-40))
+                .with_p(1)
+                .with_ln()
+                .with_z_km(-1.0)
+                .with_dz_km_s(-3.0)
+                .with_ddz_km_s2(0.0),
+        ] {
+            let mut raw = 0;
+            string3.to_raw(&mut raw);
+
+            let decoded = GlonassString3::from_raw(raw);
+            assert_eq!(decoded, string3);
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::glonass::{field, set_field, sign_magnitude, to_sign_magnitude};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GlonassString4] gives the satellite clock correction
+/// and the satellite identification.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassString4 {
+    /// Satellite clock offset to GLONASS time (in seconds)
+    pub tau: f64,
+
+    /// Time difference between L2 and L1 transmissions (in seconds)
+    pub delta_tau: f64,
+
+    /// 5-bit age of the ephemeris data (in days)
+    pub en_days: u8,
+
+    /// P4 flag: asserted when ephemeris data was updated in this frame
+    pub p4: bool,
+
+    /// 4-bit FT: predicted user range accuracy index
+    pub ft: u8,
+
+    /// 11-bit calendar day number within the four-year interval
+    pub nt_days: u16,
+
+    /// 5-bit orbital slot number of this satellite
+    pub slot: u8,
+
+    /// 2-bit M: satellite type (0: GLONASS, 1: GLONASS-M)
+    pub m: u8,
+}
+
+impl GlonassString4 {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_tau_seconds(-12_345.0 * 2.0_f64.powi(-30))
+            .with_delta_tau_seconds(4.0 * 2.0_f64.powi(-30))
+            .with_en_days(2)
+            .with_p4(true)
+            .with_ft(3)
+            .with_nt_days(1_024)
+            .with_slot(17)
+            .with_m(1)
+    }
+
+    /// Copies and returns [GlonassString4] with updated clock offset (in seconds)
+    pub fn with_tau_seconds(mut self, tau_seconds: f64) -> Self {
+        self.tau = tau_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated L2/L1 time difference (in seconds)
+    pub fn with_delta_tau_seconds(mut self, delta_tau_seconds: f64) -> Self {
+        self.delta_tau = delta_tau_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated 5-bit age of data (in days)
+    pub fn with_en_days(mut self, en_days: u8) -> Self {
+        self.en_days = en_days & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated P4 flag
+    pub fn with_p4(mut self, p4: bool) -> Self {
+        self.p4 = p4;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated 4-bit accuracy index
+    pub fn with_ft(mut self, ft: u8) -> Self {
+        self.ft = ft & 0x0f;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated 11-bit day number
+    pub fn with_nt_days(mut self, nt_days: u16) -> Self {
+        self.nt_days = nt_days & 0x7ff;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated 5-bit orbital slot number
+    pub fn with_slot(mut self, slot: u8) -> Self {
+        self.slot = slot & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GlonassString4] with updated 2-bit satellite type
+    pub fn with_m(mut self, m: u8) -> Self {
+        self.m = m & 0x03;
+        self
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        Self {
+            tau: sign_magnitude(field(raw, 80, 59), 22) as f64 * 2.0_f64.powi(-30),
+            delta_tau: sign_magnitude(field(raw, 58, 54), 5) as f64 * 2.0_f64.powi(-30),
+            en_days: field(raw, 53, 49) as u8,
+            p4: field(raw, 34, 34) > 0,
+            ft: field(raw, 33, 30) as u8,
+            nt_days: field(raw, 26, 16) as u16,
+            slot: field(raw, 15, 11) as u8,
+            m: field(raw, 10, 9) as u8,
+        }
+    }
+
+    /// Encodes this [GlonassString4] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        let tau = (self.tau * 2.0_f64.powi(30)).round() as i64;
+        let delta_tau = (self.delta_tau * 2.0_f64.powi(30)).round() as i64;
+
+        set_field(raw, 80, 59, to_sign_magnitude(tau, 22));
+        set_field(raw, 58, 54, to_sign_magnitude(delta_tau, 5));
+        set_field(raw, 53, 49, self.en_days as u32);
+        set_field(raw, 34, 34, self.p4 as u32);
+        set_field(raw, 33, 30, self.ft as u32);
+        set_field(raw, 26, 16, self.nt_days as u32);
+        set_field(raw, 15, 11, self.slot as u32);
+        set_field(raw, 10, 9, self.m as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GlonassString4;
+
+    #[test]
+    fn encoding() {
+        for string4 in [
+            GlonassString4::model(),
+            GlonassString4::model()
+                .with_tau_seconds(1.0e-4)
+                .with_delta_tau_seconds(-2.0 * 2.0_f64.powi(-30))
+                .with_en_days(31)
+                .with_p4(false)
+                .with_ft(15)
+                .with_nt_days(1461)
+                .with_slot(24)
+                .with_m(0),
+        ] {
+            let mut raw = 0;
+            string4.to_raw(&mut raw);
+
+            let decoded = GlonassString4::from_raw(raw);
+
+            assert!((decoded.tau - string4.tau).abs() <= 2.0_f64.powi(-31));
+            assert_eq!(decoded.delta_tau, string4.delta_tau);
+            assert_eq!(decoded.en_days, string4.en_days);
+            assert_eq!(decoded.p4, string4.p4);
+            assert_eq!(decoded.ft, string4.ft);
+            assert_eq!(decoded.nt_days, string4.nt_days);
+            assert_eq!(decoded.slot, string4.slot);
+            assert_eq!(decoded.m, string4.m);
+        }
+    }
+}
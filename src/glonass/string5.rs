@@ -0,0 +1,120 @@
+use crate::glonass::{field, set_field, sign_magnitude, to_sign_magnitude};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GlonassString5] gives the GLONASS time scale corrections,
+/// to UTC(SU) and GPS time.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GlonassString5 {
+    /// 11-bit calendar day number within the four-year interval (almanach reference)
+    pub na_days: u16,
+
+    /// GLONASS time scale correction to UTC(SU) (in seconds)
+    pub tau_c: f64,
+
+    /// 5-bit four-year interval number, starting from 1996
+    pub n4: u8,
+
+    /// Fractional part of the GPS / GLONASS time difference (in seconds)
+    pub tau_gps: f64,
+
+    /// ln health flag: asserted means unhealthy
+    pub ln: bool,
+}
+
+impl GlonassString5 {
+    /// Generates a realistic string model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_na_days(1_024)
+            .with_tau_c_seconds(-25.0 * 2.0_f64.powi(-31))
+            .with_n4(8)
+            .with_tau_gps_seconds(100.0 * 2.0_f64.powi(-30))
+    }
+
+    /// Copies and returns [GlonassString5] with updated 11-bit day number
+    pub fn with_na_days(mut self, na_days: u16) -> Self {
+        self.na_days = na_days & 0x7ff;
+        self
+    }
+
+    /// Copies and returns [GlonassString5] with updated UTC(SU) correction (in seconds)
+    pub fn with_tau_c_seconds(mut self, tau_c_seconds: f64) -> Self {
+        self.tau_c = tau_c_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassString5] with updated 5-bit four-year interval number
+    pub fn with_n4(mut self, n4: u8) -> Self {
+        self.n4 = n4 & 0x1f;
+        self
+    }
+
+    /// Copies and returns [GlonassString5] with updated GPS time correction (in seconds)
+    pub fn with_tau_gps_seconds(mut self, tau_gps_seconds: f64) -> Self {
+        self.tau_gps = tau_gps_seconds;
+        self
+    }
+
+    /// Copies and returns [GlonassString5] with ln (unhealthy) flag asserted
+    pub fn with_ln(mut self) -> Self {
+        self.ln = true;
+        self
+    }
+
+    /// Copies and returns [GlonassString5] with ln (unhealthy) flag deasserted
+    pub fn without_ln(mut self) -> Self {
+        self.ln = false;
+        self
+    }
+
+    /// Decodes [Self] from this raw string.
+    /// This method does not care for the Hamming code.
+    pub(crate) fn from_raw(raw: u128) -> Self {
+        Self {
+            na_days: field(raw, 80, 70) as u16,
+            tau_c: sign_magnitude(field(raw, 69, 38), 32) as f64 * 2.0_f64.powi(-31),
+            n4: field(raw, 36, 32) as u8,
+            tau_gps: sign_magnitude(field(raw, 31, 10), 22) as f64 * 2.0_f64.powi(-30),
+            ln: field(raw, 9, 9) > 0,
+        }
+    }
+
+    /// Encodes this [GlonassString5] data bits into raw string
+    pub(crate) fn to_raw(self, raw: &mut u128) {
+        let tau_c = (self.tau_c * 2.0_f64.powi(31)).round() as i64;
+        let tau_gps = (self.tau_gps * 2.0_f64.powi(30)).round() as i64;
+
+        set_field(raw, 80, 70, self.na_days as u32);
+        set_field(raw, 69, 38, to_sign_magnitude(tau_c, 32));
+        set_field(raw, 36, 32, self.n4 as u32);
+        set_field(raw, 31, 10, to_sign_magnitude(tau_gps, 22));
+        set_field(raw, 9, 9, self.ln as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GlonassString5;
+
+    #[test]
+    fn encoding() {
+        for string5 in [
+            GlonassString5::model(),
+            GlonassString5::model()
+                .with_na_days(1)
+                .with_tau_c_seconds(0.5)
+                .with_n4(31)
+                .with_tau_gps_seconds(-(2.0_f64.powi(-30)))
+                .with_ln(),
+        ] {
+            let mut raw = 0;
+            string5.to_raw(&mut raw);
+
+            let decoded = GlonassString5::from_raw(raw);
+            assert_eq!(decoded, string5);
+        }
+    }
+}
@@ -1,68 +1,316 @@
 use crate::{
-    gps::{GpsDataWord, GPS_WORDS_PER_FRAME},
+    bits::BitReader,
+    gps::{GpsDataWord, GpsQzssAlmanachStatus, GpsSatelliteVehicle, GPS_WORDS_PER_FRAME},
+    time::modulo_delta,
     twos_complement,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+const WORD3_DATA_ID_SHIFT: u32 = 28;
+const WORD3_SV_ID_MASK: u32 = 0x0fc00000;
+const WORD3_SV_ID_SHIFT: u32 = 22;
+const WORD3_E_SHIFT: u32 = 6;
+
+const WORD4_TOA_SHIFT: u32 = 22;
+const WORD4_DI_SHIFT: u32 = 6;
+
+const WORD5_OMEGADOT_SHIFT: u32 = 14;
+const WORD5_HEALTH_SHIFT: u32 = 6;
+
+const WORD6_SQRTA_SHIFT: u32 = 6;
+
+const WORD7_OMEGA0_SHIFT: u32 = 6;
+
+const WORD8_OMEGA_SHIFT: u32 = 6;
+
+const WORD9_M0_SHIFT: u32 = 6;
+
+const WORD10_AF0_MSB_SHIFT: u32 = 22;
+const WORD10_AF1_SHIFT: u32 = 11;
+const WORD10_AF0_LSB_SHIFT: u32 = 8;
+
+/// Reference inclination of the GPS orbital planes (in semicircles),
+/// the almanach only transmits the correction to this value
+const REFERENCE_INCLINATION_SEMICIRCLES: f64 = 0.3;
+
+/// 2-bit Data ID of the current navigation message structure
+const DATA_ID: u8 = 0x01;
+
 /// [GpsQzssAlmanach] frame found in some reconfigured Frame-4 pages (when reconfigured),
 /// or Frame-5 page 1 to 24.
+#[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssAlmanach {
-    /// Eccentricity (16 bit)
-    pub eccentricity: f32,
+    /// 6-bit SV ID
+    pub sv_id: u8,
+
+    /// Eccentricity (dimensionless)
+    pub eccentricity: f64,
 
     /// Time of issue of Almanach (in seconds)
-    pub toa_seconds: u8,
+    pub toa_seconds: u32,
 
-    /// Mean motion difference from computed value (in semi-circles)
-    pub di: f32,
+    /// Correction to inclination (in semicircles)
+    pub di: f64,
 
-    /// Omega_dot (in semi circles.s⁻¹)
-    pub omega_dot: f32,
+    /// Rate of right ascension (in semicircles.s⁻¹).
+    /// Transmitted on 16 bits with 2^-38 scaling, which is coarser than
+    /// the 24-bit 2^-43 scaling of [GpsQzssFrame3](crate::GpsQzssFrame3).
+    pub omega_dot: f64,
 
     /// SV health (8-bit)
     pub sv_health: u8,
 
     /// Square root of semi-major axis, in square root of meters.
-    pub sqrt_a: f32,
+    pub sqrt_a: f64,
+
+    /// Longitude of ascending node of orbit plane at weekly epoch (in semicircles)
+    pub omega0: f64,
 
-    /// Longitude of ascending node of orbit plane at weekly epoch (in semi circles)
-    pub omega0: f32,
+    /// Argument of perigee (in semicircles)
+    pub omega: f64,
 
-    /// Omega (in semi circles)
-    pub omega: f32,
-    
-    /// Mean anomaly at reference time (in semi-circles)
-    pub m0: f32,
+    /// Mean anomaly at reference time (in semicircles)
+    pub m0: f64,
 
-    /// af1 (in seconds per second)
+    /// 11-bit af1 (in seconds per second)
     pub af1: f64,
 
-    /// 22-bit af0 (in seconds)
+    /// 11-bit af0 (in seconds)
     pub af0: f64,
 }
 
 impl PartialEq for GpsQzssAlmanach {
     fn eq(&self, rhs: &Self) -> bool {
+        if self.sv_id != rhs.sv_id {
+            return false;
+        }
+
+        if (self.eccentricity - rhs.eccentricity).abs() > 1e-6 {
+            return false;
+        }
+
+        if self.toa_seconds != rhs.toa_seconds {
+            return false;
+        }
+
+        if (self.di - rhs.di).abs() > 1e-5 {
+            return false;
+        }
+
+        if (self.omega_dot - rhs.omega_dot).abs() > 1e-11 {
+            return false;
+        }
+
+        if self.sv_health != rhs.sv_health {
+            return false;
+        }
+
+        if (self.sqrt_a - rhs.sqrt_a).abs() > 1e-3 {
+            return false;
+        }
+
+        if (self.omega0 - rhs.omega0).abs() > 1e-6 {
+            return false;
+        }
+
+        if (self.omega - rhs.omega).abs() > 1e-6 {
+            return false;
+        }
+
+        if (self.m0 - rhs.m0).abs() > 1e-6 {
+            return false;
+        }
+
+        if (self.af0 - rhs.af0).abs() > 1e-6 {
+            return false;
+        }
+
+        if (self.af1 - rhs.af1).abs() > 1e-11 {
+            return false;
+        }
+
         true
     }
 }
 
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssAlmanach {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "SV={} - TOA={}s - HEALTH=0x{:02X} - E={:.9} - SQRT(A)={:.6}m^1/2 - DI={:.9}sc - OMEGA0={:.9}sc - OMEGA={:.9}sc - OMEGA_DOT={:.6e}sc/s - M0={:.9}sc - AF0={:.6e}s - AF1={:.6e}s/s",
+            self.sv_id,
+            self.toa_seconds,
+            self.sv_health,
+            self.eccentricity,
+            self.sqrt_a,
+            self.di,
+            self.omega0,
+            self.omega,
+            self.omega_dot,
+            self.m0,
+            self.af0,
+            self.af1,
+        )
+    }
+}
+
 impl GpsQzssAlmanach {
+    /// Generates a realistic frame model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_sv_id(1)
+            .with_eccentricity(1.0e-2)
+            .with_toa_seconds(319_488)
+            .with_delta_inclination_semicircles(1.0e-2)
+            .with_omega_dot_semicircles_s(-2.6e-9)
+            .with_sv_health(0)
+            .with_square_root_semi_major_axis(5153.6)
+            .with_longitude_ascending_node_semicircles(-0.4)
+            .with_omega_semicircles(0.3)
+            .with_mean_anomaly_semicircles(0.6)
+            .with_clock_offset_seconds(1.0e-4)
+            .with_clock_drift_seconds_s(-1.0e-11)
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated 6-bit SV ID
+    pub fn with_sv_id(mut self, sv_id: u8) -> Self {
+        self.sv_id = sv_id & 0x3f;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated eccentricity
+    pub fn with_eccentricity(mut self, e: f64) -> Self {
+        self.eccentricity = e;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated time of issue (in seconds).
+    /// Should be a multiple of 4096 seconds, to be exactly encoded.
+    pub fn with_toa_seconds(mut self, toa_seconds: u32) -> Self {
+        self.toa_seconds = toa_seconds;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated correction to inclination (in semicircles)
+    pub fn with_delta_inclination_semicircles(mut self, di: f64) -> Self {
+        self.di = di;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated rate of right ascension (in semicircles.s⁻¹)
+    pub fn with_omega_dot_semicircles_s(mut self, omega_dot: f64) -> Self {
+        self.omega_dot = omega_dot;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated 8-bit SV health
+    pub fn with_sv_health(mut self, health: u8) -> Self {
+        self.sv_health = health;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated square root of semi-major axis (in square root of meters)
+    pub fn with_square_root_semi_major_axis(mut self, sqrt_a: f64) -> Self {
+        self.sqrt_a = sqrt_a;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated longitude of ascending node (in semicircles)
+    pub fn with_longitude_ascending_node_semicircles(mut self, angle_semicircles: f64) -> Self {
+        self.omega0 = angle_semicircles;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated argument of perigee (in semicircles)
+    pub fn with_omega_semicircles(mut self, angle_semicircles: f64) -> Self {
+        self.omega = angle_semicircles;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated mean anomaly (in semicircles)
+    pub fn with_mean_anomaly_semicircles(mut self, m0_semicircles: f64) -> Self {
+        self.m0 = m0_semicircles;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated clock offset (in seconds)
+    pub fn with_clock_offset_seconds(mut self, af0_seconds: f64) -> Self {
+        self.af0 = af0_seconds;
+        self
+    }
+
+    /// Copies and returns [GpsQzssAlmanach] with updated clock drift (in seconds.s⁻¹)
+    pub fn with_clock_drift_seconds_s(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Returns the PRN number of the satellite this [GpsQzssAlmanach] describes.
+    /// The 6-bit SV ID of Almanach pages is the satellite PRN (1 to 32),
+    /// for Frame-4 pages 2 to 5 and 7 to 10 as well as Frame-5 pages 1 to 24.
+    /// 0 means dummy satellite, refer to [Self::is_dummy].
+    pub fn prn(&self) -> u8 {
+        self.sv_id
+    }
+
+    /// Returns true when this [GpsQzssAlmanach] is a dummy page (SV ID = 0),
+    /// which is transmitted when no satellite is assigned to this page.
+    pub fn is_dummy(&self) -> bool {
+        self.sv_id == 0
+    }
+
+    /// Returns the [GpsSatelliteVehicle] this GPS [GpsQzssAlmanach] describes,
+    /// at this GPS week number (latest known assignment when [None]).
+    /// Refer to [GpsSatelliteVehicle::from_prn].
+    pub fn svn(&self, gps_week: Option<u32>) -> Option<GpsSatelliteVehicle> {
+        GpsSatelliteVehicle::from_prn(self.prn(), gps_week)
+    }
+
+    /// Returns the reference instant of this [GpsQzssAlmanach], as
+    /// (GPS week number, time of week in seconds). The time of week is the 8-bit toa
+    /// (2^12 seconds resolution), the week is the 8-bit reference week number of the
+    /// [GpsQzssAlmanachStatus] (Frame-5 page 25), which is resolved using `gps_week`:
+    /// the current GPS week number, that should be within 127 weeks of the reference week.
+    pub fn reference_time(&self, status: &GpsQzssAlmanachStatus, gps_week: u32) -> (u32, u32) {
+        let weeks = modulo_delta(gps_week, status.week as u32, 256);
+        let week = (gps_week as i64 - weeks as i64).max(0) as u32;
+        (week, self.toa_seconds)
+    }
+
+    /// Returns the orbit inclination (in semicircles), which is the reference
+    /// inclination (0.3 semicircles) corrected by the transmitted delta inclination.
+    pub fn inclination_semicircles(&self) -> f64 {
+        REFERENCE_INCLINATION_SEMICIRCLES + self.di
+    }
+
+    /// Returns the orbit inclination (in radians), refer to [Self::inclination_semicircles].
+    pub fn inclination_radians(&self) -> f64 {
+        self.inclination_semicircles() * core::f64::consts::PI
+    }
 
     /// Decodes [Self] from 8 [GpsDataWord]s.
     /// This method does not care for frames parity.
     pub(crate) fn from_words(words: &[GpsDataWord]) -> Self {
         let mut s = Self::default();
 
-        for i in 0..GPS_WORDS_PER_FRAME - 2 {
+        for (i, word) in words.iter().enumerate().take(GPS_WORDS_PER_FRAME - 2) {
             match i {
-                0 => s.set_word3(Word3::from_word(words[i])),
-                1 => s.set_word4(Word4::from_word(words[i])),
-                2 => s.set_word5(Word5::from_word(words[i])),
-                3 => s.set_word6(Word6::from_word(words[i])),
-                4 => s.set_word7(Word7::from_word(words[i])),
-                5 => s.set_word8(Word8::from_word(words[i])),
-                6 => s.set_word9(Word9::from_word(words[i])),
-                7 => s.set_word10(Word10::from_word(words[i])),
+                0 => s.set_word3(Word3::from_word(*word)),
+                1 => s.set_word4(Word4::from_word(*word)),
+                2 => s.set_word5(Word5::from_word(*word)),
+                3 => s.set_word6(Word6::from_word(*word)),
+                4 => s.set_word7(Word7::from_word(*word)),
+                5 => s.set_word8(Word8::from_word(*word)),
+                6 => s.set_word9(Word9::from_word(*word)),
+                7 => s.set_word10(Word10::from_word(*word)),
                 _ => unreachable!("expecting 8 data words"),
             }
         }
@@ -70,120 +318,104 @@ impl GpsQzssAlmanach {
         s
     }
 
-    /// Updates scaled content from [Word3]
     fn set_word3(&mut self, word: Word3) {
-        self.week = word.week;
-        self.ura = word.ura;
-        self.ca_or_p_l2 = word.ca_or_p_l2;
-        self.health = word.health;
-        self.iodc = (word.iodc_msb as u16) << 8;
+        self.sv_id = word.sv_id;
+        self.eccentricity = (word.e as f64) / 2.0_f64.powi(21);
     }
 
-    /// Encodes a [Word3] from [GpsQzssAlmanach]
     fn word3(&self) -> Word3 {
         Word3 {
-            week: self.week,
-            ura: self.ura,
-            health: self.health,
-            ca_or_p_l2: self.ca_or_p_l2,
-            iodc_msb: ((self.iodc & 0x300) >> 8) as u8,
+            data_id: DATA_ID,
+            sv_id: self.sv_id,
+            e: (self.eccentricity * 2.0_f64.powi(21)).round() as u16,
         }
     }
 
-    /// Updates scaled content from [Word4]
     fn set_word4(&mut self, word: Word4) {
-        self.l2_p_data_flag = word.l2_p_data_flag;
-        self.reserved_word4 = word.reserved;
+        self.toa_seconds = (word.toa as u32) * 4096;
+        self.di = (word.di as f64) / 2.0_f64.powi(19);
     }
 
-    /// Encodes a [Word4] from [GpsQzssAlmanach]
     fn word4(&self) -> Word4 {
         Word4 {
-            reserved: self.reserved_word4,
-            l2_p_data_flag: self.l2_p_data_flag,
+            toa: (self.toa_seconds / 4096) as u8,
+            di: (self.di * 2.0_f64.powi(19)).round() as i16,
         }
     }
 
-    /// Updates scaled content from [Word5]
     fn set_word5(&mut self, word: Word5) {
-        self.reserved_word5 = word.reserved;
+        self.omega_dot = (word.omega_dot as f64) / 2.0_f64.powi(38);
+        self.sv_health = word.health;
     }
 
-    /// Encodes a [Word5] from [GpsQzssAlmanach]
     fn word5(&self) -> Word5 {
         Word5 {
-            reserved: self.reserved_word5,
+            omega_dot: (self.omega_dot * 2.0_f64.powi(38)).round() as i16,
+            health: self.sv_health,
         }
     }
 
-    /// Updates scaled content from [Word6]
     fn set_word6(&mut self, word: Word6) {
-        self.reserved_word6 = word.reserved;
+        self.sqrt_a = (word.sqrt_a as f64) / 2.0_f64.powi(11);
     }
 
-    /// Encodes a [Word6] from [GpsQzssAlmanach]
     fn word6(&self) -> Word6 {
         Word6 {
-            reserved: self.reserved_word6,
+            sqrt_a: (self.sqrt_a * 2.0_f64.powi(11)).round() as u32,
         }
     }
 
-    /// Updates scaled content from [Word7]
     fn set_word7(&mut self, word: Word7) {
-        self.reserved_word7 = word.reserved;
-        self.tgd = (word.tgd as f64) / 2.0_f64.powi(31);
+        self.omega0 = (word.omega0 as f64) / 2.0_f64.powi(23);
     }
 
-    /// Encodes a [Word7] from [GpsQzssAlmanach]
     fn word7(&self) -> Word7 {
         Word7 {
-            reserved: self.reserved_word7,
-            tgd: (self.tgd * 2.0_f64.powi(31)).round() as i8,
+            omega0: (self.omega0 * 2.0_f64.powi(23)).round() as i32,
         }
     }
 
-    /// Updates scaled content from [Word8]
     fn set_word8(&mut self, word: Word8) {
-        self.toc = (word.toc as u32) * 16;
-        self.iodc |= word.iodc_lsb as u16;
+        self.omega = (word.omega as f64) / 2.0_f64.powi(23);
     }
 
-    /// Encodes a [Word8] from [GpsQzssAlmanach]
     fn word8(&self) -> Word8 {
         Word8 {
-            toc: (self.toc / 16) as u16,
-            iodc_lsb: (self.iodc & 0xff) as u8,
+            omega: (self.omega * 2.0_f64.powi(23)).round() as i32,
         }
     }
 
-    /// Updates scaled content from [Word9]
     fn set_word9(&mut self, word: Word9) {
-        self.af2 = (word.af2 as f64) * 2.0_f64.powi(-55);
-        self.af1 = (word.af1 as f64) * 2.0_f64.powi(-43);
+        self.m0 = (word.m0 as f64) / 2.0_f64.powi(23);
     }
 
-    /// Encodes a [Word9] from [GpsQzssAlmanach]
     fn word9(&self) -> Word9 {
         Word9 {
-            af2: (self.af2 * 2.0_f64.powi(55)).round() as i8,
-            af1: (self.af1 * 2.0_f64.powi(43)).round() as i16,
+            m0: (self.m0 * 2.0_f64.powi(23)).round() as i32,
         }
     }
 
-    /// Updates scaled content from [Word10]
     fn set_word10(&mut self, word: Word10) {
-        self.af0 = (word.af0 as f64) * 2.0_f64.powi(-31);
+        let af0 = ((word.af0_msb as u32) << 3) | (word.af0_lsb as u32);
+        let af0 = twos_complement(af0, 0x7ff, 0x400);
+
+        self.af0 = (af0 as f64) / 2.0_f64.powi(20);
+        self.af1 = (word.af1 as f64) / 2.0_f64.powi(38);
     }
 
-    /// Encodes a [Word10] from [GpsQzssAlmanach]
     fn word10(&self) -> Word10 {
+        // 11-bit two's complement
+        let af0 = ((self.af0 * 2.0_f64.powi(20)).round() as i32) as u32 & 0x7ff;
+
         Word10 {
-            af0: (self.af0 * 2.0_f64.powi(31)).round() as i32,
+            af0_msb: ((af0 & 0x7f8) >> 3) as u8,
+            af0_lsb: (af0 & 0x007) as u8,
+            af1: (self.af1 * 2.0_f64.powi(38)).round() as i16,
         }
     }
 
-    /// Encodes this [GpsQzssFrame1] as a burst of 8 [GpsDataWord]s.
-    pub(crate) fn to_words(&self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
+    /// Encodes this [GpsQzssAlmanach] as a burst of 8 [GpsDataWord]s.
+    pub(crate) fn to_words(self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
         [
             self.word3().to_word(),
             self.word4().to_word(),
@@ -197,347 +429,331 @@ impl GpsQzssAlmanach {
     }
 }
 
-#[derive(Debug, Copy, Default, Clone, PartialEq)]
-pub(crate) struct Word3 {
-    /// 10-bit week counter
-    pub week: u16,
+/// Returns the 6-bit SV ID (page ID) encoded in word #3 of Frame-4 and Frame-5 pages
+pub(crate) fn sv_id(word3: GpsDataWord) -> u8 {
+    ((word3.value() & WORD3_SV_ID_MASK) >> WORD3_SV_ID_SHIFT) as u8
+}
 
-    /// 2 bits C/A or P ON L2
-    pub ca_or_p_l2: u8,
+/// Copies and returns this word #3 of Frame-4 or Frame-5 pages, with updated 6-bit SV ID (page ID)
+pub(crate) fn with_sv_id(word3: GpsDataWord, sv_id: u8) -> GpsDataWord {
+    let mut value = word3.value() & !WORD3_SV_ID_MASK;
+    value |= ((sv_id & 0x3f) as u32) << WORD3_SV_ID_SHIFT;
+    value <<= 2;
+    GpsDataWord::from(value)
+}
 
-    /// 4-bit URA index
-    pub ura: u8,
+/// Returns this word as 4 bytes: 24 data bits, 6 parity bits, then 2 padding bits,
+/// ready to be parsed by a [BitReader].
+fn data_bytes(word: GpsDataWord) -> [u8; 4] {
+    (word.value() << 2).to_be_bytes()
+}
 
-    /// 6-bit SV Health
-    pub health: u8,
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Word3 {
+    /// 2-bit Data ID
+    pub data_id: u8,
+
+    /// 6-bit SV ID
+    pub sv_id: u8,
 
-    /// 2-bit (MSB) IODC, you will have to associate this to Word # 8
-    pub iodc_msb: u8,
+    /// 16-bit eccentricity
+    pub e: u16,
 }
 
 impl Word3 {
-    /// Interprets this [GpsDataWord] as [Word3].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-
-        let week = ((value & WORD3_WEEK_MASK) >> WORD3_WEEK_SHIFT) as u16;
-        let ca_or_p_l2 = ((value & WORD3_CA_P_L2_MASK) >> WORD3_CA_P_L2_SHIFT) as u8;
-        let ura = ((value & WORD3_URA_MASK) >> WORD3_URA_SHIFT) as u8;
-        let health = ((value & WORD3_HEALTH_MASK) >> WORD3_HEALTH_SHIFT) as u8;
-        let iodc_msb = ((value & WORD3_IODC_MASK) >> WORD3_IODC_SHIFT) as u8;
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
 
         Self {
-            week,
-            ca_or_p_l2,
-            ura,
-            health,
-            iodc_msb,
+            data_id: reader.read_u32(2).unwrap_or_default() as u8,
+            sv_id: reader.read_u32(6).unwrap_or_default() as u8,
+            e: reader.read_u32(16).unwrap_or_default() as u16,
         }
     }
 
-    /// Encodes this [Word3] as [GpsDataWord].
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
-
-        value |= ((self.week & 0x3ff) as u32) << WORD3_WEEK_SHIFT;
-        value |= ((self.ca_or_p_l2 & 0x3) as u32) << WORD3_CA_P_L2_SHIFT;
-        value |= ((self.ura & 0x07) as u32) << WORD3_URA_SHIFT;
-        value |= ((self.health & 0x3f) as u32) << WORD3_HEALTH_SHIFT;
-        value |= ((self.iodc_msb & 0x03) as u32) << WORD3_IODC_SHIFT;
-
+        let mut value = 0;
+        value |= ((self.data_id & 0x03) as u32) << WORD3_DATA_ID_SHIFT;
+        value |= ((self.sv_id & 0x3f) as u32) << WORD3_SV_ID_SHIFT;
+        value |= (self.e as u32) << WORD3_E_SHIFT;
         value <<= 2;
-
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word4 {
-    pub l2_p_data_flag: bool,
-    pub reserved: u32,
+struct Word4 {
+    /// 8-bit toa
+    pub toa: u8,
+
+    /// 16-bit delta inclination
+    pub di: i16,
 }
 
 impl Word4 {
-    /// Interprets this [GpsDataWord] as [Word4].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let l2_p_data_flag = (value & WORD4_L2P_DATA_MASK) > 0;
-        let reserved = (value & WORD4_RESERVED_MASK) >> WORD4_RESERVED_SHIFT;
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
 
         Self {
-            l2_p_data_flag,
-            reserved,
+            toa: reader.read_u32(8).unwrap_or_default() as u8,
+            di: reader.read_i32(16).unwrap_or_default() as i16,
         }
     }
 
-    /// Encodes this [Word4] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
-
-        if self.l2_p_data_flag {
-            value |= WORD4_L2P_DATA_MASK;
-        }
-
-        value |= (self.reserved & 0x7fffff) << WORD4_RESERVED_SHIFT;
+        let mut value = 0;
+        value |= (self.toa as u32) << WORD4_TOA_SHIFT;
+        value |= ((self.di as u32) & 0xffff) << WORD4_DI_SHIFT;
         value <<= 2;
-
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word5 {
-    /// 24-bit reserved
-    pub reserved: u32,
+struct Word5 {
+    /// 16-bit Omega_dot
+    pub omega_dot: i16,
+
+    /// 8-bit SV health
+    pub health: u8,
 }
 
 impl Word5 {
-    /// Interprets this [GpsDataWord] as [Word5].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let reserved = (word.value() & WORD5_RESERVED_MASK) >> WORD5_RESERVED_SHIFT;
-        Self { reserved }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            omega_dot: reader.read_i32(16).unwrap_or_default() as i16,
+            health: reader.read_u32(8).unwrap_or_default() as u8,
+        }
     }
 
-    /// Encodes this [Word5] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
         let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD5_RESERVED_SHIFT;
+        value |= ((self.omega_dot as u32) & 0xffff) << WORD5_OMEGADOT_SHIFT;
+        value |= (self.health as u32) << WORD5_HEALTH_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
-#[derive(Debug, Default, PartialEq, Clone)]
-pub(crate) struct Word6 {
-    /// 24-bit reserved
-    pub reserved: u32,
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Word6 {
+    /// 24-bit square root of semi-major axis
+    pub sqrt_a: u32,
 }
 
 impl Word6 {
-    /// Interprets this [GpsDataWord] as [Word6].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let reserved = (word.value() & WORD6_RESERVED_MASK) >> WORD6_RESERVED_SHIFT;
-        Self { reserved }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            sqrt_a: reader.read_u32(24).unwrap_or_default(),
+        }
     }
 
-    /// Encodes this [Word6] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD6_RESERVED_SHIFT;
+        let mut value = (self.sqrt_a & 0xffffff) << WORD6_SQRTA_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word7 {
-    /// 16-bit reserved
-    pub reserved: u16,
-
-    /// TGD
-    pub tgd: i8,
+struct Word7 {
+    /// 24-bit Omega0
+    pub omega0: i32,
 }
 
 impl Word7 {
-    /// Interprets this [GpsDataWord] as [Word7].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let reserved = ((value & WORD7_RESERVED_MASK) >> WORD7_RESERVED_SHIFT) as u16;
-        let tgd = ((value & WORD7_TGD_MASK) >> WORD7_TGD_SHIFT) as i8;
-        Self { reserved, tgd }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            omega0: reader.read_i32(24).unwrap_or_default(),
+        }
     }
 
-    /// Encodes this [Word7] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.reserved as u32) & 0x0ffff) << WORD7_RESERVED_SHIFT;
-        value |= ((self.tgd as u32) & 0xff) << WORD7_TGD_SHIFT;
+        let mut value = ((self.omega0 as u32) & 0xffffff) << WORD7_OMEGA0_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word8 {
-    /// 8-bit IODC LSB to associate with Word # 3
-    pub iodc_lsb: u8,
-
-    /// 16 bit ToC
-    pub toc: u16,
+struct Word8 {
+    /// 24-bit Omega
+    pub omega: i32,
 }
 
 impl Word8 {
-    /// Interprets this [GpsDataWord] as [Word8].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let iodc_lsb = ((value & WORD8_IODC_MASK) >> WORD8_IODC_SHIFT) as u8;
-        let toc = ((value & WORD8_TOC_MASK) >> WORD8_TOC_SHIFT) as u16;
-        Self { iodc_lsb, toc }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            omega: reader.read_i32(24).unwrap_or_default(),
+        }
     }
 
-    /// Encodes this [Word8] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.iodc_lsb as u32) & 0xff) << WORD8_IODC_SHIFT;
-        value |= ((self.toc as u32) & 0x0ffff) << WORD8_TOC_SHIFT;
+        let mut value = ((self.omega as u32) & 0xffffff) << WORD8_OMEGA_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word9 {
-    /// 8 bit af2
-    pub af2: i8,
-
-    /// 16 bit af1
-    pub af1: i16,
+struct Word9 {
+    /// 24-bit M0
+    pub m0: i32,
 }
 
 impl Word9 {
-    /// Interprets this [GpsDataWord] as [Word9].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let af2 = ((value & WORD9_AF2_MASK) >> WORD9_AF2_SHIFT) as i8;
-        let af1 = ((value & WORD9_AF1_MASK) >> WORD9_AF1_SHIFT) as i16;
-        Self { af2, af1 }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            m0: reader.read_i32(24).unwrap_or_default(),
+        }
     }
 
-    /// Encodes this [Word9] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.af2 as u32) & 0x0ff) << WORD9_AF2_SHIFT;
-        value |= ((self.af1 as u32) & 0x0ffff) << WORD9_AF1_SHIFT;
+        let mut value = ((self.m0 as u32) & 0xffffff) << WORD9_M0_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-pub(crate) struct Word10 {
-    /// 22-bit af0
-    pub af0: i32,
+struct Word10 {
+    /// af0 (8) MSB
+    pub af0_msb: u8,
+
+    /// 11-bit af1
+    pub af1: i16,
+
+    /// af0 (3) LSB
+    pub af0_lsb: u8,
 }
 
 impl Word10 {
-    /// Interprets this [GpsDataWord] as [Word10].
     pub fn from_word(word: GpsDataWord) -> Self {
-        let af0 = (word.value() & WORD10_AF0_MASK) >> WORD10_AF0_SHIFT;
-        let af0 = twos_complement(af0, 0x3fffff, 0x200000);
-        Self { af0 }
+        let bytes = data_bytes(word);
+        let mut reader = BitReader::new(&bytes, 0);
+
+        Self {
+            af0_msb: reader.read_u32(8).unwrap_or_default() as u8,
+            af1: reader.read_i32(11).unwrap_or_default() as i16,
+            af0_lsb: reader.read_u32(3).unwrap_or_default() as u8,
+        }
     }
 
-    /// Encodes this [Word10] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = ((self.af0 & 0x3fffff) as u32) << WORD10_AF0_SHIFT;
+        let mut value = 0;
+        value |= (self.af0_msb as u32) << WORD10_AF0_MSB_SHIFT;
+        value |= ((self.af1 as u32) & 0x7ff) << WORD10_AF1_SHIFT;
+        value |= ((self.af0_lsb & 0x07) as u32) << WORD10_AF0_LSB_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
 }
 
 #[cfg(test)]
-mod frame1 {
+mod test {
     use super::*;
+    use crate::gps::GpsQzssFrame3;
 
     #[test]
-    fn dword3() {
-        for dword3 in [
-            Word3 {
-                week: 1,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 1,
-                ura: 0,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 1,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 1,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 0,
-                iodc_msb: 1,
-            },
-            Word3 {
-                week: 1,
-                ca_or_p_l2: 2,
-                ura: 5,
-                health: 1,
-                iodc_msb: 0,
-            },
+    fn encoding() {
+        for almanach in [
+            GpsQzssAlmanach::model(),
+            GpsQzssAlmanach::model()
+                .with_sv_id(24)
+                .with_toa_seconds(602_112)
+                .with_delta_inclination_semicircles(-1.0e-2)
+                .with_omega_dot_semicircles_s(2.0e-9)
+                .with_sv_health(0x3f)
+                .with_longitude_ascending_node_semicircles(0.9)
+                .with_omega_semicircles(-0.7)
+                .with_mean_anomaly_semicircles(-0.2)
+                .with_clock_offset_seconds(-3.0e-4)
+                .with_clock_drift_seconds_s(2.0e-12),
         ] {
-            let gps_word = dword3.to_word();
-            let decoded = Word3::from_word(gps_word);
-            assert_eq!(decoded, dword3);
-            assert_eq!(
-                decoded.to_word(),
-                gps_word,
-                "Reciprocal failed for {:?}",
-                dword3
-            );
+            let encoded = almanach.to_words();
+            let decoded = GpsQzssAlmanach::from_words(&encoded);
+            assert_eq!(decoded, almanach);
         }
     }
 
     #[test]
-    fn dword4() {
-        for dword4 in [
-            Word4 {
-                l2_p_data_flag: true,
-                reserved: 0,
-            },
-            Word4 {
-                l2_p_data_flag: false,
-                reserved: 1,
-            },
-            Word4 {
-                l2_p_data_flag: true,
-                reserved: 123,
-            },
-        ] {
-            let gps_word = dword4.to_word();
-            let decoded = Word4::from_word(gps_word);
-            assert_eq!(decoded, dword4);
-            assert_eq!(
-                decoded.to_word(),
-                gps_word,
-                "Reciprocal failed for {:?}",
-                dword4
-            );
+    fn svn() {
+        let almanach = GpsQzssAlmanach::default().with_sv_id(1);
+        assert_eq!(almanach.svn(None).map(|sv| sv.svn), Some(63));
+        assert_eq!(almanach.svn(Some(1600)).map(|sv| sv.svn), Some(49));
+
+        assert!(GpsQzssAlmanach::default().svn(None).is_none());
+    }
+
+    #[test]
+    fn toa_scaling() {
+        for (toa_seconds, toa) in [(0, 0), (4_096, 1), (319_488, 78), (602_112, 147)] {
+            let almanach = GpsQzssAlmanach::default().with_toa_seconds(toa_seconds);
+
+            let encoded = almanach.to_words();
+            assert_eq!(Word4::from_word(encoded[1]).toa, toa);
+
+            let decoded = GpsQzssAlmanach::from_words(&encoded);
+            assert_eq!(decoded.toa_seconds, toa_seconds);
         }
+
+        // not a multiple of 2^12: truncated
+        let almanach = GpsQzssAlmanach::default().with_toa_seconds(319_500);
+        let decoded = GpsQzssAlmanach::from_words(&almanach.to_words());
+        assert_eq!(decoded.toa_seconds, 319_488);
     }
 
     #[test]
-    fn dword5() {
-        for dword5 in [Word5 { reserved: 0 }, Word5 { reserved: 120 }] {
-            let gps_word = dword5.to_word();
-            let decoded = Word5::from_word(gps_word);
-            assert_eq!(decoded, dword5);
-            assert_eq!(decoded.to_word(), gps_word);
+    fn reference_time() {
+        let almanach = GpsQzssAlmanach::model().with_toa_seconds(319_488);
+
+        let status = GpsQzssAlmanachStatus::default().with_week((2300 % 256) as u8);
+
+        for gps_week in [2300, 2301, 2250, 2420] {
+            assert_eq!(almanach.reference_time(&status, gps_week), (2300, 319_488));
         }
+
+        // 8-bit week rollover
+        let status = status.with_week(255);
+        assert_eq!(almanach.reference_time(&status, 2304), (2303, 319_488));
+        assert_eq!(almanach.reference_time(&status, 2303), (2303, 319_488));
+    }
+
+    #[test]
+    fn inclination() {
+        let almanach = GpsQzssAlmanach::default();
+        assert_eq!(almanach.inclination_semicircles(), 0.3);
+        assert!((almanach.inclination_radians() - 0.942_477_796).abs() < 1e-9);
+
+        let almanach = almanach.with_delta_inclination_semicircles(-1.0e-2);
+        assert!((almanach.inclination_semicircles() - 0.29).abs() < 1e-12);
+        assert!((almanach.inclination_radians().to_degrees() - 52.2).abs() < 1e-9);
     }
 
     #[test]
     fn dword6() {
-        for dword6 in [Word6 { reserved: 0 }, Word6 { reserved: 120 }] {
+        for dword6 in [
+            Word6 { sqrt_a: 0 },
+            Word6 { sqrt_a: 10_554_163 },
+            Word6 { sqrt_a: 0xffffff },
+        ] {
             let gps_word = dword6.to_word();
             let decoded = Word6::from_word(gps_word);
             assert_eq!(decoded, dword6);
@@ -548,38 +764,12 @@ mod frame1 {
     #[test]
     fn dword7() {
         for dword7 in [
-            Word7 {
-                reserved: 0,
-                tgd: 1,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: 0,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: 23,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: -1,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -10,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -100,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -128,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: 127,
-            },
+            Word7 { omega0: 0 },
+            Word7 { omega0: 3_355_443 },
+            Word7 { omega0: -1 },
+            Word7 { omega0: -3_355_443 },
+            Word7 { omega0: -8_388_608 },
+            Word7 { omega0: 8_388_607 },
         ] {
             let gps_word = dword7.to_word();
             let decoded = Word7::from_word(gps_word);
@@ -591,14 +781,11 @@ mod frame1 {
     #[test]
     fn dword8() {
         for dword8 in [
-            Word8 {
-                iodc_lsb: 10,
-                toc: 30,
-            },
-            Word8 {
-                iodc_lsb: 30,
-                toc: 10,
-            },
+            Word8 { omega: 0 },
+            Word8 { omega: 2_516_582 },
+            Word8 { omega: -1 },
+            Word8 { omega: -5_872_025 },
+            Word8 { omega: -8_388_608 },
         ] {
             let gps_word = dword8.to_word();
             let decoded = Word8::from_word(gps_word);
@@ -609,7 +796,13 @@ mod frame1 {
 
     #[test]
     fn dword9() {
-        for dword9 in [Word9 { af2: 10, af1: 9 }, Word9 { af2: 9, af1: 100 }] {
+        for dword9 in [
+            Word9 { m0: 0 },
+            Word9 { m0: 5_033_165 },
+            Word9 { m0: -1 },
+            Word9 { m0: -1_677_722 },
+            Word9 { m0: 8_388_607 },
+        ] {
             let gps_word = dword9.to_word();
             let decoded = Word9::from_word(gps_word);
             assert_eq!(decoded, dword9);
@@ -618,117 +811,130 @@ mod frame1 {
     }
 
     #[test]
-    fn dword10() {
-        for dword10 in [
-            Word10 { af0: 0 },
-            Word10 { af0: 100 },
-            Word10 { af0: -1230 },
-            Word10 { af0: -3140 },
+    fn clock_terms() {
+        for (af0, af1) in [
+            (1.0e-4, -1.0e-11),
+            (-3.0e-4, 2.0e-12),
+            (9.5e-4, 3.5e-12),
+            (-9.7e-4, -3.6e-12),
         ] {
-            let gps_word = dword10.to_word();
-            let decoded = Word10::from_word(gps_word);
-            assert_eq!(decoded, dword10);
-            assert_eq!(decoded.to_word(), gps_word);
+            let almanach = GpsQzssAlmanach::default()
+                .with_clock_offset_seconds(af0)
+                .with_clock_drift_seconds_s(af1);
+
+            let words = almanach.to_words();
+
+            let raw = Word10::from_word(words[7]);
+            assert_eq!(raw.af1, (af1 * 2.0_f64.powi(38)).round() as i16);
+
+            let decoded = GpsQzssAlmanach::from_words(&words);
+            assert!((decoded.af0 - af0).abs() <= 2.0_f64.powi(-21));
+            assert!((decoded.af1 - af1).abs() <= 2.0_f64.powi(-39));
         }
     }
 
     #[test]
-    fn encoding() {
-        for (
-            week,
-            ca_or_p_l2,
-            ura,
-            health,
-            iodc,
-            toc,
-            tgd,
-            af0,
-            af1,
-            af2,
-            l2_p_data_flag,
-            reserved_word4,
-            reserved_word5,
-            reserved_word6,
-            reserved_word7,
-        ) in [
-            (
-                350, 1, 2, 3, 10, 50_000, 6.0E-9, 7.0E-9, 8.0E-13, 9.0E-15, true, 10, 11, 12, 13,
-            ),
-            (
-                900, 1, 2, 3, 10, 24_992, -1.0E-9, -7.0E-9, 8.0E-13, 9.0E-15, true, 10, 11, 12, 13,
-            ),
-        ] {
-            let frame1 = GpsQzssFrame1 {
-                week,
-                ca_or_p_l2,
-                ura,
-                health,
-                iodc,
-                toc,
-                tgd: tgd,
-                af0: af0,
-                af1: af1,
-                af2: af2,
-                l2_p_data_flag,
-                reserved_word4,
-                reserved_word5,
-                reserved_word6,
-                reserved_word7,
-            };
-
-            let words = frame1.to_words();
-
-            let decoded = GpsQzssFrame1::from_words(&words);
-
-            assert_eq!(decoded.ura, frame1.ura);
-            assert_eq!(decoded.week, frame1.week);
-            assert_eq!(decoded.toc, frame1.toc);
-            assert_eq!(decoded.ca_or_p_l2, frame1.ca_or_p_l2);
-            assert_eq!(decoded.l2_p_data_flag, frame1.l2_p_data_flag);
-            assert_eq!(decoded.reserved_word4, frame1.reserved_word4);
-            assert_eq!(decoded.reserved_word5, frame1.reserved_word5);
-            assert_eq!(decoded.reserved_word6, frame1.reserved_word6);
-            assert_eq!(decoded.reserved_word7, frame1.reserved_word7);
-
-            assert!((decoded.af0 - frame1.af0).abs() < 1E-10);
-            assert!((decoded.af1 - frame1.af1).abs() < 1E-14);
-            assert!((decoded.af2 - frame1.af2).abs() < 1E-14);
+    fn equality() {
+        let lhs = GpsQzssAlmanach::model();
+        let rhs = GpsQzssAlmanach::model();
+        assert_eq!(lhs, rhs);
+
+        let rhs = GpsQzssAlmanach::model().with_eccentricity(2.0e-2);
+        assert_ne!(lhs, rhs);
+
+        // within one LSB of the 2^-21 eccentricity scaling
+        let rhs = GpsQzssAlmanach::model().with_eccentricity(1.0e-2 + 2.0_f64.powi(-22));
+        assert_eq!(lhs, rhs);
+    }
+
+    #[test]
+    fn omega_dot_scaling() {
+        for omega_dot in [-2.6e-9, -8.0e-10, 1.2e-9, 0.0] {
+            // ephemeris: 24-bit 2^-43
+            let frame3 = GpsQzssFrame3::default().with_omega_dot_semicircles_s(omega_dot);
+            let words = frame3.to_words();
+            let raw = (words[6].value() & 0x3fffffc0) >> 6;
+            let raw = twos_complement(raw, 0xffffff, 0x800000);
+            assert_eq!(raw, (omega_dot * 2.0_f64.powi(43)).round() as i32);
+
+            // almanach: 16-bit 2^-38
+            let almanach = GpsQzssAlmanach::default().with_omega_dot_semicircles_s(omega_dot);
+            let words = almanach.to_words();
+            let raw = (words[2].value() & 0x3fffc000) >> 14;
+            let raw = twos_complement(raw, 0xffff, 0x8000);
+            assert_eq!(raw, (omega_dot * 2.0_f64.powi(38)).round() as i32);
+
+            // both describe the same physical quantity
+            let frame3 = GpsQzssFrame3::from_words(&frame3.to_words());
+            let almanach = GpsQzssAlmanach::from_words(&almanach.to_words());
+
+            assert!((frame3.omega_dot - omega_dot).abs() <= 2.0_f64.powi(-44));
+            assert!((almanach.omega_dot - omega_dot).abs() <= 2.0_f64.powi(-39));
+            assert!((almanach.omega_dot - frame3.omega_dot).abs() <= 2.0_f64.powi(-38));
         }
     }
 
     #[test]
-    fn user_range_accuracy() {
-        for (value_m, encoded_ura) in [
-            (0.1, 0),
-            (1.0, 0),
-            (2.4, 0),
-            (2.5, 1),
-            (2.6, 1),
-            (3.4, 1),
-            (3.5, 2),
-            (95.0, 8),
-            (96.0, 8),
-            (96.1, 9),
-            (3071.0, 13),
-            (3072.0, 13),
-            (3072.1, 14),
-            (4000.1, 14),
+    fn prn() {
+        use crate::gps::{GpsQzssFrame4, GpsQzssFrame5};
+
+        assert!(GpsQzssAlmanach::default().is_dummy());
+        assert!(!GpsQzssAlmanach::model().is_dummy());
+
+        // Frame-4 page 2 describes PRN #25
+        let page = GpsQzssFrame4::Page2(GpsQzssAlmanach::model());
+        assert_eq!(page.prn(), Some(25));
+
+        match GpsQzssFrame4::from_words(&page.to_words()).unwrap() {
+            GpsQzssFrame4::Page2(almanach) => {
+                assert_eq!(almanach.prn(), 25);
+                assert!(!almanach.is_dummy());
+            },
+            page => panic!("wrong page decoded: {:?}", page),
+        }
+
+        for (page, prn) in [
+            (GpsQzssFrame4::Page5(Default::default()), Some(28)),
+            (GpsQzssFrame4::Page7(Default::default()), Some(29)),
+            (GpsQzssFrame4::Page10(Default::default()), Some(32)),
+            (GpsQzssFrame4::Page1(Default::default()), None),
+            (GpsQzssFrame4::Page25(Default::default()), None),
         ] {
-            let ura = GpsQzssFrame1::compute_ura(value_m);
-            assert_eq!(ura, encoded_ura, "encoded incorrect URA from {}m", value_m);
+            assert_eq!(page.prn(), prn, "invalid PRN for page {}", page.page_id());
+        }
 
-            let mut frame1 = GpsQzssFrame1::default().with_user_range_accuracy_m(value_m);
+        // Frame-5 page 1 describes PRN #1
+        let page = GpsQzssFrame5::Page1(GpsQzssAlmanach::model().with_sv_id(0));
+        assert_eq!(page.prn(), Some(1));
 
-            assert_eq!(frame1.ura, encoded_ura);
+        match GpsQzssFrame5::from_words(&page.to_words()).unwrap() {
+            GpsQzssFrame5::Page1(almanach) => assert_eq!(almanach.prn(), 1),
+            page => panic!("wrong page decoded: {:?}", page),
+        }
 
-            let mut expected = GpsQzssFrame1::default();
-            expected.ura = encoded_ura;
+        assert_eq!(GpsQzssFrame5::Page25(Default::default()).prn(), None);
+    }
 
-            // assert_eq!(
-            //     frame1.with_nominal_user_range_accuracy_m(value_m).ura,
-            //     encoded_ura,
-            //     "failed for value={}m, encoded={}", value_m, encoded_ura,
-            // );
+    #[test]
+    fn display() {
+        let display = GpsQzssAlmanach::model().to_string();
+
+        assert!(!display.contains('\n'), "not compact: {}", display);
+
+        for label in [
+            "SV=",
+            "TOA=",
+            "HEALTH=",
+            "E=",
+            "SQRT(A)=",
+            "DI=",
+            "OMEGA0=",
+            "OMEGA_DOT=",
+            "M0=",
+            "AF0=",
+            "AF1=",
+        ] {
+            assert!(display.contains(label), "missing {} in {}", label, display);
         }
     }
 }
@@ -1,5 +1,23 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Copy, Clone, PartialEq)]
-/// [GpsDataByte] aligned to 32 bits
+/// [GpsDataByte] is the unit of sub-byte assembly, used when GPS/QZSS frames
+/// are built (or read) bit by bit. GPS/QZSS words are 30 bit long,
+/// so one byte per word may only contain 6 meaningful bits: these are stored
+/// with 2-bit LSB padding ([GpsDataByte::LsbPadded]), to align the word to [u32].
+///
+/// Bits are pushed and popped MSB first, in order of transmission:
+/// ```
+/// use gnss_protos::GpsDataByte;
+///
+/// let mut byte = GpsDataByte::from_bits(&[true, false, false, false, true, false, true, true]);
+/// assert_eq!(byte.as_u8(), 0x8B);
+///
+/// assert_eq!(byte.pop_bit(), true);
+/// assert_eq!(byte.pop_bit(), false);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GpsDataByte {
     /// 2-bit LSB padding, used to align the received bits to [u32].
     LsbPadded(u8),
@@ -8,14 +26,14 @@ pub enum GpsDataByte {
     Byte(u8),
 }
 
-impl std::fmt::Debug for GpsDataByte {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Debug for GpsDataByte {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{:x}", self)
     }
 }
 
 impl core::fmt::LowerHex for GpsDataByte {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "0x{:02X}", self.as_u8())
     }
 }
@@ -34,9 +52,52 @@ impl GpsDataByte {
         Self::LsbPadded(byte << 2)
     }
 
+    /// Packs up to 8 bits into a [GpsDataByte::Byte], MSB first.
+    /// When less than 8 bits are provided, they are right aligned.
+    /// When more than 8 bits are provided, only the last 8 are kept.
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let mut byte = Self::default();
+
+        for bit in bits {
+            byte.push_bit(*bit);
+        }
+
+        byte
+    }
+
+    /// Pushes one bit into this [GpsDataByte], from the LSB side:
+    /// the previous content is shifted left and the MSB is lost.
+    pub fn push_bit(&mut self, bit: bool) {
+        match self {
+            Self::LsbPadded(value) => {
+                *value = (((*value >> 2) << 1) | bit as u8) << 2;
+            },
+            Self::Byte(value) => {
+                *value = (*value << 1) | bit as u8;
+            },
+        }
+    }
+
+    /// Pops the first meaningful bit (MSB) of this [GpsDataByte]:
+    /// the content is shifted left and a null bit is inserted on the LSB side.
+    pub fn pop_bit(&mut self) -> bool {
+        match self {
+            Self::LsbPadded(value) => {
+                let bit = *value & 0x80 > 0;
+                *value = (*value << 1) & 0xfc;
+                bit
+            },
+            Self::Byte(value) => {
+                let bit = *value & 0x80 > 0;
+                *value <<= 1;
+                bit
+            },
+        }
+    }
+
     /// Interprets internal value as [u8] to process
-    /// internal byte correctly.
-    pub(crate) fn as_u8(&self) -> u8 {
+    /// internal byte correctly (padding bits removed).
+    pub fn as_u8(&self) -> u8 {
         match self {
             Self::LsbPadded(value) => (value >> 2) & 0x3f,
             Self::Byte(value) => *value,
@@ -49,3 +110,50 @@ impl GpsDataByte {
         self.as_u8() as u32
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::GpsDataByte;
+
+    #[test]
+    fn bit_packing() {
+        let bits = [true, false, false, false, true, false, true, true];
+
+        let mut byte = GpsDataByte::from_bits(&bits);
+        assert_eq!(byte, GpsDataByte::Byte(0x8B));
+        assert_eq!(byte.as_u8(), 0x8B);
+
+        for bit in bits {
+            assert_eq!(byte.pop_bit(), bit);
+        }
+
+        assert_eq!(byte.as_u8(), 0);
+
+        // bits are right aligned
+        assert_eq!(GpsDataByte::from_bits(&[true, false]).as_u8(), 0x02);
+
+        // only the last 8 bits are kept
+        let byte = GpsDataByte::from_bits(&[true, false, true, true, true, true, true, true, true]);
+        assert_eq!(byte.as_u8(), 0x7F);
+    }
+
+    #[test]
+    fn padded_bit_packing() {
+        let bits = [true, true, false, true, false, true];
+
+        let mut byte = GpsDataByte::padded(0);
+
+        for bit in bits {
+            byte.push_bit(bit);
+        }
+
+        assert_eq!(byte, GpsDataByte::padded(0x35));
+        assert_eq!(byte.as_u8(), 0x35);
+
+        for bit in bits {
+            assert_eq!(byte.pop_bit(), bit);
+        }
+
+        assert_eq!(byte, GpsDataByte::padded(0));
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    bits::get_bits,
+    gps::{GpsCnavFrame, GPS_CNAV_MESSAGE_BITS, GPS_CNAV_MESSAGE_BYTES, GPS_PREAMBLE_BYTE},
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [GpsCnavDecoder] can decode GPS CNAV messages (L2C and L5), from a stream of
+/// 300-bit messages that were already Viterbi decoded.
+///
+/// The stream is synchronized on the preamble byte. By [Default], our [GpsCnavDecoder]
+/// also verifies the CRC-24Q, which makes the synchronization much more robust.
+#[derive(Debug, Copy, Clone)]
+pub struct GpsCnavDecoder {
+    /// True when CRC verification is requested
+    crc_verification: bool,
+}
+
+impl Default for GpsCnavDecoder {
+    /// Creates a default [GpsCnavDecoder] that verifies the CRC.
+    fn default() -> Self {
+        Self {
+            crc_verification: true,
+        }
+    }
+}
+
+impl GpsCnavDecoder {
+    /// Copies and returns a [GpsCnavDecoder] that does not verify the CRC.
+    /// Every message is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_crc_verification(mut self) -> Self {
+        self.crc_verification = false;
+        self
+    }
+
+    /// Locates the first preamble that is followed by a message,
+    /// within this buffer, at any bit offset.
+    fn find_message(&self, buffer: &[u8], size: usize) -> Option<usize> {
+        let total_bits = size * 8;
+
+        if total_bits < GPS_CNAV_MESSAGE_BITS {
+            return None;
+        }
+
+        (0..=total_bits - GPS_CNAV_MESSAGE_BITS).find(|offset| {
+            get_bits(buffer, *offset, 8) == GPS_PREAMBLE_BYTE as u32
+                && (!self.crc_verification || GpsCnavFrame::crc_ok(buffer, *offset))
+        })
+    }
+
+    /// Decodes the first valid [GpsCnavFrame] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same message twice.
+    /// - Optional [GpsCnavFrame] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When no complete message was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next message: simply append the following bytes
+    /// and call [Self::decode] again.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<GpsCnavFrame>) {
+        let size = size.min(buffer.len());
+
+        let offset_bit = match self.find_message(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(GPS_CNAV_MESSAGE_BITS - 1), None);
+            },
+        };
+
+        #[cfg(feature = "log")]
+        trace!("(GPS) [cnav]: pos={}", offset_bit);
+
+        let mut message = [0u8; GPS_CNAV_MESSAGE_BYTES];
+
+        for (i, byte) in message.iter_mut().enumerate() {
+            let nbits = (GPS_CNAV_MESSAGE_BITS - i * 8).min(8);
+            *byte = (get_bits(buffer, offset_bit + i * 8, nbits) << (8 - nbits)) as u8;
+        }
+
+        let end = offset_bit + GPS_CNAV_MESSAGE_BITS;
+
+        match GpsCnavFrame::decode_message(&message) {
+            Ok(frame) => (end, Some(frame)),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(GPS) [cnav]: {}", e);
+                (end, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        bits::{get_bits, set_bits},
+        gps::{
+            GpsCnavDecoder, GpsCnavFrame, GpsCnavMessage, GpsCnavType10, GpsCnavType11,
+            GpsCnavType30, GPS_CNAV_MESSAGE_BITS,
+        },
+        testing::insert_bit_delay,
+    };
+
+    fn models() -> Vec<GpsCnavFrame> {
+        [
+            GpsCnavMessage::Type10(GpsCnavType10::model()),
+            GpsCnavMessage::Type11(GpsCnavType11::model()),
+            GpsCnavMessage::Type30(GpsCnavType30::model()),
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, message)| {
+            GpsCnavFrame::default()
+                .with_prn(5)
+                .with_tow_seconds(345_612 + 12 * i as u32)
+                .with_message(*message)
+        })
+        .collect()
+    }
+
+    /// Concatenates (bitwise) these messages, like a synchronous stream does.
+    fn stream(models: &[GpsCnavFrame]) -> Vec<u8> {
+        let total_bits = models.len() * GPS_CNAV_MESSAGE_BITS;
+        let mut buffer = vec![0u8; (total_bits + 7) / 8];
+
+        for (i, model) in models.iter().enumerate() {
+            let message = model.encode_message();
+
+            for bit in (0..GPS_CNAV_MESSAGE_BITS).step_by(4) {
+                let offset = i * GPS_CNAV_MESSAGE_BITS + bit;
+                set_bits(&mut buffer, offset, 4, get_bits(&message, bit, 4));
+            }
+        }
+
+        buffer
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let models = models();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&models), delay);
+
+            let mut decoder = GpsCnavDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, frame) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(frame) = frame {
+                    decoded.push(frame);
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+
+    #[test]
+    fn crc_rejection() {
+        let model = models()[0];
+        let mut buffer = stream(&[model]);
+
+        let mut decoder = GpsCnavDecoder::default();
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GPS_CNAV_MESSAGE_BITS);
+        assert_eq!(frame, Some(model));
+
+        // corrupt the week number
+        buffer[5] ^= 0x10;
+
+        let (_, frame) = decoder.decode(&buffer, buffer.len());
+        assert!(frame.is_none());
+
+        let mut decoder = GpsCnavDecoder::default().without_crc_verification();
+        let (processed, frame) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, GPS_CNAV_MESSAGE_BITS);
+        assert!(frame.is_some());
+        assert_ne!(frame, Some(model));
+    }
+}
@@ -0,0 +1,370 @@
+use crate::{
+    bits::{get_bits, set_bits},
+    crc24q,
+    gps::{GpsError, GPS_PREAMBLE_BYTE},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// CNAV message size (in bits!), CRC included
+pub const GPS_CNAV_MESSAGE_BITS: usize = 300;
+
+/// CNAV message size (in bytes!), once padded
+pub const GPS_CNAV_MESSAGE_BYTES: usize = (GPS_CNAV_MESSAGE_BITS + 7) / 8;
+
+/// Preamble, PRN, message type ID, TOW count and alert flag size (in bits!)
+const HEADER_BITS: usize = 38;
+
+/// Message size (in bits!), protected by the CRC
+const DATA_BITS: usize = 276;
+
+/// CRC size (in bits!)
+const CRC_BITS: usize = 24;
+
+/// Message type specific data size (in bits!)
+const RAW_BITS: usize = DATA_BITS - HEADER_BITS;
+
+/// Message type specific data size (in bytes!), once padded
+const RAW_BYTES: usize = (RAW_BITS + 7) / 8;
+
+mod decoder;
+pub use decoder::GpsCnavDecoder;
+
+mod type10;
+pub use type10::GpsCnavType10;
+
+mod type11;
+pub use type11::GpsCnavType11;
+
+mod type30;
+pub use type30::GpsCnavType30;
+
+/// Reads the 33-bit field at bit position `pos` of this message.
+pub(crate) fn get_bits33(message: &[u8], pos: usize) -> u64 {
+    ((get_bits(message, pos, 1) as u64) << 32) | get_bits(message, pos + 1, 32) as u64
+}
+
+/// Reads the 33-bit two's complement field at bit position `pos` of this message.
+pub(crate) fn get_signed_bits33(message: &[u8], pos: usize) -> i64 {
+    ((get_bits33(message, pos) << 31) as i64) >> 31
+}
+
+/// Writes the 33-bit field at bit position `pos` of this message.
+pub(crate) fn set_bits33(message: &mut [u8], pos: usize, value: u64) {
+    set_bits(message, pos, 1, ((value >> 32) & 0x01) as u32);
+    set_bits(message, pos + 1, 32, value as u32);
+}
+
+/// Rounds and saturates this scaled value to a 33-bit two's complement field.
+pub(crate) fn to_signed_bits33(value: f64) -> u64 {
+    let max = (1i64 << 32) - 1;
+    ((value.round() as i64).clamp(-max - 1, max) as u64) & 0x1_ffff_ffff
+}
+
+/// Rounds and saturates this scaled value to a 33-bit unsigned field.
+pub(crate) fn to_unsigned_bits33(value: f64) -> u64 {
+    (value.round().max(0.0) as u64).min(0x1_ffff_ffff)
+}
+
+/// [GpsCnavMessage] is the content of a CNAV message, identified by its 6-bit message type ID.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GpsCnavMessage {
+    /// Message type 10: [GpsCnavType10] (ephemeris 1 and health)
+    Type10(GpsCnavType10),
+
+    /// Message type 11: [GpsCnavType11] (ephemeris 2)
+    Type11(GpsCnavType11),
+
+    /// Message type 30: [GpsCnavType30] (clock, ionosphere and group delay)
+    Type30(GpsCnavType30),
+
+    /// Other message types, not interpreted yet: message type ID and the 238 bits
+    /// that follow the message header (up to the CRC), left aligned.
+    Raw(u8, [u8; RAW_BYTES]),
+}
+
+impl Default for GpsCnavMessage {
+    fn default() -> Self {
+        Self::Type10(Default::default())
+    }
+}
+
+impl GpsCnavMessage {
+    /// Returns the 6-bit message type ID
+    pub fn message_type(&self) -> u8 {
+        match self {
+            Self::Type10(_) => 10,
+            Self::Type11(_) => 11,
+            Self::Type30(_) => 30,
+            Self::Raw(message_type, _) => *message_type,
+        }
+    }
+}
+
+/// [GpsCnavFrame] is a complete CNAV message, broadcast on L2C and L5.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GpsCnavFrame {
+    /// 6-bit PRN of the transmitting satellite
+    pub prn: u8,
+
+    /// Time of week (in seconds) at the start of the next message,
+    /// transmitted as a 17-bit count of 6 seconds.
+    pub tow: u32,
+
+    /// Alert flag: the signal accuracy may be worse than indicated
+    pub alert: bool,
+
+    /// [GpsCnavMessage]
+    pub message: GpsCnavMessage,
+}
+
+impl GpsCnavFrame {
+    /// Copies and returns [GpsCnavFrame] with updated 6-bit PRN
+    pub fn with_prn(mut self, prn: u8) -> Self {
+        self.prn = prn & 0x3f;
+        self
+    }
+
+    /// Copies and returns [GpsCnavFrame] with updated time of week (in seconds).
+    /// Should be a multiple of 6 seconds, to be exactly encoded.
+    pub fn with_tow_seconds(mut self, tow: u32) -> Self {
+        self.tow = tow;
+        self
+    }
+
+    /// Copies and returns [GpsCnavFrame] with alert flag asserted
+    pub fn with_alert_bit(mut self) -> Self {
+        self.alert = true;
+        self
+    }
+
+    /// Copies and returns [GpsCnavFrame] with alert flag deasserted
+    pub fn without_alert_bit(mut self) -> Self {
+        self.alert = false;
+        self
+    }
+
+    /// Copies and returns [GpsCnavFrame] with updated [GpsCnavMessage]
+    pub fn with_message(mut self, message: GpsCnavMessage) -> Self {
+        self.message = message;
+        self
+    }
+
+    /// Returns the 6-bit message type ID
+    pub fn message_type(&self) -> u8 {
+        self.message.message_type()
+    }
+
+    /// Returns true if this message's CRC is correct
+    pub(crate) fn crc_ok(message: &[u8], offset: usize) -> bool {
+        let mut data = [0u8; GPS_CNAV_MESSAGE_BYTES];
+
+        for bit in (0..DATA_BITS).step_by(16) {
+            let nbits = (DATA_BITS - bit).min(16);
+            set_bits(
+                &mut data,
+                bit,
+                nbits,
+                get_bits(message, offset + bit, nbits),
+            );
+        }
+
+        crc24q(&data, DATA_BITS) == get_bits(message, offset + DATA_BITS, CRC_BITS)
+    }
+
+    /// Decodes a [GpsCnavFrame] from this 300-bit message (left aligned).
+    /// This method does not care for the CRC.
+    ///
+    /// ## Output
+    /// - [GpsError::WouldNotFit] when this buffer is shorter than [GPS_CNAV_MESSAGE_BYTES]
+    /// - [GpsError::InvalidPreamble] when the message does not start with the preamble
+    pub fn decode_message(message: &[u8]) -> Result<Self, GpsError> {
+        if message.len() < GPS_CNAV_MESSAGE_BYTES {
+            return Err(GpsError::WouldNotFit);
+        }
+
+        if get_bits(message, 0, 8) != GPS_PREAMBLE_BYTE as u32 {
+            return Err(GpsError::InvalidPreamble);
+        }
+
+        let content = match get_bits(message, 14, 6) {
+            10 => GpsCnavMessage::Type10(GpsCnavType10::from_message(message)),
+            11 => GpsCnavMessage::Type11(GpsCnavType11::from_message(message)),
+            30 => GpsCnavMessage::Type30(GpsCnavType30::from_message(message)),
+            message_type => {
+                let mut data = [0u8; RAW_BYTES];
+
+                for bit in (0..RAW_BITS).step_by(16) {
+                    let nbits = (RAW_BITS - bit).min(16);
+                    set_bits(
+                        &mut data,
+                        bit,
+                        nbits,
+                        get_bits(message, HEADER_BITS + bit, nbits),
+                    );
+                }
+
+                GpsCnavMessage::Raw(message_type as u8, data)
+            },
+        };
+
+        Ok(Self {
+            prn: get_bits(message, 8, 6) as u8,
+            tow: get_bits(message, 20, 17) * 6,
+            alert: get_bits(message, 37, 1) > 0,
+            message: content,
+        })
+    }
+
+    /// Encodes this [GpsCnavFrame] as a 300-bit message (left aligned), CRC included.
+    pub fn encode_message(&self) -> [u8; GPS_CNAV_MESSAGE_BYTES] {
+        let mut message = [0u8; GPS_CNAV_MESSAGE_BYTES];
+
+        match self.message {
+            GpsCnavMessage::Type10(data) => data.to_message(&mut message),
+            GpsCnavMessage::Type11(data) => data.to_message(&mut message),
+            GpsCnavMessage::Type30(data) => data.to_message(&mut message),
+            GpsCnavMessage::Raw(_, data) => {
+                for bit in (0..RAW_BITS).step_by(16) {
+                    let nbits = (RAW_BITS - bit).min(16);
+                    set_bits(
+                        &mut message,
+                        HEADER_BITS + bit,
+                        nbits,
+                        get_bits(&data, bit, nbits),
+                    );
+                }
+            },
+        }
+
+        set_bits(&mut message, 0, 8, GPS_PREAMBLE_BYTE as u32);
+        set_bits(&mut message, 8, 6, (self.prn & 0x3f) as u32);
+        set_bits(&mut message, 14, 6, (self.message_type() & 0x3f) as u32);
+        set_bits(&mut message, 20, 17, (self.tow / 6) & 0x1ffff);
+        set_bits(&mut message, 37, 1, self.alert as u32);
+
+        let crc = crc24q(&message, DATA_BITS);
+        set_bits(&mut message, DATA_BITS, CRC_BITS, crc);
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn models() -> Vec<GpsCnavFrame> {
+        let mut raw = [0u8; RAW_BYTES];
+        raw[0] = 0x14;
+        raw[13] = 0xAB;
+        raw[29] = 0xC0;
+
+        [
+            GpsCnavMessage::Type10(GpsCnavType10::model()),
+            GpsCnavMessage::Type11(GpsCnavType11::model()),
+            GpsCnavMessage::Type30(GpsCnavType30::model()),
+            GpsCnavMessage::Raw(12, raw),
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, message)| {
+            GpsCnavFrame::default()
+                .with_prn(17)
+                .with_tow_seconds(345_600 + 12 * i as u32)
+                .with_message(*message)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn cnav_properties() {
+        assert_eq!(GPS_CNAV_MESSAGE_BYTES, 38);
+        assert_eq!(DATA_BITS + CRC_BITS, GPS_CNAV_MESSAGE_BITS);
+        assert_eq!(RAW_BYTES, 30);
+    }
+
+    #[test]
+    fn bits33() {
+        let mut message = [0u8; 8];
+
+        for value in [
+            0,
+            1,
+            -1,
+            0xffff_ffff,
+            -0x1_0000_0000,
+            123_456_789,
+            -987_654_321,
+        ] {
+            set_bits33(&mut message, 7, to_signed_bits33(value as f64));
+            assert_eq!(get_signed_bits33(&message, 7), value);
+        }
+
+        set_bits33(&mut message, 3, to_unsigned_bits33(8_589_934_591.0));
+        assert_eq!(get_bits33(&message, 3), 0x1_ffff_ffff);
+
+        // saturation
+        assert_eq!(to_signed_bits33(1.0e12), 0x0_ffff_ffff);
+        assert_eq!(to_signed_bits33(-1.0e12), 0x1_0000_0000);
+        assert_eq!(to_unsigned_bits33(-1.0), 0);
+        assert_eq!(to_unsigned_bits33(1.0e12), 0x1_ffff_ffff);
+    }
+
+    #[test]
+    fn message_reciprocal() {
+        for model in models() {
+            let message = model.encode_message();
+
+            assert_eq!(get_bits(&message, 0, 8), 0x8B);
+            assert!(GpsCnavFrame::crc_ok(&message, 0));
+
+            let decoded = GpsCnavFrame::decode_message(&message).unwrap_or_else(|e| {
+                panic!("failed to decode message #{}: {}", model.message_type(), e);
+            });
+
+            assert_eq!(decoded, model);
+            assert_eq!(decoded.encode_message(), message);
+        }
+    }
+
+    #[test]
+    fn header() {
+        let frame = GpsCnavFrame::default()
+            .with_prn(31)
+            .with_tow_seconds(604_794)
+            .with_alert_bit()
+            .with_message(GpsCnavMessage::Type30(Default::default()));
+
+        let message = frame.encode_message();
+
+        assert_eq!(get_bits(&message, 8, 6), 31);
+        assert_eq!(get_bits(&message, 14, 6), 30);
+        assert_eq!(get_bits(&message, 20, 17), 100_799);
+        assert_eq!(get_bits(&message, 37, 1), 1);
+
+        let decoded = GpsCnavFrame::decode_message(&message).unwrap();
+        assert_eq!(decoded, frame);
+        assert!(decoded.alert);
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let mut message = GpsCnavFrame::default().encode_message();
+
+        assert!(matches!(
+            GpsCnavFrame::decode_message(&message[..GPS_CNAV_MESSAGE_BYTES - 1]),
+            Err(GpsError::WouldNotFit)
+        ));
+
+        message[0] = 0x8A;
+
+        assert!(matches!(
+            GpsCnavFrame::decode_message(&message),
+            Err(GpsError::InvalidPreamble)
+        ));
+
+        assert!(!GpsCnavFrame::crc_ok(&message, 0));
+    }
+}
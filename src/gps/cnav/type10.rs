@@ -0,0 +1,261 @@
+use crate::{
+    bits::{get_bits, get_signed_bits, set_bits, to_signed_bits},
+    gps::cnav::{get_bits33, get_signed_bits33, set_bits33, to_signed_bits33, to_unsigned_bits33},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GpsCnavType10] (message type 10) gives the first part of the ephemeris,
+/// along with the signals health.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GpsCnavType10 {
+    /// 13-bit week number
+    pub week: u16,
+
+    /// 3-bit signals health: L1 (MSB), L2 and L5 (LSB). Zero means healthy.
+    pub health: u8,
+
+    /// Data predict time of week (in seconds)
+    pub top: u32,
+
+    /// 5-bit elevation dependent URA index (two's complement)
+    pub ura_ed: i8,
+
+    /// Ephemeris reference time of week (in seconds)
+    pub toe: u32,
+
+    /// Semi-major axis difference at reference time (in meters)
+    pub delta_a: f64,
+
+    /// Change rate of semi-major axis (in meters.s⁻¹)
+    pub a_dot: f64,
+
+    /// Mean motion difference from computed value at reference time (in semi-circles.s⁻¹)
+    pub delta_n0: f64,
+
+    /// Rate of mean motion difference from computed value (in semi-circles.s⁻²)
+    pub delta_n0_dot: f64,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    pub m0: f64,
+
+    /// Eccentricity
+    pub e: f64,
+
+    /// Argument of perigee (in semi-circles)
+    pub omega: f64,
+
+    /// Integrity status flag: enhanced level of integrity assurance
+    pub integrity: bool,
+
+    /// L2C phasing flag: L2C is in phase quadrature with L2 P(Y)
+    pub l2c_phasing: bool,
+
+    /// 3-bit reserved
+    pub reserved: u8,
+}
+
+impl GpsCnavType10 {
+    /// Generates a realistic message model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_week(2_345)
+            .with_health(0x02)
+            .with_top(345_300)
+            .with_ura_ed(-3)
+            .with_toe(352_800)
+            .with_delta_a(-123_456.0 * 2.0_f64.powi(-9))
+            .with_a_dot(-654_321.0 * 2.0_f64.powi(-21))
+            .with_delta_n0(45_678.0 * 2.0_f64.powi(-44))
+            .with_delta_n0_dot(-1_234_567.0 * 2.0_f64.powi(-57))
+            .with_m0(-3_456_789_012.0 * 2.0_f64.powi(-32))
+            .with_e(123_456_789.0 * 2.0_f64.powi(-34))
+            .with_omega(2_987_654_321.0 * 2.0_f64.powi(-32))
+            .with_integrity_flag()
+            .with_l2c_phasing_flag()
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated 13-bit week number
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.week = week & 0x1fff;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated 3-bit signals health
+    pub fn with_health(mut self, health: u8) -> Self {
+        self.health = health & 0x07;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated data predict time of week (in seconds).
+    /// Should be a multiple of 300 seconds, to be exactly encoded.
+    pub fn with_top(mut self, top: u32) -> Self {
+        self.top = top;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated 5-bit elevation dependent URA index
+    pub fn with_ura_ed(mut self, ura_ed: i8) -> Self {
+        self.ura_ed = ura_ed.clamp(-16, 15);
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated ephemeris reference time of week (in seconds).
+    /// Should be a multiple of 300 seconds, to be exactly encoded.
+    pub fn with_toe(mut self, toe: u32) -> Self {
+        self.toe = toe;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated semi-major axis difference (in meters)
+    pub fn with_delta_a(mut self, delta_a: f64) -> Self {
+        self.delta_a = delta_a;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated change rate of semi-major axis (in meters.s⁻¹)
+    pub fn with_a_dot(mut self, a_dot: f64) -> Self {
+        self.a_dot = a_dot;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated mean motion difference (in semi-circles.s⁻¹)
+    pub fn with_delta_n0(mut self, delta_n0: f64) -> Self {
+        self.delta_n0 = delta_n0;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated rate of mean motion difference (in semi-circles.s⁻²)
+    pub fn with_delta_n0_dot(mut self, delta_n0_dot: f64) -> Self {
+        self.delta_n0_dot = delta_n0_dot;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated mean anomaly (in semi-circles)
+    pub fn with_m0(mut self, m0: f64) -> Self {
+        self.m0 = m0;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated eccentricity
+    pub fn with_e(mut self, e: f64) -> Self {
+        self.e = e;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with updated argument of perigee (in semi-circles)
+    pub fn with_omega(mut self, omega: f64) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with integrity status flag asserted
+    pub fn with_integrity_flag(mut self) -> Self {
+        self.integrity = true;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with integrity status flag deasserted
+    pub fn without_integrity_flag(mut self) -> Self {
+        self.integrity = false;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with L2C phasing flag asserted
+    pub fn with_l2c_phasing_flag(mut self) -> Self {
+        self.l2c_phasing = true;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType10] with L2C phasing flag deasserted
+    pub fn without_l2c_phasing_flag(mut self) -> Self {
+        self.l2c_phasing = false;
+        self
+    }
+
+    /// Decodes [Self] from this 300-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        Self {
+            week: get_bits(message, 38, 13) as u16,
+            health: get_bits(message, 51, 3) as u8,
+            top: get_bits(message, 54, 11) * 300,
+            ura_ed: get_signed_bits(message, 65, 5) as i8,
+            toe: get_bits(message, 70, 11) * 300,
+            delta_a: get_signed_bits(message, 81, 26) as f64 * 2.0_f64.powi(-9),
+            a_dot: get_signed_bits(message, 107, 25) as f64 * 2.0_f64.powi(-21),
+            delta_n0: get_signed_bits(message, 132, 17) as f64 * 2.0_f64.powi(-44),
+            delta_n0_dot: get_signed_bits(message, 149, 23) as f64 * 2.0_f64.powi(-57),
+            m0: get_signed_bits33(message, 172) as f64 * 2.0_f64.powi(-32),
+            e: get_bits33(message, 205) as f64 * 2.0_f64.powi(-34),
+            omega: get_signed_bits33(message, 238) as f64 * 2.0_f64.powi(-32),
+            integrity: get_bits(message, 271, 1) > 0,
+            l2c_phasing: get_bits(message, 272, 1) > 0,
+            reserved: get_bits(message, 273, 3) as u8,
+        }
+    }
+
+    /// Encodes this [GpsCnavType10] into this 300-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 38, 13, self.week as u32);
+        set_bits(message, 51, 3, self.health as u32);
+        set_bits(message, 54, 11, (self.top / 300) & 0x7ff);
+        set_bits(message, 65, 5, to_signed_bits(self.ura_ed as f64, 5));
+        set_bits(message, 70, 11, (self.toe / 300) & 0x7ff);
+        set_bits(
+            message,
+            81,
+            26,
+            to_signed_bits(self.delta_a / 2.0_f64.powi(-9), 26),
+        );
+        set_bits(
+            message,
+            107,
+            25,
+            to_signed_bits(self.a_dot / 2.0_f64.powi(-21), 25),
+        );
+        set_bits(
+            message,
+            132,
+            17,
+            to_signed_bits(self.delta_n0 / 2.0_f64.powi(-44), 17),
+        );
+        set_bits(
+            message,
+            149,
+            23,
+            to_signed_bits(self.delta_n0_dot / 2.0_f64.powi(-57), 23),
+        );
+        set_bits33(message, 172, to_signed_bits33(self.m0 / 2.0_f64.powi(-32)));
+        set_bits33(message, 205, to_unsigned_bits33(self.e / 2.0_f64.powi(-34)));
+        set_bits33(
+            message,
+            238,
+            to_signed_bits33(self.omega / 2.0_f64.powi(-32)),
+        );
+        set_bits(message, 271, 1, self.integrity as u32);
+        set_bits(message, 272, 1, self.l2c_phasing as u32);
+        set_bits(message, 273, 3, (self.reserved & 0x07) as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gps::GPS_CNAV_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = GpsCnavType10::model();
+
+        let mut message = [0u8; GPS_CNAV_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(get_bits(&message, 0, 32), 0);
+        assert_eq!(get_bits(&message, 32, 6), 0);
+        assert_eq!(get_bits(&message, 54, 11), 1_151);
+        assert_eq!(get_bits(&message, 70, 11), 1_176);
+        assert_eq!(GpsCnavType10::from_message(&message), model);
+    }
+}
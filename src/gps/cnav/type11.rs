@@ -0,0 +1,228 @@
+use crate::{
+    bits::{get_bits, get_signed_bits, set_bits, to_signed_bits},
+    gps::cnav::{get_signed_bits33, set_bits33, to_signed_bits33},
+};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GpsCnavType11] (message type 11) gives the second part of the ephemeris.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GpsCnavType11 {
+    /// Ephemeris reference time of week (in seconds)
+    pub toe: u32,
+
+    /// Longitude of ascending node at weekly epoch (in semi-circles)
+    pub omega0: f64,
+
+    /// Inclination angle at reference time (in semi-circles)
+    pub i0: f64,
+
+    /// Rate of right ascension difference (in semi-circles.s⁻¹)
+    pub delta_omega_dot: f64,
+
+    /// Rate of inclination angle (in semi-circles.s⁻¹)
+    pub i0_dot: f64,
+
+    /// Amplitude of the sine harmonic correction term to the angle of inclination (in radians)
+    pub cis: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the angle of inclination (in radians)
+    pub cic: f64,
+
+    /// Amplitude of the sine harmonic correction term to the orbit radius (in meters)
+    pub crs: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the orbit radius (in meters)
+    pub crc: f64,
+
+    /// Amplitude of the sine harmonic correction term to the argument of latitude (in radians)
+    pub cus: f64,
+
+    /// Amplitude of the cosine harmonic correction term to the argument of latitude (in radians)
+    pub cuc: f64,
+
+    /// 7-bit reserved
+    pub reserved: u8,
+}
+
+impl GpsCnavType11 {
+    /// Generates a realistic message model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_toe(352_800)
+            .with_omega0(-2_345_678_901.0 * 2.0_f64.powi(-32))
+            .with_i0(1_234_567_890.0 * 2.0_f64.powi(-32))
+            .with_delta_omega_dot(-23_456.0 * 2.0_f64.powi(-44))
+            .with_i0_dot(1_234.0 * 2.0_f64.powi(-44))
+            .with_cis(-123.0 * 2.0_f64.powi(-30))
+            .with_cic(456.0 * 2.0_f64.powi(-30))
+            .with_crs(-12_345.0 * 2.0_f64.powi(-8))
+            .with_crc(54_321.0 * 2.0_f64.powi(-8))
+            .with_cus(12_345.0 * 2.0_f64.powi(-30))
+            .with_cuc(-5_432.0 * 2.0_f64.powi(-30))
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated ephemeris reference time of week (in seconds).
+    /// Should be a multiple of 300 seconds, to be exactly encoded.
+    pub fn with_toe(mut self, toe: u32) -> Self {
+        self.toe = toe;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated longitude of ascending node (in semi-circles)
+    pub fn with_omega0(mut self, omega0: f64) -> Self {
+        self.omega0 = omega0;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated inclination angle (in semi-circles)
+    pub fn with_i0(mut self, i0: f64) -> Self {
+        self.i0 = i0;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated rate of right ascension difference (in semi-circles.s⁻¹)
+    pub fn with_delta_omega_dot(mut self, delta_omega_dot: f64) -> Self {
+        self.delta_omega_dot = delta_omega_dot;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated rate of inclination angle (in semi-circles.s⁻¹)
+    pub fn with_i0_dot(mut self, i0_dot: f64) -> Self {
+        self.i0_dot = i0_dot;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Cis (in radians)
+    pub fn with_cis(mut self, cis: f64) -> Self {
+        self.cis = cis;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Cic (in radians)
+    pub fn with_cic(mut self, cic: f64) -> Self {
+        self.cic = cic;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Crs (in meters)
+    pub fn with_crs(mut self, crs: f64) -> Self {
+        self.crs = crs;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Crc (in meters)
+    pub fn with_crc(mut self, crc: f64) -> Self {
+        self.crc = crc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Cus (in radians)
+    pub fn with_cus(mut self, cus: f64) -> Self {
+        self.cus = cus;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType11] with updated Cuc (in radians)
+    pub fn with_cuc(mut self, cuc: f64) -> Self {
+        self.cuc = cuc;
+        self
+    }
+
+    /// Decodes [Self] from this 300-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        Self {
+            toe: get_bits(message, 38, 11) * 300,
+            omega0: get_signed_bits33(message, 49) as f64 * 2.0_f64.powi(-32),
+            i0: get_signed_bits33(message, 82) as f64 * 2.0_f64.powi(-32),
+            delta_omega_dot: get_signed_bits(message, 115, 17) as f64 * 2.0_f64.powi(-44),
+            i0_dot: get_signed_bits(message, 132, 15) as f64 * 2.0_f64.powi(-44),
+            cis: get_signed_bits(message, 147, 16) as f64 * 2.0_f64.powi(-30),
+            cic: get_signed_bits(message, 163, 16) as f64 * 2.0_f64.powi(-30),
+            crs: get_signed_bits(message, 179, 24) as f64 * 2.0_f64.powi(-8),
+            crc: get_signed_bits(message, 203, 24) as f64 * 2.0_f64.powi(-8),
+            cus: get_signed_bits(message, 227, 21) as f64 * 2.0_f64.powi(-30),
+            cuc: get_signed_bits(message, 248, 21) as f64 * 2.0_f64.powi(-30),
+            reserved: get_bits(message, 269, 7) as u8,
+        }
+    }
+
+    /// Encodes this [GpsCnavType11] into this 300-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 38, 11, (self.toe / 300) & 0x7ff);
+        set_bits33(
+            message,
+            49,
+            to_signed_bits33(self.omega0 / 2.0_f64.powi(-32)),
+        );
+        set_bits33(message, 82, to_signed_bits33(self.i0 / 2.0_f64.powi(-32)));
+        set_bits(
+            message,
+            115,
+            17,
+            to_signed_bits(self.delta_omega_dot / 2.0_f64.powi(-44), 17),
+        );
+        set_bits(
+            message,
+            132,
+            15,
+            to_signed_bits(self.i0_dot / 2.0_f64.powi(-44), 15),
+        );
+        set_bits(
+            message,
+            147,
+            16,
+            to_signed_bits(self.cis / 2.0_f64.powi(-30), 16),
+        );
+        set_bits(
+            message,
+            163,
+            16,
+            to_signed_bits(self.cic / 2.0_f64.powi(-30), 16),
+        );
+        set_bits(
+            message,
+            179,
+            24,
+            to_signed_bits(self.crs / 2.0_f64.powi(-8), 24),
+        );
+        set_bits(
+            message,
+            203,
+            24,
+            to_signed_bits(self.crc / 2.0_f64.powi(-8), 24),
+        );
+        set_bits(
+            message,
+            227,
+            21,
+            to_signed_bits(self.cus / 2.0_f64.powi(-30), 21),
+        );
+        set_bits(
+            message,
+            248,
+            21,
+            to_signed_bits(self.cuc / 2.0_f64.powi(-30), 21),
+        );
+        set_bits(message, 269, 7, (self.reserved & 0x7f) as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gps::GPS_CNAV_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = GpsCnavType11::model();
+
+        let mut message = [0u8; GPS_CNAV_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(get_bits(&message, 38, 11), 1_176);
+        assert_eq!(GpsCnavType11::from_message(&message), model);
+    }
+}
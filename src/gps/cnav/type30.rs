@@ -0,0 +1,310 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [GpsCnavType30] (message type 30) gives the clock correction,
+/// the ionosphere model and the group delay corrections.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GpsCnavType30 {
+    /// Data predict time of week (in seconds)
+    pub top: u32,
+
+    /// 5-bit non elevation dependent URA index, bias term (two's complement)
+    pub ura_ned0: i8,
+
+    /// 3-bit non elevation dependent URA index, drift term
+    pub ura_ned1: u8,
+
+    /// 3-bit non elevation dependent URA index, drift rate term
+    pub ura_ned2: u8,
+
+    /// Clock reference time of week (in seconds)
+    pub toc: u32,
+
+    /// Clock bias (in seconds)
+    pub af0: f64,
+
+    /// Clock drift (in seconds.s⁻¹)
+    pub af1: f64,
+
+    /// Clock drift rate (in seconds.s⁻²)
+    pub af2: f64,
+
+    /// Total group delay (in seconds)
+    pub tgd: f64,
+
+    /// L1 C/A inter signal correction (in seconds)
+    pub isc_l1ca: f64,
+
+    /// L2C inter signal correction (in seconds)
+    pub isc_l2c: f64,
+
+    /// L5 I5 inter signal correction (in seconds)
+    pub isc_l5i5: f64,
+
+    /// L5 Q5 inter signal correction (in seconds)
+    pub isc_l5q5: f64,
+
+    /// Klobuchar alpha coefficients (in s, s.semi-circle⁻¹, s.semi-circle⁻², s.semi-circle⁻³)
+    pub alpha: [f64; 4],
+
+    /// Klobuchar beta coefficients (in s, s.semi-circle⁻¹, s.semi-circle⁻², s.semi-circle⁻³)
+    pub beta: [f64; 4],
+
+    /// 8-bit week number of the ionosphere data
+    pub wn_op: u8,
+
+    /// 12-bit reserved
+    pub reserved: u16,
+}
+
+/// Scaling of the Klobuchar alpha coefficients
+const ALPHA_SCALING: [i32; 4] = [-30, -27, -24, -24];
+
+/// Scaling of the Klobuchar beta coefficients
+const BETA_SCALING: [i32; 4] = [11, 14, 16, 16];
+
+impl GpsCnavType30 {
+    /// Generates a realistic message model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_top(345_300)
+            .with_ura_ned0(-5)
+            .with_ura_ned1(3)
+            .with_ura_ned2(6)
+            .with_toc(352_800)
+            .with_af0(-12_345_678.0 * 2.0_f64.powi(-35))
+            .with_af1(123_456.0 * 2.0_f64.powi(-48))
+            .with_af2(-123.0 * 2.0_f64.powi(-60))
+            .with_tgd(-1_234.0 * 2.0_f64.powi(-35))
+            .with_isc_l1ca(567.0 * 2.0_f64.powi(-35))
+            .with_isc_l2c(-89.0 * 2.0_f64.powi(-35))
+            .with_isc_l5i5(4_095.0 * 2.0_f64.powi(-35))
+            .with_isc_l5q5(-4_096.0 * 2.0_f64.powi(-35))
+            .with_alpha([
+                12.0 * 2.0_f64.powi(-30),
+                -(2.0_f64.powi(-27)),
+                -2.0 * 2.0_f64.powi(-24),
+                3.0 * 2.0_f64.powi(-24),
+            ])
+            .with_beta([
+                49.0 * 2.0_f64.powi(11),
+                -3.0 * 2.0_f64.powi(14),
+                -128.0 * 2.0_f64.powi(16),
+                127.0 * 2.0_f64.powi(16),
+            ])
+            .with_wn_op(123)
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated data predict time of week (in seconds).
+    /// Should be a multiple of 300 seconds, to be exactly encoded.
+    pub fn with_top(mut self, top: u32) -> Self {
+        self.top = top;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated 5-bit URA NED0 index
+    pub fn with_ura_ned0(mut self, ura_ned0: i8) -> Self {
+        self.ura_ned0 = ura_ned0.clamp(-16, 15);
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated 3-bit URA NED1 index
+    pub fn with_ura_ned1(mut self, ura_ned1: u8) -> Self {
+        self.ura_ned1 = ura_ned1 & 0x07;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated 3-bit URA NED2 index
+    pub fn with_ura_ned2(mut self, ura_ned2: u8) -> Self {
+        self.ura_ned2 = ura_ned2 & 0x07;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated clock reference time of week (in seconds).
+    /// Should be a multiple of 300 seconds, to be exactly encoded.
+    pub fn with_toc(mut self, toc: u32) -> Self {
+        self.toc = toc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated clock bias (in seconds)
+    pub fn with_af0(mut self, af0: f64) -> Self {
+        self.af0 = af0;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated clock drift (in seconds.s⁻¹)
+    pub fn with_af1(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated clock drift rate (in seconds.s⁻²)
+    pub fn with_af2(mut self, af2: f64) -> Self {
+        self.af2 = af2;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated total group delay (in seconds)
+    pub fn with_tgd(mut self, tgd: f64) -> Self {
+        self.tgd = tgd;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated L1 C/A inter signal correction (in seconds)
+    pub fn with_isc_l1ca(mut self, isc: f64) -> Self {
+        self.isc_l1ca = isc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated L2C inter signal correction (in seconds)
+    pub fn with_isc_l2c(mut self, isc: f64) -> Self {
+        self.isc_l2c = isc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated L5 I5 inter signal correction (in seconds)
+    pub fn with_isc_l5i5(mut self, isc: f64) -> Self {
+        self.isc_l5i5 = isc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated L5 Q5 inter signal correction (in seconds)
+    pub fn with_isc_l5q5(mut self, isc: f64) -> Self {
+        self.isc_l5q5 = isc;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated Klobuchar alpha coefficients
+    pub fn with_alpha(mut self, alpha: [f64; 4]) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated Klobuchar beta coefficients
+    pub fn with_beta(mut self, beta: [f64; 4]) -> Self {
+        self.beta = beta;
+        self
+    }
+
+    /// Copies and returns [GpsCnavType30] with updated 8-bit week number of the ionosphere data
+    pub fn with_wn_op(mut self, wn_op: u8) -> Self {
+        self.wn_op = wn_op;
+        self
+    }
+
+    /// Decodes [Self] from this 300-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        let mut alpha = [0.0; 4];
+        let mut beta = [0.0; 4];
+
+        for i in 0..4 {
+            alpha[i] =
+                get_signed_bits(message, 192 + i * 8, 8) as f64 * 2.0_f64.powi(ALPHA_SCALING[i]);
+            beta[i] =
+                get_signed_bits(message, 224 + i * 8, 8) as f64 * 2.0_f64.powi(BETA_SCALING[i]);
+        }
+
+        Self {
+            top: get_bits(message, 38, 11) * 300,
+            ura_ned0: get_signed_bits(message, 49, 5) as i8,
+            ura_ned1: get_bits(message, 54, 3) as u8,
+            ura_ned2: get_bits(message, 57, 3) as u8,
+            toc: get_bits(message, 60, 11) * 300,
+            af0: get_signed_bits(message, 71, 26) as f64 * 2.0_f64.powi(-35),
+            af1: get_signed_bits(message, 97, 20) as f64 * 2.0_f64.powi(-48),
+            af2: get_signed_bits(message, 117, 10) as f64 * 2.0_f64.powi(-60),
+            tgd: get_signed_bits(message, 127, 13) as f64 * 2.0_f64.powi(-35),
+            isc_l1ca: get_signed_bits(message, 140, 13) as f64 * 2.0_f64.powi(-35),
+            isc_l2c: get_signed_bits(message, 153, 13) as f64 * 2.0_f64.powi(-35),
+            isc_l5i5: get_signed_bits(message, 166, 13) as f64 * 2.0_f64.powi(-35),
+            isc_l5q5: get_signed_bits(message, 179, 13) as f64 * 2.0_f64.powi(-35),
+            alpha,
+            beta,
+            wn_op: get_bits(message, 256, 8) as u8,
+            reserved: get_bits(message, 264, 12) as u16,
+        }
+    }
+
+    /// Encodes this [GpsCnavType30] into this 300-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 38, 11, (self.top / 300) & 0x7ff);
+        set_bits(message, 49, 5, to_signed_bits(self.ura_ned0 as f64, 5));
+        set_bits(message, 54, 3, (self.ura_ned1 & 0x07) as u32);
+        set_bits(message, 57, 3, (self.ura_ned2 & 0x07) as u32);
+        set_bits(message, 60, 11, (self.toc / 300) & 0x7ff);
+        set_bits(
+            message,
+            71,
+            26,
+            to_signed_bits(self.af0 / 2.0_f64.powi(-35), 26),
+        );
+        set_bits(
+            message,
+            97,
+            20,
+            to_signed_bits(self.af1 / 2.0_f64.powi(-48), 20),
+        );
+        set_bits(
+            message,
+            117,
+            10,
+            to_signed_bits(self.af2 / 2.0_f64.powi(-60), 10),
+        );
+
+        for (pos, value) in [
+            (127, self.tgd),
+            (140, self.isc_l1ca),
+            (153, self.isc_l2c),
+            (166, self.isc_l5i5),
+            (179, self.isc_l5q5),
+        ] {
+            set_bits(
+                message,
+                pos,
+                13,
+                to_signed_bits(value / 2.0_f64.powi(-35), 13),
+            );
+        }
+
+        for i in 0..4 {
+            set_bits(
+                message,
+                192 + i * 8,
+                8,
+                to_signed_bits(self.alpha[i] / 2.0_f64.powi(ALPHA_SCALING[i]), 8),
+            );
+            set_bits(
+                message,
+                224 + i * 8,
+                8,
+                to_signed_bits(self.beta[i] / 2.0_f64.powi(BETA_SCALING[i]), 8),
+            );
+        }
+
+        set_bits(message, 256, 8, self.wn_op as u32);
+        set_bits(message, 264, 12, (self.reserved & 0xfff) as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gps::GPS_CNAV_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = GpsCnavType30::model();
+
+        let mut message = [0u8; GPS_CNAV_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(get_bits(&message, 38, 11), 1_151);
+        assert_eq!(get_bits(&message, 60, 11), 1_176);
+        assert_eq!(get_bits(&message, 256, 8), 123);
+        assert_eq!(GpsCnavType30::from_message(&message), model);
+    }
+}
@@ -0,0 +1,57 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// [GpsQzssConstellation] selects the interpretation of the (very similar)
+/// GPS and QZSS navigation messages.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GpsQzssConstellation {
+    /// GPS (US), as specified in IS-GPS-200
+    #[default]
+    Gps,
+
+    /// QZSS (Jap), as specified in IS-QZSS-PNT
+    Qzss,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssConstellation {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Gps => write!(f, "GPS"),
+            Self::Qzss => write!(f, "QZSS"),
+        }
+    }
+}
+
+impl GpsQzssConstellation {
+    /// Returns the [GpsQzssConstellation] a PRN number belongs to:
+    /// 1-32 for GPS, 193-202 for QZSS.
+    pub fn from_prn(prn: u8) -> Option<Self> {
+        match prn {
+            1..=32 => Some(Self::Gps),
+            193..=202 => Some(Self::Qzss),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GpsQzssConstellation;
+
+    #[test]
+    fn from_prn() {
+        for (prn, expected) in [
+            (0, None),
+            (1, Some(GpsQzssConstellation::Gps)),
+            (32, Some(GpsQzssConstellation::Gps)),
+            (33, None),
+            (193, Some(GpsQzssConstellation::Qzss)),
+            (202, Some(GpsQzssConstellation::Qzss)),
+            (203, None),
+        ] {
+            assert_eq!(GpsQzssConstellation::from_prn(prn), expected);
+        }
+    }
+}
@@ -1,10 +1,53 @@
-use crate::gps::{
-    GpsDataWord, GpsQzssFrame, GpsQzssHow, GpsQzssSubframe, GpsQzssTelemetry, GPS_FRAME_BITS,
-    GPS_FRAME_BYTES, GPS_PREAMBLE_BYTE, GPS_WORDS_PER_FRAME,
+use crate::{
+    bits::{read_word_be, BitIter},
+    gps::{
+        GpsDataWord, GpsError, GpsQzssConstellation, GpsQzssFrame, GpsQzssHow, GpsQzssSubframe,
+        GpsQzssTelemetry, GPS_FRAME_BITS, GPS_FRAME_BYTES, GPS_PREAMBLE_BYTE, GPS_WORDS_PER_FRAME,
+        GPS_WORD_BITS,
+    },
 };
 
 #[cfg(feature = "log")]
-use log::{debug, error, trace};
+use log::{debug, error, trace, warn};
+
+/// [GpsQzssDecoderStats] are the counters of a [GpsQzssDecoder],
+/// to monitor the quality of a real-time link.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GpsQzssDecoderStats {
+    /// Number of [GpsQzssFrame]s correctly decoded
+    pub frames: usize,
+
+    /// Number of preambles that were not followed by a valid frame
+    /// (invalid HOW or subframe content), most likely false locks.
+    pub false_locks: usize,
+
+    /// Number of frames rejected by parity verification
+    pub parity_failures: usize,
+
+    /// Total number of bits consumed
+    pub consumed_bits: usize,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssDecoderStats {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "FRAMES={} - FALSE_LOCKS={} - PARITY_FAILURES={} - CONSUMED={}",
+            self.frames,
+            self.false_locks,
+            self.parity_failures,
+            self.consumed_bytes(),
+        )
+    }
+}
+
+impl GpsQzssDecoderStats {
+    /// Returns the total number of bytes consumed
+    pub fn consumed_bytes(&self) -> usize {
+        self.consumed_bits / 8
+    }
+}
 
 /// [GpsQzssDecoder] can decode GPS (or QZSS) messages.
 /// By [Default], our [GpsQzssDecoder] does not verify parity,
@@ -42,6 +85,30 @@ pub struct GpsQzssDecoder {
 
     /// True when parity verification is requested
     parity_verification: bool,
+
+    /// True when the bits are delivered LSB first within each byte
+    lsb_first: bool,
+
+    /// True when the data polarity is inverted (every bit is flipped)
+    inverted: bool,
+
+    /// [GpsQzssConstellation] of the decoded frames
+    constellation: GpsQzssConstellation,
+
+    /// [GpsQzssDecoderStats]
+    stats: GpsQzssDecoderStats,
+
+    /// Bytes pulled by [Self::decode_from] that were not consumed yet
+    #[cfg(feature = "std")]
+    pending: [u8; 2 * GPS_FRAME_BYTES],
+
+    /// Number of bytes in the pending buffer
+    #[cfg(feature = "std")]
+    pending_size: usize,
+
+    /// Position of the first pending bit, within the first pending byte
+    #[cfg(feature = "std")]
+    pending_bit: usize,
 }
 
 impl Default for GpsQzssDecoder {
@@ -50,12 +117,50 @@ impl Default for GpsQzssDecoder {
         Self {
             words: Default::default(),
             parity_verification: false,
+            lsb_first: false,
+            inverted: false,
             buffer: [0; GPS_FRAME_BYTES + 1],
+            constellation: GpsQzssConstellation::Gps,
+            stats: Default::default(),
+            #[cfg(feature = "std")]
+            pending: [0; 2 * GPS_FRAME_BYTES],
+            #[cfg(feature = "std")]
+            pending_size: 0,
+            #[cfg(feature = "std")]
+            pending_bit: 0,
         }
     }
 }
 
 impl GpsQzssDecoder {
+    /// Creates a default [GpsQzssDecoder] dedicated to QZSS:
+    /// decoded [GpsQzssFrame]s are marked as [GpsQzssConstellation::Qzss].
+    pub fn qzss() -> Self {
+        Self::default().with_constellation(GpsQzssConstellation::Qzss)
+    }
+
+    /// Copies and returns [GpsQzssDecoder] with updated [GpsQzssConstellation].
+    pub fn with_constellation(mut self, constellation: GpsQzssConstellation) -> Self {
+        self.constellation = constellation;
+        self
+    }
+
+    /// Returns the [GpsQzssConstellation] this [GpsQzssDecoder] is dedicated to.
+    pub fn constellation(&self) -> GpsQzssConstellation {
+        self.constellation
+    }
+
+    /// Returns the [GpsQzssDecoderStats] accumulated since this [GpsQzssDecoder]
+    /// was created, or since the last [Self::reset_stats].
+    pub fn stats(&self) -> GpsQzssDecoderStats {
+        self.stats
+    }
+
+    /// Resets the [GpsQzssDecoderStats].
+    pub fn reset_stats(&mut self) {
+        self.stats = Default::default();
+    }
+
     /// Creates a new [GpsQzssDecoder] with parity verification.
     /// Our [Default] [GpsQzssDecoder] does not verify the parity bits at the moment,
     /// you have to specifically turn it on.
@@ -65,12 +170,58 @@ impl GpsQzssDecoder {
         self
     }
 
+    /// Copies and returns [GpsQzssDecoder] for front-ends that deliver the navigation bits
+    /// LSB first: the bit order is reversed within each input byte, so the stream
+    /// does not need to be pre-processed. The returned bit counts are not affected.
+    pub fn with_lsb_first(mut self) -> Self {
+        self.lsb_first = true;
+        self
+    }
+
+    /// Returns true when the data polarity is currently inverted: tracking loops may lock
+    /// with an inverted carrier phase, which flips every data bit. This is detected
+    /// automatically, when the complemented preamble (0x74) is found instead of the preamble,
+    /// and the following bytes are then complemented.
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Returns this input byte, MSB first and with the correct polarity.
+    fn input_byte(&self, byte: u8) -> u8 {
+        let byte = if self.lsb_first {
+            byte.reverse_bits()
+        } else {
+            byte
+        };
+
+        if self.inverted {
+            !byte
+        } else {
+            byte
+        }
+    }
+
+    /// Returns the maximal number of [GpsQzssFrame]s this [GpsQzssDecoder] may buffer
+    /// internally, which is the worst case decoding latency (in frames).
+    /// - [Self::decode] and [Self::frames] work on the caller's buffer and only realign
+    ///   one frame at a time: each frame is returned as soon as its last byte is provided.
+    /// - [Self::decode_from] (`std` feature) pulls up to two frames from the reader
+    ///   before decoding, so one complete frame may be pending when another is returned.
+    pub fn max_buffered_frames(&self) -> usize {
+        #[cfg(feature = "std")]
+        return self.pending.len() / GPS_FRAME_BYTES;
+
+        #[cfg(not(feature = "std"))]
+        return self.buffer.len() / GPS_FRAME_BYTES;
+    }
+
     /// Packs 38 bytes (10x 30-bit + 4bit padding) correcty aligned to [u8], ready to process.
     ///
     /// ## Input
-    /// - slice: &[u8], will panic if not [GPS_FRAME_BYTES] byte long!
+    /// - slice: &[u8], will panic if not [GPS_FRAME_BYTES] byte long (past the preamble)!
+    /// - size: slice size (in bytes)
     /// - preamble_offset in bits!
-    fn resync_align(&mut self, slice: &[u8], preamble_offset_bit: usize) {
+    fn resync_align(&mut self, slice: &[u8], size: usize, preamble_offset_bit: usize) {
         // byte index
         let byte_index = preamble_offset_bit / 8;
 
@@ -80,70 +231,102 @@ impl GpsQzssDecoder {
         #[cfg(feature = "log")]
         trace!("(GPS/QZSS)  [preamble]: off={}", bit_index);
 
-        // copies to first position
-        self.buffer[0..GPS_FRAME_BYTES]
-            .copy_from_slice(&slice[byte_index..byte_index + GPS_FRAME_BYTES]);
-
-        if bit_index > 0 {
-            let (byte1_mask, byte2_mask) = match bit_index {
-                1 => (0x7f, 0xfe),
-                2 => (0x3f, 0xfc),
-                3 => (0x1f, 0xf8),
-                4 => (0x0f, 0xf0),
-                5 => (0x08, 0xf0),
-                6 => (0x0f, 0xf0),
-                7 => (0x0f, 0xf0),
-                _ => unreachable!("compiler issue"),
-            };
-
-            for i in 0..GPS_FRAME_BYTES {
-                let mut mask1 = byte1_mask;
-                let mut mask2 = byte2_mask;
+        for i in 0..GPS_FRAME_BYTES {
+            let byte = self.input_byte(slice[byte_index + i]);
 
-                self.buffer[i] &= mask1;
-                self.buffer[i + 1] &= mask2;
+            self.buffer[i] = if bit_index > 0 {
+                // the last byte may not be buffered, when only padding bits are missing
+                let next = if byte_index + i + 1 < size {
+                    self.input_byte(slice[byte_index + i + 1])
+                } else {
+                    0
+                };
 
-                self.buffer[i] >>= bit_index;
-                self.buffer[i + 1] >>= bit_index;
-            }
+                (byte << bit_index) | (next >> (8 - bit_index))
+            } else {
+                byte
+            };
         }
     }
 
-    /// Locates the preamble bit marker (sync byte) within a buffer
+    /// Locates the preamble bit marker (sync byte) within a buffer.
+    /// The complete buffer is scanned, so the preamble may be returned
+    /// even though the following frame is not entirely buffered yet.
+    ///
+    /// The complemented preamble (inverted polarity) is also searched for, but is only
+    /// retained when the TLM (parity included) and HOW words that follow are valid, which avoids
+    /// switching polarity on random data. It is also returned when these words are not
+    /// entirely buffered yet.
     ///
     /// ## Input
-    /// - slice: slice of bytes, must be [GPS_FRAME_BYTES] byte long
+    /// - slice: slice of bytes
     /// - size: total number of bytes
+    /// - offset: position of the first bit to scan
     ///
     /// ## Returns
-    /// - offset in bits !
-    fn find_preamble(slice: &[u8], size: usize) -> Option<usize> {
-        for i in 0..size - GPS_FRAME_BYTES + 1 {
-            if slice[i] == GPS_PREAMBLE_BYTE {
-                return Some(i * 8);
-            }
+    /// - offset in bits ! and whether the polarity is inverted
+    fn find_preamble(&self, slice: &[u8], size: usize, offset: usize) -> Option<(usize, bool)> {
+        // 8-bit sliding window
+        let mut window = 0u8;
+
+        for (pos, bit) in self.input_bits(&slice[..size], offset).enumerate() {
+            window = (window << 1) | (bit ^ self.inverted) as u8;
 
-            // intra byte test
-            let mut byte1_mask = 0x7F;
-            let mut byte2_mask = 0x80;
+            if pos < 7 {
+                continue;
+            }
 
-            for j in 1..8 {
-                let mut value = slice[i + 1];
-                value >>= 8 - j;
-                value |= (slice[i] & byte1_mask) << j;
+            let preamble_offset_bit = offset + pos - 7;
 
-                byte1_mask >>= 1;
-                byte2_mask |= 0x1 << (8 - j);
+            if window == GPS_PREAMBLE_BYTE {
+                return Some((preamble_offset_bit, self.inverted));
+            }
 
-                if value == GPS_PREAMBLE_BYTE {
-                    return Some(i * 8 + j);
-                }
+            if window == !GPS_PREAMBLE_BYTE
+                && self.complemented_sync_ok(&slice[..size], preamble_offset_bit) != Some(false)
+            {
+                return Some((preamble_offset_bit, !self.inverted));
             }
         }
 
         None
     }
 
+    /// Returns an [Iterator] over the input bits, starting at `offset`, in order of transmission.
+    fn input_bits<'a>(&self, slice: &'a [u8], offset: usize) -> BitIter<'a> {
+        let bits = BitIter::new(slice, offset);
+
+        if self.lsb_first {
+            bits.with_lsb_first()
+        } else {
+            bits
+        }
+    }
+
+    /// Verifies the TLM and HOW words that follow this complemented preamble, once complemented:
+    /// the TLM parity must be correct and the HOW must be valid.
+    /// Returns [None] when they are not entirely buffered.
+    fn complemented_sync_ok(&self, slice: &[u8], preamble_offset_bit: usize) -> Option<bool> {
+        let mut words = [0u32; 2];
+        let mut bits = self.input_bits(slice, preamble_offset_bit);
+
+        for word in words.iter_mut() {
+            for _ in 0..GPS_WORD_BITS {
+                let bit = bits.next()? ^ !self.inverted;
+                *word = (*word << 1) | bit as u32;
+            }
+        }
+
+        // previous subframe always ends with D29=D30=0
+        let tlm = GpsDataWord::from(words[0] << 2);
+        let how = GpsDataWord::from(words[1] << 2);
+
+        Some(
+            tlm.parity_check(&GpsDataWord::default(), false).is_ok()
+                && GpsQzssHow::from_word(how.descramble(tlm.d30())).is_ok(),
+        )
+    }
+
     /// Decodes the first valid [GpsQzssFrame] found in this read-only [u8] buffer.
     /// [GpsQzssDecoder] will align itself to the Sync byte, which is not aligned to [u8],
     /// because GPS/QZSS is made of 30 bit data words.
@@ -156,19 +339,221 @@ impl GpsQzssDecoder {
     /// - Total number of _bits_ that were consumed (not bytes!).
     /// You are expected to discard all processed _bits_ not to decode the same frame twice.
     /// - Optional [GpsQzssFrame] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When the buffer ends before the frame does (partial read), nothing past the preamble
+    /// is consumed and [None] is returned: simply append the following bytes and call
+    /// [Self::decode] again. When no preamble was found, the last byte is not consumed,
+    /// because it may contain the beginning of the next preamble.
     pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<GpsQzssFrame>) {
-        let mut dword;
+        let (processed_size, frame) = self.try_decode(buffer, size);
+        (processed_size, frame.unwrap_or_default())
+    }
+
+    /// Decodes the first [GpsQzssFrame] found in this read-only [u8] buffer, like [Self::decode],
+    /// but reports why a frame was rejected.
+    ///
+    /// ## Output
+    /// - Total number of _bits_ that were consumed (not bytes!), like [Self::decode].
+    /// - `Ok(None)` when more data is needed: no preamble was found or the frame is not entirely buffered yet.
+    /// - `Ok(Some(frame))` for each [GpsQzssFrame] correctly decoded.
+    /// - [GpsError] when a frame was found but is structurally invalid (invalid TLM or HOW),
+    ///   which most likely is a false lock on a data byte that looks like the preamble.
+    /// - [GpsError::Parity] when parity verification is turned on and one word is corrupt.
+    ///
+    /// On error, only the preamble (sync byte) is consumed: the next preamble is
+    /// searched right after it, so a following valid frame is not lost.
+    /// You may simply move on.
+    pub fn try_decode(
+        &mut self,
+        buffer: &[u8],
+        size: usize,
+    ) -> (usize, Result<Option<GpsQzssFrame>, GpsError>) {
+        self.try_decode_from(buffer, size, 0)
+    }
+
+    /// Returns an [Iterator] over all the [GpsQzssFrame]s contained in this read-only [u8] buffer,
+    /// in order of appearance. The [GpsQzssDecoder] keeps track of the bit position within the buffer,
+    /// so frames need not be aligned to bytes. Invalid frames are skipped and the iteration ends
+    /// once the data is exhausted (the trailing partial frame, if any, is not consumed).
+    pub fn frames<'a>(&'a mut self, src: &'a [u8]) -> impl Iterator<Item = GpsQzssFrame> + 'a {
+        let mut bit_offset = 0;
+
+        core::iter::from_fn(move || loop {
+            let (processed_size, frame) = self.try_decode_from(src, src.len(), bit_offset);
+
+            match frame {
+                Ok(Some(frame)) => {
+                    bit_offset = processed_size;
+                    return Some(frame);
+                },
+                Ok(None) => return None,
+                Err(_) => bit_offset = processed_size,
+            }
+        })
+    }
+
+    /// Decodes as many [GpsQzssFrame]s as `out` can hold, from this read-only [u8] buffer,
+    /// in a single call. Like [Self::frames], invalid frames are skipped.
+    /// Returns the number of [GpsQzssFrame]s written to `out`, in order of appearance.
+    pub fn decode_all(&mut self, src: &[u8], out: &mut [GpsQzssFrame]) -> usize {
+        let mut count = 0;
+
+        for (slot, frame) in out.iter_mut().zip(self.frames(src)) {
+            *slot = frame;
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Pulls bytes from any [Read](std::io::Read)er (serial port, file..) and decodes
+    /// the next valid [GpsQzssFrame]. The bytes that were pulled but not consumed are
+    /// kept internally, for the next call. Invalid frames are skipped.
+    ///
+    /// ## Output
+    /// - `Ok(Some(frame))` for each [GpsQzssFrame] correctly decoded, in order of appearance.
+    /// - `Ok(None)` once the reader is exhausted.
+    /// - [std::io::Error] when the reader failed.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use gnss_protos::{GpsQzssDecoder, GpsQzssFrame};
+    ///
+    /// let frame = GpsQzssFrame::default();
+    /// let mut reader = Cursor::new(frame.encode_raw());
+    ///
+    /// let mut decoder = GpsQzssDecoder::default();
+    ///
+    /// let decoded = decoder.decode_from(&mut reader)
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(decoded, frame);
+    /// assert!(decoder.decode_from(&mut reader).unwrap().is_none());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn decode_from<R: std::io::Read>(
+        &mut self,
+        reader: &mut R,
+    ) -> std::io::Result<Option<GpsQzssFrame>> {
+        loop {
+            let mut pending = self.pending;
+            let size = self.pending_size;
+
+            let (processed_size, frame) = self.try_decode_from(&pending, size, self.pending_bit);
+
+            // discard the consumed bytes
+            let consumed = (processed_size / 8).min(size);
+            pending.copy_within(consumed..size, 0);
+
+            self.pending = pending;
+            self.pending_size = size - consumed;
+            self.pending_bit = if consumed < size {
+                processed_size % 8
+            } else {
+                0
+            };
+
+            match frame {
+                Ok(Some(frame)) => return Ok(Some(frame)),
+                Err(_) => continue,
+                Ok(None) => {},
+            }
+
+            // more data is needed
+            let free = &mut self.pending[self.pending_size..];
+
+            let read = loop {
+                match reader.read(free) {
+                    Ok(read) => break read,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {},
+                    Err(e) => return Err(e),
+                }
+            };
+
+            if read == 0 {
+                return Ok(None);
+            }
+
+            self.pending_size += read;
+        }
+    }
+
+    /// [Self::try_decode] implementation, scanning for the preamble from `offset_bit` onwards,
+    /// that updates the [GpsQzssDecoderStats].
+    fn try_decode_from(
+        &mut self,
+        buffer: &[u8],
+        size: usize,
+        offset_bit: usize,
+    ) -> (usize, Result<Option<GpsQzssFrame>, GpsError>) {
+        let (processed_size, frame) = self.decode_at(buffer, size, offset_bit);
+
+        self.stats.consumed_bits += processed_size.saturating_sub(offset_bit);
+
+        match frame {
+            Ok(Some(_)) => self.stats.frames += 1,
+            Ok(None) => {},
+            Err(GpsError::Parity) => self.stats.parity_failures += 1,
+            Err(_) => self.stats.false_locks += 1,
+        }
+
+        (processed_size, frame)
+    }
+
+    /// Decodes the first [GpsQzssFrame] found from `offset_bit` onwards.
+    fn decode_at(
+        &mut self,
+        buffer: &[u8],
+        size: usize,
+        offset_bit: usize,
+    ) -> (usize, Result<Option<GpsQzssFrame>, GpsError>) {
+        // never read past the actual buffer
+        let size = size.min(buffer.len());
 
         // locate preamble
-        let preamble_offset_bit = Self::find_preamble(buffer, size);
+        let (preamble_offset_bit, inverted) = match self.find_preamble(buffer, size, offset_bit) {
+            Some(preamble) => preamble,
+            None => {
+                // marks all bits as consumed, but the last byte
+                return (size.saturating_sub(1) * 8, Ok(None));
+            },
+        };
 
-        if preamble_offset_bit.is_none() {
-            // marks all bits as consumed
-            return (size * 8 - GPS_FRAME_BITS, None);
+        if inverted == self.inverted {
+            return self.decode_frame(buffer, size, preamble_offset_bit);
         }
 
-        // align to sync byte
-        let preamble_offset_bit = preamble_offset_bit.unwrap();
+        // polarity inversion: only confirmed once a frame was correctly decoded
+        self.inverted = inverted;
+
+        let (processed_size, frame) = self.decode_frame(buffer, size, preamble_offset_bit);
+
+        if let Ok(Some(_)) = frame {
+            #[cfg(feature = "log")]
+            debug!("(GPS/QZSS) [polarity]: inverted={}", self.inverted);
+        } else {
+            self.inverted = !inverted;
+        }
+
+        (processed_size, frame)
+    }
+
+    /// Decodes the [GpsQzssFrame] starting at this preamble position (in bits).
+    fn decode_frame(
+        &mut self,
+        buffer: &[u8],
+        size: usize,
+        preamble_offset_bit: usize,
+    ) -> (usize, Result<Option<GpsQzssFrame>, GpsError>) {
+        // wait for the complete frame
+        let frame_end = (preamble_offset_bit / 8 + GPS_FRAME_BYTES)
+            .max((preamble_offset_bit + GPS_FRAME_BITS + 7) / 8);
+
+        if frame_end > size {
+            return (preamble_offset_bit, Ok(None));
+        }
 
         #[cfg(feature = "log")]
         trace!(
@@ -180,19 +565,10 @@ impl GpsQzssDecoder {
             buffer[preamble_offset_bit / 8],
         );
 
-        self.resync_align(buffer, preamble_offset_bit);
-
-        dword = u32::from_be_bytes([
-            self.buffer[0],
-            self.buffer[1],
-            self.buffer[2],
-            self.buffer[3],
-        ]);
+        self.resync_align(buffer, size, preamble_offset_bit);
 
-        let gps_word = GpsDataWord::from(dword);
-        let parity = gps_word.parity(&Default::default(), false);
-
-        // panic!("LSB=0x{:02}X PAR=0x{:02X}", (gps_word.value() & 0x3f) as u8, parity);
+        let gps_word = GpsDataWord::from(read_word_be(&self.buffer, 0));
+        let tlm_word = gps_word;
 
         let telemetry = match GpsQzssTelemetry::from_word(gps_word) {
             Ok(telemetry) => {
@@ -200,129 +576,114 @@ impl GpsQzssDecoder {
                 debug!("(GPS/QZSS) [telemetry]: {}", telemetry);
                 telemetry
             },
-            #[cfg(not(feature = "log"))]
-            Err(_) => {
-                return (preamble_offset_bit + GPS_FRAME_BITS, None);
-            },
-            #[cfg(feature = "log")]
             Err(e) => {
+                #[cfg(feature = "log")]
                 error!("(GPS/QZSS) [telemetry]: {} ({:?})", e, gps_word);
-                return (preamble_offset_bit + GPS_FRAME_BITS, None);
+                return (preamble_offset_bit + 8, Err(e));
             },
         };
 
-        dword = self.buffer[7] as u32;
-        dword |= (self.buffer[6] as u32) << 8;
-        dword |= (self.buffer[5] as u32) << 16;
-        dword |= (self.buffer[4] as u32) << 24;
-        dword >>= 2;
-        dword |= ((self.buffer[3] as u32) & 0x03) << 28;
-
-        let gps_word = GpsDataWord::from(dword);
-        let parity = gps_word.parity(&Default::default(), false);
+        let gps_word = GpsDataWord::from(read_word_be(&self.buffer, GPS_WORD_BITS));
+        let how_word = gps_word;
 
-        let how = match GpsQzssHow::from_word(gps_word) {
+        // HOW data bits are inverted when the TLM ends with D30=1
+        let how = match GpsQzssHow::from_word(gps_word.descramble(tlm_word.d30())) {
             Ok(how) => {
                 #[cfg(feature = "log")]
                 debug!("(GPS/QZSS)       [how]: {}", how);
                 how
             },
-            #[cfg(not(feature = "log"))]
-            Err(_) => {
-                return (preamble_offset_bit + GPS_FRAME_BITS, None);
-            },
-            #[cfg(feature = "log")]
             Err(e) => {
+                #[cfg(feature = "log")]
                 error!("(GPS/QZSS) [how]: {}", e);
-                return (preamble_offset_bit + GPS_FRAME_BITS, None);
+
+                // most likely a data byte that looks like the preamble (false lock):
+                // a real frame may start within the next 300 bits
+                return (preamble_offset_bit + 8, Err(e));
             },
         };
 
         // collect 8 data words
-        dword = ((self.buffer[11] & 0xC0) as u32) >> 4;
-        dword |= ((self.buffer[10] & 0x0f) as u32) << 4;
-        dword |= (((self.buffer[10] & 0xf0) as u32) >> 4) << 8;
-        dword |= ((self.buffer[9] & 0x0f) as u32) << 12;
-        dword |= (((self.buffer[9] & 0xf0) as u32) >> 4) << 16;
-        dword |= ((self.buffer[8] & 0x0f) as u32) << 20;
-        dword |= (((self.buffer[8] & 0xf0) as u32) >> 4) << 24;
-        dword |= ((self.buffer[7] & 0x0f) as u32) << 28;
-
-        self.words[0] = GpsDataWord::from(dword);
-
-        dword = (self.buffer[14] as u32) << (8 - 6);
-        dword |= (self.buffer[13] as u32) << (16 - 6);
-        dword |= (self.buffer[12] as u32) << (24 - 6);
-        dword |= (self.buffer[11] as u32) << (32 - 6);
-
-        self.words[1] = GpsDataWord::from(dword);
-
-        dword = (self.buffer[15] as u32) << 24;
-        dword |= (self.buffer[16] as u32) << 16;
-        dword |= (self.buffer[17] as u32) << 8;
-        dword |= self.buffer[18] as u32;
-
-        self.words[2] = GpsDataWord::from(dword);
-
-        dword = (self.buffer[22] as u32) << 4;
-        dword |= (self.buffer[21] as u32) << (8 - 2);
-        dword |= (self.buffer[20] as u32) << (16 - 2);
-        dword |= (self.buffer[19] as u32) << (24 - 2);
-        dword |= (self.buffer[18] as u32) << (32 - 2);
-
-        self.words[3] = GpsDataWord::from(dword);
-
-        dword = self.buffer[25] as u32;
-        dword |= (self.buffer[24] as u32) << 8;
-        dword |= (self.buffer[23] as u32) << 16;
-        dword |= (self.buffer[22] as u32) << 24;
-        dword <<= 4;
-        dword |= ((self.buffer[26] & 0xC0) as u32) >> 6;
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word = GpsDataWord::from(read_word_be(&self.buffer, (i + 2) * GPS_WORD_BITS));
+        }
 
-        self.words[4] = GpsDataWord::from(dword);
+        if self.parity_verification {
+            // previous subframe always ends with D29=D30=0
+            let mut prev = GpsDataWord::default();
 
-        dword = self.buffer[29] as u32;
-        dword |= (self.buffer[28] as u32) << 8;
-        dword |= (self.buffer[27] as u32) << 16;
-        dword |= (self.buffer[26] as u32) << 24;
-        dword <<= 2;
+            for word in [tlm_word, how_word].iter().chain(self.words.iter()) {
+                if let Err(e) = word.parity_check(&prev, false) {
+                    #[cfg(feature = "log")]
+                    error!("(GPS/QZSS) [parity]: invalid parity ({:?})", word);
 
-        self.words[5] = GpsDataWord::from(dword);
+                    // the preamble may have been a false lock, or the following frame
+                    // may start within this one (truncated frame): resync right after
+                    // the sync byte, so we do not lock onto the same preamble twice.
+                    return (preamble_offset_bit + 8, Err(e));
+                }
 
-        dword = (self.buffer[30] as u32) << 24;
-        dword |= (self.buffer[31] as u32) << 16;
-        dword |= (self.buffer[32] as u32) << 8;
-        dword |= self.buffer[33] as u32;
-        self.words[6] = GpsDataWord::from(dword);
+                prev = *word;
+            }
+        }
 
-        dword = (self.buffer[37] as u32) << 4;
-        dword |= (self.buffer[36] as u32) << (8 - 2);
-        dword |= (self.buffer[35] as u32) << (16 - 2);
-        dword |= (self.buffer[34] as u32) << (24 - 2);
-        dword |= (self.buffer[33] as u32) << (32 - 2);
+        // interprets
+        // unsupported pages are passed through
+        let subframe =
+            GpsQzssSubframe::decode_raw(how.frame_id.encode(), &self.words, how_word.d30());
 
-        self.words[7] = GpsDataWord::from(dword);
+        #[cfg(feature = "log")]
+        if subframe.as_raw().is_some() {
+            warn!("(GPS/QZSS) [{}]: unsupported page", how.frame_id);
+        }
 
-        // interprets
         let frame = GpsQzssFrame {
             how,
             telemetry,
-            subframe: GpsQzssSubframe::decode(how.frame_id, &self.words),
+            subframe,
+            constellation: self.constellation,
         };
 
-        (preamble_offset_bit + GPS_FRAME_BITS, Some(frame))
+        (preamble_offset_bit + GPS_FRAME_BITS, Ok(Some(frame)))
+    }
+
+    /// Decodes the first valid [GpsQzssFrame] found in this read-only [u8] buffer,
+    /// like [Self::decode], but reports the consumption in _bytes_ rather than bits.
+    /// This is convenient to reconcile input offsets with the decoded output.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Output
+    /// - [GpsQzssFrame] correctly decoded and total number of bytes that
+    ///   may be discarded. The byte that contains the last bits of the frame is not
+    ///   included, because it may contain the beginning of the next frame.
+    ///
+    /// [None] is returned when no frame could be decoded: use [Self::decode]
+    /// if you need to know how many bits may be discarded in that case.
+    pub fn decode_counted(&mut self, buffer: &[u8], size: usize) -> Option<(GpsQzssFrame, usize)> {
+        let (processed_size, frame) = self.decode(buffer, size);
+        frame.map(|frame| (frame, processed_size / 8))
     }
 }
 
 #[cfg(test)]
 mod decoder {
-    use std::{fs::File, io::Read};
+    use std::{
+        fs::File,
+        io::{Cursor, Read},
+    };
 
     use crate::{
+        bits::{get_bits, read_word_be, set_bits},
         gps::{
-            GpsQzssDecoder, GpsQzssFrame, GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3,
-            GpsQzssFrameId, GPS_FRAME_BITS, GPS_FRAME_BYTES,
+            GpsDataWord, GpsError, GpsQzssAlmanachStatus, GpsQzssConstellation, GpsQzssDecoder,
+            GpsQzssFrame, GpsQzssFrame2, GpsQzssFrame4, GpsQzssFrame5, GpsQzssFrameId,
+            GpsQzssSubframe, GpsQzssTelemetry, GPS_FRAME_BITS, GPS_FRAME_BYTES,
+            GPS_WORDS_PER_FRAME, GPS_WORD_BITS,
         },
+        testing::insert_garbage,
         tests::insert_zeros,
     };
 
@@ -330,6 +691,128 @@ mod decoder {
 
     use log::info;
 
+    /// Word extraction, as it was hand written prior [read_word_be].
+    fn hand_shifted_words(buffer: &[u8]) -> [u32; GPS_WORDS_PER_FRAME] {
+        let mut dword;
+        let mut words = [0; GPS_WORDS_PER_FRAME];
+
+        dword = u32::from_be_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]);
+        words[0] = dword;
+
+        dword = ((buffer[7] & 0xf0) as u32) >> 2;
+        dword |= (buffer[6] as u32) << 6;
+        dword |= (buffer[5] as u32) << 14;
+        dword |= (buffer[4] as u32) << 22;
+        dword |= ((buffer[3] as u32) & 0x03) << 30;
+        words[1] = dword;
+
+        dword = ((buffer[11] & 0xC0) as u32) >> 4;
+        dword |= ((buffer[10] & 0x0f) as u32) << 4;
+        dword |= (((buffer[10] & 0xf0) as u32) >> 4) << 8;
+        dword |= ((buffer[9] & 0x0f) as u32) << 12;
+        dword |= (((buffer[9] & 0xf0) as u32) >> 4) << 16;
+        dword |= ((buffer[8] & 0x0f) as u32) << 20;
+        dword |= (((buffer[8] & 0xf0) as u32) >> 4) << 24;
+        dword |= ((buffer[7] & 0x0f) as u32) << 28;
+        words[2] = dword;
+
+        dword = (buffer[14] as u32) << (8 - 6);
+        dword |= (buffer[13] as u32) << (16 - 6);
+        dword |= (buffer[12] as u32) << (24 - 6);
+        dword |= (buffer[11] as u32) << (32 - 6);
+        words[3] = dword;
+
+        dword = (buffer[15] as u32) << 24;
+        dword |= (buffer[16] as u32) << 16;
+        dword |= (buffer[17] as u32) << 8;
+        dword |= buffer[18] as u32;
+        words[4] = dword;
+
+        dword = ((buffer[22] & 0xf0) as u32) >> 2;
+        dword |= (buffer[21] as u32) << (8 - 2);
+        dword |= (buffer[20] as u32) << (16 - 2);
+        dword |= (buffer[19] as u32) << (24 - 2);
+        dword |= (buffer[18] as u32) << (32 - 2);
+        words[5] = dword;
+
+        dword = buffer[25] as u32;
+        dword |= (buffer[24] as u32) << 8;
+        dword |= (buffer[23] as u32) << 16;
+        dword |= (buffer[22] as u32) << 24;
+        dword <<= 4;
+        dword |= ((buffer[26] & 0xC0) as u32) >> 4;
+        words[6] = dword;
+
+        dword = buffer[29] as u32;
+        dword |= (buffer[28] as u32) << 8;
+        dword |= (buffer[27] as u32) << 16;
+        dword |= (buffer[26] as u32) << 24;
+        dword <<= 2;
+        words[7] = dword;
+
+        dword = (buffer[30] as u32) << 24;
+        dword |= (buffer[31] as u32) << 16;
+        dword |= (buffer[32] as u32) << 8;
+        dword |= buffer[33] as u32;
+        words[8] = dword;
+
+        dword = (buffer[37] as u32) << 4;
+        dword |= (buffer[36] as u32) << (8 - 2);
+        dword |= (buffer[35] as u32) << (16 - 2);
+        dword |= (buffer[34] as u32) << (24 - 2);
+        dword |= (buffer[33] as u32) << (32 - 2);
+        words[9] = dword;
+
+        words
+    }
+
+    #[test]
+    fn word_extraction() {
+        // pseudo random content
+        let mut seed = 0x1234_5678u32;
+        let mut buffer = [0u8; GPS_FRAME_BYTES + 1];
+
+        for _ in 0..64 {
+            for byte in buffer.iter_mut() {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                *byte = (seed >> 16) as u8;
+            }
+
+            // the hand written extraction of the 10th word was only correct
+            // when the last byte (4 bits of the 10th word + padding) was null
+            let last_nibble = buffer[GPS_FRAME_BYTES - 1] >> 4;
+            buffer[GPS_FRAME_BYTES - 1] = 0;
+
+            let expected = hand_shifted_words(&buffer);
+
+            for (i, expected) in expected.iter().enumerate() {
+                let word = read_word_be(&buffer, i * GPS_WORD_BITS);
+
+                assert_eq!(
+                    GpsDataWord::from(word).value(),
+                    GpsDataWord::from(*expected).value(),
+                    "word #{} extraction error",
+                    i + 1
+                );
+            }
+
+            buffer[GPS_FRAME_BYTES - 1] = last_nibble << 4;
+
+            let word = GpsDataWord::from(read_word_be(&buffer, 9 * GPS_WORD_BITS));
+            assert_eq!(word.value() & 0x0f, last_nibble as u32);
+        }
+
+        assert_eq!(
+            read_word_be(&[0x8B, 0x12, 0x34, 0x56, 0x78], 0),
+            0x8B12_3456
+        );
+        assert_eq!(
+            read_word_be(&[0x8B, 0x12, 0x34, 0x56, 0x78], 4),
+            0xB123_4567
+        );
+        assert_eq!(read_word_be(&[0x8B, 0x12], 4), 0xB120_0000);
+    }
+
     #[test]
     fn preamble_search() {
         init_logger();
@@ -345,13 +828,20 @@ mod decoder {
 
             file.read(&mut buffer).unwrap();
 
-            assert_eq!(GpsQzssDecoder::find_preamble(&buffer, 8192), Some(0));
+            assert_eq!(
+                GpsQzssDecoder::default()
+                    .find_preamble(&buffer, 8192, 0)
+                    .map(|(pos, _)| pos),
+                Some(0)
+            );
 
             // test delay < 1 byte
             for i in 1..7 {
                 let delayed = insert_zeros(&buffer, i);
                 assert_eq!(
-                    GpsQzssDecoder::find_preamble(&delayed, 8192),
+                    GpsQzssDecoder::default()
+                        .find_preamble(&delayed, 8192, 0)
+                        .map(|(pos, _)| pos),
                     Some(i),
                     "failed for bit position {}",
                     i
@@ -362,7 +852,9 @@ mod decoder {
             let delayed = insert_zeros(&buffer, 8);
 
             assert_eq!(
-                GpsQzssDecoder::find_preamble(&delayed, 8192),
+                GpsQzssDecoder::default()
+                    .find_preamble(&delayed, 8192, 0)
+                    .map(|(pos, _)| pos),
                 Some(8),
                 "failed for bit position 8"
             );
@@ -372,7 +864,9 @@ mod decoder {
                 let delayed = insert_zeros(&buffer, i + 8);
 
                 assert_eq!(
-                    GpsQzssDecoder::find_preamble(&delayed, 8192),
+                    GpsQzssDecoder::default()
+                        .find_preamble(&delayed, 8192, 0)
+                        .map(|(pos, _)| pos),
                     Some(8 + i),
                     "failed for bit position {}",
                     8 + i
@@ -384,7 +878,9 @@ mod decoder {
                 let delayed = insert_zeros(&buffer, i * 8);
 
                 assert_eq!(
-                    GpsQzssDecoder::find_preamble(&delayed, 8192),
+                    GpsQzssDecoder::default()
+                        .find_preamble(&delayed, 8192, 0)
+                        .map(|(pos, _)| pos),
                     Some(i * 8),
                     "failed for bit position {}",
                     i * 8
@@ -393,7 +889,9 @@ mod decoder {
                 for j in 1..7 {
                     let delayed = insert_zeros(&buffer, i * 8 + j);
                     assert_eq!(
-                        GpsQzssDecoder::find_preamble(&delayed, 8192),
+                        GpsQzssDecoder::default()
+                            .find_preamble(&delayed, 8192, 0)
+                            .map(|(pos, _)| pos),
                         Some(i * 8 + j),
                         "failed for bit position {}",
                         i * 8 + j
@@ -403,6 +901,248 @@ mod decoder {
         }
     }
 
+    #[test]
+    fn sub_byte_synchronization() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+            GpsQzssFrameId::Ephemeris4,
+            GpsQzssFrameId::Ephemeris5,
+        ] {
+            let encoded = GpsQzssFrame::model(frame_id).encode_raw();
+
+            // reference: byte aligned frame (model may not be exactly encoded)
+            let (_, model) = GpsQzssDecoder::default().decode(&encoded, encoded.len());
+            assert!(model.is_some(), "{:?}: aligned frame not decoded", frame_id);
+
+            // up to a full byte + 7 bits
+            for zeros in 1..16 {
+                let delayed = insert_zeros(&encoded, zeros);
+
+                let mut decoder = GpsQzssDecoder::default();
+                let (processed, decoded) = decoder.decode(&delayed, delayed.len());
+
+                assert_eq!(
+                    processed,
+                    zeros + GPS_FRAME_BITS,
+                    "{:?}: invalid size for bit position {}",
+                    frame_id,
+                    zeros
+                );
+
+                assert_eq!(
+                    decoded, model,
+                    "{:?}: failed for bit position {}",
+                    frame_id, zeros
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn delayed_stream() {
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..8 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&frame.encode_raw());
+        }
+
+        for zeros in 0..16 {
+            let delayed = insert_zeros(&stream, zeros);
+
+            let mut decoder = GpsQzssDecoder::default();
+
+            let messages = decoder
+                .frames(&delayed)
+                .map(|frame| frame.telemetry.message - model.telemetry.message)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                messages,
+                [0, 1, 2, 3, 4, 5, 6, 7],
+                "failed for bit position {}",
+                zeros
+            );
+        }
+    }
+
+    #[test]
+    fn qzss_decoder() {
+        let frame2 = GpsQzssFrame2::default().with_fit_interval_flag();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris2)
+            .with_subframe(GpsQzssSubframe::Ephemeris2(frame2));
+
+        let encoded = model.encode_raw();
+
+        let mut decoder = GpsQzssDecoder::default();
+        assert_eq!(decoder.constellation(), GpsQzssConstellation::Gps);
+
+        let (_, decoded) = decoder.decode(&encoded, encoded.len());
+        let decoded = decoded.unwrap();
+
+        assert_eq!(decoded.constellation, GpsQzssConstellation::Gps);
+
+        let mut decoder = GpsQzssDecoder::qzss();
+        assert_eq!(decoder.constellation(), GpsQzssConstellation::Qzss);
+
+        let (_, decoded) = decoder.decode(&encoded, encoded.len());
+        let decoded = decoded.unwrap();
+
+        assert_eq!(decoded.constellation, GpsQzssConstellation::Qzss);
+        assert_eq!(
+            decoded,
+            model.with_constellation(GpsQzssConstellation::Qzss)
+        );
+
+        let frame2 = decoded.subframe.as_eph2().unwrap();
+
+        assert_eq!(frame2.fit_interval_hours(decoded.constellation, 0), None);
+        assert_eq!(
+            frame2.fit_interval_hours(GpsQzssConstellation::Gps, 0),
+            Some(6.0)
+        );
+    }
+
+    #[test]
+    fn error_surfacing() {
+        let encoded = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1).encode_raw();
+
+        // need more data
+        let mut decoder = GpsQzssDecoder::default();
+        let (processed, decoded) = decoder.try_decode(&encoded, GPS_FRAME_BYTES - 2);
+        assert_eq!(processed, 0);
+        assert!(matches!(decoded, Ok(None)));
+
+        let (_, decoded) = decoder.try_decode(&encoded, encoded.len());
+        assert!(matches!(decoded, Ok(Some(_))));
+
+        // unknown frame ID (#0, #6 or #7): toggles the HOW frame ID bits
+        for toggle in [0x1, 0x6, 0x7] {
+            let mut corrupt = encoded;
+            let frame_id = get_bits(&corrupt, 49, 3);
+            set_bits(&mut corrupt, 49, 3, frame_id ^ toggle);
+
+            // only the preamble is consumed (resync)
+            let (processed, decoded) = decoder.try_decode(&corrupt, corrupt.len());
+            assert_eq!(processed, 8);
+            assert!(
+                decoded == Err(GpsError::UnknownFrameType((frame_id ^ toggle) as u8)),
+                "{:?}",
+                decoded
+            );
+
+            // not surfaced by the infallible API
+            let (processed, decoded) = decoder.decode(&corrupt, corrupt.len());
+            assert_eq!(processed, 8);
+            assert!(decoded.is_none());
+        }
+
+        // corrupt data bit
+        let mut corrupt = encoded;
+        corrupt[20] ^= 0x10;
+
+        let mut decoder = GpsQzssDecoder::default().with_parity_verification();
+        let (processed, decoded) = decoder.try_decode(&corrupt, corrupt.len());
+        assert_eq!(processed, 8);
+        assert!(matches!(decoded, Err(GpsError::Parity)), "{:?}", decoded);
+    }
+
+    #[test]
+    fn spurious_preamble() {
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        // data byte that looks like a preamble, a few bytes before the actual frame:
+        // the following HOW (null subframe ID) gives the false lock away
+        let mut stream = vec![0; 10];
+        stream[0] = 0x8B;
+        stream.extend_from_slice(&model.encode_raw());
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        let (processed, decoded) = decoder.try_decode(&stream, stream.len());
+        assert_eq!(processed, 8);
+        assert_eq!(decoded, Err(GpsError::UnknownFrameType(0)));
+
+        // the actual frame is not swallowed by the false lock
+        let (processed, decoded) = decoder.try_decode_from(&stream, stream.len(), processed);
+        assert_eq!(processed, 10 * 8 + GPS_FRAME_BITS);
+        assert_eq!(decoded, Ok(Some(model)));
+
+        let mut decoder = GpsQzssDecoder::default();
+        assert_eq!(decoder.frames(&stream).collect::<Vec<_>>(), [model]);
+        assert_eq!(decoder.stats().false_locks, 1);
+    }
+
+    #[test]
+    fn frame5_page25() {
+        let status = GpsQzssAlmanachStatus::default()
+            .with_toa_seconds(319_488)
+            .with_week(12)
+            .with_sv_health(1, 0x3f)
+            .with_sv_health(12, 0x01)
+            .with_sv_health(24, 0x2a);
+
+        let frame = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris5)
+            .with_subframe(GpsQzssSubframe::Frame5(GpsQzssFrame5::Page25(status)));
+
+        assert_eq!(frame.how.frame_id, GpsQzssFrameId::Ephemeris5);
+
+        let encoded = frame.encode_raw();
+
+        let mut decoder = GpsQzssDecoder::default().with_parity_verification();
+        let (processed, decoded) = decoder.try_decode(&encoded, encoded.len());
+
+        assert_eq!(processed, GPS_FRAME_BITS);
+
+        let decoded = decoded.unwrap().unwrap();
+        assert_eq!(decoded, frame);
+
+        match decoded.subframe.as_frame5() {
+            Some(GpsQzssFrame5::Page25(decoded)) => {
+                assert_eq!(decoded.sv_health[0], 0x3f);
+                assert_eq!(decoded.sv_health[11], 0x01);
+                assert_eq!(decoded.sv_health[23], 0x2a);
+            },
+            other => panic!("wrong subframe decoded: {:?}", other),
+        }
+
+        // unknown frame 5 page: page 25 of frame 4 (SV ID #63), passed through
+        let frame = frame
+            .with_subframe(GpsQzssSubframe::Frame4(GpsQzssFrame4::Page25(
+                Default::default(),
+            )))
+            .with_hand_over_word(frame.how);
+
+        assert_eq!(frame.how.frame_id, GpsQzssFrameId::Ephemeris5);
+
+        let encoded = frame.encode_raw();
+
+        let (processed, decoded) = decoder.try_decode(&encoded, encoded.len());
+        assert_eq!(processed, GPS_FRAME_BITS);
+
+        let decoded = decoded.unwrap().unwrap();
+        assert_eq!(decoded.how, frame.how);
+        assert!(decoded.subframe.as_frame5().is_none());
+
+        // data bits passed through (parity bits are kept as received)
+        let raw = decoded.subframe.as_raw().unwrap();
+
+        for (decoded, expected) in raw.iter().zip(frame.subframe.to_words().iter()) {
+            assert_eq!(decoded.bits(1, 24), expected.bits(1, 24));
+        }
+
+        let (_, decoded) = decoder.decode(&encoded, encoded.len());
+        assert!(decoded.is_some());
+    }
+
     #[test]
     fn eph1_bin() {
         init_logger();
@@ -437,9 +1177,9 @@ mod decoder {
                 // first RX
                 assert_eq!(processed_size, GPS_FRAME_BITS); // bits!
             } else {
-                // following RX
-                // TODO +8 expected here, not 16
-                assert_eq!(processed_size, GPS_FRAME_BITS + 16); // bits!
+                // following RX: 4 bits of the previous frame (not discarded, because they share
+                // the same byte), followed by the 4 padding bits of the previous frame
+                assert_eq!(processed_size, GPS_FRAME_BITS + 8); // bits!
             }
 
             let decoded = decoded.unwrap(); // success (we have 128 frames)
@@ -460,29 +1200,495 @@ mod decoder {
                     message
                 );
 
-                if message % 2 == 0 {
-                    assert_eq!(decoded.telemetry.integrity, false);
-                    assert_eq!(decoded.telemetry.reserved_bit, false);
-                    assert_eq!(decoded.how.alert, false);
-                    assert_eq!(decoded.how.anti_spoofing, false);
-                } else {
-                    assert_eq!(decoded.telemetry.integrity, true);
-                    assert_eq!(decoded.telemetry.reserved_bit, true);
-                    assert_eq!(decoded.how.alert, true);
-                    assert_eq!(decoded.how.anti_spoofing, true);
-                }
-            }
+                if message % 2 == 0 {
+                    assert_eq!(decoded.telemetry.integrity, false);
+                    assert_eq!(decoded.telemetry.reserved_bit, false);
+                    assert_eq!(decoded.how.alert, false);
+                    assert_eq!(decoded.how.anti_spoofing, false);
+                } else {
+                    assert_eq!(decoded.telemetry.integrity, true);
+                    assert_eq!(decoded.telemetry.reserved_bit, true);
+                    assert_eq!(decoded.how.alert, true);
+                    assert_eq!(decoded.how.anti_spoofing, true);
+                }
+            }
+
+            info!("EPH-1.bin MESSAGE {}", message + 1);
+
+            ptr += processed_size / 8;
+            size -= processed_size / 8;
+
+            if size <= GPS_FRAME_BYTES - 2 {
+                assert_eq!(message, 128, "did not parse enough messages");
+            }
+        }
+        assert_eq!(message, 128, "did not parse enough messages");
+    }
+
+    #[test]
+    fn eph1_bin_frames() {
+        init_logger();
+
+        let mut buffer = [0; 8192]; // single read
+
+        let mut file = File::open("data/GPS/eph1.bin").unwrap();
+        let size = file.read(&mut buffer).unwrap();
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut message = 0;
+
+        for frame in decoder.frames(&buffer[..size]) {
+            assert!(
+                frame.subframe.as_eph1().is_some(),
+                "wrong frame type decoded"
+            );
+
+            assert_eq!(
+                frame.telemetry.message,
+                model.telemetry.message + message,
+                "error at message {}",
+                message + 1
+            );
+
+            message += 1;
+        }
+
+        assert_eq!(message, 128, "did not parse enough messages");
+    }
+
+    #[test]
+    fn bulk_decoding() {
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = vec![0; 128 * GPS_FRAME_BYTES];
+        let mut bit_offset = 0;
+
+        for i in 0..128 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            bit_offset = frame.encode_continuous(&mut stream, bit_offset).unwrap();
+        }
+
+        let mut decoder = GpsQzssDecoder::default();
+        let mut frames = [GpsQzssFrame::default(); 128];
+
+        assert_eq!(decoder.decode_all(&stream, &mut frames), 128);
+
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(
+                frame.telemetry.message - model.telemetry.message,
+                i as u16,
+                "frame #{} is out of order",
+                i
+            );
+        }
+
+        // output is full: remaining frames are left aside
+        let mut frames = [GpsQzssFrame::default(); 16];
+        assert_eq!(decoder.decode_all(&stream, &mut frames), 16);
+        assert_eq!(frames[15].telemetry.message, model.telemetry.message + 15);
+
+        // trailing frames only
+        let mut frames = [GpsQzssFrame::default(); 128];
+        let offset = 120 * GPS_FRAME_BITS / 8;
+        assert_eq!(decoder.decode_all(&stream[offset..], &mut frames), 8);
+
+        assert_eq!(decoder.decode_all(&[], &mut frames), 0);
+    }
+
+    #[test]
+    fn frames_iterator() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        // continuous stream (frames are not aligned to bytes), with a few delay bits
+        // and an invalid frame in between
+        let mut stream = vec![0; 16 * GPS_FRAME_BYTES + 8];
+        let mut bit_offset = 3;
+
+        for i in 0..16 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            bit_offset = frame.encode_continuous(&mut stream, bit_offset).unwrap();
+        }
+
+        // truncated frame at the end of the stream
+        let mut truncated = [0; GPS_FRAME_BYTES];
+        model.encode_continuous(&mut truncated, 0).unwrap();
+
+        set_bits(&mut stream, bit_offset, 32, get_bits(&truncated, 0, 32));
+
+        // invalid HOW frame ID (0) on the 5th frame
+        set_bits(&mut stream, 3 + 4 * GPS_FRAME_BITS + 49, 3, 0);
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        let messages = decoder
+            .frames(&stream)
+            .map(|frame| frame.telemetry.message - model.telemetry.message)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [0, 1, 2, 3, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+
+        assert_eq!(GpsQzssDecoder::default().frames(&[]).count(), 0);
+    }
+
+    #[test]
+    fn eph1_bin_reader() {
+        init_logger();
+
+        let mut buffer = Vec::new();
+
+        let mut file = File::open("data/GPS/eph1.bin").unwrap();
+        file.read_to_end(&mut buffer).unwrap();
+
+        let mut reader = Cursor::new(buffer);
+        let mut decoder = GpsQzssDecoder::default();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut message = 0;
+
+        while let Some(frame) = decoder.decode_from(&mut reader).unwrap() {
+            assert!(
+                frame.subframe.as_eph1().is_some(),
+                "wrong frame type decoded"
+            );
+
+            assert_eq!(
+                frame.telemetry.message,
+                model.telemetry.message + message,
+                "error at message {}",
+                message + 1
+            );
+
+            message += 1;
+        }
+
+        assert_eq!(message, 128, "did not parse enough messages");
+    }
+
+    /// [Read]er that never returns more than a few bytes at once, like a serial port
+    struct SlowReader<'a>(&'a [u8]);
+
+    impl Read for SlowReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let size = buf.len().min(self.0.len()).min(7);
+            buf[..size].copy_from_slice(&self.0[..size]);
+            self.0 = &self.0[size..];
+            Ok(size)
+        }
+    }
+
+    #[test]
+    fn reader_decoding() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        // continuous stream (frames are not aligned to bytes), with a few delay bits
+        // and an invalid frame in between
+        let mut stream = vec![0; 16 * GPS_FRAME_BYTES];
+        let mut bit_offset = 5;
+
+        for i in 0..16 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            bit_offset = frame.encode_continuous(&mut stream, bit_offset).unwrap();
+        }
+
+        // invalid HOW frame ID (0) on the 3rd frame
+        set_bits(&mut stream, 5 + 2 * GPS_FRAME_BITS + 49, 3, 0);
+
+        let expected = [0, 1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+        let mut decoder = GpsQzssDecoder::default();
+        let mut reader = Cursor::new(&stream);
+        let mut messages = Vec::new();
+
+        while let Some(frame) = decoder.decode_from(&mut reader).unwrap() {
+            assert_eq!(frame.subframe, model.subframe);
+            messages.push(frame.telemetry.message - model.telemetry.message);
+        }
+
+        assert_eq!(messages, expected);
+        assert_eq!(decoder.stats().frames, 15);
+
+        // the invalid frame, then a preamble-like byte within it
+        assert_eq!(decoder.stats().false_locks, 2);
+
+        // exhausted
+        assert!(decoder.decode_from(&mut reader).unwrap().is_none());
+
+        let mut decoder = GpsQzssDecoder::default();
+        let mut reader = SlowReader(&stream);
+        let mut messages = Vec::new();
+
+        while let Some(frame) = decoder.decode_from(&mut reader).unwrap() {
+            messages.push(frame.telemetry.message - model.telemetry.message);
+        }
+
+        assert_eq!(messages, expected);
+    }
+
+    /// Decodes this stream of Ephemeris #1 frames, that was interrupted by a burst of garbage
+    /// within frame #`interrupted`, and verifies that the decoder locks again.
+    /// Up to `lost` frames that follow the interrupted frame may be lost as well,
+    /// because a false lock within the garbage may swallow the following preamble.
+    fn verify_resync(
+        mut decoder: GpsQzssDecoder,
+        stream: &[u8],
+        num_frames: u16,
+        interrupted: u16,
+        lost: u16,
+        first_message: u16,
+    ) {
+        // false locks within the garbage may look like valid frames, when parity is not verified
+        let messages = decoder
+            .frames(stream)
+            .filter(|frame| frame.subframe.as_eph1().is_some())
+            .map(|frame| frame.telemetry.message.wrapping_sub(first_message))
+            .filter(|message| *message < num_frames)
+            .collect::<Vec<_>>();
+
+        for message in (0..interrupted).chain(interrupted + 1 + lost..num_frames) {
+            assert!(
+                messages.contains(&message),
+                "frame #{} not decoded: {:?}",
+                message,
+                messages
+            );
+        }
+    }
+
+    #[test]
+    fn garbage_resync() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..16 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&with_valid_parity(&frame.encode_raw()));
+        }
+
+        for (interrupted, offset, len) in [(0, 0, 17), (5, 10, 64), (8, 37, 3), (14, 1, 200)] {
+            let at = interrupted * GPS_FRAME_BYTES + offset;
+            let corrupt = insert_garbage(&stream, at, len);
+
+            // parity verification rejects any false lock: only the interrupted frame is lost
+            verify_resync(
+                GpsQzssDecoder::default().with_parity_verification(),
+                &corrupt,
+                16,
+                interrupted as u16,
+                0,
+                model.telemetry.message,
+            );
+
+            verify_resync(
+                GpsQzssDecoder::default(),
+                &corrupt,
+                16,
+                interrupted as u16,
+                1,
+                model.telemetry.message,
+            );
+        }
+    }
+
+    #[test]
+    fn eph1_bin_garbage() {
+        init_logger();
+
+        let mut buffer = Vec::new();
+
+        let mut file = File::open("data/GPS/eph1.bin").unwrap();
+        file.read_to_end(&mut buffer).unwrap();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        // lost lock in the middle of the file
+        let corrupt = insert_garbage(&buffer, 64 * GPS_FRAME_BYTES + 10, 100);
+
+        verify_resync(
+            GpsQzssDecoder::default(),
+            &corrupt,
+            128,
+            64,
+            1,
+            model.telemetry.message,
+        );
+    }
+
+    #[test]
+    fn lsb_first() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris2);
+
+        let mut stream = vec![0; 8 * GPS_FRAME_BYTES + 8];
+        let mut bit_offset = 11;
+
+        for i in 0..8 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            bit_offset = frame.encode_continuous(&mut stream, bit_offset).unwrap();
+        }
+
+        let expected = GpsQzssDecoder::default()
+            .frames(&stream)
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected.len(), 8);
+
+        let reversed = stream.iter().map(|b| b.reverse_bits()).collect::<Vec<_>>();
+
+        // not understood as is
+        let decoded = GpsQzssDecoder::default()
+            .frames(&reversed)
+            .collect::<Vec<_>>();
+
+        assert_ne!(decoded, expected);
+
+        let mut decoder = GpsQzssDecoder::default().with_lsb_first();
+        let decoded = decoder.frames(&reversed).collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+
+        // consumption is not affected
+        let (processed, frame) = GpsQzssDecoder::default().decode(&stream, stream.len());
+        let (processed_lsb, frame_lsb) = GpsQzssDecoder::default()
+            .with_lsb_first()
+            .decode(&reversed, reversed.len());
+
+        assert_eq!(processed, processed_lsb);
+        assert_eq!(frame, frame_lsb);
+    }
+
+    #[test]
+    fn eph1_bin_lsb_first() {
+        init_logger();
+
+        let mut buffer = Vec::new();
+
+        let mut file = File::open("data/GPS/eph1.bin").unwrap();
+        file.read_to_end(&mut buffer).unwrap();
+
+        let expected = GpsQzssDecoder::default()
+            .frames(&buffer)
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected.len(), 128, "did not parse enough messages");
+
+        let reversed = buffer.iter().map(|b| b.reverse_bits()).collect::<Vec<_>>();
+
+        let mut decoder = GpsQzssDecoder::default().with_lsb_first();
+        let decoded = decoder.frames(&reversed).collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+    }
+
+    #[test]
+    fn inverted_polarity() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = vec![0; 8 * GPS_FRAME_BYTES + 8];
+        let mut bit_offset = 3;
+
+        for i in 0..8 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            bit_offset = frame.encode_continuous(&mut stream, bit_offset).unwrap();
+        }
+
+        let expected = GpsQzssDecoder::default()
+            .frames(&stream)
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected.len(), 8);
+
+        let inverted = stream.iter().map(|b| !b).collect::<Vec<_>>();
+
+        let mut decoder = GpsQzssDecoder::default();
+        assert!(!decoder.is_inverted());
+
+        let decoded = decoder.frames(&inverted).collect::<Vec<_>>();
+        assert_eq!(decoded, expected);
+        assert!(decoder.is_inverted());
+
+        // switches back
+        let decoded = decoder.frames(&stream).collect::<Vec<_>>();
+        assert_eq!(decoded, expected);
+        assert!(!decoder.is_inverted());
+
+        // complemented preamble in the junk that precedes a frame: ignored
+        let mut junk = vec![0x74, 0x12, 0x34, 0x74, 0x74, 0x00];
+        junk.extend_from_slice(&stream);
+
+        let mut decoder = GpsQzssDecoder::default();
+        let decoded = decoder.frames(&junk).collect::<Vec<_>>();
+        assert_eq!(decoded, expected);
+        assert!(!decoder.is_inverted());
+
+        // partial frame: polarity is not switched until a frame is decoded
+        let mut decoder = GpsQzssDecoder::default();
+        let (processed, frame) = decoder.decode(&inverted, GPS_FRAME_BYTES - 4);
+        assert!(frame.is_none());
+        assert_eq!(processed, 3);
+        assert!(!decoder.is_inverted());
+
+        let (_, frame) = decoder.decode(&inverted, inverted.len());
+        assert_eq!(frame, Some(expected[0]));
+        assert!(decoder.is_inverted());
+    }
+
+    #[test]
+    fn eph1_bin_inverted() {
+        init_logger();
+
+        let mut buffer = Vec::new();
 
-            info!("EPH-1.bin MESSAGE {}", message + 1);
+        let mut file = File::open("data/GPS/eph1.bin").unwrap();
+        file.read_to_end(&mut buffer).unwrap();
 
-            ptr += processed_size / 8 - 1;
-            size -= processed_size / 8 - 1;
+        let expected = GpsQzssDecoder::default()
+            .frames(&buffer)
+            .collect::<Vec<_>>();
 
-            if size <= GPS_FRAME_BYTES - 2 {
-                assert_eq!(message, 128, "did not parse enough messages");
-            }
-        }
-        assert_eq!(message, 128, "did not parse enough messages");
+        assert_eq!(expected.len(), 128, "did not parse enough messages");
+
+        let inverted = buffer.iter().map(|b| !b).collect::<Vec<_>>();
+
+        let mut decoder = GpsQzssDecoder::default();
+        let decoded = decoder.frames(&inverted).collect::<Vec<_>>();
+
+        assert_eq!(decoded, expected);
+        assert!(decoder.is_inverted());
     }
 
     #[test]
@@ -491,8 +1697,8 @@ mod decoder {
 
         let mut buffer = [0; 8192];
 
-        // TODO add more cases
-        for zeros in 1..7 {
+        // up to a full byte + 7 bits
+        for zeros in 1..16 {
             let mut ptr = 0;
             let mut message = 0;
             let mut buffer = [0; 8192]; // single read
@@ -508,6 +1714,9 @@ mod decoder {
 
             let delayed = insert_zeros(&buffer, zeros);
 
+            // the delay pushes the last frame into the following byte(s)
+            size += (zeros + 7) / 8;
+
             // consume everything
             loop {
                 if message == 128 {
@@ -520,14 +1729,12 @@ mod decoder {
 
                 message += 1;
 
-                if message == 1 {
-                    // first RX
-                    assert_eq!(processed_size, GPS_FRAME_BITS + zeros); // bits!
-                } else {
-                    // following RX
-                    // TODO +8 expected here, not 16
-                    assert_eq!(processed_size, GPS_FRAME_BITS + 16 + zeros); // bits!
-                }
+                // frames are padded to bytes: each one ends exactly at its position in the file,
+                // relative to the bytes that were discarded
+                assert_eq!(
+                    processed_size,
+                    zeros + (message as usize - 1) * GPS_FRAME_BYTES * 8 + GPS_FRAME_BITS - ptr * 8
+                ); // bits!
 
                 let decoded = decoded.unwrap(); // success (we have 128 frames)
 
@@ -562,8 +1769,8 @@ mod decoder {
 
                 info!("EPH-1.bin MESSAGE {}", message + 1);
 
-                ptr += processed_size / 8 - 1;
-                size -= processed_size / 8 - 1;
+                ptr += processed_size / 8;
+                size -= processed_size / 8;
 
                 if size <= GPS_FRAME_BYTES - 2 {
                     assert_eq!(message, 128, "did not parse enough messages");
@@ -607,9 +1814,9 @@ mod decoder {
                 // first RX
                 assert_eq!(processed_size, GPS_FRAME_BITS); // bits!
             } else {
-                // following RX
-                // TODO +8 expected here, not 16
-                assert_eq!(processed_size, GPS_FRAME_BITS + 16); // bits!
+                // following RX: 4 bits of the previous frame (not discarded, because they share
+                // the same byte), followed by the 4 padding bits of the previous frame
+                assert_eq!(processed_size, GPS_FRAME_BITS + 8); // bits!
             }
 
             let decoded = decoded.unwrap(); // success (we have 128 frames)
@@ -645,8 +1852,8 @@ mod decoder {
 
             info!("EPH-2.bin MESSAGE {}", message + 1);
 
-            ptr += processed_size / 8 - 1;
-            size -= processed_size / 8 - 1;
+            ptr += processed_size / 8;
+            size -= processed_size / 8;
 
             if size <= GPS_FRAME_BYTES - 2 {
                 assert_eq!(message, 128, "did not parse enough messages");
@@ -689,9 +1896,9 @@ mod decoder {
                 // first RX
                 assert_eq!(processed_size, GPS_FRAME_BITS); // bits!
             } else {
-                // following RX
-                // TODO +8 expected here, not 16
-                assert_eq!(processed_size, GPS_FRAME_BITS + 16); // bits!
+                // following RX: 4 bits of the previous frame (not discarded, because they share
+                // the same byte), followed by the 4 padding bits of the previous frame
+                assert_eq!(processed_size, GPS_FRAME_BITS + 8); // bits!
             }
 
             let decoded = decoded.unwrap(); // success (we have 128 frames)
@@ -727,8 +1934,8 @@ mod decoder {
 
             info!("EPH-3.bin MESSAGE {}", message + 1);
 
-            ptr += processed_size / 8 - 1;
-            size -= processed_size / 8 - 1;
+            ptr += processed_size / 8;
+            size -= processed_size / 8;
 
             if size <= GPS_FRAME_BYTES - 2 {
                 assert_eq!(message, 128, "did not parse enough messages");
@@ -749,7 +1956,12 @@ mod decoder {
 
         let mut decoder = GpsQzssDecoder::default();
 
-        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris3);
+        // burst pattern (see generate_burst_bin)
+        let pattern = [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+        ];
 
         let mut size = file.read(&mut buffer).unwrap();
 
@@ -771,18 +1983,30 @@ mod decoder {
                 // first RX
                 assert_eq!(processed_size, GPS_FRAME_BITS); // bits!
             } else {
-                // following RX
-                // TODO +8 expected here, not 16
-                assert_eq!(processed_size, GPS_FRAME_BITS + 16); // bits!
+                // following RX: 4 bits of the previous frame (not discarded, because they share
+                // the same byte), followed by the 4 padding bits of the previous frame
+                assert_eq!(processed_size, GPS_FRAME_BITS + 8); // bits!
             }
 
             let decoded = decoded.unwrap(); // success (we have 128 frames)
 
-            // TODO
-            info!("BURST.bin MESSAGE {}", message + 1);
+            let frame_id = pattern[(message - 1) % pattern.len()];
+
+            assert_eq!(
+                decoded.how.frame_id, frame_id,
+                "wrong frame id for message #{}",
+                message
+            );
+
+            assert_eq!(
+                decoded.subframe.frame_id(),
+                Some(frame_id),
+                "wrong subframe for message #{}",
+                message
+            );
 
-            ptr += processed_size / 8 - 1;
-            size -= processed_size / 8 - 1;
+            ptr += processed_size / 8;
+            size -= processed_size / 8;
 
             if size <= GPS_FRAME_BYTES - 2 {
                 assert_eq!(message, 128, "did not parse enough messages");
@@ -791,4 +2015,482 @@ mod decoder {
 
         assert_eq!(message, 128, "did not parse enough messages");
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn max_buffered_frames() {
+        let decoder = GpsQzssDecoder::default();
+        assert_eq!(decoder.max_buffered_frames(), 2);
+
+        let decoder = GpsQzssDecoder::default().with_parity_verification();
+        assert_eq!(decoder.max_buffered_frames(), 2);
+
+        // the reader is drained by pairs of frames
+        let mut stream = Vec::new();
+
+        for _ in 0..8 {
+            stream.extend_from_slice(&GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1).encode_raw());
+        }
+
+        let mut reader = std::io::Cursor::new(stream);
+        let mut decoder = GpsQzssDecoder::default();
+        let mut max_pending = 0;
+
+        while decoder.decode_from(&mut reader).unwrap().is_some() {
+            max_pending = max_pending.max(decoder.pending_size);
+        }
+
+        assert!(
+            max_pending > GPS_FRAME_BYTES,
+            "a complete frame should be pending"
+        );
+        assert!(max_pending <= decoder.max_buffered_frames() * GPS_FRAME_BYTES);
+    }
+
+    #[test]
+    fn short_inputs() {
+        let encoded = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1).encode_raw();
+
+        for size in [0, 1, GPS_FRAME_BYTES - 1] {
+            let mut decoder = GpsQzssDecoder::default();
+
+            let (processed_size, decoded) = decoder.decode(&encoded[..size], size);
+            assert_eq!(processed_size, 0, "invalid size for {} bytes", size);
+            assert!(decoded.is_none(), "decoded {} bytes", size);
+
+            let (processed_size, decoded) = decoder.try_decode(&encoded[..size], size);
+            assert_eq!(processed_size, 0, "invalid size for {} bytes", size);
+            assert!(decoded.unwrap().is_none(), "decoded {} bytes", size);
+        }
+
+        // size larger than the actual buffer
+        let mut decoder = GpsQzssDecoder::default();
+
+        let (processed_size, decoded) = decoder.decode(&encoded[..10], 1024);
+        assert_eq!(processed_size, 0);
+        assert!(decoded.is_none());
+
+        let (processed_size, decoded) = decoder.decode(&encoded, 1024);
+        assert_eq!(processed_size, GPS_FRAME_BITS);
+        assert!(decoded.is_some());
+    }
+
+    #[test]
+    fn partial_reads() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..4 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&frame.encode_raw());
+        }
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        let mut pending = Vec::new();
+        let mut decoded = Vec::new();
+
+        // feed one byte at a time
+        for byte in stream {
+            pending.push(byte);
+
+            let (processed_size, frame) = decoder.decode(&pending, pending.len());
+
+            match frame {
+                Some(frame) => decoded.push(frame),
+                None => {
+                    assert!(
+                        pending.len() < GPS_FRAME_BYTES + processed_size / 8,
+                        "complete frame was not decoded"
+                    );
+                },
+            }
+
+            pending.drain(..processed_size / 8);
+        }
+
+        assert_eq!(decoded.len(), 4, "did not decode all frames");
+
+        for (i, frame) in decoded.iter().enumerate() {
+            assert_eq!(frame.telemetry.message, model.telemetry.message + i as u16);
+            assert_eq!(frame.how, model.how);
+            assert_eq!(frame.subframe, model.subframe);
+        }
+    }
+
+    #[test]
+    fn trailing_partial_preamble() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+        let encoded = model.encode_raw();
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        // 1.5 frames
+        let mut stream = encoded.to_vec();
+        stream.extend_from_slice(&encoded[..GPS_FRAME_BYTES / 2]);
+
+        let (processed_size, decoded) = decoder.decode(&stream, stream.len());
+        assert_eq!(processed_size, GPS_FRAME_BITS);
+        assert_eq!(decoded, Some(model));
+
+        stream.drain(..processed_size / 8);
+
+        // half frame is not consumed: only what precedes its preamble
+        let (processed_size, decoded) = decoder.decode(&stream, stream.len());
+        assert!(decoded.is_none());
+        assert_eq!(processed_size, 8);
+
+        stream.drain(..processed_size / 8);
+        assert_eq!(stream.len(), GPS_FRAME_BYTES / 2);
+        assert_eq!(&stream[..], &encoded[..GPS_FRAME_BYTES / 2]);
+
+        // frame, followed by the first 4 bits of the next preamble
+        let mut stream = encoded.to_vec();
+        stream.push(0x08);
+
+        let (processed_size, decoded) = decoder.decode(&stream, stream.len());
+        assert_eq!(decoded, Some(model));
+
+        stream.drain(..processed_size / 8);
+
+        let (processed_size, decoded) = decoder.decode(&stream, stream.len());
+        assert!(decoded.is_none());
+
+        stream.drain(..processed_size / 8);
+        assert_eq!(stream.last(), Some(&0x08), "partial preamble was consumed");
+
+        // completes the preamble
+        stream.push(0xb0);
+
+        let (processed_size, decoded) = decoder.decode(&stream, stream.len());
+        assert!(decoded.is_none());
+        assert_eq!(processed_size, (stream.len() - 2) * 8 + 4);
+    }
+
+    /// Rewrites the subframe words (3 to 10) of this encoded frame with valid parity bits,
+    /// inverting the data bits when D30* is set, like a real transmitter.
+    /// TLM and HOW are already correctly encoded.
+    fn with_valid_parity(encoded: &[u8]) -> Vec<u8> {
+        let mut encoded = encoded.to_vec();
+        let mut prev = GpsDataWord::default();
+
+        for i in 0..GPS_WORDS_PER_FRAME {
+            let mut value = 0u32;
+
+            for bit in 0..30 {
+                let pos = i * 30 + bit;
+                value <<= 1;
+                value |= ((encoded[pos / 8] >> (7 - pos % 8)) & 0x01) as u32;
+            }
+
+            let word = GpsDataWord::from(value << 2);
+
+            if i < 2 {
+                prev = word;
+                continue;
+            }
+
+            let word = word.transmitted(&prev, false);
+            let value = word.value();
+
+            for bit in 0..30 {
+                let pos = i * 30 + bit;
+                let mask = 0x80 >> (pos % 8);
+
+                if (value >> (29 - bit)) & 0x01 > 0 {
+                    encoded[pos / 8] |= mask;
+                } else {
+                    encoded[pos / 8] &= !mask;
+                }
+            }
+
+            prev = word;
+        }
+
+        encoded
+    }
+
+    #[test]
+    fn parity_verification() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..4 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&with_valid_parity(&frame.encode_raw()));
+        }
+
+        // single bit error, in the 5th word of the 2nd frame
+        let mut corrupted = stream.clone();
+        corrupted[GPS_FRAME_BYTES + 16] ^= 0x10;
+
+        for (verification, stream, expected) in [
+            (false, &stream, [true, true, true, true]),
+            (true, &stream, [true, true, true, true]),
+            (false, &corrupted, [true, true, true, true]),
+            (true, &corrupted, [true, false, true, true]),
+        ] {
+            let mut decoder = GpsQzssDecoder::default();
+
+            if verification {
+                decoder = decoder.with_parity_verification();
+            }
+
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < stream.len() {
+                let (processed_size, frame) = decoder.decode(&stream[ptr..], stream.len() - ptr);
+
+                if processed_size < 8 {
+                    break;
+                }
+
+                if let Some(frame) = frame {
+                    assert_eq!(frame.how, model.how);
+                }
+
+                decoded.push(frame.is_some());
+                ptr += processed_size / 8;
+            }
+
+            assert_eq!(
+                decoded, expected,
+                "failed with parity verification={}",
+                verification
+            );
+        }
+    }
+
+    #[test]
+    fn parity_resync() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let frames = (0..3)
+            .map(|i| {
+                let frame = model.with_telemetry(
+                    GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+                );
+
+                with_valid_parity(&frame.encode_raw())
+            })
+            .collect::<Vec<_>>();
+
+        // 1st frame is interrupted (loss of lock) after 12 bytes, the 2nd frame
+        // follows immediately and the 3rd frame has a single bit error
+        let mut stream = frames[0][..12].to_vec();
+        stream.extend_from_slice(&frames[1]);
+        stream.extend_from_slice(&frames[2]);
+        stream.extend_from_slice(&frames[0]);
+
+        stream[12 + 2 * GPS_FRAME_BYTES - 20] ^= 0x04;
+
+        let mut decoder = GpsQzssDecoder::default().with_parity_verification();
+
+        // truncated frame is rejected, without consuming the following preamble
+        let (processed, decoded) = decoder.try_decode(&stream, stream.len());
+        assert_eq!(processed, 8);
+        assert!(matches!(decoded, Err(GpsError::Parity)), "{:?}", decoded);
+
+        let messages = decoder
+            .frames(&stream)
+            .map(|frame| frame.telemetry.message - model.telemetry.message)
+            .collect::<Vec<_>>();
+
+        assert_eq!(messages, [1, 0]);
+
+        // byte oriented management
+        let mut ptr = 0;
+        let mut messages = Vec::new();
+
+        loop {
+            let (processed_size, decoded) = decoder.try_decode(&stream[ptr..], stream.len() - ptr);
+
+            if let Ok(Some(frame)) = decoded {
+                messages.push(frame.telemetry.message - model.telemetry.message);
+            }
+
+            if processed_size < 8 {
+                break;
+            }
+
+            ptr += processed_size / 8;
+        }
+
+        assert_eq!(messages, [1, 0]);
+    }
+
+    #[test]
+    fn decoder_stats() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..6 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&with_valid_parity(&frame.encode_raw()));
+        }
+
+        // invalid HOW frame ID (0) on the 2nd frame: false lock
+        set_bits(&mut stream, GPS_FRAME_BYTES * 8 + 49, 3, 0);
+
+        // single bit error in the 4th frame
+        stream[3 * GPS_FRAME_BYTES + 16] ^= 0x10;
+
+        let mut decoder = GpsQzssDecoder::default().with_parity_verification();
+        assert_eq!(decoder.stats(), Default::default());
+
+        let decoded = decoder.frames(&stream).count();
+        assert_eq!(decoded, 4);
+
+        let stats = decoder.stats();
+        assert_eq!(stats.frames, 4);
+        assert_eq!(stats.false_locks, 1);
+        assert_eq!(stats.parity_failures, 1);
+        assert_eq!(
+            stats.consumed_bits,
+            5 * GPS_FRAME_BYTES * 8 + GPS_FRAME_BITS
+        );
+        assert_eq!(stats.consumed_bytes(), stream.len() - 1);
+
+        decoder.reset_stats();
+        assert_eq!(decoder.stats(), Default::default());
+
+        // byte oriented management
+        let mut ptr = 0;
+
+        loop {
+            let (processed_size, _) = decoder.try_decode(&stream[ptr..], stream.len() - ptr);
+
+            if processed_size < 8 {
+                break;
+            }
+
+            ptr += processed_size / 8;
+        }
+
+        let stats = decoder.stats();
+        assert_eq!(stats.frames, 4);
+        assert_eq!(stats.false_locks, 1);
+        assert_eq!(stats.parity_failures, 1);
+    }
+
+    #[test]
+    fn decode_counted() {
+        init_logger();
+
+        let model = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut stream = Vec::new();
+
+        for i in 0..16 {
+            let frame = model.with_telemetry(
+                GpsQzssTelemetry::model().with_message(model.telemetry.message + i),
+            );
+
+            stream.extend_from_slice(&frame.encode_raw());
+        }
+
+        let mut ptr = 0;
+        let mut total = 0;
+        let mut decoder = GpsQzssDecoder::default();
+
+        while let Some((frame, consumed)) =
+            decoder.decode_counted(&stream[ptr..], stream.len() - ptr)
+        {
+            assert_eq!(
+                frame.telemetry.message,
+                model.telemetry.message + total as u16
+            );
+
+            ptr += consumed;
+            total += 1;
+        }
+
+        assert_eq!(total, 16, "did not decode all frames");
+
+        // only the last byte (last frame bits) remains
+        assert_eq!(ptr, stream.len() - 1);
+    }
+
+    #[test]
+    fn burst_dispatch() {
+        init_logger();
+
+        let pattern = [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+        ];
+
+        let mut stream = Vec::new();
+
+        for _ in 0..4 {
+            for frame_id in pattern {
+                stream.extend_from_slice(&GpsQzssFrame::model(frame_id).encode_raw());
+            }
+        }
+
+        let mut ptr = 0;
+        let mut decoded = Vec::new();
+        let mut decoder = GpsQzssDecoder::default();
+
+        while ptr < stream.len() {
+            let size = stream.len() - ptr;
+            let (processed_size, frame) = decoder.decode(&stream[ptr..], size);
+
+            if let Some(frame) = frame {
+                decoded.push(frame);
+            }
+
+            if processed_size < 8 {
+                break;
+            }
+
+            ptr += processed_size / 8;
+        }
+
+        assert_eq!(
+            decoded.len(),
+            4 * pattern.len(),
+            "did not decode all frames"
+        );
+
+        for (i, frame) in decoded.iter().enumerate() {
+            let frame_id = pattern[i % pattern.len()];
+
+            assert_eq!(frame.how.frame_id, frame_id, "wrong frame id for #{}", i);
+
+            let dispatched = match frame_id {
+                GpsQzssFrameId::Ephemeris1 => frame.subframe.as_eph1().is_some(),
+                GpsQzssFrameId::Ephemeris2 => frame.subframe.as_eph2().is_some(),
+                GpsQzssFrameId::Ephemeris3 => frame.subframe.as_eph3().is_some(),
+                GpsQzssFrameId::Ephemeris4 => frame.subframe.as_frame4().is_some(),
+                GpsQzssFrameId::Ephemeris5 => frame.subframe.as_frame5().is_some(),
+            };
+
+            assert!(dispatched, "wrong subframe type for #{}", i);
+        }
+    }
 }
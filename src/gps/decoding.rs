@@ -14,7 +14,8 @@ impl GpsQzssFrame {
     /// you will prefer working with the [GpsQzssDecoder].
     ///
     /// ## Input
-    /// - array of [GPS_WORDS_PER_FRAME] [GpsDataWord]s.
+    /// - array of [GPS_WORDS_PER_FRAME] [GpsDataWord]s, as transmitted: the data bits
+    ///   of each word are un-inverted (descrambled) using D30* of the previous word.
     /// - check_parity: true if parity verification is required.
     /// In this case, the method will return None on parity errors.
     ///
@@ -24,11 +25,20 @@ impl GpsQzssFrame {
         words: &[GpsDataWord; GPS_WORDS_PER_FRAME],
         check_parity: bool,
     ) -> Option<GpsQzssFrame> {
-        // if check_parity {
-        //     let parity = words[0].parity(&Default::default(), false);
-        //     let value = words[0].value() & 0x3f;
-        //     panic!("PARITY expecting 0x{:02X}, got 0x{:02X}", parity, value);
-        // }
+        if check_parity {
+            // previous subframe always ends with D29=D30=0
+            let mut prev = GpsDataWord::default();
+
+            for word in words.iter() {
+                if word.parity_check(&prev, false).is_err() {
+                    #[cfg(feature = "log")]
+                    error!("invalid parity: {:?}", word);
+                    return None;
+                }
+
+                prev = *word;
+            }
+        }
 
         // TLM
         let telemetry = match GpsQzssTelemetry::from_word(words[0]) {
@@ -44,15 +54,8 @@ impl GpsQzssFrame {
             },
         };
 
-        // TODO
-        // if check_parity {
-        //     let parity = words[1].parity(&Default::default(), false);
-        //     let value = words[1].value() & 0x3f;
-        //     panic!("PARITY expecting 0x{:02X}, got 0x{:02X}", parity, value);
-        // }
-
-        // HOW
-        let how = match GpsQzssHow::from_word(words[1]) {
+        // HOW data bits are inverted when the TLM ends with D30=1
+        let how = match GpsQzssHow::from_word(words[1].descramble(words[0].d30())) {
             Ok(how) => how,
             #[cfg(not(feature = "log"))]
             Err(_) => {
@@ -65,19 +68,14 @@ impl GpsQzssFrame {
             },
         };
 
-        // TODO
-        // if check_parity {
-        //  for i in 2..GPS_WORDS_PER_FRAME {
-        //      let parity = words[i].parity(&Default::default(), false);
-        //      let value = words[i].value() & 0x3f;
-        //      panic!("PARITY expecting 0x{:02X}, got 0x{:02X}", parity, value);
-        //  }
-        // }
+        let subframe =
+            GpsQzssSubframe::decode_raw(how.frame_id.encode(), &words[2..], words[1].d30());
 
         Some(GpsQzssFrame {
-            subframe: GpsQzssSubframe::decode(how.frame_id, &words[2..]),
+            subframe,
             telemetry,
             how,
+            constellation: Default::default(),
         })
     }
 }
@@ -87,7 +85,7 @@ mod test {
     // use std::{fs::File, io::Read};
 
     use crate::{
-        gps::{GpsQzssFrame, GpsQzssFrameId},
+        gps::{GpsDataWord, GpsQzssFrame, GpsQzssFrameId, GPS_WORDS_PER_FRAME},
         tests::from_ublox_bytes,
     };
 
@@ -235,4 +233,50 @@ mod test {
             assert!((frame3.omega - -6.554632573389e-001).abs() < 1e-9);
         }
     }
+
+    #[test]
+    fn inverted_words() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+        ] {
+            let frame = GpsQzssFrame::model(frame_id);
+            let words = frame.encode();
+
+            // some words follow a word that ends with D30=1 (inverted data bits)
+            assert!(
+                words[..GPS_WORDS_PER_FRAME - 1]
+                    .iter()
+                    .any(|word| word.d30()),
+                "{:?} model does not exercise D30* inversion",
+                frame_id
+            );
+
+            let decoded = GpsQzssFrame::decode(&words, false).unwrap();
+
+            assert_eq!(decoded.telemetry, frame.telemetry);
+            assert_eq!(decoded.how, frame.how);
+            assert_eq!(decoded.encode(), words);
+        }
+    }
+
+    #[test]
+    fn parity_verification() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+        ] {
+            let mut words = GpsQzssFrame::model(frame_id).encode();
+
+            assert!(GpsQzssFrame::decode(&words, true).is_some());
+
+            // single bit error in word #4
+            words[3] = GpsDataWord::from((words[3].value() ^ 0x100) << 2);
+
+            assert!(GpsQzssFrame::decode(&words, true).is_none());
+            assert!(GpsQzssFrame::decode(&words, false).is_some());
+        }
+    }
 }
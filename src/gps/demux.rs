@@ -0,0 +1,125 @@
+use crate::{
+    bits::{get_bits, BitWriter},
+    gps::GPS_WORD_BITS,
+};
+
+/// Size of one tagged [GpsDataWord](crate::gps::GpsDataWord) (in bytes!), see [demux_tagged_words].
+pub const GPS_TAGGED_WORD_BYTES: usize = 5;
+
+/// Extracts the words of a single channel from an interleaved stream of tagged words,
+/// as some receivers output the L1 and L2 navigation words in one block.
+///
+/// Tagging convention: each word is [GPS_TAGGED_WORD_BYTES] long, a channel byte (freely defined
+/// by the receiver, for example 0 for L1 and 1 for L2) followed by the 30-bit word (parity included),
+/// left aligned in 4 big endian bytes. The 2 final padding bits are ignored.
+///
+/// ## Input
+/// - src: read-only interleaved stream. The trailing incomplete word, if any, is ignored.
+/// - channel: channel byte to retain, words of other channels are skipped.
+/// - dst: mutable [u8] buffer the retained words are packed into, back to back (30 bits each),
+///   ready to be fed to a [GpsQzssDecoder](crate::gps::GpsQzssDecoder).
+///
+/// ## Output
+/// - Total number of _bits_ written (not bytes!). Demuxing stops once `dst` is full.
+pub fn demux_tagged_words(src: &[u8], channel: u8, dst: &mut [u8]) -> usize {
+    let mut writer = BitWriter::new(dst, 0);
+
+    for tagged in src.chunks_exact(GPS_TAGGED_WORD_BYTES) {
+        if tagged[0] != channel {
+            continue;
+        }
+
+        let word = get_bits(&tagged[1..], 0, GPS_WORD_BITS);
+
+        if writer.write_u32(word, GPS_WORD_BITS).is_none() {
+            break;
+        }
+    }
+
+    writer.position()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gps::{
+        GpsQzssDecoder, GpsQzssFrame, GpsQzssFrameId, GpsQzssHow, GPS_FRAME_BITS,
+        GPS_WORDS_PER_FRAME,
+    };
+
+    #[test]
+    fn interleaved_channels() {
+        let frame_ids = [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+        ];
+
+        let l1 = frame_ids.map(|frame_id| {
+            let how = GpsQzssHow::model(frame_id).with_tow_seconds(15_000);
+            GpsQzssFrame::model(frame_id).with_hand_over_word(how)
+        });
+
+        let l2 = frame_ids.map(|frame_id| {
+            let how = GpsQzssHow::model(frame_id)
+                .with_tow_seconds(30_000)
+                .without_alert_bit();
+            GpsQzssFrame::model(frame_id).with_hand_over_word(how)
+        });
+
+        // word by word interleaving, L1 tagged 0 and L2 tagged 1
+        let mut stream = Vec::new();
+
+        for (l1, l2) in l1.iter().zip(l2.iter()) {
+            let (l1, l2) = (l1.encode_raw(), l2.encode_raw());
+
+            for i in 0..GPS_WORDS_PER_FRAME {
+                for (channel, encoded) in [(0, &l1), (1, &l2)] {
+                    let word = get_bits(encoded, i * GPS_WORD_BITS, GPS_WORD_BITS);
+                    stream.push(channel);
+                    stream.extend_from_slice(&(word << 2).to_be_bytes());
+                }
+            }
+        }
+
+        // trailing incomplete word
+        stream.extend_from_slice(&[0, 0x8B]);
+
+        for (channel, models) in [(0, l1), (1, l2)] {
+            let mut buffer = [0u8; 128];
+            let size = demux_tagged_words(&stream, channel, &mut buffer);
+            assert_eq!(size, models.len() * GPS_FRAME_BITS);
+
+            // each channel decodes like its own single channel stream
+            let expected = models
+                .iter()
+                .flat_map(|model| {
+                    GpsQzssDecoder::default()
+                        .frames(&model.encode_raw())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(expected.len(), models.len());
+
+            let mut decoder = GpsQzssDecoder::default();
+            let decoded = decoder.frames(&buffer).collect::<Vec<_>>();
+            assert_eq!(decoded, expected, "channel #{} demuxing failed", channel);
+
+            for (decoded, model) in decoded.iter().zip(models.iter()) {
+                assert_eq!(decoded.how, model.how);
+            }
+        }
+
+        // unknown channel
+        let mut buffer = [0u8; 128];
+        assert_eq!(demux_tagged_words(&stream, 2, &mut buffer), 0);
+
+        // only complete words are written
+        let mut buffer = [0u8; 8];
+        assert_eq!(
+            demux_tagged_words(&stream, 0, &mut buffer),
+            2 * GPS_WORD_BITS
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{
+    bits::get_bits,
+    gps::{GpsQzssFrame, GPS_FRAME_BITS, GPS_FRAME_BYTES},
+};
+
+#[cfg(feature = "log")]
+use log::trace;
+
+/// [GpsQzssEncoder] emits a stream of [GpsQzssFrame]s incrementally,
+/// through the [std::io::Read] interface, so you can pull exactly as many bytes
+/// as your transmitter (or hardware FIFO) can accept, without encoding the whole burst.
+///
+/// Unlike [GpsQzssFrame::encode_raw], frames are truly concatenated: the 4 padding bits
+/// are removed, so the next frame starts right after the 300th bit of the previous one.
+///
+/// [GpsQzssEncoder] only stores one frame at a time. [std::io::Read::read] returns 0
+/// when no complete byte can be emitted: [Self::push] the following frame and read again.
+///
+/// ```
+/// use std::io::Read;
+/// use gnss_protos::{GpsQzssEncoder, GpsQzssFrame};
+///
+/// let mut encoder = GpsQzssEncoder::default();
+///
+/// assert!(encoder.push(&GpsQzssFrame::default()));
+///
+/// let mut fifo = [0; 16];
+/// let size = encoder.read(&mut fifo).unwrap();
+/// assert_eq!(size, 16);
+///
+/// // still busy: the frame is not entirely emitted
+/// assert!(!encoder.push(&GpsQzssFrame::default()));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct GpsQzssEncoder {
+    /// Frame being emitted, aligned to [u8]
+    frame: [u8; GPS_FRAME_BYTES],
+
+    /// Number of bits of the frame that were already emitted
+    frame_ptr: usize,
+
+    /// Bits that were not emitted yet (LSB aligned)
+    pending: u32,
+
+    /// Number of bits stored in pending (always < 8 between reads)
+    pending_bits: usize,
+}
+
+impl Default for GpsQzssEncoder {
+    /// Creates a [GpsQzssEncoder] that has nothing to emit yet.
+    fn default() -> Self {
+        Self {
+            frame: [0; GPS_FRAME_BYTES],
+            frame_ptr: GPS_FRAME_BITS,
+            pending: 0,
+            pending_bits: 0,
+        }
+    }
+}
+
+impl GpsQzssEncoder {
+    /// Returns true if this [GpsQzssEncoder] can accept a new [GpsQzssFrame],
+    /// meaning the previous frame was entirely emitted (except for less than a byte).
+    pub fn is_ready(&self) -> bool {
+        self.frame_ptr == GPS_FRAME_BITS
+    }
+
+    /// Returns the number of bits that are still to be emitted.
+    pub fn pending_bits(&self) -> usize {
+        self.pending_bits + GPS_FRAME_BITS - self.frame_ptr
+    }
+
+    /// Pushes a new [GpsQzssFrame] to be emitted right after the previous one.
+    /// Returns false (and the [GpsQzssFrame] is dropped) when this [GpsQzssEncoder]
+    /// is still emitting the previous frame: read some more and try again.
+    pub fn push(&mut self, frame: &GpsQzssFrame) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+
+        self.frame = frame.encode_raw();
+        self.frame_ptr = 0;
+
+        #[cfg(feature = "log")]
+        trace!("(GPS/QZSS) [encoder]: {:?}", frame.how.frame_id);
+
+        true
+    }
+
+    /// Emits as many complete bytes as possible into this buffer and returns
+    /// the number of bytes that were written. This is the [std::io::Read] implementation,
+    /// also available in `no_std` environments.
+    pub fn drain(&mut self, buf: &mut [u8]) -> usize {
+        let mut size = 0;
+
+        for byte in buf.iter_mut() {
+            // complete the pending bits with the frame
+            let nbits = (8 - self.pending_bits).min(GPS_FRAME_BITS - self.frame_ptr);
+
+            if nbits > 0 {
+                self.pending <<= nbits;
+                self.pending |= get_bits(&self.frame, self.frame_ptr, nbits);
+                self.pending_bits += nbits;
+                self.frame_ptr += nbits;
+            }
+
+            if self.pending_bits < 8 {
+                break;
+            }
+
+            *byte = self.pending as u8;
+            self.pending = 0;
+            self.pending_bits = 0;
+            size += 1;
+        }
+
+        size
+    }
+
+    /// Terminates the stream: returns the last bits (less than a byte),
+    /// padded with zeros, if any. Once the pending frame was entirely read.
+    pub fn finish(&mut self) -> Option<u8> {
+        if !self.is_ready() || self.pending_bits == 0 {
+            return None;
+        }
+
+        let byte = (self.pending << (8 - self.pending_bits)) as u8;
+
+        self.pending = 0;
+        self.pending_bits = 0;
+
+        Some(byte)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::io::Read for GpsQzssEncoder {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.drain(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Read;
+
+    use crate::{
+        bits::BitIter,
+        gps::{GpsQzssDecoder, GpsQzssEncoder, GpsQzssFrame, GpsQzssFrameId, GPS_FRAME_BITS},
+    };
+
+    #[test]
+    fn streamed_encoding() {
+        let frames = [
+            GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1),
+            GpsQzssFrame::model(GpsQzssFrameId::Ephemeris2),
+        ];
+
+        let mut encoder = GpsQzssEncoder::default();
+        assert_eq!(encoder.pending_bits(), 0);
+
+        let mut stream = Vec::new();
+        let mut fifo = [0; 7];
+
+        let mut pushed = 0;
+
+        loop {
+            let size = encoder.read(&mut fifo).unwrap();
+
+            if size == 0 {
+                if pushed == frames.len() {
+                    break;
+                }
+
+                assert!(encoder.is_ready());
+                assert!(encoder.push(&frames[pushed]));
+                pushed += 1;
+                continue;
+            }
+
+            // not accepting anything new while busy
+            if !encoder.is_ready() {
+                assert!(!encoder.push(&frames[1]));
+            }
+
+            stream.extend_from_slice(&fifo[..size]);
+        }
+
+        // 2x300 bits are exactly 75 bytes
+        assert_eq!(stream.len(), 75);
+        assert_eq!(encoder.pending_bits(), 0);
+        assert_eq!(encoder.finish(), None);
+
+        // frames are truly concatenated
+        for (i, frame) in frames.iter().enumerate() {
+            let encoded = frame.encode_raw();
+
+            let expected = BitIter::new(&encoded, 0).take(GPS_FRAME_BITS);
+            let emitted = BitIter::new(&stream, i * GPS_FRAME_BITS).take(GPS_FRAME_BITS);
+
+            assert!(
+                expected.eq(emitted),
+                "frame #{} is not correctly emitted",
+                i
+            );
+        }
+
+        let mut decoder = GpsQzssDecoder::default();
+
+        let (processed, decoded) = decoder.decode(&stream, stream.len());
+        assert_eq!(processed, GPS_FRAME_BITS);
+        assert_eq!(decoded.unwrap().how.frame_id, GpsQzssFrameId::Ephemeris1);
+
+        // second frame starts on 4th bit of byte #37
+        let (processed, decoded) = decoder.decode(&stream[37..], stream.len() - 37);
+        assert_eq!(processed, 4 + GPS_FRAME_BITS);
+        assert_eq!(decoded.unwrap().how.frame_id, GpsQzssFrameId::Ephemeris2);
+    }
+
+    #[test]
+    fn stream_termination() {
+        let frame = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris3);
+        let encoded = frame.encode_raw();
+
+        let mut encoder = GpsQzssEncoder::default();
+        assert!(encoder.push(&frame));
+
+        let mut buffer = [0; 64];
+        let size = encoder.read(&mut buffer).unwrap();
+
+        assert_eq!(size, 37);
+        assert_eq!(buffer[..37], encoded[..37]);
+        assert_eq!(encoder.pending_bits(), 4);
+        assert_eq!(encoder.read(&mut buffer).unwrap(), 0);
+
+        // padded like encode_raw()
+        assert_eq!(encoder.finish(), Some(encoded[37]));
+        assert_eq!(encoder.pending_bits(), 0);
+        assert_eq!(encoder.finish(), None);
+    }
+}
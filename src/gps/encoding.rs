@@ -1,22 +1,35 @@
-use crate::gps::{
-    GpsDataWord, GpsQzssFrame, GpsQzssFrameId, GPS_FRAME_BITS, GPS_FRAME_BYTES, GPS_PREAMBLE_BYTE,
-    GPS_WORDS_PER_FRAME,
+use crate::{
+    bits::set_bits,
+    encoding_fits,
+    gps::{
+        GpsDataWord, GpsError, GpsQzssFrame, GPS_FRAME_BITS, GPS_FRAME_BYTES, GPS_WORDS_PER_FRAME,
+        GPS_WORD_BITS,
+    },
 };
 
 impl GpsQzssFrame {
+    /// Total number of bytes needed to encode a [GpsQzssFrame] to binary, aligned to [u8].
+    /// Usable in const context, for example to size buffers.
+    pub const ENCODED_BYTES: usize = GPS_FRAME_BYTES;
+
+    /// Exact number of bits needed to encode a [GpsQzssFrame]
+    pub const ENCODED_BITS: usize = GPS_FRAME_BITS;
+
     /// Returns total number of bytes needed to encode this [GpsQzssFrame] to binary
     /// aligned to [u8]
     pub const fn encoding_size() -> usize {
-        GPS_FRAME_BYTES
+        Self::ENCODED_BYTES
     }
 
     /// Returns exact number of bits needed to encode this [GpsQzssFrame]
     pub const fn encoding_bits() -> usize {
-        GPS_FRAME_BITS
+        Self::ENCODED_BITS
     }
 
     /// Encodes this [GpsQzssFrame] as serie of 10 [GpsDataWord]s, aligned to 32 bits
-    /// and parity bits correctly encoded.  
+    /// and parity bits correctly encoded: the words are ready to transmit (IS-GPS-200),
+    /// data bits are inverted when the previous word ends with D30=1.
+    /// Bits 23 and 24 of the HOW and of the last word are solved so these words end with D29=D30=0.
     /// When working with a real-time transmitter, you should prefer
     /// [Self::encode_raw] which is not aligned.
     pub fn encode(&self) -> [GpsDataWord; GPS_WORDS_PER_FRAME] {
@@ -35,21 +48,36 @@ impl GpsQzssFrame {
             subwords[7],
         ];
 
-        // calculate parity for each word
+        // previous subframe always ends with D29=D30=0
+        let mut prev = GpsDataWord::default();
+
         for (ith, word) in words.iter_mut().enumerate() {
-            // TODO
-            // let parity = word.parity(&Default::default(), false);
-            // *word |= 0x00u8;
+            *word = if ith == 1 || ith == GPS_WORDS_PER_FRAME - 1 {
+                word.transmitted(&prev, true)
+            } else {
+                word.with_parity(prev.value() & 0x2 > 0, prev.d30())
+            };
+
+            prev = *word;
         }
 
         words
     }
 
+    /// Verifies this [GpsQzssFrame] is internally consistent: the 10 words are encoded
+    /// and their parity bits computed (IS-GPS-200), then each word must pass the parity check
+    /// and the words must be interpreted as this exact [GpsQzssFrame].
+    /// Returns false when any field does not fit its binary representation,
+    /// in which case the transmitted frame would differ from this one.
+    pub fn verify_parity(&self) -> bool {
+        GpsQzssFrame::decode(&self.encode(), true) == Some(*self)
+    }
+
     /// Encodes this [GpsQzssFrame] as a 300 bit burst (38 bytes).
     /// Because [GpsQzssFrame] is not aligned to [u8], the very last byte contains 4 MSB padding bits, set to zeros
     /// (unsigned). If you leave it to that, any streaming/transmitter looses a little bit of efficiency
-    /// any time a [GpsQzssFrame] is encoded/transmitted. The only solution then, is to manually remove this padding
-    /// an truly concatenate your frames, but one can't expect easy processes when working with poorly designed and very old protocols.
+    /// any time a [GpsQzssFrame] is encoded/transmitted. The solution then, is to remove this padding
+    /// and truly concatenate your frames, which is what our [GpsQzssEncoder] does.
     /// A true synchronous [GpsQzssFrame] emitter is supposed to transmit one frame every 6 seconds,
     /// that is 50 bits per second.
     /// NB: this [GpsQzssFrame] is not ready to transmit as-is and must be CDMA encoded
@@ -57,302 +85,10 @@ impl GpsQzssFrame {
     pub fn encode_raw(&self) -> [u8; GPS_FRAME_BYTES] {
         let mut encoded = [0; GPS_FRAME_BYTES];
 
-        encoded[0] = GPS_PREAMBLE_BYTE;
-        encoded[1] = ((self.telemetry.message & 0x3fc0) >> 6) as u8;
-        encoded[2] = (self.telemetry.message & 0x3f) as u8;
-        encoded[2] <<= 2;
-
-        if self.telemetry.integrity {
-            encoded[2] |= 0x02;
-        }
-
-        if self.telemetry.reserved_bit {
-            encoded[2] |= 0x01;
-        }
-
-        let gps_word =
-            GpsDataWord::from_be_bytes(&[encoded[0], encoded[1], encoded[2], encoded[3]]);
-
-        let parity = gps_word.parity(&Default::default(), false);
-
-        encoded[3] = parity;
-        encoded[3] <<= 2;
-
-        let tow = self.how.tow * 2 / 3;
-
-        encoded[3] |= ((tow & 0x1_8000) >> 15) as u8;
-        encoded[4] = ((tow & 0x0_7f80) >> 7) as u8;
-        encoded[5] = (tow & 0x0_007f) as u8;
-        encoded[5] <<= 1;
-
-        if self.how.alert {
-            encoded[5] |= 0x01;
-        }
-
-        if self.how.anti_spoofing {
-            encoded[6] |= 0x80;
-        }
-
-        let gps_word = GpsDataWord::from_be_bytes(&[
-            ((encoded[3] & 0x03) << 6) | (encoded[4] & 0xfc) >> 2,
-            ((encoded[4] & 0x03) << 6) | (encoded[5] & 0xfc) >> 2,
-            ((encoded[5] & 0x03) << 6) | (encoded[6] & 0xfc) >> 2,
-            ((encoded[6] & 0x03) << 6) | (encoded[7] & 0xfc) >> 2,
-        ]);
-
-        let parity = gps_word.parity(&Default::default(), true);
-
-        encoded[6] |= self.how.frame_id.encode() << 4;
-        encoded[6] |= (parity & 0xf0) >> 4;
-        encoded[7] |= (parity & 0x0f) << 4;
-
-        match self.how.frame_id {
-            GpsQzssFrameId::Ephemeris1 => {
-                let subf = self.subframe.as_eph1().unwrap_or_default();
-
-                encoded[7] |= ((subf.week & 0x3c0) >> 6) as u8;
-                encoded[8] = (subf.week & 0x03f) as u8;
-                encoded[8] <<= 2;
-                encoded[8] |= (subf.ca_or_p_l2) & 0x03;
-
-                encoded[9] = subf.ura & 0x0f;
-                encoded[9] <<= 4;
-                encoded[9] |= (subf.health & 0x3c) >> 2;
-
-                encoded[10] = subf.health & 0x03;
-                encoded[10] <<= 6;
-                encoded[10] |= (((subf.iodc & 0x300) >> 8) as u8) << 4;
-
-                encoded[11] <<= 6; // TODO (PAR)
-
-                if subf.l2_p_data_flag {
-                    encoded[11] |= 0x20;
-                }
-
-                encoded[11] |= ((subf.reserved_word4 & 0x7c_0000) >> 18) as u8;
-                encoded[12] = ((subf.reserved_word4 & 0x03_fc00) >> 10) as u8;
-                encoded[13] = ((subf.reserved_word4 & 0x00_03fc) >> 2) as u8;
-
-                encoded[14] = (subf.reserved_word4 & 0x3) as u8;
-                encoded[14] <<= 6; // TODO
-
-                encoded[15] = ((subf.reserved_word5 & 0xff_0000) >> 16) as u8;
-                encoded[16] = ((subf.reserved_word5 & 0x00_ff00) >> 8) as u8;
-                encoded[17] = (subf.reserved_word5 & 0x00_00ff) as u8;
-                encoded[18] <<= 2; // TODO
-
-                encoded[18] |= ((subf.reserved_word6 & 0xc0_0000) >> 22) as u8;
-                encoded[19] = ((subf.reserved_word6 & 0x3f_c000) >> 14) as u8;
-                encoded[20] = ((subf.reserved_word6 & 0x00_3fc0) >> 6) as u8;
-                encoded[21] = (subf.reserved_word6 & 0x00_003f) as u8;
-                encoded[21] <<= 2;
-                encoded[22] <<= 4; // TODO
-
-                encoded[22] |= ((subf.reserved_word7 & 0xf000) >> 12) as u8;
-                encoded[23] = ((subf.reserved_word7 & 0x0ff0) >> 4) as u8;
-                encoded[24] = (subf.reserved_word7 & 0x000f) as u8;
-                encoded[24] <<= 4;
-
-                let mut tgd = (subf.tgd * 2.0_f64.powi(31)).round() as u8;
-
-                if subf.tgd < 0.0 {
-                    tgd += 128;
-                    tgd -= 1;
-                }
-
-                encoded[24] |= (tgd & 0xf0) >> 4;
-
-                encoded[25] = tgd & 0x0f;
-                encoded[25] <<= 4; // TODO
-                encoded[26] <<= 6; // TODO
-                encoded[26] |= ((subf.iodc & 0x0fc) >> 2) as u8;
-
-                encoded[27] = (subf.iodc & 0x03) as u8;
-                encoded[27] <<= 6;
-
-                let toc = subf.toc / 16;
-                encoded[27] |= ((toc & 0xfc00) >> 10) as u8;
-                encoded[28] = ((toc & 0x03fc) >> 2) as u8;
-                encoded[29] = (toc & 0x0003) as u8;
-                encoded[29] <<= 6; // TODO
-
-                let af2 = (subf.af2 * 2.0_f64.powi(55)).round() as u8;
-                let af1 = (subf.af1 * 2.0_f64.powi(43)).round() as u16;
-                let af0 = (subf.af0 * 2.0_f64.powi(31)).round() as u32;
-
-                encoded[30] = af2;
-                encoded[31] = ((af1 & 0xff00) >> 8) as u8;
-                encoded[32] = (af1 & 0x00ff) as u8;
-                encoded[33] <<= 2; // TODO
-
-                encoded[33] |= ((af0 & 0x30_0000) >> 20) as u8;
-                encoded[34] = ((af0 & 0x0f_f000) >> 12) as u8;
-                encoded[35] = ((af0 & 0x00_0ff0) >> 4) as u8;
-                encoded[36] = (af0 & 0x00_000f) as u8;
-                encoded[36] <<= 4; // TODO
-
-                encoded[37] <<= 4;
-            },
-            GpsQzssFrameId::Ephemeris2 => {
-                let subf = self.subframe.as_eph2().unwrap_or_default();
-
-                encoded[7] |= (subf.iode & 0xf0) >> 4;
-                encoded[8] |= subf.iode & 0x0f;
-                encoded[8] <<= 4;
-
-                let crs = (subf.crs * 2.0_f64.powi(5)).round() as u16;
-                encoded[8] |= ((crs & 0xf000) >> 12) as u8;
-                encoded[9] |= ((crs & 0x0ff0) >> 4) as u8;
-                encoded[10] |= (crs & 0x000f) as u8;
-                encoded[10] <<= 4; // TODO
-
-                let dn = (subf.dn * 2.0_f64.powi(43)).round() as u16;
-
-                encoded[11] |= ((dn & 0xfc00) >> 10) as u8;
-
-                encoded[12] |= ((dn & 0x03fc) >> 2) as u8;
-                encoded[13] = (dn & 0x0003) as u8;
-                encoded[13] <<= 6;
-
-                let m0 = (subf.m0 * 2.0_f64.powi(31)).round() as u32;
-
-                encoded[13] |= (((m0 & 0xfc000000) >> 26) as u8) & 0x3f;
-                encoded[14] = ((m0 & 0x03000000) >> 24) as u8;
-                encoded[14] <<= 6; //TODO
-
-                encoded[15] |= ((m0 & 0x00ff0000) >> 16) as u8;
-                encoded[16] |= ((m0 & 0x0000ff00) >> 8) as u8;
-                encoded[17] |= (m0 & 0x000000ff) as u8;
-
-                encoded[18] <<= 2; // TODO
-
-                let cuc = (subf.cuc * 2.0_f64.powi(29)).round() as u16;
-                encoded[18] |= ((cuc & 0xc000) >> 14) as u8;
-                encoded[19] |= ((cuc & 0x3fc0) >> 6) as u8;
-                encoded[20] |= (cuc & 0x003f) as u8;
-                encoded[20] <<= 2;
-
-                let e = (subf.e * 2.0_f64.powi(33)).round() as u32;
-
-                encoded[20] |= ((e & 0xc0000000) >> 30) as u8;
-                encoded[21] |= ((e & 0x3f000000) >> 24) as u8;
-                encoded[21] <<= 2; // TODO
-                encoded[22] <<= 4; // TODO
-
-                encoded[22] |= ((e & 0x00f00000) >> 20) as u8;
-                encoded[23] |= ((e & 0x000ff000) >> 12) as u8;
-                encoded[24] |= ((e & 0x00000ff0) >> 4) as u8;
-                encoded[25] |= (e & 0x0000000f) as u8;
-                encoded[25] <<= 4; // TODO
-                encoded[26] <<= 2; // TODO
-
-                let cus = (subf.cus * 2.0_f64.powi(29)).round() as u16;
-                encoded[26] |= ((cus & 0xfc00) >> 10) as u8;
-                encoded[27] |= ((cus & 0x03fc) >> 2) as u8;
-                encoded[28] |= (cus & 0x3) as u8;
-                encoded[28] <<= 6;
-
-                let sqrt_a = (subf.sqrt_a * 2.0_f64.powi(19)).round() as u32;
-                encoded[28] |= ((sqrt_a & 0xfc000000) >> 26) as u8;
-                encoded[29] |= ((sqrt_a & 0x03000000) >> 24) as u8;
-                encoded[29] <<= 6; // TODO
-
-                encoded[30] |= ((sqrt_a & 0x00ff0000) >> 16) as u8;
-                encoded[31] |= ((sqrt_a & 0x0000ff00) >> 8) as u8;
-                encoded[32] |= (sqrt_a & 0x000000ff) as u8;
-
-                let toe = (subf.toe / 16) as u16;
-
-                encoded[33] <<= 2; // TODO
-                encoded[33] |= ((toe & 0xc000) >> 14) as u8;
-                encoded[34] |= ((toe & 0x3fc0) >> 6) as u8;
-                encoded[35] |= (toe & 0x003f) as u8;
-                encoded[35] <<= 2;
-
-                if subf.fit_int_flag {
-                    encoded[35] |= 0x02;
-                }
-
-                encoded[35] |= (subf.aodo & 0x10) >> 4;
-
-                encoded[36] |= subf.aodo & 0x0f;
-                encoded[36] <<= 4;
-
-                encoded[36] |= 0x00; // two non-information bits for parity calculations
-                encoded[37] |= 0x00;
-                encoded[37] <<= 4; // TODO
-            },
-            GpsQzssFrameId::Ephemeris3 => {
-                let subf = self.subframe.as_eph3().unwrap_or_default();
-
-                let cic = (subf.cic * 2.0_f64.powi(29)).round() as u16;
-
-                encoded[7] |= ((cic & 0xf000) >> 12) as u8;
-                encoded[8] |= ((cic & 0x0ff0) >> 4) as u8;
-
-                encoded[9] |= (cic & 0x000f) as u8;
-                encoded[9] <<= 4;
-
-                let omega0 = (subf.omega0 * 2.0_f64.powi(31)).round() as u32;
-
-                encoded[9] |= (omega0 >> 28) as u8;
-                encoded[10] = ((omega0 >> 24) & 0x0f) as u8;
-                encoded[10] <<= 4; // TODO
-
-                encoded[11] |= ((omega0 & 0x00fc0000) >> 18) as u8;
-                encoded[12] = ((omega0 & 0x0003fc00) >> 10) as u8;
-                encoded[13] = ((omega0 & 0x000003fC) >> 2) as u8;
-                encoded[14] = (omega0 & 0x00000003) as u8;
-                // TODO
-
-                let cis = (subf.cis * 2.0_f64.powi(29)).round() as u32;
-
-                encoded[15] = ((cis & 0xff00) >> 8) as u8;
-                encoded[16] = (cis & 0x00ff) as u8;
-
-                let i0 = (subf.i0 * 2.0_f64.powi(31)).round() as u32;
-
-                encoded[17] = ((i0 & 0xff000000) >> 24) as u8;
-                encoded[18] = ((i0 & 0x00c00000) >> 22) as u8; // TODO
-                encoded[19] = ((i0 & 0x003fc000) >> 14) as u8;
-                encoded[20] = ((i0 & 0x00003fc0) >> 6) as u8;
-                encoded[21] = ((i0 & 0x0000003f) >> 6) as u8;
-                encoded[21] <<= 2; // TODO
-
-                let crc = (subf.crc * 2.0_f64.powi(5)).round() as u32;
-
-                encoded[22] |= ((crc & 0xf000) >> 12) as u8;
-                encoded[23] |= ((crc & 0x0ff0) >> 4) as u8;
-                encoded[24] |= (crc & 0x000f) as u8;
-
-                let omega = (subf.omega * 2.0_f64.powi(31)).round() as u32;
-
-                encoded[24] |= ((omega & 0xf0000000) >> 28) as u8;
-                encoded[25] |= ((omega & 0x0f000000) >> 24) as u8; // TODO
-
-                encoded[26] = ((omega & 0x00fc0000) >> 18) as u8;
-                encoded[27] = ((omega & 0x0003fc00) >> 10) as u8;
-                encoded[28] = ((omega & 0x000003fc) >> 2) as u8;
-                encoded[29] = (omega & 0x00000003) as u8;
-                encoded[29] <<= 6; // TODO
-
-                let omegadot = (subf.omega_dot * 2.0_f64.powi(43)).round() as u32;
-
-                encoded[30] = ((omegadot & 0x00ff0000) >> 16) as u8;
-                encoded[31] = ((omegadot & 0x0000ff00) >> 8) as u8;
-                encoded[32] = omegadot as u8;
-
-                encoded[33] = (subf.iode & 0xc0) >> 6; // TODO
-                encoded[34] = subf.iode & 0x3f;
-                encoded[34] <<= 2;
-
-                let idot = (subf.idot * 2.0_f64.powi(43)).round() as u32;
-
-                encoded[34] |= ((idot & 0x3000) >> 12) as u8;
-                encoded[35] = ((idot & 0x0ff0) >> 4) as u8;
-                encoded[36] = (idot & 0xf) as u8;
-                encoded[36] <<= 4; // TODO
-            },
+        // words are ready to transmit: parity is encoded and data bits are inverted
+        // when the previous word ends with D30=1.
+        for (i, word) in self.encode().iter().enumerate() {
+            set_bits(&mut encoded, i * GPS_WORD_BITS, GPS_WORD_BITS, word.value());
         }
 
         encoded
@@ -373,20 +109,92 @@ impl GpsQzssFrame {
     /// - buffer: mutable [u8] buffer
     ///
     /// ## Output
-    /// - Err if buffer can't accept this [GpsQzssFrame] entirely
+    /// - Err if buffer can't accept this [GpsQzssFrame] entirely,
+    ///   or if this [GpsQzssFrame] is not consistent (see [Self::validate])
     /// - total number of bits that were encoded
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn encode_to_buffer(&self, buffer: &mut [u8]) -> std::io::Result<usize> {
-        if buffer.len() < GPS_FRAME_BYTES {
+        if !encoding_fits(buffer.len(), Self::encoding_bits()) {
             return Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 "would not fit",
             ));
         }
 
+        if let Err(e) = self.validate() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, e));
+        }
+
         buffer[..GPS_FRAME_BYTES].copy_from_slice(&self.encode_raw());
 
         Ok(GPS_FRAME_BYTES)
     }
+
+    /// Encodes this [GpsQzssFrame] into a new [Vec] of [Self::encoding_size] bytes,
+    /// so you do not have to dimension the buffer yourself.
+    /// The final byte is padded.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn encode_to_vec(&self) -> Vec<u8> {
+        self.encode_raw().to_vec()
+    }
+
+    /// Encodes this [GpsQzssFrame] into mutable [u8] buffer directly,
+    /// like [Self::encode_to_buffer], but returns the exact number of _bits_
+    /// that were encoded ([GPS_FRAME_BITS]), not the number of bytes.
+    /// The 4 padding bits of the final byte are not accounted for,
+    /// which is what bit-packing applications need.
+    ///
+    /// ## Input
+    /// - dest: mutable [u8] buffer
+    ///
+    /// ## Output
+    /// - [GpsError::WouldNotFit] if buffer can't accept this [GpsQzssFrame] entirely
+    /// - [GpsError::FrameIdMismatch] if this [GpsQzssFrame] is not consistent (see [Self::validate])
+    /// - total number of bits that were encoded
+    pub fn encode_bits(&self, dest: &mut [u8]) -> Result<usize, GpsError> {
+        if !encoding_fits(dest.len(), Self::encoding_bits()) {
+            return Err(GpsError::WouldNotFit);
+        }
+
+        self.validate()?;
+
+        dest[..GPS_FRAME_BYTES].copy_from_slice(&self.encode_raw());
+
+        Ok(GPS_FRAME_BITS)
+    }
+
+    /// Encodes this [GpsQzssFrame] into mutable [u8] buffer, starting at any bit position,
+    /// without padding. Exactly [GPS_FRAME_BITS] are written, surrounding bits are preserved,
+    /// so you can chain frames into a continuous stream (like the 50 bps transmission).
+    ///
+    /// ## Input
+    /// - dest: mutable [u8] buffer
+    /// - bit_offset: position of the first bit (preamble MSB) within the buffer
+    ///
+    /// ## Output
+    /// - [GpsError::WouldNotFit] if buffer can't accept this [GpsQzssFrame] entirely
+    /// - [GpsError::FrameIdMismatch] if this [GpsQzssFrame] is not consistent (see [Self::validate])
+    /// - new bit offset, where the following frame should be encoded
+    pub fn encode_continuous(&self, dest: &mut [u8], bit_offset: usize) -> Result<usize, GpsError> {
+        if dest.len() * 8 < bit_offset + GPS_FRAME_BITS {
+            return Err(GpsError::WouldNotFit);
+        }
+
+        self.validate()?;
+
+        let encoded = self.encode_raw();
+
+        for (i, byte) in encoded.iter().enumerate() {
+            // last byte is padded
+            let nbits = (GPS_FRAME_BITS - i * 8).min(8);
+            let value = (*byte >> (8 - nbits)) as u32;
+            set_bits(dest, bit_offset + i * 8, nbits, value);
+        }
+
+        Ok(bit_offset + GPS_FRAME_BITS)
+    }
 }
 
 #[cfg(test)]
@@ -399,9 +207,13 @@ mod encoding {
 
     use log::info;
 
-    use crate::gps::{
-        GpsQzssDecoder, GpsQzssFrame, GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3, GpsQzssFrameId,
-        GpsQzssHow, GpsQzssSubframe, GpsQzssTelemetry, GPS_FRAME_BITS, GPS_FRAME_BYTES,
+    use crate::{
+        gps::{
+            GpsDataWord, GpsError, GpsQzssDecoder, GpsQzssFrame, GpsQzssFrame1, GpsQzssFrame2,
+            GpsQzssFrame3, GpsQzssFrameId, GpsQzssHow, GpsQzssSubframe, GpsQzssTelemetry,
+            GPS_FRAME_BITS, GPS_FRAME_BYTES, GPS_PREAMBLE_BYTE,
+        },
+        MAX_MESSAGE_BITS,
     };
 
     #[test]
@@ -414,6 +226,100 @@ mod encoding {
         assert_eq!(GpsQzssFrame::encoding_bits(), 300);
     }
 
+    #[test]
+    fn encoded_size_constants() {
+        const _: () = assert!(GpsQzssFrame::ENCODED_BYTES == GPS_FRAME_BYTES);
+        const _: () = assert!(GpsQzssFrame::ENCODED_BITS == GPS_FRAME_BITS);
+        const _: () = assert!(GpsQzssFrame::encoding_size() == GpsQzssFrame::ENCODED_BYTES);
+
+        let mut buffer = [0u8; GpsQzssFrame::ENCODED_BYTES];
+        let frame = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        assert_eq!(
+            frame.encode_continuous(&mut buffer, 0),
+            Ok(GpsQzssFrame::ENCODED_BITS)
+        );
+    }
+
+    #[test]
+    fn encode_bits() {
+        let frame = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+
+        let mut buffer = [0; 64];
+
+        let encoded = frame.encode_bits(&mut buffer).unwrap();
+        assert_eq!(encoded, GPS_FRAME_BITS);
+        assert_eq!(buffer[..GPS_FRAME_BYTES], frame.encode_raw());
+
+        let mut buffer = [0; GPS_FRAME_BYTES - 1];
+        assert!(matches!(
+            frame.encode_bits(&mut buffer),
+            Err(GpsError::WouldNotFit)
+        ));
+
+        let mut buffer = [0; (MAX_MESSAGE_BITS + 7) / 8];
+        assert!(frame.encode_bits(&mut buffer).is_ok());
+    }
+
+    #[test]
+    fn encode_to_vec() {
+        for frame_id in [GpsQzssFrameId::Ephemeris1, GpsQzssFrameId::Ephemeris4] {
+            let frame = GpsQzssFrame::model(frame_id);
+            let encoded = frame.encode_to_vec();
+
+            assert_eq!(encoded.len(), GpsQzssFrame::encoding_size());
+            assert_eq!(encoded[0], GPS_PREAMBLE_BYTE);
+            assert_eq!(encoded, frame.encode_raw());
+        }
+    }
+
+    #[test]
+    fn encode_continuous() {
+        let frames = [
+            GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1),
+            GpsQzssFrame::model(GpsQzssFrameId::Ephemeris2),
+            GpsQzssFrame::model(GpsQzssFrameId::Ephemeris3),
+        ];
+
+        // delayed stream, that does not start on a byte boundary
+        let mut buffer = [0xff; 128];
+        let mut offset = 3;
+
+        for frame in frames.iter() {
+            offset = frame.encode_continuous(&mut buffer, offset).unwrap();
+        }
+
+        assert_eq!(offset, 3 + 3 * GPS_FRAME_BITS);
+
+        // surrounding bits are preserved
+        assert_eq!(buffer[0] & 0xe0, 0xe0);
+        assert_eq!(buffer[113] & 0x0f, 0x0f);
+        assert_eq!(buffer[114], 0xff);
+
+        let mut decoder = GpsQzssDecoder::default();
+        let mut ptr = 0;
+
+        for frame in frames.iter() {
+            let (processed, decoded) = decoder.decode(&buffer[ptr / 8..], 128 - ptr / 8);
+
+            let decoded = decoded.unwrap();
+            assert_eq!(decoded.how, frame.how);
+            assert_eq!(decoded.telemetry, frame.telemetry);
+
+            ptr = (ptr / 8) * 8 + processed;
+        }
+
+        assert_eq!(ptr, 3 + 3 * GPS_FRAME_BITS);
+
+        assert!(matches!(
+            frames[0].encode_continuous(&mut buffer, 128 * 8 - GPS_FRAME_BITS + 1),
+            Err(GpsError::WouldNotFit)
+        ));
+        assert!(frames[0]
+            .encode_continuous(&mut buffer, 128 * 8 - GPS_FRAME_BITS)
+            .is_ok());
+    }
+
     #[test]
     fn default_frame() {
         #[cfg(all(feature = "std", feature = "log"))]
@@ -431,11 +337,11 @@ mod encoding {
         assert_eq!(encoded[0], 0x8B, "does not start with preamble bits");
         assert_eq!(encoded[1], 0x00);
         assert_eq!(encoded[2], 0x00);
-        assert_eq!(encoded[3], 0x00);
+        assert_eq!(encoded[3], 0x48);
         assert_eq!(encoded[4], 0x00);
         assert_eq!(encoded[5], 0x00);
-        assert_eq!(encoded[6], 0x10);
-        assert_eq!(encoded[7], 0x00);
+        assert_eq!(encoded[6], 0x1D);
+        assert_eq!(encoded[7], 0x40);
         assert_eq!(encoded[8], 0x00);
         assert_eq!(encoded[9], 0x00);
         assert_eq!(encoded[10], 0x00);
@@ -521,42 +427,42 @@ mod encoding {
         assert_eq!(encoded[0], 0x8B, "does not start with preamble bits");
         assert_eq!(encoded[1], 0x48);
         assert_eq!(encoded[2], 0xD0 | 0x02 | 0x01);
-        assert_eq!(encoded[3], 0x4C);
+        assert_eq!(encoded[3], 0x08);
 
-        assert_eq!(encoded[4], 0x60);
-        assert_eq!(encoded[5], 0x69);
-        assert_eq!(encoded[6], 0x92);
+        assert_eq!(encoded[4], 0x18);
+        assert_eq!(encoded[5], 0x1B);
+        assert_eq!(encoded[6], 0x97);
         assert_eq!(encoded[7], 0x04);
 
-        assert_eq!(encoded[8], 0x8f);
+        assert_eq!(encoded[8], 0x8F);
         assert_eq!(encoded[9], 0x20);
-        assert_eq!(encoded[10], 0x10);
-        assert_eq!(encoded[11], 0x24);
+        assert_eq!(encoded[10], 0x1E);
+        assert_eq!(encoded[11], 0xA4);
         assert_eq!(encoded[12], 0x8D);
         assert_eq!(encoded[13], 0x15);
-        assert_eq!(encoded[14], 0x80);
+        assert_eq!(encoded[14], 0x82);
         assert_eq!(encoded[15], 0x34);
         assert_eq!(encoded[16], 0x56);
         assert_eq!(encoded[17], 0x78);
-        assert_eq!(encoded[18], 0x02);
+        assert_eq!(encoded[18], 0x4A);
         assert_eq!(encoded[19], 0x61);
         assert_eq!(encoded[20], 0xD9);
-        assert_eq!(encoded[21], 0x50);
-        assert_eq!(encoded[22], 0x01);
+        assert_eq!(encoded[21], 0x52);
+        assert_eq!(encoded[22], 0xE1);
         assert_eq!(encoded[23], 0x23);
         assert_eq!(encoded[24], 0x40);
-        assert_eq!(encoded[25], 0x20);
-        assert_eq!(encoded[26], 0x08);
-        assert_eq!(encoded[27], 0xC0);
-        assert_eq!(encoded[28], 0xBB);
-        assert_eq!(encoded[29], 0x80);
+        assert_eq!(encoded[25], 0x2F);
+        assert_eq!(encoded[26], 0x77);
+        assert_eq!(encoded[27], 0x3F);
+        assert_eq!(encoded[28], 0x44);
+        assert_eq!(encoded[29], 0x50);
         assert_eq!(encoded[30], 0x24);
         assert_eq!(encoded[31], 0x00);
         assert_eq!(encoded[32], 0x09);
-        assert_eq!(encoded[33], 0x00);
-        assert_eq!(encoded[34], 0x00);
-        assert_eq!(encoded[35], 0x00);
-        assert_eq!(encoded[36], 0x20);
+        assert_eq!(encoded[33], 0x27);
+        assert_eq!(encoded[34], 0xFF);
+        assert_eq!(encoded[35], 0xFF);
+        assert_eq!(encoded[36], 0xDC);
         assert_eq!(encoded[37], 0x00);
 
         // reciprocal
@@ -603,16 +509,16 @@ mod encoding {
         assert_eq!(encoded[0], 0x8B, "does not start with preamble bits");
         assert_eq!(encoded[1], 0x48);
         assert_eq!(encoded[2], 0x34 << 2 | 0x01);
-        assert_eq!(encoded[3], 0x4D);
+        assert_eq!(encoded[3], 0x9E);
 
-        assert_eq!(encoded[4], 0xDF);
-        assert_eq!(encoded[5], 0x61);
-        assert_eq!(encoded[6], 0x10);
-        assert_eq!(encoded[7], 0x04);
+        assert_eq!(encoded[4], 0x88);
+        assert_eq!(encoded[5], 0x26);
+        assert_eq!(encoded[6], 0xEA);
+        assert_eq!(encoded[7], 0x44);
 
         assert_eq!(encoded[8], 0x8D);
         assert_eq!(encoded[9], 0x60);
-        assert_eq!(encoded[10], 0x30);
+        assert_eq!(encoded[10], 0x34);
         assert_eq!(encoded[11], 0x00);
         assert_eq!(encoded[12], 0x00);
         assert_eq!(encoded[13], 0x00);
@@ -631,14 +537,14 @@ mod encoding {
         assert_eq!(encoded[26], 0x11);
         assert_eq!(encoded[27], 0x40);
         assert_eq!(encoded[28], 0x00);
-        assert_eq!(encoded[29], 0x00);
+        assert_eq!(encoded[29], 0x0E);
         assert_eq!(encoded[30], 0x48);
         assert_eq!(encoded[31], 0x00);
         assert_eq!(encoded[32], 0x12);
-        assert_eq!(encoded[33], 0x00);
-        assert_eq!(encoded[34], 0x00);
-        assert_eq!(encoded[35], 0x00);
-        assert_eq!(encoded[36], 0x40);
+        assert_eq!(encoded[33], 0xEF);
+        assert_eq!(encoded[34], 0xFF);
+        assert_eq!(encoded[35], 0xFF);
+        assert_eq!(encoded[36], 0xB8);
         assert_eq!(encoded[37], 0x00);
 
         let frame = GpsQzssFrame::default()
@@ -677,44 +583,43 @@ mod encoding {
         assert_eq!(encoded[0], 0x8B, "does not start with preamble bits");
         assert_eq!(encoded[1], 0x04);
         assert_eq!(encoded[2], 0x23 << 2);
-        assert_eq!(encoded[3], 0x30);
+        assert_eq!(encoded[3], 0x5F);
 
-        assert_eq!(encoded[4], 0x4E);
-        assert_eq!(encoded[5], 0x20);
-        assert_eq!(encoded[6], 0x10);
-        assert_eq!(encoded[7], 0x0C);
+        assert_eq!(encoded[4], 0xEC);
+        assert_eq!(encoded[5], 0x77);
+        assert_eq!(encoded[6], 0xE6);
+        assert_eq!(encoded[7], 0xCC);
 
         assert_eq!(encoded[8], 0x86);
-        // TODO
-        // assert_eq!(encoded[9], 0x00);
-        // assert_eq!(encoded[10], 0x00);
-        // assert_eq!(encoded[11], 0x00);
-        // assert_eq!(encoded[12], 0x00);
-        // assert_eq!(encoded[13], 0x00);
-        // assert_eq!(encoded[14], 0x00);
-        // assert_eq!(encoded[15], 0x00);
-        // assert_eq!(encoded[16], 0x00);
-        // assert_eq!(encoded[17], 0x00);
-        // assert_eq!(encoded[18], 0x00);
-        // assert_eq!(encoded[19], 0x00);
-        // assert_eq!(encoded[20], 0x00);
-        // assert_eq!(encoded[21], 0x00);
-        // assert_eq!(encoded[22], 0x00);
-        // assert_eq!(encoded[23], 0x00);
-        // assert_eq!(encoded[24], 0x00);
-        // assert_eq!(encoded[25], 0x00);
-        // assert_eq!(encoded[26], 0x00);
-        // assert_eq!(encoded[27], 0x00);
-        // assert_eq!(encoded[28], 0x00);
-        // assert_eq!(encoded[29], 0x00);
-        // assert_eq!(encoded[30], 0x00);
-        // assert_eq!(encoded[31], 0x00);
-        // assert_eq!(encoded[32], 0x00);
-        // assert_eq!(encoded[33], 0x00);
-        // assert_eq!(encoded[34], 0x00);
-        // assert_eq!(encoded[35], 0x00);
-        // assert_eq!(encoded[36], 0x00);
-        // assert_eq!(encoded[37], 0x00);
+        assert_eq!(encoded[9], 0x40);
+        assert_eq!(encoded[10], 0x3A);
+        assert_eq!(encoded[11], 0xFB);
+        assert_eq!(encoded[12], 0x72);
+        assert_eq!(encoded[13], 0xEA);
+        assert_eq!(encoded[14], 0x7E);
+        assert_eq!(encoded[15], 0x34);
+        assert_eq!(encoded[16], 0x56);
+        assert_eq!(encoded[17], 0x78);
+        assert_eq!(encoded[18], 0x4A);
+        assert_eq!(encoded[19], 0x61);
+        assert_eq!(encoded[20], 0xD9);
+        assert_eq!(encoded[21], 0x52);
+        assert_eq!(encoded[22], 0xE1);
+        assert_eq!(encoded[23], 0x23);
+        assert_eq!(encoded[24], 0x40);
+        assert_eq!(encoded[25], 0x6D);
+        assert_eq!(encoded[26], 0x88);
+        assert_eq!(encoded[27], 0x41);
+        assert_eq!(encoded[28], 0x77);
+        assert_eq!(encoded[29], 0x0A);
+        assert_eq!(encoded[30], 0x48);
+        assert_eq!(encoded[31], 0x00);
+        assert_eq!(encoded[32], 0x12);
+        assert_eq!(encoded[33], 0xEF);
+        assert_eq!(encoded[34], 0xFF);
+        assert_eq!(encoded[35], 0xFF);
+        assert_eq!(encoded[36], 0xB8);
+        assert_eq!(encoded[37], 0x00);
 
         // reciprocal
         let mut decoder = GpsQzssDecoder::default();
@@ -731,6 +636,62 @@ mod encoding {
         );
     }
 
+    #[test]
+    fn encode_parity() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+            GpsQzssFrameId::Ephemeris4,
+            GpsQzssFrameId::Ephemeris5,
+        ] {
+            let words = GpsQzssFrame::model(frame_id).encode();
+
+            let mut prev = GpsDataWord::default();
+
+            for (i, word) in words.iter().enumerate() {
+                assert!(
+                    word.parity_check(&prev, false).is_ok(),
+                    "{:?}: invalid parity for word #{}",
+                    frame_id,
+                    i + 1
+                );
+
+                prev = *word;
+            }
+
+            // HOW and last word end with D29=D30=0
+            assert_eq!(words[1].value() & 0x3, 0, "{:?}: HOW", frame_id);
+            assert_eq!(words[9].value() & 0x3, 0, "{:?}: last word", frame_id);
+        }
+    }
+
+    #[test]
+    fn verify_parity() {
+        let frame = GpsQzssFrame::default()
+            .with_telemetry(GpsQzssTelemetry::default().with_message(0x13E))
+            .with_hand_over_word(
+                GpsQzssHow::default()
+                    .with_tow_seconds(259_200)
+                    .with_frame_id(GpsQzssFrameId::Ephemeris1),
+            )
+            .with_subframe(GpsQzssSubframe::Ephemeris1(
+                GpsQzssFrame1::default().with_week(10).with_iodc(0x10),
+            ));
+
+        assert!(frame.verify_parity());
+
+        // 14-bit telemetry message
+        let mut mutated = frame;
+        mutated.telemetry.message = 0x4000;
+        assert!(!mutated.verify_parity());
+
+        // 10-bit IODC
+        let mut mutated = frame;
+        mutated.subframe.as_mut_eph1().unwrap().iodc = 0x400;
+        assert!(!mutated.verify_parity());
+    }
+
     #[test]
     fn ephemeris1_reciprocal() {
         #[cfg(all(feature = "std", feature = "log"))]
@@ -817,86 +778,82 @@ mod encoding {
                 3.0E-12,
                 3.0E-14,
             ),
-            // TODO: signed
-            // (
-            //     15_000,
-            //     true,
-            //     false,
-            //     GpsQzssFrameId::Ephemeris1,
-            //     0x13F,
-            //     false,
-            //     true,
-            //     1024,
-            //     0x03,
-            //     3,
-            //     3,
-            //     0x123,
-            //     16_432,
-            //     -4.0,
-            //     4.0E-9,
-            //     4.0E-12,
-            //     4.0E-14,
-            // ),
-            // TODO: signed
-            // (
-            //     15_000,
-            //     true,
-            //     false,
-            //     GpsQzssFrameId::Ephemeris1,
-            //     0x13F,
-            //     false,
-            //     true,
-            //     1024,
-            //     0x03,
-            //     3,
-            //     3,
-            //     0x123,
-            //     16_432,
-            //     5.0,
-            //     -4.0E-9,
-            //     4.0E-12,
-            //     4.0E-14,
-            // ),
-            // TODO: signed
-            // (
-            //     15_000,
-            //     true,
-            //     false,
-            //     GpsQzssFrameId::Ephemeris1,
-            //     0x13F,
-            //     false,
-            //     true,
-            //     1024,
-            //     0x03,
-            //     3,
-            //     3,
-            //     0x123,
-            //     16_432,
-            //     5.0,
-            //     6.0E-9,
-            //     -4.0E-12,
-            //     4.0E-14,
-            // ),
-            // TODO: signed
-            // (
-            //     15_000,
-            //     true,
-            //     false,
-            //     GpsQzssFrameId::Ephemeris1,
-            //     0x13F,
-            //     false,
-            //     true,
-            //     1024,
-            //     0x03,
-            //     3,
-            //     3,
-            //     0x123,
-            //     16_432,
-            //     5.0,
-            //     6.0E-9,
-            //     4.0E-12,
-            //     -4.0E-14,
-            // ),
+            (
+                15_000,
+                true,
+                false,
+                GpsQzssFrameId::Ephemeris1,
+                0x13F,
+                false,
+                true,
+                1024,
+                0x03,
+                3,
+                3,
+                0x123,
+                16_432,
+                -4.0,
+                4.0E-9,
+                4.0E-12,
+                4.0E-14,
+            ),
+            (
+                15_000,
+                true,
+                false,
+                GpsQzssFrameId::Ephemeris1,
+                0x13F,
+                false,
+                true,
+                1024,
+                0x03,
+                3,
+                3,
+                0x123,
+                16_432,
+                5.0,
+                -4.0E-9,
+                4.0E-12,
+                4.0E-14,
+            ),
+            (
+                15_000,
+                true,
+                false,
+                GpsQzssFrameId::Ephemeris1,
+                0x13F,
+                false,
+                true,
+                1024,
+                0x03,
+                3,
+                3,
+                0x123,
+                16_432,
+                5.0,
+                6.0E-9,
+                -4.0E-12,
+                4.0E-14,
+            ),
+            (
+                15_000,
+                true,
+                false,
+                GpsQzssFrameId::Ephemeris1,
+                0x13F,
+                false,
+                true,
+                1024,
+                0x03,
+                3,
+                3,
+                0x123,
+                16_432,
+                5.0,
+                6.0E-9,
+                4.0E-12,
+                -4.0E-14,
+            ),
         ]
         .iter()
         .enumerate()
@@ -971,11 +928,11 @@ mod encoding {
             .with_subframe(GpsQzssSubframe::Ephemeris2(
                 GpsQzssFrame2::default()
                     .with_iode(0x12)
-                    .with_crs_meters(1.8)
+                    .with_crs_meters(1.8125)
                     .with_cuc_radians(9e-7)
                     .with_cus_radians(2e-6)
                     .with_eccentricity(0.001)
-                    .with_mean_motion_difference_semicircles(4e-9)
+                    .with_mean_motion_difference_semicircles(1.4e-9)
                     .with_mean_anomaly_semicircles(9.768415465951e-001)
                     .with_toe_seconds(345_600)
                     .with_square_root_semi_major_axis(5.153602432251e+003)
@@ -989,44 +946,7 @@ mod encoding {
         assert_eq!(encoded[0], 0x8B, "does not start with preamble bits");
         assert_eq!(encoded[1], 0x66);
         assert_eq!(encoded[2], 0x99 << 2 | 0x02 | 0x01);
-        assert_eq!(encoded[3], 0x9C);
-
-        // assert_eq!(encoded[4], 0x33);
-        // assert_eq!(encoded[5], 0x33);
-        // assert_eq!(encoded[6], 0xA0);
-        // assert_eq!(encoded[7], 0x01);
-
-        // assert_eq!(encoded[8], 0x20);
-        // assert_eq!(encoded[9], 0x03);
-        // assert_eq!(encoded[10], 0xA0);
-        // assert_eq!(encoded[11], 0x3F);
-
-        // assert_eq!(encoded[12], 0xFF);
-        // assert_eq!(encoded[13], 0xDF);
-        // assert_eq!(encoded[14], 0x40);
-        // assert_eq!(encoded[15], 0x09);
-        // assert_eq!(encoded[16], 0x24);
-        // assert_eq!(encoded[17], 0xD0);
-        // assert_eq!(encoded[18], 0x00);
-        // assert_eq!(encoded[19], 0x00);
-        // assert_eq!(encoded[20], 0x00);
-        // assert_eq!(encoded[21], 0x00);
-        // assert_eq!(encoded[22], 0x00);
-        // assert_eq!(encoded[23], 0x00);
-        // assert_eq!(encoded[24], 0x00);
-        // assert_eq!(encoded[25], 0x00);
-        // assert_eq!(encoded[26], 0x00);
-        // assert_eq!(encoded[27], 0x00);
-        // assert_eq!(encoded[28], 40);
-        // assert_eq!(encoded[29], 64);
-        // assert_eq!(encoded[30], 12);
-        // assert_eq!(encoded[31], 209);
-        // assert_eq!(encoded[32], 200);
-        // assert_eq!(encoded[33], 0x00);
-        // assert_eq!(encoded[34], 40);
-        // assert_eq!(encoded[35], 0x03);
-        // assert_eq!(encoded[36], 0x50);
-        // assert_eq!(encoded[37], 0x00);
+        assert_eq!(encoded[3], 0xDF);
 
         // reciprocal
         let mut decoder = GpsQzssDecoder::default();
@@ -1077,8 +997,8 @@ mod encoding {
                 0x13E,
                 true,
                 false,
-                435_500,
-                1.0e-1,
+                435_488,
+                1.2e-9,
                 5153.0,
                 0x12,
                 1e-6,
@@ -1093,12 +1013,12 @@ mod encoding {
                 0x13E,
                 false,
                 true,
-                435_500,
-                1.0e-1,
+                435_488,
+                -1.2e-9,
                 5152.0,
                 0x21,
-                2e-6,
-                1e-6,
+                -2e-6,
+                -1e-6,
                 0x52,
             ),
         ]
@@ -1242,7 +1162,7 @@ mod encoding {
                 12.0,
                 0.1,
                 3e-1,
-                0.3,
+                -2.6e-9,
                 0x34,
                 1.6e-10,
                 6e-1,
@@ -1260,7 +1180,7 @@ mod encoding {
                 14.0,
                 0.2,
                 4e-1,
-                0.4,
+                4.0e-9,
                 0x43,
                 1.5e-10,
                 7e-1,
@@ -1278,6 +1198,8 @@ mod encoding {
                 .with_omega_semicircles(*omega)
                 .with_omega_dot_semicircles_s(*omega_dot)
                 .with_inclination_semicircles(*i0)
+                .with_inclination_rate_semicircles_s(*idot)
+                .with_longitude_ascending_node_semicircles(*omega0)
                 .with_cis_radians(*cis);
 
             if *alert {
@@ -1313,6 +1235,87 @@ mod encoding {
         }
     }
 
+    #[test]
+    fn signed_values() {
+        let frame2 = GpsQzssFrame2::default()
+            .with_iode(0x12)
+            .with_mean_anomaly_semicircles(-0.25)
+            .with_mean_motion_difference_semicircles(-1.2e-9)
+            .with_cuc_radians(-1e-6)
+            .with_cus_radians(-2e-6)
+            .with_crs_meters(-87.5)
+            .with_eccentricity(0.01)
+            .with_square_root_semi_major_axis(5153.6)
+            .with_toe_seconds(345_600);
+
+        let frame3 = GpsQzssFrame3::default()
+            .with_iode(0x12)
+            .with_cic_radians(-1e-6)
+            .with_cis_radians(-2e-6)
+            .with_crc_meters(-122.0)
+            .with_inclination_semicircles(-0.3)
+            .with_inclination_rate_semicircles_s(-8e-10)
+            .with_longitude_ascending_node_semicircles(-0.6)
+            .with_omega_semicircles(-0.1)
+            .with_omega_dot_semicircles_s(-2.6e-9);
+
+        for subframe in [
+            GpsQzssSubframe::Ephemeris2(frame2),
+            GpsQzssSubframe::Ephemeris3(frame3),
+        ] {
+            let frame = GpsQzssFrame::default()
+                .with_hand_over_word(GpsQzssHow::default().with_tow_seconds(15_000))
+                .with_subframe(subframe);
+
+            assert!(frame.verify_parity(), "{:?}: parity", frame.how.frame_id);
+
+            let encoded = frame.encode_raw();
+            let (_, decoded) = GpsQzssDecoder::default().decode(&encoded, encoded.len());
+            let decoded = decoded.unwrap();
+
+            assert_eq!(decoded, frame, "{:?}: reciprocal", frame.how.frame_id);
+
+            // the tolerances of PartialEq are coarser than some fields:
+            // verify the sign survived, down to the LSB
+            if let Some(decoded) = decoded.subframe.as_eph2() {
+                for (value, expected, lsb) in [
+                    (decoded.m0, frame2.m0, 2.0_f64.powi(-31)),
+                    (decoded.dn, frame2.dn, 2.0_f64.powi(-43)),
+                    (decoded.cuc, frame2.cuc, 2.0_f64.powi(-29)),
+                    (decoded.cus, frame2.cus, 2.0_f64.powi(-29)),
+                    (decoded.crs, frame2.crs, 2.0_f64.powi(-5)),
+                ] {
+                    assert!(
+                        (value - expected).abs() <= lsb / 2.0,
+                        "{} != {}",
+                        value,
+                        expected
+                    );
+                }
+            }
+
+            if let Some(decoded) = decoded.subframe.as_eph3() {
+                for (value, expected, lsb) in [
+                    (decoded.cic, frame3.cic, 2.0_f64.powi(-29)),
+                    (decoded.cis, frame3.cis, 2.0_f64.powi(-29)),
+                    (decoded.crc, frame3.crc, 2.0_f64.powi(-5)),
+                    (decoded.i0, frame3.i0, 2.0_f64.powi(-31)),
+                    (decoded.idot, frame3.idot, 2.0_f64.powi(-43)),
+                    (decoded.omega0, frame3.omega0, 2.0_f64.powi(-31)),
+                    (decoded.omega, frame3.omega, 2.0_f64.powi(-31)),
+                    (decoded.omega_dot, frame3.omega_dot, 2.0_f64.powi(-43)),
+                ] {
+                    assert!(
+                        (value - expected).abs() <= lsb / 2.0,
+                        "{} != {}",
+                        value,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn generate_eph1_bin() {
         let mut fd = File::create("data/GPS/eph1.bin").unwrap_or_else(|e| {
@@ -1,26 +1,65 @@
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+/// [GpsError] is returned when decoding or encoding GPS (or QZSS) frames.
+/// It implements [core::fmt::Display] and the standard `Error` trait.
+#[derive(Error, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum GpsError {
     /// Not a valid GPS preamble
     #[error("invalid GPS preamble")]
     InvalidPreamble,
 
-    /// Frame Type is either invalid or not supported
-    #[error("unknown GPS subframe type")]
-    UnknownFrameType,
+    /// 3-bit subframe ID of the HOW is either invalid or not supported (only 1 to 5 are)
+    #[error("unknown GPS subframe type #{0}")]
+    UnknownFrameType(u8),
 
-    /// Internal error: internal FSM reached invalid state,
-    /// most likely due to corruption in the handling of successive
-    /// data words in the stream. Should never happen.
-    #[error("internal FSM error")]
-    InternalFSM,
-
-    /// Size is too small to encode a correct data frame
-    #[error("buffer to small for this GPS frame")]
+    /// Buffer is too small to encode (or decode) a complete data frame
+    #[error("buffer too small for this GPS frame")]
     WouldNotFit,
 
+    /// Frame-4 or Frame-5 page is either invalid or not supported
+    #[error("invalid or unsupported page")]
+    InvalidPage,
+
     /// Invalid Word Parity
     #[error("invalid word parity")]
     Parity,
+
+    /// Ephemeris frames do not belong to the same issue of data
+    #[error("IODE / IODC mismatch")]
+    IssueOfDataMismatch,
+
+    /// Ephemeris frames do not refer to the same instant (toc / toe)
+    #[error("toc / toe mismatch")]
+    ReferenceTimeMismatch,
+
+    /// [GpsQzssSubframe](crate::gps::GpsQzssSubframe) does not match the frame ID of the HOW
+    #[error("subframe does not match HOW frame ID")]
+    FrameIdMismatch,
+}
+
+#[cfg(test)]
+mod test {
+    use super::GpsError;
+
+    #[test]
+    fn display() {
+        for (error, expected) in [
+            (GpsError::InvalidPreamble, "invalid GPS preamble"),
+            (
+                GpsError::UnknownFrameType(6),
+                "unknown GPS subframe type #6",
+            ),
+            (GpsError::WouldNotFit, "buffer too small for this GPS frame"),
+            (GpsError::InvalidPage, "invalid or unsupported page"),
+            (GpsError::Parity, "invalid word parity"),
+            (GpsError::IssueOfDataMismatch, "IODE / IODC mismatch"),
+            (GpsError::ReferenceTimeMismatch, "toc / toe mismatch"),
+            (
+                GpsError::FrameIdMismatch,
+                "subframe does not match HOW frame ID",
+            ),
+        ] {
+            assert_eq!(error.to_string(), expected);
+        }
+    }
 }
@@ -1,7 +1,17 @@
-use crate::gps::{GpsQzssFrameId, GpsQzssHow, GpsQzssSubframe, GpsQzssTelemetry};
+use crate::gps::{
+    how::GPS_WEEK_SECONDS, GpsError, GpsQzssConstellation, GpsQzssFrameId, GpsQzssHow,
+    GpsQzssSubframe, GpsQzssTelemetry,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(doc)]
+use crate::gps::GpsQzssFrame1;
 
 /// GPS / QZSS interpreted frame.
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssFrame {
     /// [GpsQzssTelemetry] describes the following frame and contains
     /// the sync-byter, therefore initiates a [GpsQzssFrame].
@@ -12,6 +22,10 @@ pub struct GpsQzssFrame {
 
     /// [GpsQzssSubframe] depends on associated [GpsQzssHow].
     pub subframe: GpsQzssSubframe,
+
+    /// [GpsQzssConstellation] this frame was received from,
+    /// which drives the interpretation of some fields (like health and fit interval).
+    pub constellation: GpsQzssConstellation,
 }
 
 impl GpsQzssFrame {
@@ -36,16 +50,189 @@ impl GpsQzssFrame {
         self
     }
 
-    /// Copies and returns an updated [GpsQzssSubframe]
+    /// Copies and returns with updated [GpsQzssConstellation]
+    pub fn with_constellation(mut self, constellation: GpsQzssConstellation) -> Self {
+        self.constellation = constellation;
+        self
+    }
+
+    /// Returns true if this [GpsQzssFrame] may be used safely: the alert bit of the [GpsQzssHow]
+    /// is not asserted and, for [GpsQzssSubframe::Ephemeris1], the satellite is healthy
+    /// (see [GpsQzssFrame1::is_healthy]). The health is not known from other subframes,
+    /// in which case only the alert bit is considered.
+    pub fn usable(&self) -> bool {
+        if self.how.is_alert() {
+            return false;
+        }
+
+        match self.subframe {
+            GpsQzssSubframe::Ephemeris1(frame1) => frame1.is_healthy(self.constellation),
+            _ => true,
+        }
+    }
+
+    /// Copies and returns an updated [GpsQzssSubframe].
+    /// The [GpsQzssFrameId] of the HOW follows, unless this is [GpsQzssSubframe::Raw] data.
     pub fn with_subframe(mut self, subframe: GpsQzssSubframe) -> Self {
         self.subframe = subframe;
 
-        match subframe {
-            GpsQzssSubframe::Ephemeris1(_) => self.how.frame_id = GpsQzssFrameId::Ephemeris1,
-            GpsQzssSubframe::Ephemeris2(_) => self.how.frame_id = GpsQzssFrameId::Ephemeris2,
-            GpsQzssSubframe::Ephemeris3(_) => self.how.frame_id = GpsQzssFrameId::Ephemeris3,
+        if let Some(frame_id) = GpsQzssFrameId::from_subframe(&subframe) {
+            self.how.frame_id = frame_id;
         }
 
         self
     }
+
+    /// Verifies this [GpsQzssFrame] is consistent: the [GpsQzssSubframe] must match
+    /// the [GpsQzssFrameId] of the [GpsQzssHow], otherwise the frame would not be interpreted
+    /// correctly once encoded. [GpsQzssSubframe::Raw] data matches any [GpsQzssFrameId].
+    /// [Self::with_subframe] keeps both in sync, but the [GpsQzssHow] may be replaced afterwards.
+    ///
+    /// ## Output
+    /// - [GpsError::FrameIdMismatch] when the subframe and HOW disagree
+    pub fn validate(&self) -> Result<(), GpsError> {
+        match self.subframe.frame_id() {
+            Some(frame_id) if frame_id != self.how.frame_id => Err(GpsError::FrameIdMismatch),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the GPS time of week (in seconds) at the start of this [GpsQzssFrame].
+    ///
+    /// The TOW of the [GpsQzssHow] does not date this frame, but the _next_ one:
+    /// it is the time of transmission of the first bit of the following subframe.
+    /// This frame therefore started 6 seconds (one subframe) earlier, which is what we return.
+    /// The first frame of a week refers to the end of the previous week (604794 s).
+    pub fn gps_time_of_week_seconds(&self) -> u32 {
+        (self.how.tow_count() * 6 + GPS_WEEK_SECONDS - 6) % GPS_WEEK_SECONDS
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gps::{
+        GpsError, GpsQzssConstellation, GpsQzssFrame, GpsQzssFrame1, GpsQzssFrameId, GpsQzssHow,
+        GpsQzssSubframe, GPS_FRAME_BYTES,
+    };
+
+    #[test]
+    fn usable() {
+        for (alert, health, expected) in [
+            (false, 0, true),
+            (false, 0x3f, false),
+            (true, 0, false),
+            (true, 0x3f, false),
+        ] {
+            let mut how = GpsQzssHow::ephemeris1().without_alert_bit();
+
+            if alert {
+                how = how.with_alert_bit();
+            }
+
+            assert_eq!(how.is_alert(), alert);
+
+            let frame1 = GpsQzssFrame1 {
+                health,
+                ..Default::default()
+            };
+
+            let frame = GpsQzssFrame::default()
+                .with_hand_over_word(how)
+                .with_subframe(GpsQzssSubframe::Ephemeris1(frame1));
+
+            assert_eq!(
+                frame.usable(),
+                expected,
+                "failed for alert={} health=0x{:02X}",
+                alert,
+                health
+            );
+        }
+
+        // QZSS: only the L1 C/A health bit matters
+        let frame1 = GpsQzssFrame1 {
+            health: 0x1f,
+            ..Default::default()
+        };
+
+        let frame = GpsQzssFrame::default()
+            .with_subframe(GpsQzssSubframe::Ephemeris1(frame1))
+            .with_constellation(GpsQzssConstellation::Qzss);
+
+        assert!(frame.usable());
+        assert!(!frame.with_constellation(GpsQzssConstellation::Gps).usable());
+
+        // health is not known from other subframes
+        for frame_id in [GpsQzssFrameId::Ephemeris2, GpsQzssFrameId::Ephemeris3] {
+            let frame = GpsQzssFrame::model(frame_id);
+            assert!(frame.how.is_anti_spoofing());
+            assert_eq!(frame.usable(), !frame.how.is_alert());
+        }
+    }
+
+    #[test]
+    fn frame_id_mismatch() {
+        let frame = GpsQzssFrame::model(GpsQzssFrameId::Ephemeris1);
+        assert_eq!(frame.validate(), Ok(()));
+
+        // HOW replaced after the subframe
+        let frame = frame.with_hand_over_word(GpsQzssHow::model(GpsQzssFrameId::Ephemeris2));
+        assert_eq!(frame.validate(), Err(GpsError::FrameIdMismatch));
+
+        let mut buffer = [0u8; GPS_FRAME_BYTES];
+        assert_eq!(
+            frame.encode_bits(&mut buffer),
+            Err(GpsError::FrameIdMismatch)
+        );
+        assert_eq!(
+            frame.encode_continuous(&mut buffer, 0),
+            Err(GpsError::FrameIdMismatch)
+        );
+
+        // raw data matches any frame ID
+        let frame = frame.with_subframe(GpsQzssSubframe::Raw(Default::default()));
+        assert_eq!(frame.how.frame_id, GpsQzssFrameId::Ephemeris2);
+        assert_eq!(frame.validate(), Ok(()));
+        assert!(frame.encode_bits(&mut buffer).is_ok());
+    }
+
+    #[test]
+    fn gps_time_of_week() {
+        for (how_tow, expected) in [
+            (6, 0),
+            (15_000, 14_994),
+            (15_003, 14_994),
+            (604_794, 604_788),
+            (0, 604_794),
+        ] {
+            let frame = GpsQzssFrame::default()
+                .with_hand_over_word(GpsQzssHow::default().with_tow_seconds(how_tow));
+
+            assert_eq!(
+                frame.gps_time_of_week_seconds(),
+                expected,
+                "failed for HOW TOW={}",
+                how_tow
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+            GpsQzssFrameId::Ephemeris4,
+            GpsQzssFrameId::Ephemeris5,
+        ] {
+            let frame = GpsQzssFrame::model(frame_id);
+
+            let json = serde_json::to_string(&frame).unwrap();
+            let parsed: GpsQzssFrame = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(parsed, frame, "{:?}: serde round trip failed", frame_id);
+        }
+    }
 }
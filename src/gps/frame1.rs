@@ -1,8 +1,22 @@
 use crate::{
-    gps::{GpsDataWord, GPS_WORDS_PER_FRAME},
-    twos_complement,
+    bits::BitWriter,
+    gps::{time_of_week_difference, GpsDataWord, GpsQzssConstellation, GPS_WORDS_PER_FRAME},
+    reconstruct_full_week, twos_complement,
 };
 
+#[cfg(feature = "std")]
+use crate::gps::GpsQzssFrame2;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// Relativistic correction term constant F (in s.m^-1/2)
+#[cfg(feature = "std")]
+const RELATIVISTIC_F: f64 = -4.442807633e-10;
+
 const WORD3_WEEK_MASK: u32 = 0x3ff00000;
 const WORD3_WEEK_SHIFT: u32 = 20;
 const WORD3_CA_P_L2_MASK: u32 = 0x000C0000;
@@ -44,6 +58,7 @@ const WORD10_AF0_SHIFT: u32 = 8;
 
 /// [GpsQzssFrame1] Ephemeris #1 frame interpretation.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssFrame1 {
     /// 10-bit wrapped week counter.
     pub week: u16,
@@ -80,7 +95,9 @@ pub struct GpsQzssFrame1 {
     /// Time of clock (in seconds)
     pub toc: u32,
 
-    /// 8-bit TGD (in seconds)
+    /// 8-bit TGD (in seconds), with 2^-31 s LSB.
+    /// Encodable range is therefore [-128; 127] x 2^-31 s (about ±59.6 ns),
+    /// values outside this range saturate when encoded.
     pub tgd: f64,
 
     /// af2 (in seconds per squared second)
@@ -174,6 +191,17 @@ impl PartialEq for GpsQzssFrame1 {
     }
 }
 
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssFrame1 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "WEEK={} - IODC=0x{:03X} - TOC={}s - HEALTH=0x{:02X} - URA={} - TGD={:.3e}s - AF0={:.6e}s - AF1={:.6e}s/s - AF2={:.6e}s/s²",
+            self.week, self.iodc, self.toc, self.health, self.ura, self.tgd, self.af0, self.af1, self.af2,
+        )
+    }
+}
+
 impl GpsQzssFrame1 {
     /// Generates a realistic frame model for testing purposes
     #[cfg(test)]
@@ -231,15 +259,18 @@ impl GpsQzssFrame1 {
         }
     }
 
-    /// Calculates nominal User Range Accuracy in meters
+    /// Calculates nominal User Range Accuracy in meters, from the URA index N (IS-GPS-200 20.3.3.3.1.3).
+    /// - N <= 6: 2**(1+N/2), rounded to 2.8, 5.7 and 11.3 for N=1, 3 and 5
+    /// - 6 < N < 15: 2**(N-2)
+    /// - N = 15: no accuracy prediction is available, returns [f64::NAN].
     pub fn nominal_user_range_accuracy(&self) -> f64 {
-        // For each URA index, users may compute a nominal URA value (x)
-        //  - ura < 6: 2**(1+N/2)
-        //  - ura > 6: 2**(N-2)
-        if self.ura <= 6 {
-            2.0_f64.powi((1 + self.ura / 2) as i32)
-        } else {
-            2.0_f64.powi((self.ura / 2) as i32)
+        match self.ura {
+            1 => 2.8,
+            3 => 5.7,
+            5 => 11.3,
+            0..=6 => 2.0_f64.powi(1 + self.ura as i32 / 2),
+            7..=14 => 2.0_f64.powi(self.ura as i32 - 2),
+            _ => f64::NAN,
         }
     }
 
@@ -249,6 +280,13 @@ impl GpsQzssFrame1 {
         self
     }
 
+    /// Returns the full GPS week number, resolving the 1024-week rollovers
+    /// of our 10-bit week counter with this reference GPS week.
+    /// See [reconstruct_full_week].
+    pub fn full_week(&self, reference_gps_week: u16) -> u16 {
+        reconstruct_full_week(self.week, reference_gps_week)
+    }
+
     /// Copies and returns [GpsQzssFrame1] with updated 10-bit IODC mask
     pub fn with_iodc(mut self, iodc: u16) -> Self {
         self.iodc = iodc & 0x3ff;
@@ -296,6 +334,18 @@ impl GpsQzssFrame1 {
         self.health == 0
     }
 
+    /// Returns true if [GpsQzssFrame1] indicates the L1 C/A signal may be used,
+    /// interpreting the health bits as specified for this [GpsQzssConstellation]:
+    /// - GPS: all signals must be marked OK, like [Self::healthy].
+    /// - QZSS: health is a per-signal bitmask and the MSB describes the L1 C/A signal.
+    ///   The other signals do not affect the L1 C/A navigation.
+    pub fn is_healthy(&self, constellation: GpsQzssConstellation) -> bool {
+        match constellation {
+            GpsQzssConstellation::Gps => self.healthy(),
+            GpsQzssConstellation::Qzss => self.health & 0x20 == 0,
+        }
+    }
+
     /// Returns true if [GpsQzssFrame1] indicates this satellite is temporarily
     /// out of service
     pub fn unavailable(&self) -> bool {
@@ -372,6 +422,11 @@ impl GpsQzssFrame1 {
         self
     }
 
+    /// Returns Total Group Delay (TGD) in nanoseconds
+    pub fn total_group_delay_nanos(&self) -> f64 {
+        self.tgd * 1e9
+    }
+
     /// Copies and returns [GpsQzssFrame1] with updated User Range Accuracy
     /// in meters.
     pub fn with_user_range_accuracy_m(mut self, ura_m: f64) -> Self {
@@ -386,18 +441,11 @@ impl GpsQzssFrame1 {
     }
 
     /// Copies and returns [GpsQzssFrame1] with updated User Range Accuracy
-    /// from a nominal User Range Accuracy in meters.
+    /// from a nominal User Range Accuracy in meters (see [Self::nominal_user_range_accuracy]).
+    /// Each nominal value lies within the accuracy range of its index, so this is the exact
+    /// inverse of [Self::nominal_user_range_accuracy], and agrees with [Self::with_user_range_accuracy_m].
     pub fn with_nominal_user_range_accuracy_m(mut self, ura_m: f64) -> Self {
-        // For each URA index, users may compute a nominal URA value (x)
-        //  - ura < 6: 2**(1+N/2)
-        //  - ura > 6: 2**(N-2)
-        let ura = if ura_m <= 24.0 {
-            2.0 * (ura_m.log2() - 1.0)
-        } else {
-            2.0 + ura_m.log2()
-        };
-
-        self.ura = ura.round() as u8;
+        self.ura = Self::compute_ura(ura_m);
         self
     }
 
@@ -425,6 +473,31 @@ impl GpsQzssFrame1 {
         self
     }
 
+    /// Returns the satellite clock correction (in seconds) at this GPS time of week (in seconds),
+    /// evaluating the af0 + af1 (t - toc) + af2 (t - toc)² polynomial.
+    /// Beginning and end of week crossovers are taken care of.
+    /// The relativistic term is not included, refer to [Self::clock_correction_with_relativity_seconds],
+    /// nor the group delay, which only applies to L1 single frequency users.
+    pub fn clock_correction_seconds(&self, t_gps_seconds: f64) -> f64 {
+        let dt = time_of_week_difference(t_gps_seconds, self.toc as f64);
+        self.af0 + self.af1 * dt + self.af2 * dt * dt
+    }
+
+    /// Returns the satellite clock correction (in seconds) at this GPS time of week (in seconds),
+    /// like [Self::clock_correction_seconds], with the relativistic term (F e √A sin(E))
+    /// deduced from the orbit described by this [GpsQzssFrame2].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn clock_correction_with_relativity_seconds(
+        &self,
+        t_gps_seconds: f64,
+        frame2: &GpsQzssFrame2,
+    ) -> f64 {
+        let ek = frame2.eccentric_anomaly_radians(t_gps_seconds);
+        let relativistic = RELATIVISTIC_F * frame2.e * frame2.sqrt_a * ek.sin();
+        self.clock_correction_seconds(t_gps_seconds) + relativistic
+    }
+
     /// Decodes [Self] from 8 [GpsDataWord]s.
     /// This method does not care for frames parity.
     pub(crate) fn from_words(words: &[GpsDataWord]) -> Self {
@@ -515,7 +588,9 @@ impl GpsQzssFrame1 {
     fn word7(&self) -> Word7 {
         Word7 {
             reserved: self.reserved_word7,
-            tgd: (self.tgd * 2.0_f64.powi(31)).round() as i8,
+            tgd: (self.tgd * 2.0_f64.powi(31))
+                .round()
+                .clamp(i8::MIN as f64, i8::MAX as f64) as i8,
         }
     }
 
@@ -555,7 +630,9 @@ impl GpsQzssFrame1 {
     /// Encodes a [Word10] from [GpsQzssFrame1]
     fn word10(&self) -> Word10 {
         Word10 {
-            af0: (self.af0 * 2.0_f64.powi(31)).round() as i32,
+            af0: (self.af0 * 2.0_f64.powi(31))
+                .round()
+                .clamp(-(1 << 21) as f64, ((1 << 21) - 1) as f64) as i32,
         }
     }
 
@@ -614,17 +691,16 @@ impl Word3 {
 
     /// Encodes this [Word3] as [GpsDataWord].
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
+        let mut bytes = [0u8; 4];
+        let mut writer = BitWriter::new(&mut bytes, 0);
 
-        value |= ((self.week & 0x3ff) as u32) << WORD3_WEEK_SHIFT;
-        value |= ((self.ca_or_p_l2 & 0x3) as u32) << WORD3_CA_P_L2_SHIFT;
-        value |= ((self.ura & 0x07) as u32) << WORD3_URA_SHIFT;
-        value |= ((self.health & 0x3f) as u32) << WORD3_HEALTH_SHIFT;
-        value |= ((self.iodc_msb & 0x03) as u32) << WORD3_IODC_SHIFT;
+        writer.write_u32(self.week as u32, 10);
+        writer.write_u32(self.ca_or_p_l2 as u32, 2);
+        writer.write_u32(self.ura as u32, 4);
+        writer.write_u32(self.health as u32, 6);
+        writer.write_u32(self.iodc_msb as u32, 2);
 
-        value <<= 2;
-
-        GpsDataWord::from(value)
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -649,16 +725,13 @@ impl Word4 {
 
     /// Encodes this [Word4] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
+        let mut bytes = [0u8; 4];
+        let mut writer = BitWriter::new(&mut bytes, 0);
 
-        if self.l2_p_data_flag {
-            value |= WORD4_L2P_DATA_MASK;
-        }
+        writer.write_u32(self.l2_p_data_flag as u32, 1);
+        writer.write_u32(self.reserved, 23);
 
-        value |= (self.reserved & 0x7fffff) << WORD4_RESERVED_SHIFT;
-        value <<= 2;
-
-        GpsDataWord::from(value)
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -677,10 +750,9 @@ impl Word5 {
 
     /// Encodes this [Word5] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD5_RESERVED_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        BitWriter::new(&mut bytes, 0).write_u32(self.reserved, 24);
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -699,10 +771,9 @@ impl Word6 {
 
     /// Encodes this [Word6] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD6_RESERVED_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        BitWriter::new(&mut bytes, 0).write_u32(self.reserved, 24);
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -726,11 +797,13 @@ impl Word7 {
 
     /// Encodes this [Word7] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.reserved as u32) & 0x0ffff) << WORD7_RESERVED_SHIFT;
-        value |= ((self.tgd as u32) & 0xff) << WORD7_TGD_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        let mut writer = BitWriter::new(&mut bytes, 0);
+
+        writer.write_u32(self.reserved as u32, 16);
+        writer.write_i32(self.tgd as i32, 8);
+
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -754,11 +827,13 @@ impl Word8 {
 
     /// Encodes this [Word8] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.iodc_lsb as u32) & 0xff) << WORD8_IODC_SHIFT;
-        value |= ((self.toc as u32) & 0x0ffff) << WORD8_TOC_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        let mut writer = BitWriter::new(&mut bytes, 0);
+
+        writer.write_u32(self.iodc_lsb as u32, 8);
+        writer.write_u32(self.toc as u32, 16);
+
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -782,11 +857,13 @@ impl Word9 {
 
     /// Encodes this [Word9] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.af2 as u32) & 0x0ff) << WORD9_AF2_SHIFT;
-        value |= ((self.af1 as u32) & 0x0ffff) << WORD9_AF1_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        let mut writer = BitWriter::new(&mut bytes, 0);
+
+        writer.write_i32(self.af2 as i32, 8);
+        writer.write_i32(self.af1 as i32, 16);
+
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -806,9 +883,9 @@ impl Word10 {
 
     /// Encodes this [Word10] as [GpsDataWord]
     pub fn to_word(&self) -> GpsDataWord {
-        let mut value = ((self.af0 & 0x3fffff) as u32) << WORD10_AF0_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        let mut bytes = [0u8; 4];
+        BitWriter::new(&mut bytes, 0).write_i32(self.af0, 22);
+        GpsDataWord::from_be_bytes(&bytes)
     }
 }
 
@@ -861,6 +938,13 @@ mod frame1 {
                 health: 1,
                 iodc_msb: 0,
             },
+            Word3 {
+                week: 1023,
+                ca_or_p_l2: 3,
+                ura: 12,
+                health: 0x3f,
+                iodc_msb: 3,
+            },
         ] {
             let gps_word = dword3.to_word();
             let decoded = Word3::from_word(gps_word);
@@ -986,7 +1070,19 @@ mod frame1 {
 
     #[test]
     fn dword9() {
-        for dword9 in [Word9 { af2: 10, af1: 9 }, Word9 { af2: 9, af1: 100 }] {
+        for dword9 in [
+            Word9 { af2: 10, af1: 9 },
+            Word9 { af2: 9, af1: 100 },
+            Word9 { af2: -1, af1: -1 },
+            Word9 {
+                af2: i8::MIN,
+                af1: i16::MIN,
+            },
+            Word9 {
+                af2: i8::MAX,
+                af1: i16::MAX,
+            },
+        ] {
             let gps_word = dword9.to_word();
             let decoded = Word9::from_word(gps_word);
             assert_eq!(decoded, dword9);
@@ -1000,6 +1096,8 @@ mod frame1 {
             Word10 { af0: 0 },
             Word10 { af0: 100 },
             Word10 { af0: -1230 },
+            Word10 { af0: -(1 << 21) },
+            Word10 { af0: (1 << 21) - 1 },
             Word10 { af0: -3140 },
         ] {
             let gps_word = dword10.to_word();
@@ -1009,6 +1107,36 @@ mod frame1 {
         }
     }
 
+    #[test]
+    fn total_group_delay() {
+        let lsb = 2.0_f64.powi(-31);
+
+        for raw in [-128, -127, -1, 0, 1, 23, 127] {
+            let tgd = raw as f64 * lsb;
+            let frame1 = GpsQzssFrame1::default().with_total_group_delay_seconds(tgd);
+
+            let decoded = GpsQzssFrame1::from_words(&frame1.to_words());
+            assert_eq!(decoded.tgd, tgd, "failed for {} LSB", raw);
+            assert_eq!(decoded.total_group_delay_nanos(), tgd * 1e9);
+        }
+
+        // saturates, does not wrap
+        for (tgd, raw) in [
+            (128.0 * lsb, 127),
+            (1.0e-6, 127),
+            (-129.0 * lsb, -128),
+            (-1.0e-6, -128),
+        ] {
+            let frame1 = GpsQzssFrame1::default().with_total_group_delay_seconds(tgd);
+
+            let decoded = GpsQzssFrame1::from_words(&frame1.to_words());
+            assert_eq!(decoded.tgd, raw as f64 * lsb, "failed for {}", tgd);
+        }
+
+        let frame1 = GpsQzssFrame1::default().with_total_group_delay_nanos(-10.0);
+        assert!((frame1.total_group_delay_nanos() + 10.0).abs() < 1e-9);
+    }
+
     #[test]
     fn encoding() {
         for (
@@ -1073,6 +1201,39 @@ mod frame1 {
         }
     }
 
+    #[test]
+    fn signed_values() {
+        for (tgd, af0, af1, af2) in [
+            (-4.0E-9, -4.0E-9, -4.0E-12, -2.0E-15),
+            (-1.0E-9, 4.0E-9, -2.0E-12, 1.0E-15),
+            (5.0E-9, -0.5E-3, 2.0E-12, -1.0E-15),
+        ] {
+            let frame1 = GpsQzssFrame1 {
+                tgd,
+                af0,
+                af1,
+                af2,
+                ..Default::default()
+            };
+
+            let decoded = GpsQzssFrame1::from_words(&frame1.to_words());
+
+            assert!((decoded.tgd - tgd).abs() < 1E-9);
+            assert!((decoded.af0 - af0).abs() < 1E-9);
+            assert!((decoded.af1 - af1).abs() < 1E-13);
+            assert!((decoded.af2 - af2).abs() < 1E-16);
+        }
+
+        // af0 saturates within its 22-bit field
+        let frame1 = GpsQzssFrame1 {
+            af0: -1.0,
+            ..Default::default()
+        };
+
+        let decoded = GpsQzssFrame1::from_words(&frame1.to_words());
+        assert_eq!(decoded.af0, -(1 << 21) as f64 * 2.0_f64.powi(-31));
+    }
+
     #[test]
     fn user_range_accuracy() {
         for (value_m, encoded_ura) in [
@@ -1090,6 +1251,10 @@ mod frame1 {
             (3072.0, 13),
             (3072.1, 14),
             (4000.1, 14),
+            (24.0, 6),
+            (24.1, 7),
+            (6144.0, 14),
+            (6144.1, 15),
         ] {
             let ura = GpsQzssFrame1::compute_ura(value_m);
             assert_eq!(ura, encoded_ura, "encoded incorrect URA from {}m", value_m);
@@ -1101,11 +1266,167 @@ mod frame1 {
             let mut expected = GpsQzssFrame1::default();
             expected.ura = encoded_ura;
 
-            // assert_eq!(
-            //     frame1.with_nominal_user_range_accuracy_m(value_m).ura,
-            //     encoded_ura,
-            //     "failed for value={}m, encoded={}", value_m, encoded_ura,
-            // );
+            assert_eq!(
+                frame1.with_nominal_user_range_accuracy_m(value_m).ura,
+                encoded_ura,
+                "failed for value={}m, encoded={}",
+                value_m,
+                encoded_ura,
+            );
+        }
+    }
+
+    #[test]
+    fn nominal_user_range_accuracy() {
+        for (ura, nominal_m) in [
+            (0, 2.0),
+            (1, 2.8),
+            (2, 4.0),
+            (3, 5.7),
+            (4, 8.0),
+            (5, 11.3),
+            (6, 16.0),
+            (7, 32.0),
+            (8, 64.0),
+            (9, 128.0),
+            (10, 256.0),
+            (11, 512.0),
+            (12, 1024.0),
+            (13, 2048.0),
+            (14, 4096.0),
+        ] {
+            let frame1 = GpsQzssFrame1 {
+                ura,
+                ..Default::default()
+            };
+
+            assert_eq!(
+                frame1.nominal_user_range_accuracy(),
+                nominal_m,
+                "invalid nominal value for URA={}",
+                ura
+            );
+
+            // nominal value lies within the URA range
+            assert_eq!(GpsQzssFrame1::compute_ura(nominal_m), ura);
+
+            let frame1 = GpsQzssFrame1::default().with_nominal_user_range_accuracy_m(nominal_m);
+            assert_eq!(frame1.ura, ura, "inverse failed for URA={}", ura);
+        }
+
+        let frame1 = GpsQzssFrame1 {
+            ura: 15,
+            ..Default::default()
+        };
+
+        assert!(frame1.nominal_user_range_accuracy().is_nan());
+    }
+
+    #[test]
+    fn clock_correction() {
+        let af0 = 1.0e-4;
+        let af1 = -2.0e-11;
+        let af2 = 1.0e-18;
+
+        for (toc, t, dt) in [
+            (388_800, 388_800.0, 0.0),
+            (388_800, 392_400.0, 3_600.0),
+            (388_800, 381_600.0, -7_200.0),
+            // beginning of week crossover
+            (604_000, 400.0, 1_200.0),
+            // end of week crossover
+            (0, 604_000.0, -800.0),
+        ] {
+            let frame1 = GpsQzssFrame1::default()
+                .with_time_of_clock_seconds(toc)
+                .with_clock_offset_seconds(af0)
+                .with_clock_drift_seconds_s(af1)
+                .with_clock_drift_rate_seconds_s2(af2);
+
+            let expected = af0 + af1 * dt + af2 * dt * dt;
+            let correction = frame1.clock_correction_seconds(t);
+
+            assert!(
+                (correction - expected).abs() < 1e-15,
+                "toc={} t={}: got {}, expecting {}",
+                toc,
+                t,
+                correction,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn clock_correction_relativity() {
+        use crate::gps::GpsQzssFrame2;
+
+        let frame1 = GpsQzssFrame1::default()
+            .with_time_of_clock_seconds(345_600)
+            .with_clock_offset_seconds(1.0e-4);
+
+        let frame2 = GpsQzssFrame2::default()
+            .with_toe_seconds(345_600)
+            .with_mean_anomaly_semicircles(0.5)
+            .with_eccentricity(0.01)
+            .with_square_root_semi_major_axis(5153.6);
+
+        for (t, relativistic) in [
+            (345_600.0, -2.289530871876318e-08),
+            (347_400.0, -2.205356791238365e-08),
+        ] {
+            let correction = frame1.clock_correction_with_relativity_seconds(t, &frame2);
+            let expected = frame1.clock_correction_seconds(t) + relativistic;
+            assert!((correction - expected).abs() < 1e-15, "t={}", t);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let display = GpsQzssFrame1::model().to_string();
+
+        assert!(!display.contains('\n'), "not compact: {}", display);
+
+        for label in [
+            "WEEK=", "IODC=", "TOC=", "HEALTH=", "URA=", "TGD=", "AF0=", "AF1=", "AF2=",
+        ] {
+            assert!(display.contains(label), "missing {} in {}", label, display);
+        }
+    }
+
+    #[test]
+    fn full_week() {
+        // 2019 rollover: week 2047 was broadcast as 1023, week 2048 as 0
+        let frame1 = GpsQzssFrame1::default().with_week(2047);
+        assert_eq!(frame1.week, 1023);
+        assert_eq!(frame1.full_week(2048), 2047);
+
+        let frame1 = GpsQzssFrame1::default().with_week(2048);
+        assert_eq!(frame1.week, 0);
+        assert_eq!(frame1.full_week(2047), 2048);
+        assert_eq!(frame1.full_week(1023), 1024);
+    }
+
+    #[test]
+    fn constellation_health() {
+        let frame1 = GpsQzssFrame1::default().with_all_signals_ok();
+
+        assert!(frame1.is_healthy(GpsQzssConstellation::Gps));
+        assert!(frame1.is_healthy(GpsQzssConstellation::Qzss));
+
+        // QZSS: L2C / L5 flags do not affect L1 C/A
+        for (health, gps, qzss) in [
+            (0x10, false, true),
+            (0x01, false, true),
+            (0x20, false, false),
+        ] {
+            let frame1 = GpsQzssFrame1 {
+                health,
+                ..Default::default()
+            };
+
+            assert_eq!(frame1.is_healthy(GpsQzssConstellation::Gps), gps);
+            assert_eq!(frame1.is_healthy(GpsQzssConstellation::Qzss), qzss);
         }
     }
 }
@@ -1,10 +1,31 @@
 use crate::{
-    gps::{rad_to_semicircles, GpsDataByte, GpsDataWord, GpsError, GPS_WORDS_PER_FRAME},
+    gps::{
+        rad_to_semicircles, GpsDataByte, GpsDataWord, GpsError, GpsQzssConstellation,
+        GPS_WORDS_PER_FRAME,
+    },
     twos_complement,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
 use core::f64::consts::PI;
 
+/// WGS-84 Earth universal gravitational constant (in m³.s⁻²)
+#[cfg(feature = "std")]
+const GM_M3_S2: f64 = 3.986005e14;
+
+/// Maximal number of iterations when solving Kepler's equation
+#[cfg(feature = "std")]
+const KEPLER_MAX_ITER: usize = 30;
+
+/// Eccentric anomaly convergence criteria (in radians)
+#[cfg(feature = "std")]
+const KEPLER_TOLERANCE_RAD: f64 = 1.0e-14;
+
 const WORD3_IODE_MASK: u32 = 0x3fc00000;
 const WORD3_IODE_SHIFT: u32 = 22;
 const WORD3_CRS_MASK: u32 = 0x003fffc0;
@@ -42,6 +63,7 @@ const WORD10_AODO_SHIFT: u32 = 8;
 
 /// [GpsQzssFrame2] Ephemeris #2 frame interpretation.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssFrame2 {
     /// Time of issue of ephemeris (in seconds of week)
     /// at instant of transmission of the next MSB.
@@ -66,10 +88,14 @@ pub struct GpsQzssFrame2 {
     /// Orbit radius (sine harmonic) in meters.
     pub crs: f64,
 
-    /// Orbit eccentricity.
+    /// Orbit eccentricity, 32-bit unsigned with 2^-33 LSB.
+    /// Encodable range is therefore [0; 0.5[ (operational values remain below 0.03),
+    /// values outside this range saturate when encoded.
     pub e: f64,
 
     /// Square root of semi-major axis, in square root of meters.
+    /// 32-bit unsigned with 2^-19 LSB: encodable range is [0; 8192[,
+    /// values outside this range saturate when encoded.
     pub sqrt_a: f64,
 
     /// Fit interval flag
@@ -129,6 +155,27 @@ impl PartialEq for GpsQzssFrame2 {
     }
 }
 
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssFrame2 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "IODE=0x{:02X} - TOE={}s - M0={:.9}sc - DN={:.6e}sc/s - E={:.9} - SQRT(A)={:.6}m^1/2 - CUC={:.6e}rad - CUS={:.6e}rad - CRS={:.3}m - FIT={} - AODO={}",
+            self.iode,
+            self.toe,
+            self.m0,
+            self.dn,
+            self.e,
+            self.sqrt_a,
+            self.cuc,
+            self.cus,
+            self.crs,
+            self.fit_int_flag,
+            self.aodo,
+        )
+    }
+}
+
 impl GpsQzssFrame2 {
     #[cfg(test)]
     pub fn model() -> Self {
@@ -181,7 +228,97 @@ impl GpsQzssFrame2 {
         self.with_mean_motion_difference_semicircles(rad_to_semicircles(dn_rad))
     }
 
+    /// Returns the mean anomaly at reference time (in radians)
+    pub fn mean_anomaly_radians(&self) -> f64 {
+        self.m0 * PI
+    }
+
+    /// Returns the mean motion difference from computed value (in radians)
+    pub fn mean_motion_difference_radians(&self) -> f64 {
+        self.dn * PI
+    }
+
+    /// Returns the semi-major axis (in meters)
+    pub fn semi_major_axis_meters(&self) -> f64 {
+        self.sqrt_a * self.sqrt_a
+    }
+
+    /// Returns the curve fit interval (in hours), which depends on the fit interval flag,
+    /// the [GpsQzssConstellation] and the IODC of the associated [GpsQzssFrame1](crate::gps::GpsQzssFrame1).
+    /// - GPS (IS-GPS-200 20.3.4.4): 4 hours when the flag is deasserted,
+    ///   6 hours or more (depending on the IODC) otherwise.
+    /// - QZSS (IS-QZSS-PNT): 2 hours when the flag is deasserted. [None] otherwise,
+    ///   because the interval is only specified as greater than 2 hours.
+    pub fn fit_interval_hours(
+        &self,
+        constellation: GpsQzssConstellation,
+        iodc: u16,
+    ) -> Option<f64> {
+        match (constellation, self.fit_int_flag) {
+            (GpsQzssConstellation::Gps, false) => Some(4.0),
+            (GpsQzssConstellation::Gps, true) => match iodc {
+                240..=247 => Some(8.0),
+                248..=255 | 496 => Some(14.0),
+                497..=503 | 1021..=1023 => Some(26.0),
+                _ => Some(6.0),
+            },
+            (GpsQzssConstellation::Qzss, false) => Some(2.0),
+            (GpsQzssConstellation::Qzss, true) => None,
+        }
+    }
+
+    /// Returns the computed mean motion `n0 = sqrt(mu/a³)` (in radians.s⁻¹)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn computed_mean_motion_rad_s(&self) -> f64 {
+        (GM_M3_S2 / self.semi_major_axis_meters().powi(3)).sqrt()
+    }
+
+    /// Returns the corrected mean motion `n = n0 + dn` (in radians.s⁻¹)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn corrected_mean_motion_rad_s(&self) -> f64 {
+        self.computed_mean_motion_rad_s() + self.mean_motion_difference_radians()
+    }
+
+    /// Returns the orbital period (in seconds), deduced from the corrected mean motion
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn orbital_period_seconds(&self) -> f64 {
+        2.0 * PI / self.corrected_mean_motion_rad_s()
+    }
+
+    /// Returns the eccentric anomaly (in radians) at this GPS time of week (in seconds),
+    /// solving Kepler's equation as specified in IS-GPS-200.
+    /// Beginning and end of week crossovers are taken care of.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn eccentric_anomaly_radians(&self, t_gps_seconds: f64) -> f64 {
+        let tk = crate::gps::time_of_week_difference(t_gps_seconds, self.toe as f64);
+
+        // corrected mean motion and mean anomaly
+        let n = self.corrected_mean_motion_rad_s();
+        let mk = self.mean_anomaly_radians() + n * tk;
+
+        let mut ek = mk;
+
+        for _ in 0..KEPLER_MAX_ITER {
+            let next = mk + self.e * ek.sin();
+            let converged = (next - ek).abs() < KEPLER_TOLERANCE_RAD;
+
+            ek = next;
+
+            if converged {
+                break;
+            }
+        }
+
+        ek
+    }
+
     /// Copies and returns [GpsQzssFrame2] with updated semi-major axis (in meters)
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
     pub fn with_semi_major_axis_meters(mut self, semi_major_m: f64) -> Self {
         self.sqrt_a = semi_major_m.sqrt();
         self
@@ -270,7 +407,7 @@ impl GpsQzssFrame2 {
     }
 
     fn word4(&self) -> Word4 {
-        let m0 = (self.m0 * 2.0_f64.powi(31)).round() as u32;
+        let m0 = (self.m0 * 2.0_f64.powi(31)).round() as i32 as u32;
         let dn = (self.dn * 2.0_f64.powi(43)).round() as i16;
         Word4 {
             dn,
@@ -297,8 +434,15 @@ impl GpsQzssFrame2 {
         self.m0 = ((m0 as i32) as f64) / 2.0_f64.powi(31);
     }
 
+    /// Returns the 32-bit scaled eccentricity field, saturated to the field capacity.
+    pub(crate) fn e_bits(&self) -> u32 {
+        (self.e * 2.0_f64.powi(33))
+            .round()
+            .clamp(0.0, u32::MAX as f64) as u32
+    }
+
     fn word6(&self) -> Word6 {
-        let e = (self.e * 2.0_f64.powi(33)).round() as u32;
+        let e = self.e_bits();
 
         Word6 {
             e_msb: ((e & 0xff000000) >> 24) as u8,
@@ -312,9 +456,9 @@ impl GpsQzssFrame2 {
     }
 
     fn word7(&self) -> Word7 {
-        let e = (self.e * 2.0_f64.powi(33)).round() as i32;
+        let e = self.e_bits();
         Word7 {
-            e_lsb: (e & 0x00ffffff) as u32,
+            e_lsb: e & 0x00ffffff,
         }
     }
 
@@ -325,8 +469,15 @@ impl GpsQzssFrame2 {
         self.e = (e as f64) / 2.0_f64.powi(33);
     }
 
+    /// Returns the 32-bit scaled √A field, saturated to the field capacity.
+    pub(crate) fn sqrt_a_bits(&self) -> u32 {
+        (self.sqrt_a * 2.0_f64.powi(19))
+            .round()
+            .clamp(0.0, u32::MAX as f64) as u32
+    }
+
     fn word8(&self) -> Word8 {
-        let sqrt_a = (self.sqrt_a * 2.0_f64.powi(19)).round() as u32;
+        let sqrt_a = self.sqrt_a_bits();
 
         Word8 {
             sqrt_a_msb: ((sqrt_a & 0xff000000) >> 24) as u8,
@@ -340,7 +491,7 @@ impl GpsQzssFrame2 {
     }
 
     fn word9(&self) -> Word9 {
-        let sqrt_a = (self.sqrt_a * 2.0_f64.powi(19)).round() as u32;
+        let sqrt_a = self.sqrt_a_bits();
         Word9 {
             sqrt_a_lsb: sqrt_a & 0x00ffffff,
         }
@@ -431,7 +582,7 @@ impl Word4 {
 
     pub fn to_word(&self) -> GpsDataWord {
         let mut value = 0;
-        value |= (self.dn as u32) << WORD4_DELTA_N_SHIFT;
+        value |= ((self.dn as u32) & 0xffff) << WORD4_DELTA_N_SHIFT;
         value |= (self.m0_msb as u32) << WORD4_M0_MSB_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
@@ -477,7 +628,7 @@ impl Word6 {
 
     pub fn to_word(&self) -> GpsDataWord {
         let mut value = 0;
-        value |= (self.cuc as u32) << WORD6_CUC_SHIFT;
+        value |= ((self.cuc as u32) & 0xffff) << WORD6_CUC_SHIFT;
         value |= (self.e_msb as u32) << WORD6_E_MSB_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
@@ -525,7 +676,7 @@ impl Word8 {
     pub fn to_word(&self) -> GpsDataWord {
         let mut value = 0;
         value |= (self.sqrt_a_msb as u32) << WORD8_SQRTA_MSB_SHIFT;
-        value |= (self.cus as u32) << WORD8_CUS_SHIFT;
+        value |= ((self.cus as u32) & 0xffff) << WORD8_CUS_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
     }
@@ -589,6 +740,67 @@ impl Word10 {
 #[cfg(test)]
 mod frame2 {
     use super::*;
+    use crate::gps::{GpsQzssDecoder, GpsQzssFrame, GpsQzssSubframe};
+
+    #[test]
+    fn eccentricity_range() {
+        let lsb = 2.0_f64.powi(-33);
+
+        for e in [0.0, lsb, 0.00125, 0.03, 0.25, 0.5 - lsb] {
+            let frame2 = GpsQzssFrame2::default().with_eccentricity(e);
+
+            let decoded = GpsQzssFrame2::from_words(&frame2.to_words());
+            assert!((decoded.e - e).abs() <= lsb / 2.0, "failed for e={}", e);
+        }
+
+        // saturates, does not wrap
+        for (e, expected) in [(0.5, 0.5 - lsb), (1.5, 0.5 - lsb), (-0.01, 0.0)] {
+            let frame2 = GpsQzssFrame2::default().with_eccentricity(e);
+
+            let decoded = GpsQzssFrame2::from_words(&frame2.to_words());
+            assert_eq!(decoded.e, expected, "failed for e={}", e);
+
+            let encoded = GpsQzssFrame::default()
+                .with_subframe(GpsQzssSubframe::Ephemeris2(frame2))
+                .encode_raw();
+
+            let decoded = GpsQzssDecoder::default()
+                .decode(&encoded, encoded.len())
+                .1
+                .unwrap();
+
+            match decoded.subframe {
+                GpsQzssSubframe::Ephemeris2(decoded) => {
+                    assert_eq!(decoded.e, expected, "failed for e={}", e)
+                },
+                _ => panic!("incorrect subframe decoded"),
+            }
+        }
+    }
+
+    #[test]
+    fn square_root_semi_major_axis_range() {
+        let lsb = 2.0_f64.powi(-19);
+
+        for sqrt_a in [0.0, lsb, 5153.6, 6493.2, 8192.0 - lsb] {
+            let frame2 = GpsQzssFrame2::default().with_square_root_semi_major_axis(sqrt_a);
+
+            let decoded = GpsQzssFrame2::from_words(&frame2.to_words());
+            assert!(
+                (decoded.sqrt_a - sqrt_a).abs() <= lsb / 2.0,
+                "failed for sqrt_a={}",
+                sqrt_a
+            );
+        }
+
+        // saturates, does not wrap
+        for (sqrt_a, expected) in [(8192.0, 8192.0 - lsb), (1.0e5, 8192.0 - lsb), (-1.0, 0.0)] {
+            let frame2 = GpsQzssFrame2::default().with_square_root_semi_major_axis(sqrt_a);
+
+            let decoded = GpsQzssFrame2::from_words(&frame2.to_words());
+            assert_eq!(decoded.sqrt_a, expected, "failed for sqrt_a={}", sqrt_a);
+        }
+    }
 
     #[test]
     fn dword3() {
@@ -813,4 +1025,126 @@ mod frame2 {
             assert_eq!(decoded, frame2);
         }
     }
+
+    #[test]
+    fn large_sqrt_a() {
+        for (sqrt_a, cus) in [
+            (5153.6, -2.8e-6),
+            (8191.999, -3.0e-6),
+            (8192.0, -1.0e-6),
+            (8192.0, 1.0e-6),
+        ] {
+            let frame2 = GpsQzssFrame2 {
+                sqrt_a,
+                cus,
+                ..GpsQzssFrame2::model()
+            };
+
+            let decoded = GpsQzssFrame2::from_words(&frame2.to_words());
+            assert!((decoded.sqrt_a - sqrt_a).abs() < 1e-5, "{}", decoded.sqrt_a);
+            assert!((decoded.cus - cus).abs() < 1e-9, "{}", decoded.cus);
+
+            let frame = GpsQzssFrame::default().with_subframe(GpsQzssSubframe::Ephemeris2(frame2));
+            let encoded = frame.encode_raw();
+
+            let (_, decoded) = GpsQzssDecoder::default().decode(&encoded, encoded.len());
+            let decoded = decoded.unwrap_or_else(|| panic!("failed to decode sqrt_a={}", sqrt_a));
+
+            match decoded.subframe {
+                GpsQzssSubframe::Ephemeris2(decoded) => {
+                    assert!((decoded.sqrt_a - sqrt_a).abs() < 1e-5, "{}", decoded.sqrt_a);
+                    assert!((decoded.cus - cus).abs() < 1e-9, "{}", decoded.cus);
+                },
+                _ => panic!("incorrect subframe decoded"),
+            }
+        }
+
+        // saturates to the 32-bit field capacity
+        let frame2 = GpsQzssFrame2::model().with_square_root_semi_major_axis(10_000.0);
+        assert_eq!(frame2.sqrt_a_bits(), u32::MAX);
+    }
+
+    #[test]
+    fn si_units() {
+        let frame2 = GpsQzssFrame2::default()
+            .with_mean_anomaly_semicircles(0.5)
+            .with_mean_motion_difference_semicircles(1.5e-9)
+            .with_square_root_semi_major_axis(5153.6);
+
+        assert!((frame2.mean_anomaly_radians() - PI / 2.0).abs() < 1e-12);
+        assert!((frame2.mean_motion_difference_radians() - 4.71238898e-9).abs() < 1e-17);
+        assert!((frame2.semi_major_axis_meters() - 26_559_592.96).abs() < 1e-6);
+
+        // reciprocal
+        let frame2 = GpsQzssFrame2::default()
+            .with_mean_anomaly_radians(-PI / 4.0)
+            .with_mean_motion_difference_radians(4.71238898e-9);
+
+        assert!((frame2.m0 + 0.25).abs() < 1e-12);
+        assert!((frame2.mean_anomaly_radians() + PI / 4.0).abs() < 1e-12);
+        assert!((frame2.dn - 1.5e-9).abs() < 1e-17);
+    }
+
+    #[test]
+    fn mean_motion() {
+        // nominal MEO ephemeris
+        let frame2 = GpsQzssFrame2::default()
+            .with_mean_motion_difference_semicircles(1.5e-9)
+            .with_square_root_semi_major_axis(5153.6);
+
+        let n0 = frame2.computed_mean_motion_rad_s();
+        assert!((n0 - 1.458_6e-4).abs() < 1e-8, "n0={}", n0);
+
+        let n = frame2.corrected_mean_motion_rad_s();
+        assert!((n - n0 - frame2.mean_motion_difference_radians()).abs() < 1e-18);
+
+        let period = frame2.orbital_period_seconds();
+        assert!((period - 43_080.0).abs() < 10.0, "period={}", period);
+
+        // uncorrected
+        let frame2 = frame2.with_mean_motion_difference_semicircles(0.0);
+        assert!((frame2.orbital_period_seconds() - 43_077.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn display() {
+        let display = GpsQzssFrame2::model().to_string();
+
+        assert!(!display.contains('\n'), "not compact: {}", display);
+
+        for label in [
+            "IODE=", "TOE=", "M0=", "DN=", "E=", "SQRT(A)=", "CUC=", "CUS=", "CRS=", "FIT=",
+            "AODO=",
+        ] {
+            assert!(display.contains(label), "missing {} in {}", label, display);
+        }
+    }
+
+    #[test]
+    fn fit_interval() {
+        let frame2 = GpsQzssFrame2::default();
+
+        assert_eq!(
+            frame2.fit_interval_hours(GpsQzssConstellation::Gps, 0),
+            Some(4.0)
+        );
+        assert_eq!(
+            frame2.fit_interval_hours(GpsQzssConstellation::Qzss, 0),
+            Some(2.0)
+        );
+
+        let frame2 = frame2.with_fit_interval_flag();
+
+        for (iodc, hours) in [(0, 6.0), (240, 8.0), (250, 14.0), (496, 14.0), (1022, 26.0)] {
+            assert_eq!(
+                frame2.fit_interval_hours(GpsQzssConstellation::Gps, iodc),
+                Some(hours)
+            );
+        }
+
+        assert_eq!(
+            frame2.fit_interval_hours(GpsQzssConstellation::Qzss, 240),
+            None
+        );
+    }
 }
@@ -3,40 +3,35 @@ use crate::{
     twos_complement,
 };
 
-const WORD3_CIC_MASK: u32 = 0x3fffc000;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
 const WORD3_CIC_SHIFT: u32 = 14;
-const WORD3_OMEGA0_MASK: u32 = 0x00003fc0;
 const WORD3_OMEGA0_SHIFT: u32 = 6;
 
-const WORD4_OMEGA0_MASK: u32 = 0x3fffffc0;
 const WORD4_OMEGA0_SHIFT: u32 = 6;
 
-const WORD5_CIS_MASK: u32 = 0x3fffc000;
 const WORD5_CIS_SHIFT: u32 = 14;
-const WORD5_I0_MASK: u32 = 0x00003fc0;
 const WORD5_I0_SHIFT: u32 = 6;
 
-const WORD6_I0_MASK: u32 = 0x3fffffc0;
 const WORD6_I0_SHIFT: u32 = 6;
 
-const WORD7_CRC_MASK: u32 = 0x3fffc000;
 const WORD7_CRC_SHIFT: u32 = 14;
-const WORD7_OMEGA_MASK: u32 = 0x00003fc0;
 const WORD7_OMEGA_SHIFT: u32 = 6;
 
-const WORD8_OMEGA_MASK: u32 = 0x3fffffc0;
 const WORD8_OMEGA_SHIFT: u32 = 6;
 
-const WORD9_OMEGADOT_MASK: u32 = 0x3fffffc0;
 const WORD9_OMEGADOT_SHIFT: u32 = 6;
 
-const WORD10_IODE_MASK: u32 = 0x3fc00000;
 const WORD10_IODE_SHIFT: u32 = 22;
-const WORD10_IDOT_MASK: u32 = 0x003fff00;
 const WORD10_IDOT_SHIFT: u32 = 8;
 
 /// [GpsQzssFrame3] Ephemeris #3 frame interpretation.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssFrame3 {
     /// Inclination angle cosine harmonic in radians.
     pub cic: f64,
@@ -62,10 +57,31 @@ pub struct GpsQzssFrame3 {
     /// Omega (in semicircles)
     pub omega: f64,
 
-    /// Omega_dot (in semicircles.s⁻¹)
+    /// Omega_dot (in semicircles.s⁻¹).
+    /// Transmitted on 24 bits with 2^-43 scaling, which is finer than
+    /// the 16-bit 2^-38 scaling of the [GpsQzssAlmanach](crate::GpsQzssAlmanach).
     pub omega_dot: f64,
 }
 
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssFrame3 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "IODE=0x{:02X} - I0={:.9}sc - IDOT={:.6e}sc/s - OMEGA0={:.9}sc - OMEGA={:.9}sc - OMEGA_DOT={:.6e}sc/s - CIC={:.6e}rad - CIS={:.6e}rad - CRC={:.3}m",
+            self.iode,
+            self.i0,
+            self.idot,
+            self.omega0,
+            self.omega,
+            self.omega_dot,
+            self.cic,
+            self.cis,
+            self.crc,
+        )
+    }
+}
+
 impl GpsQzssFrame3 {
     #[cfg(test)]
     pub fn model() -> Self {
@@ -75,9 +91,9 @@ impl GpsQzssFrame3 {
             .with_crc_meters(122.0)
             .with_iode(0x12)
             .with_omega_semicircles(4e-1)
-            .with_omega_dot_semicircles_s(1e-3)
-            .with_inclination_semicircles(1e-3)
-            .with_inclination_rate_semicircles_s(1e-9)
+            .with_omega_dot_semicircles_s(-2.6e-9)
+            .with_inclination_semicircles(3e-1)
+            .with_inclination_rate_semicircles_s(1e-10)
             .with_longitude_ascending_node_semicircles(3e-1)
     }
 
@@ -189,7 +205,7 @@ impl GpsQzssFrame3 {
     }
 
     fn word3(&self) -> Word3 {
-        let omega0 = (self.omega0 * 2.0_f64.powi(31)).round() as u32;
+        let omega0 = (self.omega0 * 2.0_f64.powi(31)).round() as i32 as u32;
         Word3 {
             omega0_msb: ((omega0 & 0xff000000) >> 24) as u8,
             cic: (self.cic * 2.0_f64.powi(29)).round() as i16,
@@ -203,7 +219,7 @@ impl GpsQzssFrame3 {
     }
 
     fn word4(&self) -> Word4 {
-        let omega0 = (self.omega0 * 2.0_f64.powi(31)).round() as u32;
+        let omega0 = (self.omega0 * 2.0_f64.powi(31)).round() as i32 as u32;
         Word4 {
             omega0_lsb: (omega0 & 0x00ffffff) as u32,
         }
@@ -215,21 +231,21 @@ impl GpsQzssFrame3 {
     }
 
     fn word5(&self) -> Word5 {
-        let i0 = (self.i0 * 2.0_f64.powi(31)).round() as u32;
+        let i0 = (self.i0 * 2.0_f64.powi(31)).round() as i32 as u32;
         Word5 {
             i0_msb: ((i0 & 0xff000000) >> 24) as u8,
-            cis: (self.cis * 2.0_f64.powi(29)) as i32,
+            cis: (self.cis * 2.0_f64.powi(29)).round() as i32,
         }
     }
 
     fn set_word6(&mut self, word: Word6, i0_msb: u32) {
         let mut i0 = i0_msb << 24;
         i0 |= word.i0_lsb;
-        self.i0 = (i0 as f64) / 2.0_f64.powi(31);
+        self.i0 = ((i0 as i32) as f64) / 2.0_f64.powi(31);
     }
 
     fn word6(&self) -> Word6 {
-        let i0 = (self.i0 * 2.0_f64.powi(31)).round() as u32;
+        let i0 = (self.i0 * 2.0_f64.powi(31)).round() as i32 as u32;
         Word6 {
             i0_lsb: (i0 & 0x00ffffff) as u32,
         }
@@ -241,9 +257,9 @@ impl GpsQzssFrame3 {
     }
 
     fn word7(&self) -> Word7 {
-        let omega = (self.omega * 2.0_f64.powi(31)).round() as u32;
+        let omega = (self.omega * 2.0_f64.powi(31)).round() as i32 as u32;
         Word7 {
-            crc: (self.crc * 2.0_f64.powi(5)) as i32,
+            crc: (self.crc * 2.0_f64.powi(5)).round() as i32,
             omega_msb: ((omega & 0xff000000) >> 24) as u8,
         }
     }
@@ -255,7 +271,7 @@ impl GpsQzssFrame3 {
     }
 
     fn word8(&self) -> Word8 {
-        let omega = (self.omega * 2.0_f64.powi(31)).round() as u32;
+        let omega = (self.omega * 2.0_f64.powi(31)).round() as i32 as u32;
         Word8 {
             omega_lsb: (omega & 0x00ffffff) as u32,
         }
@@ -351,16 +367,14 @@ struct Word3 {
 
 impl Word3 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let cic = ((value & WORD3_CIC_MASK) >> WORD3_CIC_SHIFT) as u32;
-        let cic = twos_complement(cic, 0xffff, 0x8000) as i16;
-        let omega0_msb = ((value & WORD3_OMEGA0_MASK) >> WORD3_OMEGA0_SHIFT) as u8;
+        let cic = twos_complement(word.bits(1, 16), 0xffff, 0x8000) as i16;
+        let omega0_msb = word.bits(17, 8) as u8;
         Self { cic, omega0_msb }
     }
 
     pub fn to_word(&self) -> GpsDataWord {
         let mut value = 0;
-        value |= (self.cic as u32) << WORD3_CIC_SHIFT;
+        value |= ((self.cic as u32) & 0xffff) << WORD3_CIC_SHIFT;
         value |= (self.omega0_msb as u32) << WORD3_OMEGA0_SHIFT;
         value <<= 2;
         GpsDataWord::from(value)
@@ -375,8 +389,7 @@ struct Word4 {
 
 impl Word4 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let omega0_lsb = ((value & WORD4_OMEGA0_MASK) >> WORD4_OMEGA0_SHIFT) as u32;
+        let omega0_lsb = word.bits(1, 24);
         Self { omega0_lsb }
     }
 
@@ -397,10 +410,8 @@ struct Word5 {
 
 impl Word5 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let cis = ((value & WORD5_CIS_MASK) >> WORD5_CIS_SHIFT) as u32;
-        let cis = twos_complement(cis, 0xffff, 0x8000);
-        let i0_msb = ((value & WORD5_I0_MASK) >> WORD5_I0_SHIFT) as u8;
+        let cis = twos_complement(word.bits(1, 16), 0xffff, 0x8000);
+        let i0_msb = word.bits(17, 8) as u8;
         Self { cis, i0_msb }
     }
 
@@ -421,8 +432,7 @@ struct Word6 {
 
 impl Word6 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let i0_lsb = ((value & WORD6_I0_MASK) >> WORD6_I0_SHIFT) as u32;
+        let i0_lsb = word.bits(1, 24);
         Self { i0_lsb }
     }
 
@@ -443,10 +453,8 @@ struct Word7 {
 
 impl Word7 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let crc = ((value & WORD7_CRC_MASK) >> WORD7_CRC_SHIFT) as u32;
-        let crc = twos_complement(crc, 0xffff, 0x8000);
-        let omega_msb = ((value & WORD7_OMEGA_MASK) >> WORD7_OMEGA_SHIFT) as u8;
+        let crc = twos_complement(word.bits(1, 16), 0xffff, 0x8000);
+        let omega_msb = word.bits(17, 8) as u8;
         Self { crc, omega_msb }
     }
 
@@ -467,8 +475,7 @@ struct Word8 {
 
 impl Word8 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let omega_lsb = ((value & WORD8_OMEGA_MASK) >> WORD8_OMEGA_SHIFT) as u32;
+        let omega_lsb = word.bits(1, 24);
         Self { omega_lsb }
     }
 
@@ -487,9 +494,7 @@ struct Word9 {
 
 impl Word9 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let omega_dot = ((value & WORD9_OMEGADOT_MASK) >> WORD9_OMEGADOT_SHIFT) as u32;
-        let omega_dot = twos_complement(omega_dot, 0xffffff, 0x800000);
+        let omega_dot = twos_complement(word.bits(1, 24), 0xffffff, 0x800000);
         Self { omega_dot }
     }
 
@@ -511,12 +516,10 @@ struct Word10 {
 
 impl Word10 {
     pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let iode = ((value & WORD10_IODE_MASK) >> WORD10_IODE_SHIFT) as u8;
+        let iode = word.bits(1, 8) as u8;
 
         // 14-bit signed 2's
-        let idot = ((value & WORD10_IDOT_MASK) >> WORD10_IDOT_SHIFT) as u32;
-        let idot = twos_complement(idot, 0x3fff, 0x2000);
+        let idot = twos_complement(word.bits(9, 14), 0x3fff, 0x2000);
 
         Self { iode, idot }
     }
@@ -599,6 +602,32 @@ mod frame3 {
         }
     }
 
+    #[test]
+    fn rounding() {
+        // scaled values just below an integer: truncation would lose one LSB
+        for (cis, crc, expected_cis, expected_crc) in [
+            (
+                (1000.0 - 1e-3) / 2.0_f64.powi(29),
+                (4000.0 - 1e-3) / 2.0_f64.powi(5),
+                1000,
+                4000,
+            ),
+            (
+                (-1000.0 + 1e-3) / 2.0_f64.powi(29),
+                (-4000.0 + 1e-3) / 2.0_f64.powi(5),
+                -1000,
+                -4000,
+            ),
+        ] {
+            let frame3 = GpsQzssFrame3::default()
+                .with_cis_radians(cis)
+                .with_crc_meters(crc);
+
+            assert_eq!(frame3.word5().cis, expected_cis);
+            assert_eq!(frame3.word7().crc, expected_crc);
+        }
+    }
+
     #[test]
     fn word6() {
         for dword6 in [
@@ -721,4 +750,79 @@ mod frame3 {
             assert_eq!(decoded, frame3);
         }
     }
+
+    #[test]
+    fn reciprocal() {
+        for frame3 in [
+            GpsQzssFrame3::model(),
+            GpsQzssFrame3::model()
+                .with_cic_radians(-1.0e-6)
+                .with_cis_radians(-2.0e-6)
+                .with_crc_meters(-122.0)
+                .with_inclination_rate_semicircles_s(-8.0e-10)
+                .with_omega_dot_semicircles_s(-2.6e-9)
+                .with_omega_semicircles(-0.4)
+                .with_longitude_ascending_node_semicircles(-0.9),
+            GpsQzssFrame3::model()
+                .with_cic_radians(-(2.0_f64.powi(-14)))
+                .with_cis_radians(-(2.0_f64.powi(-29)))
+                .with_crc_meters(-1024.0)
+                .with_inclination_rate_semicircles_s(-(2.0_f64.powi(-30)))
+                .with_omega_dot_semicircles_s(-(2.0_f64.powi(-20)))
+                .with_omega_semicircles(-1.0),
+        ] {
+            let decoded = GpsQzssFrame3::from_words(&frame3.to_words());
+
+            assert_eq!(decoded.iode, frame3.iode);
+
+            // exact within one LSB
+            for (field, found, expected, lsb) in [
+                ("cic", decoded.cic, frame3.cic, 2.0_f64.powi(-29)),
+                ("cis", decoded.cis, frame3.cis, 2.0_f64.powi(-29)),
+                ("crc", decoded.crc, frame3.crc, 2.0_f64.powi(-5)),
+                ("i0", decoded.i0, frame3.i0, 2.0_f64.powi(-31)),
+                ("idot", decoded.idot, frame3.idot, 2.0_f64.powi(-43)),
+                ("omega0", decoded.omega0, frame3.omega0, 2.0_f64.powi(-31)),
+                ("omega", decoded.omega, frame3.omega, 2.0_f64.powi(-31)),
+                (
+                    "omega_dot",
+                    decoded.omega_dot,
+                    frame3.omega_dot,
+                    2.0_f64.powi(-43),
+                ),
+            ] {
+                assert!(
+                    (found - expected).abs() <= lsb / 2.0,
+                    "{}: decoded {:e}, expecting {:e}",
+                    field,
+                    found,
+                    expected
+                );
+            }
+
+            // stable once quantized
+            assert_eq!(decoded.to_words(), frame3.to_words());
+        }
+    }
+
+    #[test]
+    fn display() {
+        let display = GpsQzssFrame3::model().to_string();
+
+        assert!(!display.contains('\n'), "not compact: {}", display);
+
+        for label in [
+            "IODE=",
+            "I0=",
+            "IDOT=",
+            "OMEGA0=",
+            "OMEGA=",
+            "OMEGA_DOT=",
+            "CIC=",
+            "CIS=",
+            "CRC=",
+        ] {
+            assert!(display.contains(label), "missing {} in {}", label, display);
+        }
+    }
 }
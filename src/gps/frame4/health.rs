@@ -1,666 +1,305 @@
-use crate::{
-    gps::{GpsDataWord, GPS_WORDS_PER_FRAME},
-    twos_complement,
-};
+use crate::gps::{GpsDataWord, GPS_WORDS_PER_FRAME};
 
-pub struct SatelliteConfigHealth {
-    /// True when A/S is turned on
-    pub anti_spoofing: bool,
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-    /// 6-bit Health mask
-    pub health_mask: u8,
-}
+/// Number of data bits per word
+const DATA_BITS: usize = 24;
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct GpsQzssConfigHealth {
-    /// Array of [SatelliteConfigHealth] 
-    pub config_health: [SatelliteConfigHealth; 32]
-}
+/// Data bits are followed by the 6 parity bits
+const DATA_SHIFT: usize = 6;
 
-impl GpsQzssKlobuharUtc {
+/// 2-bit Data ID of the current navigation message structure
+const DATA_ID: u32 = 0x01;
 
-    /// Decodes [Self] from 8 [GpsDataWord]s.
-    /// This method does not care for frames parity.
-    pub(crate) fn from_words(words: &[GpsDataWord]) -> Self {
-        let mut s = Self::default();
+/// SV ID (page ID) identifying the Frame-4 page 25
+pub(crate) const PAGE25_SV_ID: u8 = 63;
 
-        for i in 0..GPS_WORDS_PER_FRAME - 2 {
-            match i {
-                0 => s.set_word3(Word3::from_word(words[i])),
-                1 => s.set_word4(Word4::from_word(words[i])),
-                2 => s.set_word5(Word5::from_word(words[i])),
-                3 => s.set_word6(Word6::from_word(words[i])),
-                4 => s.set_word7(Word7::from_word(words[i])),
-                5 => s.set_word8(Word8::from_word(words[i])),
-                6 => s.set_word9(Word9::from_word(words[i])),
-                7 => s.set_word10(Word10::from_word(words[i])),
-                _ => unreachable!("expecting 8 data words"),
-            }
-        }
+/// Number of satellites described by [GpsQzssConfigHealth]
+const NUM_SATELLITES: usize = 32;
 
-        s
-    }
+/// First satellite whose health is described by [GpsQzssConfigHealth]
+const FIRST_HEALTH_SV: usize = 25;
 
-    fn word3(&self) -> Word3 {
-        Word3 {
-            alpha0: iodc_msb: ((self.iodc & 0x300) >> 8) as u8,
-            alpha1: self.alpha1 &
-        }
-    }
+/// Position of the 4-bit A/S flag + configuration of SV #1,
+/// within the data bits of words #3 through #10 (following ones are contiguous).
+const CONFIG_POS: usize = 8;
 
-    fn set_word4(&mut self, word: Word4) {
-        self.l2_p_data_flag = word.l2_p_data_flag;
-        self.reserved_word4 = word.reserved;
-    }
-
-    fn word4(&self) -> Word4 {
-        Word4 {
-            reserved: self.reserved_word4,
-            l2_p_data_flag: self.l2_p_data_flag,
-        }
-    }
+/// Position of the 2-bit reserved field of word #8
+const RESERVED_WORD8_POS: usize = 136;
 
-    fn set_word5(&mut self, word: Word5) {
-        self.reserved_word5 = word.reserved;
-    }
+/// Position of the 6-bit health of SV #25 (following ones are contiguous)
+const HEALTH_POS: usize = 138;
 
-    fn word5(&self) -> Word5 {
-        Word5 {
-            reserved: self.reserved_word5,
-        }
-    }
+/// Position of the 4-bit reserved field of word #10
+const RESERVED_WORD10_POS: usize = 186;
 
-    fn set_word6(&mut self, word: Word6) {
-        self.reserved_word6 = word.reserved;
-    }
+/// Reads a `len`-bit field at position `pos` of the data bits of words #3 through #10.
+/// Fields never span two words.
+fn data_field(words: &[GpsDataWord], pos: usize, len: usize) -> u32 {
+    let shift = DATA_SHIFT + DATA_BITS - pos % DATA_BITS - len;
 
-    fn word6(&self) -> Word6 {
-        Word6 {
-            reserved: self.reserved_word6,
-        }
-    }
-
-    fn set_word7(&mut self, word: Word7) {
-        self.reserved_word7 = word.reserved;
-        self.tgd = (word.tgd as f64) / 2.0_f64.powi(31);
-    }
-
-    fn word7(&self) -> Word7 {
-        Word7 {
-            reserved: self.reserved_word7,
-            tgd: (self.tgd * 2.0_f64.powi(31)).round() as i8,
-        }
-    }
-
-    fn set_word8(&mut self, word: Word8) {
-        self.toc = (word.toc as u32) * 16;
-        self.iodc |= word.iodc_lsb as u16;
-    }
-
-    fn word8(&self) -> Word8 {
-        Word8 {
-            toc: (self.toc / 16) as u16,
-            iodc_lsb: (self.iodc & 0xff) as u8,
-        }
-    }
-
-    fn set_word9(&mut self, word: Word9) {
-        self.af2 = (word.af2 as f64) * 2.0_f64.powi(-55);
-        self.af1 = (word.af1 as f64) * 2.0_f64.powi(-43);
-    }
-
-    fn word9(&self) -> Word9 {
-        Word9 {
-            af2: (self.af2 * 2.0_f64.powi(55)).round() as i8,
-            af1: (self.af1 * 2.0_f64.powi(43)).round() as i16,
-        }
-    }
-
-    fn set_word10(&mut self, word: Word10) {
-        self.af0 = (word.af0 as f64) * 2.0_f64.powi(-31);
-    }
-
-    fn word10(&self) -> Word10 {
-        Word10 {
-            af0: (self.af0 * 2.0_f64.powi(31)).round() as i32,
-        }
-    }
-
-    /// Encodes this [GpsQzssFrame1] as a burst of 8 [GpsDataWord]s.
-    pub(crate) fn to_words(&self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
-        [
-            self.word3().to_word(),
-            self.word4().to_word(),
-            self.word5().to_word(),
-            self.word6().to_word(),
-            self.word7().to_word(),
-            self.word8().to_word(),
-            self.word9().to_word(),
-            self.word10().to_word(),
-        ]
-    }
+    words
+        .get(pos / DATA_BITS)
+        .map(|word| (word.value() >> shift) & ((1 << len) - 1))
+        .unwrap_or(0)
 }
 
-#[derive(Debug, Copy, Default, Clone, PartialEq)]
-struct Word3 {
-    /// alpha 0 (8-bit)
-    pub alpha0: i8,
-    
-    /// alpha 1 (8-bit)
-    pub alpha1: i8,
+/// Writes a `len`-bit field at position `pos` of the (right aligned) 30-bit words #3 through #10.
+fn set_data_field(values: &mut [u32], pos: usize, len: usize, field: u32) {
+    let shift = DATA_SHIFT + DATA_BITS - pos % DATA_BITS - len;
+    values[pos / DATA_BITS] |= (field & ((1 << len) - 1)) << shift;
 }
 
-impl Word3 {
-    /// Interprets this [GpsDataWord] as [Word3].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-
-        let week = ((value & WORD3_WEEK_MASK) >> WORD3_WEEK_SHIFT) as u16;
-        let ca_or_p_l2 = ((value & WORD3_CA_P_L2_MASK) >> WORD3_CA_P_L2_SHIFT) as u8;
-        let ura = ((value & WORD3_URA_MASK) >> WORD3_URA_SHIFT) as u8;
-        let health = ((value & WORD3_HEALTH_MASK) >> WORD3_HEALTH_SHIFT) as u8;
-        let iodc_msb = ((value & WORD3_IODC_MASK) >> WORD3_IODC_SHIFT) as u8;
-
-        Self {
-            week,
-            ca_or_p_l2,
-            ura,
-            health,
-            iodc_msb,
-        }
-    }
+/// [GpsQzssSatHealth] describes one satellite in Frame-4 page 25.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpsQzssSatHealth {
+    /// True when A/S is turned on
+    pub anti_spoofing: bool,
 
-    /// Encodes this [Word3] as [GpsDataWord].
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
+    /// 3-bit satellite configuration (signal capabilities of this satellite)
+    pub config: u8,
 
-        value |= ((self.week & 0x3ff) as u32) << WORD3_WEEK_SHIFT;
-        value |= ((self.ca_or_p_l2 & 0x3) as u32) << WORD3_CA_P_L2_SHIFT;
-        value |= ((self.ura & 0x07) as u32) << WORD3_URA_SHIFT;
-        value |= ((self.health & 0x3f) as u32) << WORD3_HEALTH_SHIFT;
-        value |= ((self.iodc_msb & 0x03) as u32) << WORD3_IODC_SHIFT;
+    /// 6-bit health status, only transmitted for satellites #25 through #32.
+    /// Satellites #1 through #24 are described by the Frame-5 page 25.
+    pub health: u8,
+}
 
-        value <<= 2;
+/// [GpsQzssConfigHealth] is found in Frame-4 page 25.
+/// It gives the A/S flag and configuration of satellites #1 (included) through #32 (included),
+/// and the health status of satellites #25 through #32.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpsQzssConfigHealth {
+    /// [GpsQzssSatHealth] of satellite #1 through #32
+    pub sv: [GpsQzssSatHealth; NUM_SATELLITES],
 
-        GpsDataWord::from(value)
-    }
-}
+    /// 2-bit reserved (system use) in word #8
+    pub reserved_word8: u8,
 
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word4 {
-    pub alpha2: i8,
-    pub alpha3: i8,
-    pub beta0: i8,
+    /// 4-bit reserved (system use) in word #10
+    pub reserved_word10: u8,
 }
 
-impl Word4 {
-    /// Interprets this [GpsDataWord] as [Word4].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let l2_p_data_flag = (value & WORD4_L2P_DATA_MASK) > 0;
-        let reserved = (value & WORD4_RESERVED_MASK) >> WORD4_RESERVED_SHIFT;
-
-        Self {
-            l2_p_data_flag,
-            reserved,
+impl GpsQzssConfigHealth {
+    /// Copies and returns [GpsQzssConfigHealth] with updated A/S flag and 3-bit configuration
+    /// of satellite #`prn` (1 to 32). Other satellites are ignored.
+    pub fn with_sv_config(mut self, prn: u8, anti_spoofing: bool, config: u8) -> Self {
+        if (1..=NUM_SATELLITES as u8).contains(&prn) {
+            let sv = &mut self.sv[prn as usize - 1];
+            sv.anti_spoofing = anti_spoofing;
+            sv.config = config & 0x07;
         }
+        self
     }
 
-    /// Encodes this [Word4] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0u32;
-
-        if self.l2_p_data_flag {
-            value |= WORD4_L2P_DATA_MASK;
+    /// Copies and returns [GpsQzssConfigHealth] with updated 6-bit health status
+    /// of satellite #`prn` (25 to 32). Other satellites are ignored.
+    pub fn with_sv_health(mut self, prn: u8, health: u8) -> Self {
+        if (FIRST_HEALTH_SV as u8..=NUM_SATELLITES as u8).contains(&prn) {
+            self.sv[prn as usize - 1].health = health & 0x3f;
         }
-
-        value |= (self.reserved & 0x7fffff) << WORD4_RESERVED_SHIFT;
-        value <<= 2;
-
-        GpsDataWord::from(value)
+        self
     }
-}
-
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word5 {
-    pub beta1: i8,
-    pub beta2: i8,
-    pub beta3: i8,
-}
 
-impl Word5 {
-    /// Interprets this [GpsDataWord] as [Word5].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let reserved = (word.value() & WORD5_RESERVED_MASK) >> WORD5_RESERVED_SHIFT;
-        Self { reserved }
-    }
+    /// Returns the 3-bit configuration of satellite #`prn` (1 to 32),
+    /// or [None] when this satellite is not described by this page.
+    pub fn config(&self, prn: u8) -> Option<u8> {
+        if prn == 0 {
+            return None;
+        }
 
-    /// Encodes this [Word5] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD5_RESERVED_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        self.sv.get(prn as usize - 1).map(|sv| sv.config)
     }
-}
 
-#[derive(Debug, Default, PartialEq, Clone)]
-pub(crate) struct Word6 {
-    /// 24-bit reserved
-    pub a1: u32,
-}
+    /// Returns the 6-bit health status of satellite #`prn` (25 to 32),
+    /// or [None] when the health of this satellite is not described by this page.
+    /// All zeros means all signals are healthy.
+    pub fn health(&self, prn: u8) -> Option<u8> {
+        if !(FIRST_HEALTH_SV as u8..=NUM_SATELLITES as u8).contains(&prn) {
+            return None;
+        }
 
-impl Word6 {
-    /// Interprets this [GpsDataWord] as [Word6].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let reserved = (word.value() & WORD6_RESERVED_MASK) >> WORD6_RESERVED_SHIFT;
-        Self { reserved }
+        self.sv.get(prn as usize - 1).map(|sv| sv.health)
     }
 
-    /// Encodes this [Word6] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= (self.reserved & 0x0ffffff) << WORD6_RESERVED_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
-    }
-}
+    /// Decodes [Self] from 8 [GpsDataWord]s.
+    /// This method does not care for frames parity.
+    pub(crate) fn from_words(words: &[GpsDataWord]) -> Self {
+        let mut s = Self::default();
 
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word7 {
-    pub a0_msb: u32,
-}
+        for (i, sv) in s.sv.iter_mut().enumerate() {
+            let config = data_field(words, CONFIG_POS + i * 4, 4);
 
-impl Word7 {
-    /// Interprets this [GpsDataWord] as [Word7].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let reserved = ((value & WORD7_RESERVED_MASK) >> WORD7_RESERVED_SHIFT) as u16;
-        let tgd = ((value & WORD7_TGD_MASK) >> WORD7_TGD_SHIFT) as i8;
-        Self { reserved, tgd }
-    }
+            sv.anti_spoofing = config & 0x08 > 0;
+            sv.config = (config & 0x07) as u8;
 
-    /// Encodes this [Word7] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.reserved as u32) & 0x0ffff) << WORD7_RESERVED_SHIFT;
-        value |= ((self.tgd as u32) & 0xff) << WORD7_TGD_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
-    }
-}
+            if i + 1 >= FIRST_HEALTH_SV {
+                let pos = HEALTH_POS + (i + 1 - FIRST_HEALTH_SV) * 6;
+                sv.health = data_field(words, pos, 6) as u8;
+            }
+        }
 
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word8 {
-    pub a0_lsb: u8,
-    pub toa: u8,
-    pub wn: u8,
-}
+        s.reserved_word8 = data_field(words, RESERVED_WORD8_POS, 2) as u8;
+        s.reserved_word10 = data_field(words, RESERVED_WORD10_POS, 4) as u8;
 
-impl Word8 {
-    /// Interprets this [GpsDataWord] as [Word8].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let iodc_lsb = ((value & WORD8_IODC_MASK) >> WORD8_IODC_SHIFT) as u8;
-        let toc = ((value & WORD8_TOC_MASK) >> WORD8_TOC_SHIFT) as u16;
-        Self { iodc_lsb, toc }
+        s
     }
 
-    /// Encodes this [Word8] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.iodc_lsb as u32) & 0xff) << WORD8_IODC_SHIFT;
-        value |= ((self.toc as u32) & 0x0ffff) << WORD8_TOC_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
-    }
-}
+    /// Encodes this [GpsQzssConfigHealth] as a burst of 8 [GpsDataWord]s.
+    pub(crate) fn to_words(self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
+        let mut values = [0u32; GPS_WORDS_PER_FRAME - 2];
 
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word9 {
-    pub delta_leap_sec: u8,
-    pub week_leap_sec: u8,
-    pub dn: u8,
-}
+        set_data_field(&mut values, 0, 2, DATA_ID);
+        set_data_field(&mut values, 2, 6, PAGE25_SV_ID as u32);
 
-impl Word9 {
-    /// Interprets this [GpsDataWord] as [Word9].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let value = word.value();
-        let af2 = ((value & WORD9_AF2_MASK) >> WORD9_AF2_SHIFT) as i8;
-        let af1 = ((value & WORD9_AF1_MASK) >> WORD9_AF1_SHIFT) as i16;
-        Self { af2, af1 }
-    }
+        for (i, sv) in self.sv.iter().enumerate() {
+            let config = ((sv.anti_spoofing as u32) << 3) | (sv.config & 0x07) as u32;
+            set_data_field(&mut values, CONFIG_POS + i * 4, 4, config);
 
-    /// Encodes this [Word9] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = 0;
-        value |= ((self.af2 as u32) & 0x0ff) << WORD9_AF2_SHIFT;
-        value |= ((self.af1 as u32) & 0x0ffff) << WORD9_AF1_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
-    }
-}
+            if i + 1 >= FIRST_HEALTH_SV {
+                let pos = HEALTH_POS + (i + 1 - FIRST_HEALTH_SV) * 6;
+                set_data_field(&mut values, pos, 6, sv.health as u32);
+            }
+        }
 
-#[derive(Debug, Default, Clone, PartialEq)]
-struct Word10 {
-    /// Delta leap second forward
-    pub delta_leap_sec_foward: u8,
+        set_data_field(
+            &mut values,
+            RESERVED_WORD8_POS,
+            2,
+            self.reserved_word8 as u32,
+        );
 
-    /// 14-bit spare
-    pub spare: u16,
-}
+        set_data_field(
+            &mut values,
+            RESERVED_WORD10_POS,
+            4,
+            self.reserved_word10 as u32,
+        );
 
-impl Word10 {
-    /// Interprets this [GpsDataWord] as [Word10].
-    pub fn from_word(word: GpsDataWord) -> Self {
-        let af0 = (word.value() & WORD10_AF0_MASK) >> WORD10_AF0_SHIFT;
-        let af0 = twos_complement(af0, 0x3fffff, 0x200000);
-        Self { af0 }
-    }
+        let mut words = [GpsDataWord::default(); GPS_WORDS_PER_FRAME - 2];
+
+        for (word, value) in words.iter_mut().zip(values.iter()) {
+            *word = GpsDataWord::from(value << 2);
+        }
 
-    /// Encodes this [Word10] as [GpsDataWord]
-    pub fn to_word(&self) -> GpsDataWord {
-        let mut value = ((self.af0 & 0x3fffff) as u32) << WORD10_AF0_SHIFT;
-        value <<= 2;
-        GpsDataWord::from(value)
+        words
     }
 }
 
 #[cfg(test)]
-mod frame1 {
+mod test {
     use super::*;
+    use crate::gps::almanach::sv_id;
 
     #[test]
-    fn dword3() {
-        for dword3 in [
-            Word3 {
-                week: 1,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 1,
-                ura: 0,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 1,
-                health: 0,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 1,
-                iodc_msb: 0,
-            },
-            Word3 {
-                week: 0,
-                ca_or_p_l2: 0,
-                ura: 0,
-                health: 0,
-                iodc_msb: 1,
-            },
-            Word3 {
-                week: 1,
-                ca_or_p_l2: 2,
-                ura: 5,
-                health: 1,
-                iodc_msb: 0,
-            },
-        ] {
-            let gps_word = dword3.to_word();
-            let decoded = Word3::from_word(gps_word);
-            assert_eq!(decoded, dword3);
-            assert_eq!(
-                decoded.to_word(),
-                gps_word,
-                "Reciprocal failed for {:?}",
-                dword3
-            );
-        }
+    fn encoding() {
+        let page = GpsQzssConfigHealth::default()
+            .with_sv_config(1, true, 0x01)
+            .with_sv_config(4, false, 0x02)
+            .with_sv_config(5, true, 0x07)
+            .with_sv_config(28, true, 0x03)
+            .with_sv_config(29, false, 0x05)
+            .with_sv_config(32, true, 0x02)
+            .with_sv_health(25, 0x3f)
+            .with_sv_health(29, 0x01)
+            .with_sv_health(30, 0x2a)
+            .with_sv_health(32, 0x15);
+
+        let words = page.to_words();
+
+        assert_eq!(sv_id(words[0]), PAGE25_SV_ID);
+
+        // A/S + configuration of SV #1 and #4 in word #3
+        assert_eq!(words[0].bits(9, 4), 0x09);
+        assert_eq!(words[0].bits(21, 4), 0x02);
+
+        // SV #5 in word #4, SV #28 in word #7, SV #29 and #32 in word #8
+        assert_eq!(words[1].bits(1, 4), 0x0f);
+        assert_eq!(words[4].bits(21, 4), 0x0b);
+        assert_eq!(words[5].bits(1, 4), 0x05);
+        assert_eq!(words[5].bits(13, 4), 0x0a);
+
+        // health of SV #25 in word #8, #29 in word #9, #30 and #32 in word #10
+        assert_eq!(words[5].bits(19, 6), 0x3f);
+        assert_eq!(words[6].bits(19, 6), 0x01);
+        assert_eq!(words[7].bits(1, 6), 0x2a);
+        assert_eq!(words[7].bits(13, 6), 0x15);
+
+        let decoded = GpsQzssConfigHealth::from_words(&words);
+        assert_eq!(decoded, page);
+        assert_eq!(decoded.to_words(), words);
     }
 
     #[test]
-    fn dword4() {
-        for dword4 in [
-            Word4 {
-                l2_p_data_flag: true,
-                reserved: 0,
-            },
-            Word4 {
-                l2_p_data_flag: false,
-                reserved: 1,
-            },
-            Word4 {
-                l2_p_data_flag: true,
-                reserved: 123,
-            },
-        ] {
-            let gps_word = dword4.to_word();
-            let decoded = Word4::from_word(gps_word);
-            assert_eq!(decoded, dword4);
-            assert_eq!(
-                decoded.to_word(),
-                gps_word,
-                "Reciprocal failed for {:?}",
-                dword4
-            );
+    fn accessors() {
+        let mut sv = [GpsQzssSatHealth::default(); NUM_SATELLITES];
+
+        for (i, sv) in sv.iter_mut().enumerate() {
+            *sv = GpsQzssSatHealth {
+                anti_spoofing: i % 2 == 0,
+                config: (i % 8) as u8,
+                health: if i + 1 >= FIRST_HEALTH_SV {
+                    (i * 3) as u8 & 0x3f
+                } else {
+                    0
+                },
+            };
         }
-    }
 
-    #[test]
-    fn dword5() {
-        for dword5 in [Word5 { reserved: 0 }, Word5 { reserved: 120 }] {
-            let gps_word = dword5.to_word();
-            let decoded = Word5::from_word(gps_word);
-            assert_eq!(decoded, dword5);
-            assert_eq!(decoded.to_word(), gps_word);
-        }
-    }
+        let page = GpsQzssConfigHealth {
+            sv,
+            ..Default::default()
+        };
 
-    #[test]
-    fn dword6() {
-        for dword6 in [Word6 { reserved: 0 }, Word6 { reserved: 120 }] {
-            let gps_word = dword6.to_word();
-            let decoded = Word6::from_word(gps_word);
-            assert_eq!(decoded, dword6);
-            assert_eq!(decoded.to_word(), gps_word);
-        }
-    }
+        let decoded = GpsQzssConfigHealth::from_words(&page.to_words());
+        assert_eq!(decoded, page);
 
-    #[test]
-    fn dword7() {
-        for dword7 in [
-            Word7 {
-                reserved: 0,
-                tgd: 1,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: 0,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: 23,
-            },
-            Word7 {
-                reserved: 120,
-                tgd: -1,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -10,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -100,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: -128,
-            },
-            Word7 {
-                reserved: 127,
-                tgd: 127,
-            },
-        ] {
-            let gps_word = dword7.to_word();
-            let decoded = Word7::from_word(gps_word);
-            assert_eq!(decoded, dword7);
-            assert_eq!(decoded.to_word(), gps_word);
-        }
-    }
+        for prn in 1..=32 {
+            let i = prn as usize - 1;
 
-    #[test]
-    fn dword8() {
-        for dword8 in [
-            Word8 {
-                iodc_lsb: 10,
-                toc: 30,
-            },
-            Word8 {
-                iodc_lsb: 30,
-                toc: 10,
-            },
-        ] {
-            let gps_word = dword8.to_word();
-            let decoded = Word8::from_word(gps_word);
-            assert_eq!(decoded, dword8);
-            assert_eq!(decoded.to_word(), gps_word);
-        }
-    }
+            assert_eq!(decoded.config(prn), Some((i % 8) as u8));
+            assert_eq!(decoded.sv[i].anti_spoofing, i % 2 == 0);
 
-    #[test]
-    fn dword9() {
-        for dword9 in [Word9 { af2: 10, af1: 9 }, Word9 { af2: 9, af1: 100 }] {
-            let gps_word = dword9.to_word();
-            let decoded = Word9::from_word(gps_word);
-            assert_eq!(decoded, dword9);
-            assert_eq!(decoded.to_word(), gps_word);
+            if prn >= 25 {
+                assert_eq!(decoded.health(prn), Some((i * 3) as u8 & 0x3f));
+            } else {
+                assert_eq!(decoded.health(prn), None);
+            }
         }
-    }
 
-    #[test]
-    fn dword10() {
-        for dword10 in [
-            Word10 { af0: 0 },
-            Word10 { af0: 100 },
-            Word10 { af0: -1230 },
-            Word10 { af0: -3140 },
-        ] {
-            let gps_word = dword10.to_word();
-            let decoded = Word10::from_word(gps_word);
-            assert_eq!(decoded, dword10);
-            assert_eq!(decoded.to_word(), gps_word);
+        for prn in [0, 33] {
+            assert_eq!(decoded.config(prn), None);
+            assert_eq!(decoded.health(prn), None);
         }
     }
 
     #[test]
-    fn encoding() {
-        for (
-            week,
-            ca_or_p_l2,
-            ura,
-            health,
-            iodc,
-            toc,
-            tgd,
-            af0,
-            af1,
-            af2,
-            l2_p_data_flag,
-            reserved_word4,
-            reserved_word5,
-            reserved_word6,
-            reserved_word7,
-        ) in [
-            (
-                350, 1, 2, 3, 10, 50_000, 6.0E-9, 7.0E-9, 8.0E-13, 9.0E-15, true, 10, 11, 12, 13,
-            ),
-            (
-                900, 1, 2, 3, 10, 24_992, -1.0E-9, -7.0E-9, 8.0E-13, 9.0E-15, true, 10, 11, 12, 13,
-            ),
-        ] {
-            let frame1 = GpsQzssFrame1 {
-                week,
-                ca_or_p_l2,
-                ura,
-                health,
-                iodc,
-                toc,
-                tgd: tgd,
-                af0: af0,
-                af1: af1,
-                af2: af2,
-                l2_p_data_flag,
-                reserved_word4,
-                reserved_word5,
-                reserved_word6,
-                reserved_word7,
-            };
+    fn reserved_bits() {
+        let page = GpsQzssConfigHealth {
+            reserved_word8: 0x03,
+            reserved_word10: 0x0a,
+            ..Default::default()
+        };
 
-            let words = frame1.to_words();
+        let words = page.to_words();
 
-            let decoded = GpsQzssFrame1::from_words(&words);
+        assert_eq!(words[5].bits(17, 2), 0x03);
+        assert_eq!(words[7].bits(19, 4), 0x0a);
 
-            assert_eq!(decoded.ura, frame1.ura);
-            assert_eq!(decoded.week, frame1.week);
-            assert_eq!(decoded.toc, frame1.toc);
-            assert_eq!(decoded.ca_or_p_l2, frame1.ca_or_p_l2);
-            assert_eq!(decoded.l2_p_data_flag, frame1.l2_p_data_flag);
-            assert_eq!(decoded.reserved_word4, frame1.reserved_word4);
-            assert_eq!(decoded.reserved_word5, frame1.reserved_word5);
-            assert_eq!(decoded.reserved_word6, frame1.reserved_word6);
-            assert_eq!(decoded.reserved_word7, frame1.reserved_word7);
-
-            assert!((decoded.af0 - frame1.af0).abs() < 1E-10);
-            assert!((decoded.af1 - frame1.af1).abs() < 1E-14);
-            assert!((decoded.af2 - frame1.af2).abs() < 1E-14);
-        }
+        assert_eq!(GpsQzssConfigHealth::from_words(&words), page);
     }
 
     #[test]
-    fn user_range_accuracy() {
-        for (value_m, encoded_ura) in [
-            (0.1, 0),
-            (1.0, 0),
-            (2.4, 0),
-            (2.5, 1),
-            (2.6, 1),
-            (3.4, 1),
-            (3.5, 2),
-            (95.0, 8),
-            (96.0, 8),
-            (96.1, 9),
-            (3071.0, 13),
-            (3072.0, 13),
-            (3072.1, 14),
-            (4000.1, 14),
-        ] {
-            let ura = GpsQzssFrame1::compute_ura(value_m);
-            assert_eq!(ura, encoded_ura, "encoded incorrect URA from {}m", value_m);
-
-            let mut frame1 = GpsQzssFrame1::default().with_user_range_accuracy_m(value_m);
-
-            assert_eq!(frame1.ura, encoded_ura);
-
-            let mut expected = GpsQzssFrame1::default();
-            expected.ura = encoded_ura;
-
-            // assert_eq!(
-            //     frame1.with_nominal_user_range_accuracy_m(value_m).ura,
-            //     encoded_ura,
-            //     "failed for value={}m, encoded={}", value_m, encoded_ura,
-            // );
-        }
+    fn out_of_range() {
+        // health of SV #1 through #24 is not transmitted in this page
+        let page = GpsQzssConfigHealth::default()
+            .with_sv_health(1, 0x3f)
+            .with_sv_health(24, 0x3f)
+            .with_sv_config(0, true, 0x07)
+            .with_sv_config(33, true, 0x07);
+
+        assert_eq!(page, GpsQzssConfigHealth::default());
     }
 }
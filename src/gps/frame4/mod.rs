@@ -1,95 +1,107 @@
 use crate::gps::{
-    GpsDataWord,
-    GpsError,
+    almanach::{sv_id, with_sv_id},
+    GpsDataWord, GpsError, GpsQzssAlmanach, GPS_WORDS_PER_FRAME,
 };
 
-mod almanach;
-mod klobuchar_utc;
-mod raw;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 mod health;
+mod raw;
+
+pub use health::{GpsQzssConfigHealth, GpsQzssSatHealth};
+pub use raw::GpsQzssFrame4Raw;
 
-pub use almanach::*;
-pub use klobuchar_utc::*;
-pub use raw::*;
-pub use health::*;
+use health::PAGE25_SV_ID;
 
 /// [GpsQzssFrame4] interpretations.
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+/// Pages are identified by the 6-bit SV ID (page ID) of word #3.
+/// Some reserved pages share the same SV ID (for example, pages 1, 6, 11, 16 and 21):
+/// they are always decoded as the first page using this SV ID.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GpsQzssFrame4 {
-    /// Reserved [Page1] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page1(GpsQzssFrame4Raw),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page2(GpsQzssFrame4Alm1),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page3(GpsQzssFrame4Alm2),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page4(GpsQzssFrame4Alm3),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page5(GpsQzssFrame4Alm4),
-    
-    /// Reserved [Page6] message
+
+    /// [GpsQzssAlmanach] for satellite #25
+    Page2(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #26
+    Page3(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #27
+    Page4(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #28
+    Page5(GpsQzssAlmanach),
+
+    /// Reserved [GpsQzssFrame4Raw] page
     Page6(GpsQzssFrame4Raw),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page7(GpsQzssFrame4Alm5),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page8(GpsQzssFrame4Alm6),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page9(GpsQzssFrame4Alm7),
-    
-    /// Almanach data for satellite 25 (included) through 32 (included).
-    Page10(GpsQzssFrame4Alm8),
-
-    /// Reserved [Page11] message
+
+    /// [GpsQzssAlmanach] for satellite #29
+    Page7(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #30
+    Page8(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #31
+    Page9(GpsQzssAlmanach),
+
+    /// [GpsQzssAlmanach] for satellite #32
+    Page10(GpsQzssAlmanach),
+
+    /// Reserved [GpsQzssFrame4Raw] page
     Page11(GpsQzssFrame4Raw),
-    
-    /// Reserved [Page12] message
+
+    /// Reserved [GpsQzssFrame4Raw] page
     Page12(GpsQzssFrame4Raw),
-    
-    /// Spare [Page13] message
+
+    /// NMCT (Navigation Message Correction Table) [GpsQzssFrame4Raw] page
     Page13(GpsQzssFrame4Raw),
-    
-    /// Spare [Page14] message
+
+    /// Reserved for system use [GpsQzssFrame4Raw] page
     Page14(GpsQzssFrame4Raw),
-    
-    /// Spare [Page15] message
+
+    /// Reserved for system use [GpsQzssFrame4Raw] page
     Page15(GpsQzssFrame4Raw),
 
-    /// Reserved [Page16] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page16(GpsQzssFrame4Raw),
 
-    /// Special [Page17] message
+    /// Special messages [GpsQzssFrame4Raw] page
     Page17(GpsQzssFrame4Raw),
 
-    /// [GpsQzssFrame4KlobucharUtc] correction message
-    Page18(GpsQzssFrame4KlobucharUtc),
+    /// Ionospheric (Klobuchar) and UTC parameters, not interpreted yet [GpsQzssFrame4Raw] page
+    Page18(GpsQzssFrame4Raw),
 
-    /// Reserved [Page19] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page19(GpsQzssFrame4Raw),
 
-    /// Reserved [Page20] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page20(GpsQzssFrame4Raw),
 
-    /// Reserved [Page21] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page21(GpsQzssFrame4Raw),
 
-    /// Reserved [Page22] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page22(GpsQzssFrame4Raw),
 
-    /// Reserved [Page23] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page23(GpsQzssFrame4Raw),
 
-    /// Reserved [Page24] message
+    /// Reserved [GpsQzssFrame4Raw] page
     Page24(GpsQzssFrame4Raw),
 
-    /// Satellite configuration for 32 satellites
-    Page25(GpsQzssSatHealth),
+    /// A-S flags / SV configurations (SV 1-32) and SV health (SV 25-32) [GpsQzssConfigHealth] page
+    Page25(GpsQzssConfigHealth),
+}
+
+impl Default for GpsQzssFrame4 {
+    /// Builds a default [GpsQzssFrame4::Page1]
+    fn default() -> Self {
+        Self::Page1(Default::default())
+    }
 }
 
 impl GpsQzssFrame4 {
@@ -124,74 +136,118 @@ impl GpsQzssFrame4 {
         }
     }
 
-    pub(crate) fn to_word(&self) -> GpsDataWord {
-        let mut word = match self {
-            Self::Page1(frame) => frame.to_word(),
-            Self::Page2(frame) => frame.to_word(),
-            Self::Page3(frame) => frame.to_word(),
-            Self::Page4(frame) => frame.to_word(),
-            Self::Page5(frame) => frame.to_word(),
-            Self::Page6(frame) => frame.to_word(),
-            Self::Page7(frame) => frame.to_word(),
-            Self::Page8(frame) => frame.to_word(),
-            Self::Page9(frame) => frame.to_word(),
-            Self::Page10(frame) => frame.to_word(),
-            Self::Page11(frame) => frame.to_word(),
-            Self::Page12(frame) => frame.to_word(),
-            Self::Page13(frame) => frame.to_word(),
-            Self::Page14(frame) => frame.to_word(),
-            Self::Page15(frame) => frame.to_word(),
-            Self::Page16(frame) => frame.to_word(),
-            Self::Page17(frame) => frame.to_word(),
-            Self::Page18(frame) => frame.to_word(),
-            Self::Page19(frame) => frame.to_word(),
-            Self::Page20(frame) => frame.to_word(),
-            Self::Page21(frame) => frame.to_word(),
-            Self::Page22(frame) => frame.to_word(),
-            Self::Page23(frame) => frame.to_word(),
-            Self::Page24(frame) => frame.to_word(),
-            Self::Page25(frame) => frame.to_word(),
+    /// Returns the 6-bit SV ID (page ID) transmitted in word #3 of this [GpsQzssFrame4] page
+    pub fn sv_id(&self) -> u8 {
+        match self {
+            Self::Page1(_) => 57,
+            Self::Page2(_) => 25,
+            Self::Page3(_) => 26,
+            Self::Page4(_) => 27,
+            Self::Page5(_) => 28,
+            Self::Page6(_) => 57,
+            Self::Page7(_) => 29,
+            Self::Page8(_) => 30,
+            Self::Page9(_) => 31,
+            Self::Page10(_) => 32,
+            Self::Page11(_) => 57,
+            Self::Page12(_) => 62,
+            Self::Page13(_) => 52,
+            Self::Page14(_) => 53,
+            Self::Page15(_) => 54,
+            Self::Page16(_) => 57,
+            Self::Page17(_) => 55,
+            Self::Page18(_) => 56,
+            Self::Page19(_) => 58,
+            Self::Page20(_) => 59,
+            Self::Page21(_) => 57,
+            Self::Page22(_) => 60,
+            Self::Page23(_) => 61,
+            Self::Page24(_) => 62,
+            Self::Page25(_) => 63,
+        }
+    }
+
+    /// Returns the PRN number of the satellite described by this [GpsQzssFrame4] page,
+    /// as specified by IS-GPS-200: pages 2 to 5 describe satellites #25 to #28,
+    /// pages 7 to 10 describe satellites #29 to #32. Other pages are not Almanach pages.
+    pub fn prn(&self) -> Option<u8> {
+        match self.page_id() {
+            page @ 2..=5 => Some(page + 23),
+            page @ 7..=10 => Some(page + 22),
+            _ => None,
+        }
+    }
+
+    /// Encodes this [GpsQzssFrame4] as a burst of 8 [GpsDataWord]s.
+    /// The SV ID (page ID) of word #3 is set according to the page number.
+    pub(crate) fn to_words(self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
+        let mut words = match self {
+            Self::Page1(raw) => raw.to_words(),
+            Self::Page2(almanach) => almanach.to_words(),
+            Self::Page3(almanach) => almanach.to_words(),
+            Self::Page4(almanach) => almanach.to_words(),
+            Self::Page5(almanach) => almanach.to_words(),
+            Self::Page6(raw) => raw.to_words(),
+            Self::Page7(almanach) => almanach.to_words(),
+            Self::Page8(almanach) => almanach.to_words(),
+            Self::Page9(almanach) => almanach.to_words(),
+            Self::Page10(almanach) => almanach.to_words(),
+            Self::Page11(raw) => raw.to_words(),
+            Self::Page12(raw) => raw.to_words(),
+            Self::Page13(raw) => raw.to_words(),
+            Self::Page14(raw) => raw.to_words(),
+            Self::Page15(raw) => raw.to_words(),
+            Self::Page16(raw) => raw.to_words(),
+            Self::Page17(raw) => raw.to_words(),
+            Self::Page18(raw) => raw.to_words(),
+            Self::Page19(raw) => raw.to_words(),
+            Self::Page20(raw) => raw.to_words(),
+            Self::Page21(raw) => raw.to_words(),
+            Self::Page22(raw) => raw.to_words(),
+            Self::Page23(raw) => raw.to_words(),
+            Self::Page24(raw) => raw.to_words(),
+            Self::Page25(config_health) => config_health.to_words(),
         };
 
-        word.with_page_id(self.page_id())
+        words[0] = with_sv_id(words[0], self.sv_id());
+        words
     }
 
-    /// Decodes this [GpsDataWord] as [GpsQzssFrame4] page,
-    /// if page is correct and supported.
-    pub(crate) fn from_word(word: GpsDataWord) -> Result<Self, GpsError> {
-        match word.page_id() {
-            1 => Ok(Self::Page1(GpsQzssFrame4Raw::from_word(word))),
-            2 => Ok(Self::Page2(GpsQzssFrame4Alm1::from_word(word))),
-            3 => Ok(Self::Page3(GpsQzssFrame4Alm2::from_word(word))),
-            4 => Ok(Self::Page4(GpsQzssFrame4Alm3::from_word(word))),
-            5 => Ok(Self::Page5(GpsQzssFrame4Alm4::from_word(word))),
-            6 => Ok(Self::Page6(GpsQzssFrame4Raw::from_word(word))),
-            7 => Ok(Self::Page7(GpsQzssFrame4Raw::from_word(word))),
-            8 => Ok(Self::Page8(GpsQzssFrame4Raw::from_word(word))),
-            9 => Ok(Self::Page9(GpsQzssFrame4Raw::from_word(word))),
-            10 => Ok(Self::Page10(GpsQzssFrame4Raw::from_word(word))),
-            11 => Ok(Self::Page11(GpsQzssFrame4Raw::from_word(word))),
-            12 => Ok(Self::Page12(GpsQzssFrame4Raw::from_word(word))),
-            13 => Ok(Self::Page13(GpsQzssFrame4Raw::from_word(word))),
-            14 => Ok(Self::Page14(GpsQzssFrame4Raw::from_word(word))),
-            15 => Ok(Self::Page15(GpsQzssFrame4Raw::from_word(word))),
-            16 => Ok(Self::Page16(GpsQzssFrame4Raw::from_word(word))),
-            17 => Ok(Self::Page17(GpsQzssFrame4Raw::from_word(word))),
-            18 => Ok(Self::Page18(GpsQzssFrame4KlobucharUtc::from_word(word))),
-            19 => Ok(Self::Page19(GpsQzssFrame4Raw::from_word(word))),
-            20 => Ok(Self::Page20(GpsQzssFrame4Raw::from_word(word))),
-            21 => Ok(Self::Page21(GpsQzssFrame4Raw::from_word(word))),
-            22 => Ok(Self::Page22(GpsQzssFrame4Raw::from_word(word))),
-            23 => Ok(Self::Page23(GpsQzssFrame4Raw::from_word(word))),
-            24 => Ok(Self::Page24(GpsQzssFrame4Raw::from_word(word))),
-            25 => Ok(Self::Page25(GpsQzssSatHealth::from_word(word))),
+    /// Decodes [GpsQzssFrame4] from 8 [GpsDataWord]s,
+    /// dispatching on the SV ID (page ID) of word #3.
+    /// This method does not care for frames parity.
+    pub(crate) fn from_words(words: &[GpsDataWord]) -> Result<Self, GpsError> {
+        let word3 = words.first().ok_or(GpsError::InvalidPage)?;
+
+        match sv_id(*word3) {
+            25 => Ok(Self::Page2(GpsQzssAlmanach::from_words(words))),
+            26 => Ok(Self::Page3(GpsQzssAlmanach::from_words(words))),
+            27 => Ok(Self::Page4(GpsQzssAlmanach::from_words(words))),
+            28 => Ok(Self::Page5(GpsQzssAlmanach::from_words(words))),
+            29 => Ok(Self::Page7(GpsQzssAlmanach::from_words(words))),
+            30 => Ok(Self::Page8(GpsQzssAlmanach::from_words(words))),
+            31 => Ok(Self::Page9(GpsQzssAlmanach::from_words(words))),
+            32 => Ok(Self::Page10(GpsQzssAlmanach::from_words(words))),
+            57 => Ok(Self::Page1(GpsQzssFrame4Raw::from_words(words))),
+            62 => Ok(Self::Page12(GpsQzssFrame4Raw::from_words(words))),
+            52 => Ok(Self::Page13(GpsQzssFrame4Raw::from_words(words))),
+            53 => Ok(Self::Page14(GpsQzssFrame4Raw::from_words(words))),
+            54 => Ok(Self::Page15(GpsQzssFrame4Raw::from_words(words))),
+            55 => Ok(Self::Page17(GpsQzssFrame4Raw::from_words(words))),
+            56 => Ok(Self::Page18(GpsQzssFrame4Raw::from_words(words))),
+            58 => Ok(Self::Page19(GpsQzssFrame4Raw::from_words(words))),
+            59 => Ok(Self::Page20(GpsQzssFrame4Raw::from_words(words))),
+            60 => Ok(Self::Page22(GpsQzssFrame4Raw::from_words(words))),
+            61 => Ok(Self::Page23(GpsQzssFrame4Raw::from_words(words))),
+            PAGE25_SV_ID => Ok(Self::Page25(GpsQzssConfigHealth::from_words(words))),
             _ => Err(GpsError::InvalidPage),
         }
     }
 }
 
 #[cfg(test)]
-mod frame4 {
+mod test {
+    use super::*;
 
     #[test]
     fn pagination_id() {
@@ -205,7 +261,101 @@ mod frame4 {
             (GpsQzssFrame4::Page24(Default::default()), 24),
             (GpsQzssFrame4::Page25(Default::default()), 25),
         ] {
-            assert_eq!(page.page_id(), id, "returned invalid page-id for {}", page);
+            assert_eq!(
+                page.page_id(),
+                id,
+                "returned invalid page-id for {:?}",
+                page
+            );
+        }
+    }
+
+    #[test]
+    fn almanach_pages() {
+        for (page, sv_id) in [
+            (
+                GpsQzssFrame4::Page2(GpsQzssAlmanach::model().with_sv_id(25)),
+                25,
+            ),
+            (
+                GpsQzssFrame4::Page3(GpsQzssAlmanach::model().with_sv_id(26)),
+                26,
+            ),
+            (
+                GpsQzssFrame4::Page5(GpsQzssAlmanach::model().with_sv_id(28)),
+                28,
+            ),
+            (
+                GpsQzssFrame4::Page7(GpsQzssAlmanach::model().with_sv_id(29)),
+                29,
+            ),
+            (
+                GpsQzssFrame4::Page10(GpsQzssAlmanach::model().with_sv_id(32)),
+                32,
+            ),
+        ] {
+            assert_eq!(page.sv_id(), sv_id);
+
+            let words = page.to_words();
+            assert_eq!(super::sv_id(words[0]), sv_id);
+
+            let decoded = GpsQzssFrame4::from_words(&words).unwrap();
+            assert_eq!(
+                decoded,
+                page,
+                "reciprocal failed for page {}",
+                page.page_id()
+            );
+        }
+    }
+
+    #[test]
+    fn raw_pages() {
+        let raw = GpsQzssFrame4Raw::default()
+            .with_data(1, 0x123456)
+            .with_data(7, 0x3fff00);
+
+        for page in [
+            GpsQzssFrame4::Page1(raw),
+            GpsQzssFrame4::Page13(raw),
+            GpsQzssFrame4::Page17(raw),
+            GpsQzssFrame4::Page18(raw),
+        ] {
+            let words = page.to_words();
+            let decoded = GpsQzssFrame4::from_words(&words).unwrap();
+
+            assert_eq!(decoded.page_id(), page.page_id());
+            assert_eq!(decoded.to_words(), words);
         }
+
+        // page 6 shares its SV ID with page 1
+        let words = GpsQzssFrame4::Page6(raw).to_words();
+        let decoded = GpsQzssFrame4::from_words(&words).unwrap();
+        assert_eq!(decoded.page_id(), 1);
+    }
+
+    #[test]
+    fn config_health_page() {
+        let page = GpsQzssFrame4::Page25(
+            GpsQzssConfigHealth::default()
+                .with_sv_config(1, true, 0x01)
+                .with_sv_config(32, false, 0x02)
+                .with_sv_health(27, 0x3f),
+        );
+
+        assert_eq!(page.sv_id(), 63);
+
+        let words = page.to_words();
+        assert_eq!(super::sv_id(words[0]), 63);
+
+        let decoded = GpsQzssFrame4::from_words(&words).unwrap();
+        assert_eq!(decoded, page);
+    }
+
+    #[test]
+    fn invalid_page() {
+        let mut words = GpsQzssAlmanach::model().to_words();
+        words[0] = with_sv_id(words[0], 1);
+        assert!(GpsQzssFrame4::from_words(&words).is_err());
     }
 }
@@ -1,19 +1,51 @@
+use crate::gps::{GpsDataWord, GPS_WORDS_PER_FRAME};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const DATA_MASK: u32 = 0x3fffffc0;
+const DATA_SHIFT: u32 = 6;
+
 /// Uninterpreted (undocumented, classified, spare)
 /// Data from GPS/QZSS Frame 4 pages.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssFrame4Raw {
-    /// 24-bit data
-    pub data: u32,
+    /// 24-bit data of words #3 through #10.
+    /// Word #3 data includes the Data ID and SV ID (page ID).
+    pub data: [u32; GPS_WORDS_PER_FRAME - 2],
 }
 
 impl GpsQzssFrame4Raw {
-    pub(crate) fn to_word(&self) -> GpsDataWord {
-        GpsDataWord::from((self.data & 0x00ffffff) <<2)
+    /// Copies and returns [GpsQzssFrame4Raw] with updated 24-bit data,
+    /// for word #3 (index=0) through word #10 (index=7).
+    pub fn with_data(mut self, index: usize, data: u32) -> Self {
+        if let Some(value) = self.data.get_mut(index) {
+            *value = data & 0xffffff;
+        }
+        self
+    }
 
+    /// Decodes [Self] from 8 [GpsDataWord]s.
+    /// This method does not care for frames parity.
+    pub(crate) fn from_words(words: &[GpsDataWord]) -> Self {
+        let mut s = Self::default();
+
+        for (data, word) in s.data.iter_mut().zip(words.iter()) {
+            *data = (word.value() & DATA_MASK) >> DATA_SHIFT;
+        }
+
+        s
     }
 
-    pub(crate) fn from_word(word: GpsDataWord) -> Self {
-        Self {
-            data: (word.value() & 0x00ffffff),
+    /// Encodes this [GpsQzssFrame4Raw] as a burst of 8 [GpsDataWord]s.
+    pub(crate) fn to_words(self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
+        let mut words = [GpsDataWord::default(); GPS_WORDS_PER_FRAME - 2];
+
+        for (word, data) in words.iter_mut().zip(self.data.iter()) {
+            *word = GpsDataWord::from(((data & 0xffffff) << DATA_SHIFT) << 2);
         }
+
+        words
     }
 }
@@ -1,24 +1,26 @@
-use crate::gps::{
-    GpsDataWord,
-    GpsError,
-    GpsQzssAlmanach,
-};
+use crate::gps::{almanach::sv_id, GpsDataWord, GpsError, GpsQzssAlmanach, GPS_WORDS_PER_FRAME};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 mod status;
-pub use status::*;
+pub use status::GpsQzssAlmanachStatus;
+
+use status::PAGE25_SV_ID;
 
 /// [GpsQzssFrame5] message interpretation
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GpsQzssFrame5 {
     /// [GpsQzssAlmanach] for satellite #1
     Page1(GpsQzssAlmanach),
-    
+
     /// [GpsQzssAlmanach] for satellite #2
     Page2(GpsQzssAlmanach),
-    
+
     /// [GpsQzssAlmanach] for satellite #3
     Page3(GpsQzssAlmanach),
-    
+
     /// [GpsQzssAlmanach] for satellite #4
     Page4(GpsQzssAlmanach),
 
@@ -87,6 +89,13 @@ pub enum GpsQzssFrame5 {
     Page25(GpsQzssAlmanachStatus),
 }
 
+impl Default for GpsQzssFrame5 {
+    /// Builds a default [GpsQzssFrame5::Page1]
+    fn default() -> Self {
+        Self::Page1(Default::default())
+    }
+}
+
 impl GpsQzssFrame5 {
     /// Returns the page identification number of this [GpsQzssFrame5] interpretation
     pub fn page_id(&self) -> u8 {
@@ -119,74 +128,87 @@ impl GpsQzssFrame5 {
         }
     }
 
-    pub(crate) fn to_word(&self) -> GpsDataWord {
-        let mut word = match self {
-            Self::Page1(frame) => frame.to_word(),
-            Self::Page2(frame) => frame.to_word(),
-            Self::Page3(frame) => frame.to_word(),
-            Self::Page4(frame) => frame.to_word(),
-            Self::Page5(frame) => frame.to_word(),
-            Self::Page6(frame) => frame.to_word(),
-            Self::Page7(frame) => frame.to_word(),
-            Self::Page8(frame) => frame.to_word(),
-            Self::Page9(frame) => frame.to_word(),
-            Self::Page10(frame) => frame.to_word(),
-            Self::Page11(frame) => frame.to_word(),
-            Self::Page12(frame) => frame.to_word(),
-            Self::Page13(frame) => frame.to_word(),
-            Self::Page14(frame) => frame.to_word(),
-            Self::Page15(frame) => frame.to_word(),
-            Self::Page16(frame) => frame.to_word(),
-            Self::Page17(frame) => frame.to_word(),
-            Self::Page18(frame) => frame.to_word(),
-            Self::Page19(frame) => frame.to_word(),
-            Self::Page20(frame) => frame.to_word(),
-            Self::Page21(frame) => frame.to_word(),
-            Self::Page22(frame) => frame.to_word(),
-            Self::Page23(frame) => frame.to_word(),
-            Self::Page24(frame) => frame.to_word(),
-            Self::Page25(frame) => frame.to_word(),
-        };
-
-        word.with_page_id(self.page_id())
+    /// Returns the PRN number of the satellite described by this [GpsQzssFrame5] page:
+    /// pages 1 to 24 describe satellites #1 to #24. Page 25 is not an Almanach page.
+    pub fn prn(&self) -> Option<u8> {
+        match self {
+            Self::Page25(_) => None,
+            _ => Some(self.page_id()),
+        }
     }
 
-    /// Decodes this [GpsDataWord] as [GpsQzssFrame5],
-    /// if page is correct and supported.
-    pub(crate) fn from_word(word: GpsDataWord) -> Result<Self, GpsError> {
-        match word.page_id() {
-            1 => Ok(Self::Page1(GpsQzssAlmanach::from_word(word))),
-            2 => Ok(Self::Page2(GpsQzssAlmanach::from_word(word))),
-            3 => Ok(Self::Page3(GpsQzssAlmanach::from_word(word))),
-            4 => Ok(Self::Page4(GpsQzssAlmanach::from_word(word))),
-            5 => Ok(Self::Page5(GpsQzssAlmanach::from_word(word))),
-            6 => Ok(Self::Page6(GpsQzssAlmanach::from_word(word))),
-            7 => Ok(Self::Page7(GpsQzssAlmanach::from_word(word))),
-            8 => Ok(Self::Page8(GpsQzssAlmanach::from_word(word))),
-            9 => Ok(Self::Page9(GpsQzssAlmanach::from_word(word))),
-            10 => Ok(Self::Page10(GpsQzssAlmanach::from_word(word))),
-            11 => Ok(Self::Page11(GpsQzssAlmanach::from_word(word))),
-            12 => Ok(Self::Page12(GpsQzssAlmanach::from_word(word))),
-            13 => Ok(Self::Page13(GpsQzssAlmanach::from_word(word))),
-            14 => Ok(Self::Page14(GpsQzssAlmanach::from_word(word))),
-            15 => Ok(Self::Page15(GpsQzssAlmanach::from_word(word))),
-            16 => Ok(Self::Page16(GpsQzssAlmanach::from_word(word))),
-            17 => Ok(Self::Page17(GpsQzssAlmanach::from_word(word))),
-            18 => Ok(Self::Page18(GpsQzssAlmanach::from_word(word))),
-            19 => Ok(Self::Page19(GpsQzssAlmanach::from_word(word))),
-            20 => Ok(Self::Page20(GpsQzssAlmanach::from_word(word))),
-            21 => Ok(Self::Page21(GpsQzssAlmanach::from_word(word))),
-            22 => Ok(Self::Page22(GpsQzssAlmanach::from_word(word))),
-            23 => Ok(Self::Page23(GpsQzssAlmanach::from_word(word))),
-            24 => Ok(Self::Page24(GpsQzssAlmanach::from_word(word))),
-            25 => Ok(Self::Page25(GpsQzssAlmanachStatus::from_word(word))),
+    /// Encodes this [GpsQzssFrame5] as a burst of 8 [GpsDataWord]s.
+    /// The SV ID (page ID) of word #3 is set according to the page number.
+    pub(crate) fn to_words(self) -> [GpsDataWord; GPS_WORDS_PER_FRAME - 2] {
+        match self {
+            Self::Page1(almanach) => almanach.with_sv_id(1).to_words(),
+            Self::Page2(almanach) => almanach.with_sv_id(2).to_words(),
+            Self::Page3(almanach) => almanach.with_sv_id(3).to_words(),
+            Self::Page4(almanach) => almanach.with_sv_id(4).to_words(),
+            Self::Page5(almanach) => almanach.with_sv_id(5).to_words(),
+            Self::Page6(almanach) => almanach.with_sv_id(6).to_words(),
+            Self::Page7(almanach) => almanach.with_sv_id(7).to_words(),
+            Self::Page8(almanach) => almanach.with_sv_id(8).to_words(),
+            Self::Page9(almanach) => almanach.with_sv_id(9).to_words(),
+            Self::Page10(almanach) => almanach.with_sv_id(10).to_words(),
+            Self::Page11(almanach) => almanach.with_sv_id(11).to_words(),
+            Self::Page12(almanach) => almanach.with_sv_id(12).to_words(),
+            Self::Page13(almanach) => almanach.with_sv_id(13).to_words(),
+            Self::Page14(almanach) => almanach.with_sv_id(14).to_words(),
+            Self::Page15(almanach) => almanach.with_sv_id(15).to_words(),
+            Self::Page16(almanach) => almanach.with_sv_id(16).to_words(),
+            Self::Page17(almanach) => almanach.with_sv_id(17).to_words(),
+            Self::Page18(almanach) => almanach.with_sv_id(18).to_words(),
+            Self::Page19(almanach) => almanach.with_sv_id(19).to_words(),
+            Self::Page20(almanach) => almanach.with_sv_id(20).to_words(),
+            Self::Page21(almanach) => almanach.with_sv_id(21).to_words(),
+            Self::Page22(almanach) => almanach.with_sv_id(22).to_words(),
+            Self::Page23(almanach) => almanach.with_sv_id(23).to_words(),
+            Self::Page24(almanach) => almanach.with_sv_id(24).to_words(),
+            Self::Page25(status) => status.to_words(),
+        }
+    }
+
+    /// Decodes [GpsQzssFrame5] from 8 [GpsDataWord]s,
+    /// dispatching on the SV ID (page ID) of word #3.
+    /// This method does not care for frames parity.
+    pub(crate) fn from_words(words: &[GpsDataWord]) -> Result<Self, GpsError> {
+        let word3 = words.first().ok_or(GpsError::InvalidPage)?;
+
+        match sv_id(*word3) {
+            1 => Ok(Self::Page1(GpsQzssAlmanach::from_words(words))),
+            2 => Ok(Self::Page2(GpsQzssAlmanach::from_words(words))),
+            3 => Ok(Self::Page3(GpsQzssAlmanach::from_words(words))),
+            4 => Ok(Self::Page4(GpsQzssAlmanach::from_words(words))),
+            5 => Ok(Self::Page5(GpsQzssAlmanach::from_words(words))),
+            6 => Ok(Self::Page6(GpsQzssAlmanach::from_words(words))),
+            7 => Ok(Self::Page7(GpsQzssAlmanach::from_words(words))),
+            8 => Ok(Self::Page8(GpsQzssAlmanach::from_words(words))),
+            9 => Ok(Self::Page9(GpsQzssAlmanach::from_words(words))),
+            10 => Ok(Self::Page10(GpsQzssAlmanach::from_words(words))),
+            11 => Ok(Self::Page11(GpsQzssAlmanach::from_words(words))),
+            12 => Ok(Self::Page12(GpsQzssAlmanach::from_words(words))),
+            13 => Ok(Self::Page13(GpsQzssAlmanach::from_words(words))),
+            14 => Ok(Self::Page14(GpsQzssAlmanach::from_words(words))),
+            15 => Ok(Self::Page15(GpsQzssAlmanach::from_words(words))),
+            16 => Ok(Self::Page16(GpsQzssAlmanach::from_words(words))),
+            17 => Ok(Self::Page17(GpsQzssAlmanach::from_words(words))),
+            18 => Ok(Self::Page18(GpsQzssAlmanach::from_words(words))),
+            19 => Ok(Self::Page19(GpsQzssAlmanach::from_words(words))),
+            20 => Ok(Self::Page20(GpsQzssAlmanach::from_words(words))),
+            21 => Ok(Self::Page21(GpsQzssAlmanach::from_words(words))),
+            22 => Ok(Self::Page22(GpsQzssAlmanach::from_words(words))),
+            23 => Ok(Self::Page23(GpsQzssAlmanach::from_words(words))),
+            24 => Ok(Self::Page24(GpsQzssAlmanach::from_words(words))),
+            PAGE25_SV_ID => Ok(Self::Page25(GpsQzssAlmanachStatus::from_words(words))),
             _ => Err(GpsError::InvalidPage),
         }
     }
 }
 
 #[cfg(test)]
-mod frame5 {
+mod test {
+    use super::*;
 
     #[test]
     fn pagination_id() {
@@ -200,7 +222,74 @@ mod frame5 {
             (GpsQzssFrame5::Page24(Default::default()), 24),
             (GpsQzssFrame5::Page25(Default::default()), 25),
         ] {
-            assert_eq!(page.page_id(), id, "returned invalid page-id for {}", page);
+            assert_eq!(
+                page.page_id(),
+                id,
+                "returned invalid page-id for {:?}",
+                page
+            );
         }
     }
+
+    #[test]
+    fn reciprocal() {
+        let status = GpsQzssAlmanachStatus::default()
+            .with_toa_seconds(319_488)
+            .with_week(12)
+            .with_sv_health(3, 0x3f);
+
+        let mut pages = [GpsQzssFrame5::Page25(status); 25];
+
+        for (i, page) in pages.iter_mut().take(24).enumerate() {
+            let sv_id = i as u8 + 1;
+            let almanach = GpsQzssAlmanach::model().with_sv_id(sv_id);
+
+            *page = match sv_id {
+                1 => GpsQzssFrame5::Page1(almanach),
+                2 => GpsQzssFrame5::Page2(almanach),
+                3 => GpsQzssFrame5::Page3(almanach),
+                4 => GpsQzssFrame5::Page4(almanach),
+                5 => GpsQzssFrame5::Page5(almanach),
+                6 => GpsQzssFrame5::Page6(almanach),
+                7 => GpsQzssFrame5::Page7(almanach),
+                8 => GpsQzssFrame5::Page8(almanach),
+                9 => GpsQzssFrame5::Page9(almanach),
+                10 => GpsQzssFrame5::Page10(almanach),
+                11 => GpsQzssFrame5::Page11(almanach),
+                12 => GpsQzssFrame5::Page12(almanach),
+                13 => GpsQzssFrame5::Page13(almanach),
+                14 => GpsQzssFrame5::Page14(almanach),
+                15 => GpsQzssFrame5::Page15(almanach),
+                16 => GpsQzssFrame5::Page16(almanach),
+                17 => GpsQzssFrame5::Page17(almanach),
+                18 => GpsQzssFrame5::Page18(almanach),
+                19 => GpsQzssFrame5::Page19(almanach),
+                20 => GpsQzssFrame5::Page20(almanach),
+                21 => GpsQzssFrame5::Page21(almanach),
+                22 => GpsQzssFrame5::Page22(almanach),
+                23 => GpsQzssFrame5::Page23(almanach),
+                24 => GpsQzssFrame5::Page24(almanach),
+                _ => unreachable!(),
+            };
+        }
+
+        for (i, page) in pages.iter().enumerate() {
+            assert_eq!(page.page_id() as usize, i + 1);
+
+            let words = page.to_words();
+            let decoded = GpsQzssFrame5::from_words(&words)
+                .unwrap_or_else(|e| panic!("failed to decode page {}: {}", i + 1, e));
+
+            assert_eq!(decoded, *page, "reciprocal failed for page {}", i + 1);
+        }
+    }
+
+    #[test]
+    fn invalid_page() {
+        let mut words = GpsQzssAlmanach::model().to_words();
+
+        // SV ID #63 is not a Frame-5 page
+        words[0] = GpsDataWord::from(words[0].value() << 2 | 0x3f00_0000);
+        assert!(GpsQzssFrame5::from_words(&words).is_err());
+    }
 }
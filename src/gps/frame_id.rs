@@ -1,6 +1,10 @@
-use crate::gps::GpsError;
+use crate::gps::{GpsError, GpsQzssSubframe};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GpsQzssFrameId {
     #[default]
     /// GPS / QZSS Ephemeris subframe #1
@@ -11,27 +15,56 @@ pub enum GpsQzssFrameId {
 
     /// GPS / QZSS Ephemeris subframe #3
     Ephemeris3,
+
+    /// GPS / QZSS subframe #4 (paginated)
+    Ephemeris4,
+
+    /// GPS / QZSS subframe #5 (paginated)
+    Ephemeris5,
 }
 
 #[cfg(feature = "std")]
-impl std::fmt::Display for GpsQzssFrameId {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GpsQzssFrameId {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             Self::Ephemeris1 => write!(f, "EPH-1"),
             Self::Ephemeris2 => write!(f, "EPH-2"),
             Self::Ephemeris3 => write!(f, "EPH-3"),
+            Self::Ephemeris4 => write!(f, "EPH-4"),
+            Self::Ephemeris5 => write!(f, "EPH-5"),
         }
     }
 }
 
 impl GpsQzssFrameId {
-    /// [GpsQzssFrameId] decoding attempt
-    pub(crate) fn decode(mask: u8) -> Result<Self, GpsError> {
+    /// [GpsQzssFrameId] decoding attempt, from the 3-bit subframe ID of the HOW.
+    /// Returns [GpsError::UnknownFrameType] for values other than 1 to 5.
+    pub fn decode(mask: u8) -> Result<Self, GpsError> {
         match mask {
             1 => Ok(Self::Ephemeris1),
             2 => Ok(Self::Ephemeris2),
             3 => Ok(Self::Ephemeris3),
-            _ => Err(GpsError::UnknownFrameType),
+            4 => Ok(Self::Ephemeris4),
+            5 => Ok(Self::Ephemeris5),
+            _ => Err(GpsError::UnknownFrameType(mask)),
+        }
+    }
+
+    /// Converts a subframe number (1 to 5) to [GpsQzssFrameId].
+    pub fn from_u8(value: u8) -> Option<Self> {
+        Self::decode(value).ok()
+    }
+
+    /// Returns the [GpsQzssFrameId] that describes this [GpsQzssSubframe],
+    /// which is not known for [GpsQzssSubframe::Raw] data.
+    pub fn from_subframe(subframe: &GpsQzssSubframe) -> Option<Self> {
+        match subframe {
+            GpsQzssSubframe::Ephemeris1(_) => Some(Self::Ephemeris1),
+            GpsQzssSubframe::Ephemeris2(_) => Some(Self::Ephemeris2),
+            GpsQzssSubframe::Ephemeris3(_) => Some(Self::Ephemeris3),
+            GpsQzssSubframe::Frame4(_) => Some(Self::Ephemeris4),
+            GpsQzssSubframe::Frame5(_) => Some(Self::Ephemeris5),
+            GpsQzssSubframe::Raw(_) => None,
         }
     }
 
@@ -41,6 +74,8 @@ impl GpsQzssFrameId {
             Self::Ephemeris1 => 1,
             Self::Ephemeris2 => 2,
             Self::Ephemeris3 => 3,
+            Self::Ephemeris4 => 4,
+            Self::Ephemeris5 => 5,
         }
     }
 }
@@ -48,6 +83,7 @@ impl GpsQzssFrameId {
 #[cfg(test)]
 mod test {
     use super::GpsQzssFrameId;
+    use crate::gps::{GpsError, GpsQzssSubframe};
 
     #[test]
     fn frame_id_decoding() {
@@ -55,9 +91,42 @@ mod test {
             (1, GpsQzssFrameId::Ephemeris1),
             (2, GpsQzssFrameId::Ephemeris2),
             (3, GpsQzssFrameId::Ephemeris3),
+            (4, GpsQzssFrameId::Ephemeris4),
+            (5, GpsQzssFrameId::Ephemeris5),
         ] {
             let frame_id = GpsQzssFrameId::decode(value).unwrap();
             assert_eq!(frame_id, expected);
+            assert_eq!(frame_id.encode(), value);
+
+            // reciprocal
+            assert_eq!(GpsQzssFrameId::decode(frame_id.encode()), Ok(frame_id));
+            assert_eq!(GpsQzssFrameId::from_u8(value), Some(frame_id));
         }
+
+        for value in [0, 6, 7] {
+            assert_eq!(
+                GpsQzssFrameId::decode(value),
+                Err(GpsError::UnknownFrameType(value))
+            );
+
+            assert!(GpsQzssFrameId::from_u8(value).is_none());
+        }
+    }
+
+    #[test]
+    fn from_subframe() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+            GpsQzssFrameId::Ephemeris4,
+            GpsQzssFrameId::Ephemeris5,
+        ] {
+            let subframe = GpsQzssSubframe::model(frame_id);
+            assert_eq!(GpsQzssFrameId::from_subframe(&subframe), Some(frame_id));
+        }
+
+        let subframe = GpsQzssSubframe::Raw(Default::default());
+        assert_eq!(GpsQzssFrameId::from_subframe(&subframe), None);
     }
 }
@@ -1,5 +1,8 @@
 use crate::gps::{GpsDataWord, GpsError};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 const ZCOUNT_MASK: u32 = 0x3fffE000;
 const ZCOUNT_SHIFT: u32 = 13;
 
@@ -9,6 +12,9 @@ const AS_MASK: u32 = 0x00000800;
 const FRAMEID_MASK: u32 = 0x00000700;
 const FRAMEID_SHIFT: u32 = 8;
 
+/// Number of seconds in one GPS week
+pub(crate) const GPS_WEEK_SECONDS: u32 = 604_800;
+
 use crate::gps::GpsQzssFrameId;
 
 #[cfg(doc)]
@@ -17,6 +23,7 @@ use crate::gps::GpsQzssTelemetry;
 /// [GpsQzssHow] (GPS Hand Over Word) marks the beginning of each frame, following [GpsQzssTelemetry],
 /// and defines the content to follow.
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssHow {
     /// TOW: elapsed time within current GPS week (in seconds),
     /// at the instant of transmission of the 1st bit of the next frame to follow
@@ -41,8 +48,8 @@ pub struct GpsQzssHow {
 }
 
 #[cfg(feature = "std")]
-impl std::fmt::Display for GpsQzssHow {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GpsQzssHow {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
             f,
             "{} TOW={} - ALERT={} - A/S={}",
@@ -64,12 +71,19 @@ impl GpsQzssHow {
 
     /// Copies and returns [GpsQzssHow] with updated TOW in seconds.
     /// This value should be aligned to midnight and always a multiple of 6 seconds,
-    /// the message transmission rate.
+    /// the message transmission rate: the TOW count is truncated to the previous 6 second boundary
+    /// when encoded. Values past the end of week (604800 s) roll over to the beginning of the next week.
     pub fn with_tow_seconds(mut self, tow_seconds: u32) -> Self {
-        self.tow = tow_seconds;
+        self.tow = tow_seconds % GPS_WEEK_SECONDS;
         self
     }
 
+    /// Returns the raw 17-bit TOW count (truncated Z-count), in units of 6 seconds,
+    /// as encoded in this [GpsQzssHow]. Ranges from 0 to 100799.
+    pub fn tow_count(&self) -> u32 {
+        (self.tow % GPS_WEEK_SECONDS) / 6
+    }
+
     /// Copies and returns [GpsQzssHow] with updated [GpsQzssFrameId]
     pub fn with_frame_id(mut self, frame_id: GpsQzssFrameId) -> Self {
         self.frame_id = frame_id;
@@ -100,6 +114,17 @@ impl GpsQzssHow {
         self
     }
 
+    /// Returns true if the alert bit is asserted: the URA may be worse than indicated
+    /// in subframe 1 and this satellite should be used at your own risk.
+    pub fn is_alert(&self) -> bool {
+        self.alert
+    }
+
+    /// Returns true if the A/S bit is asserted (Anti-Spoofing is active).
+    pub fn is_anti_spoofing(&self) -> bool {
+        self.anti_spoofing
+    }
+
     /// Constructs a default EPH-1 [GpsQzssHow]
     pub fn ephemeris1() -> Self {
         Self::default().with_frame_id(GpsQzssFrameId::Ephemeris1)
@@ -129,7 +154,7 @@ impl GpsQzssHow {
             alert,
             frame_id,
             anti_spoofing,
-            tow: zcount * 3 / 2,
+            tow: zcount * 6,
         })
     }
 
@@ -145,7 +170,7 @@ impl GpsQzssHow {
             value |= AS_MASK;
         }
 
-        value |= ((self.tow * 2 / 3) & 0x1ffff) << ZCOUNT_SHIFT;
+        value |= (self.tow_count() & 0x1ffff) << ZCOUNT_SHIFT;
         value += (self.frame_id.encode() as u32) << FRAMEID_SHIFT;
 
         // TODO parity
@@ -186,4 +211,41 @@ mod how {
             assert_eq!(decoded.anti_spoofing, anti_spoofing);
         }
     }
+
+    #[test]
+    fn end_of_week() {
+        let how = GpsQzssHow::default().with_tow_seconds(604_794);
+        assert_eq!(how.tow, 604_794);
+        assert_eq!(how.tow_count(), 100_799);
+
+        let decoded = GpsQzssHow::from_word(how.to_word()).unwrap();
+        assert_eq!(decoded.tow, 604_794);
+
+        // rollover
+        for (tow_seconds, expected) in [(604_800, 0), (604_806, 6), (2 * 604_800 + 12, 12)] {
+            let how = GpsQzssHow::default().with_tow_seconds(tow_seconds);
+            assert_eq!(how.tow, expected, "invalid rollover for {}", tow_seconds);
+            assert_eq!(how.tow_count(), expected / 6);
+        }
+    }
+
+    #[test]
+    fn six_second_alignment() {
+        for (tow_seconds, tow_count) in [
+            (0, 0),
+            (5, 0),
+            (6, 1),
+            (11, 1),
+            (15_000, 2500),
+            (15_005, 2500),
+        ] {
+            let how = GpsQzssHow::default().with_tow_seconds(tow_seconds);
+            assert_eq!(how.tow_count(), tow_count);
+
+            // truncated to previous 6 second boundary
+            let decoded = GpsQzssHow::from_word(how.to_word()).unwrap();
+            assert_eq!(decoded.tow, tow_count * 6);
+            assert_eq!(decoded.tow_count(), tow_count);
+        }
+    }
 }
@@ -25,8 +25,25 @@ pub(crate) const GPS_PARITY_SIZE: usize = 6;
 // /// L1 C/A code length
 // pub const GPS_L1_CA_CODE_LEN: usize = 1023;
 
+/// Converts this angle (in radians) to semicircles (π radians)
 pub(crate) fn rad_to_semicircles(rad: f64) -> f64 {
-    rad.to_degrees() * (2.0_f64.powi(31) / 90.0)
+    rad / core::f64::consts::PI
+}
+
+/// Returns `t - t_ref` (in seconds), both being GPS times of week (in seconds),
+/// accounting for beginning or end of week crossovers.
+pub(crate) fn time_of_week_difference(t: f64, t_ref: f64) -> f64 {
+    const HALF_WEEK_SECONDS: f64 = 302_400.0;
+
+    let dt = t - t_ref;
+
+    if dt > HALF_WEEK_SECONDS {
+        dt - 2.0 * HALF_WEEK_SECONDS
+    } else if dt < -HALF_WEEK_SECONDS {
+        dt + 2.0 * HALF_WEEK_SECONDS
+    } else {
+        dt
+    }
 }
 
 mod bytes;
@@ -38,15 +55,30 @@ pub use word::GpsDataWord;
 // mod cdma;
 // pub use cdma::GpsQzssModulator;
 
-// mod almanach;
-// pub use almanach::GpsQzssAlmanach;
+mod almanach;
+pub use almanach::GpsQzssAlmanach;
+
+mod cnav;
+pub use cnav::{
+    GpsCnavDecoder, GpsCnavFrame, GpsCnavMessage, GpsCnavType10, GpsCnavType11, GpsCnavType30,
+    GPS_CNAV_MESSAGE_BITS, GPS_CNAV_MESSAGE_BYTES,
+};
+
+mod constellation;
+pub use constellation::GpsQzssConstellation;
 
 mod decoder;
-pub use decoder::GpsQzssDecoder;
+pub use decoder::{GpsQzssDecoder, GpsQzssDecoderStats};
 
 mod decoding;
+
+mod demux;
+pub use demux::{demux_tagged_words, GPS_TAGGED_WORD_BYTES};
 mod encoding;
 
+mod encoder;
+pub use encoder::GpsQzssEncoder;
+
 mod errors;
 pub use errors::GpsError;
 
@@ -59,11 +91,11 @@ pub use frame2::GpsQzssFrame2;
 mod frame3;
 pub use frame3::GpsQzssFrame3;
 
-// mod frame4;
-// pub use frame4::GpsQzssFrame4;
+mod frame4;
+pub use frame4::{GpsQzssConfigHealth, GpsQzssFrame4, GpsQzssFrame4Raw, GpsQzssSatHealth};
 
-// mod frame5;
-// pub use frame5::GpsQzssFrame5;
+mod frame5;
+pub use frame5::{GpsQzssAlmanachStatus, GpsQzssFrame5};
 
 mod frame_id;
 pub use frame_id::GpsQzssFrameId;
@@ -78,7 +110,19 @@ mod frame;
 pub use frame::GpsQzssFrame;
 
 mod subframe;
-pub use subframe::GpsQzssSubframe;
+pub use subframe::{GpsQzssSubframe, GpsQzssSubframeKind};
+
+mod svn;
+pub use svn::{GpsSatelliteBlock, GpsSatelliteVehicle};
+
+#[cfg(feature = "orbit")]
+mod orbit;
+
+#[cfg(feature = "orbit")]
+pub use orbit::GpsQzssEphemeris;
+
+#[cfg(feature = "rinex")]
+mod rinex;
 
 #[cfg(test)]
 mod test {
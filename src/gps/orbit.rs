@@ -0,0 +1,331 @@
+use crate::gps::{
+    time_of_week_difference, GpsError, GpsQzssFrame, GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3,
+    GpsQzssHow, GpsQzssSubframe, GpsQzssTelemetry,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use core::f64::consts::PI;
+
+/// Duration of one subframe (in seconds)
+const SUBFRAME_SECONDS: u32 = 6;
+
+/// WGS-84 Earth rotation rate (in rad.s⁻¹)
+const OMEGA_E_RAD_S: f64 = 7.2921151467e-5;
+
+/// [GpsQzssEphemeris] gathers the three ephemeris frames of one satellite,
+/// which are required to propagate its orbit.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(docsrs, doc(cfg(feature = "orbit")))]
+pub struct GpsQzssEphemeris {
+    /// [GpsQzssFrame1] (clock and health)
+    pub frame1: GpsQzssFrame1,
+
+    /// [GpsQzssFrame2] (orbit, first part)
+    pub frame2: GpsQzssFrame2,
+
+    /// [GpsQzssFrame3] (orbit, second part)
+    pub frame3: GpsQzssFrame3,
+}
+
+impl GpsQzssEphemeris {
+    /// Builds a new [GpsQzssEphemeris] from the three ephemeris frames.
+    pub fn new(frame1: GpsQzssFrame1, frame2: GpsQzssFrame2, frame3: GpsQzssFrame3) -> Self {
+        Self {
+            frame1,
+            frame2,
+            frame3,
+        }
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated [GpsQzssFrame1]
+    pub fn with_frame1(mut self, frame1: GpsQzssFrame1) -> Self {
+        self.frame1 = frame1;
+        self
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated [GpsQzssFrame2]
+    pub fn with_frame2(mut self, frame2: GpsQzssFrame2) -> Self {
+        self.frame2 = frame2;
+        self
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated [GpsQzssFrame3]
+    pub fn with_frame3(mut self, frame3: GpsQzssFrame3) -> Self {
+        self.frame3 = frame3;
+        self
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated 10-bit week number.
+    pub fn with_week(mut self, week: u16) -> Self {
+        self.frame1 = self.frame1.with_week(week);
+        self
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated issue of data:
+    /// IODE of [GpsQzssFrame2] and [GpsQzssFrame3] and the 8 LSBs of the IODC
+    /// of [GpsQzssFrame1]. The 2 MSBs of the IODC are preserved.
+    pub fn with_issue_of_data(mut self, iode: u8) -> Self {
+        self.frame1 = self
+            .frame1
+            .with_iodc((self.frame1.iodc & 0x300) | iode as u16);
+
+        self.frame2 = self.frame2.with_iode(iode);
+        self.frame3 = self.frame3.with_iode(iode);
+        self
+    }
+
+    /// Copies and returns [GpsQzssEphemeris] with updated reference time (in seconds
+    /// of week), which is both the time of clock (toc) and the time of ephemeris (toe).
+    pub fn with_reference_time_seconds(mut self, seconds: u32) -> Self {
+        self.frame1 = self.frame1.with_time_of_clock_seconds(seconds);
+        self.frame2 = self.frame2.with_toe_seconds(seconds);
+        self
+    }
+
+    /// Verifies the consistency of this [GpsQzssEphemeris]:
+    /// - IODE of [GpsQzssFrame2] and [GpsQzssFrame3] must match the 8 LSBs of the IODC
+    ///   of [GpsQzssFrame1], otherwise [GpsError::IssueOfDataMismatch] is returned.
+    /// - toc and toe must be identical, otherwise [GpsError::ReferenceTimeMismatch] is returned.
+    pub fn validate(&self) -> Result<(), GpsError> {
+        let iodc_lsb = (self.frame1.iodc & 0xff) as u8;
+
+        if self.frame2.iode != self.frame3.iode || self.frame2.iode != iodc_lsb {
+            return Err(GpsError::IssueOfDataMismatch);
+        }
+
+        if self.frame1.toc != self.frame2.toe {
+            return Err(GpsError::ReferenceTimeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Converts this [GpsQzssEphemeris] to the three successive [GpsQzssFrame]s
+    /// that transmit it (subframes 1, 2 and 3), once validated (see [Self::validate]).
+    ///
+    /// ## Input
+    /// - how: [GpsQzssHow] of the first frame. Following frames are transmitted
+    ///   6 seconds later each (TOW is incremented).
+    /// - telemetry: [GpsQzssTelemetry] shared by all frames.
+    pub fn to_frames(
+        &self,
+        how: GpsQzssHow,
+        telemetry: GpsQzssTelemetry,
+    ) -> Result<[GpsQzssFrame; 3], GpsError> {
+        self.validate()?;
+
+        let subframes = [
+            GpsQzssSubframe::Ephemeris1(self.frame1),
+            GpsQzssSubframe::Ephemeris2(self.frame2),
+            GpsQzssSubframe::Ephemeris3(self.frame3),
+        ];
+
+        let mut frames: [GpsQzssFrame; 3] = Default::default();
+
+        for (i, (frame, subframe)) in frames.iter_mut().zip(subframes).enumerate() {
+            *frame = GpsQzssFrame::default()
+                .with_telemetry(telemetry)
+                .with_hand_over_word(how.with_tow_seconds(how.tow + i as u32 * SUBFRAME_SECONDS))
+                .with_subframe(subframe);
+        }
+
+        Ok(frames)
+    }
+
+    /// Computes the satellite position (in meters), expressed in the ECEF (WGS-84) frame,
+    /// by propagating this broadcast [GpsQzssEphemeris] as specified in IS-GPS-200 (table 20-IV).
+    ///
+    /// ## Input
+    /// - t_gps_seconds: GPS time of week (in seconds), at instant of transmission.
+    ///   Beginning and end of week crossovers are taken care of.
+    ///
+    /// ## Output
+    /// - (x, y, z) ECEF coordinates (in meters)
+    pub fn satellite_position_ecef(&self, t_gps_seconds: f64) -> (f64, f64, f64) {
+        let (frame2, frame3) = (&self.frame2, &self.frame3);
+
+        let a = frame2.semi_major_axis_meters();
+        let toe = frame2.toe as f64;
+        let tk = time_of_week_difference(t_gps_seconds, toe);
+
+        let ek = frame2.eccentric_anomaly_radians(t_gps_seconds);
+
+        // true anomaly and argument of latitude
+        let e = frame2.e;
+        let vk = ((1.0 - e * e).sqrt() * ek.sin()).atan2(ek.cos() - e);
+        let phik = vk + frame3.omega * PI;
+
+        // second harmonic perturbations
+        let (sin2phi, cos2phi) = (2.0 * phik).sin_cos();
+
+        let duk = frame2.cus * sin2phi + frame2.cuc * cos2phi;
+        let drk = frame2.crs * sin2phi + frame3.crc * cos2phi;
+        let dik = frame3.cis * sin2phi + frame3.cic * cos2phi;
+
+        // corrected argument of latitude, radius and inclination
+        let uk = phik + duk;
+        let rk = a * (1.0 - e * ek.cos()) + drk;
+        let ik = (frame3.i0 + frame3.idot * tk) * PI + dik;
+
+        // position in the orbital plane
+        let (xk, yk) = (rk * uk.cos(), rk * uk.sin());
+
+        // corrected longitude of ascending node
+        let omegak =
+            frame3.omega0 * PI + (frame3.omega_dot * PI - OMEGA_E_RAD_S) * tk - OMEGA_E_RAD_S * toe;
+
+        let (sin_omegak, cos_omegak) = omegak.sin_cos();
+        let (sin_ik, cos_ik) = ik.sin_cos();
+
+        (
+            xk * cos_omegak - yk * cos_ik * sin_omegak,
+            xk * sin_omegak + yk * cos_ik * cos_omegak,
+            yk * sin_ik,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ephemeris(toe: u32) -> GpsQzssEphemeris {
+        let frame2 = GpsQzssFrame2::default()
+            .with_toe_seconds(toe)
+            .with_mean_anomaly_semicircles(0.2)
+            .with_mean_motion_difference_semicircles(1.4e-9)
+            .with_eccentricity(0.0125)
+            .with_square_root_semi_major_axis(5153.6)
+            .with_cuc_radians(-1.2e-6)
+            .with_cus_radians(7.5e-6)
+            .with_crs_meters(-45.0);
+
+        let frame3 = GpsQzssFrame3 {
+            cic: 1.1e-7,
+            cis: -6.0e-8,
+            crc: 250.0,
+            i0: 0.31,
+            iode: 0,
+            idot: 1.0e-11,
+            omega0: -0.35,
+            omega: 0.45,
+            omega_dot: -2.6e-9,
+        };
+
+        GpsQzssEphemeris::default()
+            .with_frame2(frame2)
+            .with_frame3(frame3)
+    }
+
+    #[test]
+    fn satellite_position() {
+        // reference positions obtained with an independent implementation of IS-GPS-200
+        for (toe, t, (x_m, y_m, z_m)) in [
+            (
+                345_600,
+                345_600.0,
+                (7211654.953994, 16419893.197510, 19226728.749378),
+            ),
+            (
+                345_600,
+                347_400.0,
+                (5579924.717084, 20264346.972833, 15897286.070198),
+            ),
+            (
+                345_600,
+                338_400.0,
+                (19036818.882505, 1062630.759157, 18053121.053534),
+            ),
+            // end of week crossover
+            (
+                0,
+                604_000.0,
+                (7133929.631843, 15097188.363909, 20281656.902019),
+            ),
+        ] {
+            let (x, y, z) = ephemeris(toe).satellite_position_ecef(t);
+
+            assert!((x - x_m).abs() < 1e-3, "x error: {} (t={})", x - x_m, t);
+            assert!((y - y_m).abs() < 1e-3, "y error: {} (t={})", y - y_m, t);
+            assert!((z - z_m).abs() < 1e-3, "z error: {} (t={})", z - z_m, t);
+        }
+    }
+
+    #[test]
+    fn frames_builder() {
+        let eph = ephemeris(345_600)
+            .with_week(2087)
+            .with_issue_of_data(33)
+            .with_reference_time_seconds(345_600);
+
+        assert_eq!(eph.frame1.week, 2087 % 1024);
+        assert_eq!(eph.frame1.iodc, 33);
+        assert_eq!(eph.validate(), Ok(()));
+
+        let how = GpsQzssHow::default().with_tow_seconds(345_594);
+        let telemetry = GpsQzssTelemetry::default().with_message(0x123);
+
+        let frames = eph.to_frames(how, telemetry).unwrap();
+
+        for (i, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.how.frame_id.encode(), i as u8 + 1);
+            assert_eq!(frame.how.tow, 345_594 + 6 * i as u32);
+            assert_eq!(frame.telemetry, telemetry);
+        }
+
+        assert_eq!(frames[0].subframe, GpsQzssSubframe::Ephemeris1(eph.frame1));
+        assert_eq!(frames[1].subframe, GpsQzssSubframe::Ephemeris2(eph.frame2));
+        assert_eq!(frames[2].subframe, GpsQzssSubframe::Ephemeris3(eph.frame3));
+
+        // IODC MSBs are preserved
+        let eph = eph
+            .with_frame1(eph.frame1.with_iodc(0x321))
+            .with_issue_of_data(34);
+
+        assert_eq!(eph.frame1.iodc, 0x322);
+        assert_eq!(eph.validate(), Ok(()));
+    }
+
+    #[test]
+    fn frames_consistency() {
+        let eph = ephemeris(345_600)
+            .with_issue_of_data(33)
+            .with_reference_time_seconds(345_600);
+
+        let how = GpsQzssHow::default();
+        let telemetry = GpsQzssTelemetry::default();
+
+        for eph in [
+            eph.with_frame2(eph.frame2.with_iode(34)),
+            eph.with_frame3(eph.frame3.with_iode(34)),
+            eph.with_frame1(eph.frame1.with_iodc(0x122)),
+        ] {
+            assert_eq!(eph.validate(), Err(GpsError::IssueOfDataMismatch));
+            assert_eq!(
+                eph.to_frames(how, telemetry),
+                Err(GpsError::IssueOfDataMismatch)
+            );
+        }
+
+        let eph = eph.with_frame2(eph.frame2.with_toe_seconds(352_800));
+        assert_eq!(eph.validate(), Err(GpsError::ReferenceTimeMismatch));
+    }
+
+    #[test]
+    fn circular_orbit() {
+        // circular, equatorial and unperturbed orbit: radius is the semi-major axis
+        let frame2 = GpsQzssFrame2::default().with_square_root_semi_major_axis(5153.6);
+        let eph = GpsQzssEphemeris::default().with_frame2(frame2);
+
+        for t in [0.0, 1_000.0, 100_000.0, 600_000.0] {
+            let (x, y, z) = eph.satellite_position_ecef(t);
+            let r = (x * x + y * y + z * z).sqrt();
+
+            assert!((r - 5153.6 * 5153.6).abs() < 1e-6, "radius error: {}", r);
+            assert!(z.abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::gps::{GpsQzssConstellation, GpsQzssEphemeris};
+
+use core::f64::consts::PI;
+
+use std::string::String;
+
+/// Number of days between 1970-01-01 and the GPS epoch (1980-01-06)
+const GPS_EPOCH_UNIX_DAYS: i64 = 3657;
+
+/// Transmission time of message, when not known (RINEX 3)
+const UNKNOWN_TRANSMISSION_TIME: f64 = 0.9999e9;
+
+/// Formats a [f64] as a RINEX D19.12 field (one leading digit, D-exponent).
+fn format_d19(value: f64) -> String {
+    let formatted = format!("{:.12e}", value);
+
+    let (mantissa, exponent) = formatted.split_at(formatted.find('e').unwrap_or(formatted.len()));
+    let exponent = exponent.trim_start_matches('e').parse::<i32>().unwrap_or(0);
+
+    let sign = if exponent < 0 { '-' } else { '+' };
+
+    format!("{:>15}D{}{:02}", mantissa, sign, exponent.abs())
+}
+
+/// Converts a number of days since 1970-01-01 to (year, month, day).
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+impl GpsQzssEphemeris {
+    /// Formats this [GpsQzssEphemeris] as a RINEX 3 (GPS) navigation record:
+    /// the SV / EPOCH / SV CLK line followed by the 7 broadcast orbit lines,
+    /// with all parameters scaled to SI units.
+    ///
+    /// ## Input
+    /// - prn: satellite PRN number
+    /// - gps_week: continuous GPS week number of the time of clock (toc).
+    ///   It resolves the 10-bit week counter of [GpsQzssFrame1](crate::gps::GpsQzssFrame1).
+    ///
+    /// The transmission time of message is not known from the ephemeris frames
+    /// and is reported as 0.9999E9, as specified by RINEX.
+    pub fn to_rinex_nav_record(&self, prn: u8, gps_week: u32) -> String {
+        let (frame1, frame2, frame3) = (&self.frame1, &self.frame2, &self.frame3);
+
+        let seconds = frame1.toc as i64;
+        let days = GPS_EPOCH_UNIX_DAYS + gps_week as i64 * 7 + seconds / 86_400;
+        let (year, month, day) = civil_from_days(days);
+
+        let (hours, minutes, seconds) = (
+            (seconds % 86_400) / 3_600,
+            (seconds % 3_600) / 60,
+            seconds % 60,
+        );
+
+        let accuracy_m = if frame1.ura == 15 {
+            2.0_f64.powi(13)
+        } else {
+            frame1.nominal_user_range_accuracy()
+        };
+
+        let l2p_flag = if frame1.l2_p_data_flag { 1.0 } else { 0.0 };
+
+        // zero when not known
+        let fit_interval_hours = frame2
+            .fit_interval_hours(GpsQzssConstellation::Gps, frame1.iodc)
+            .unwrap_or(0.0);
+
+        let orbits = [
+            [
+                frame2.iode as f64,
+                frame2.crs,
+                frame2.mean_motion_difference_radians(),
+                frame2.mean_anomaly_radians(),
+            ],
+            [frame2.cuc, frame2.e, frame2.cus, frame2.sqrt_a],
+            [
+                frame2.toe as f64,
+                frame3.cic,
+                frame3.omega0 * PI,
+                frame3.cis,
+            ],
+            [
+                frame3.i0 * PI,
+                frame3.crc,
+                frame3.omega * PI,
+                frame3.omega_dot * PI,
+            ],
+            [
+                frame3.idot * PI,
+                frame1.ca_or_p_l2 as f64,
+                gps_week as f64,
+                l2p_flag,
+            ],
+            [
+                accuracy_m,
+                frame1.health as f64,
+                frame1.tgd,
+                frame1.iodc as f64,
+            ],
+        ];
+
+        let mut record = format!(
+            "G{:02} {:04} {:02} {:02} {:02} {:02} {:02}{}{}{}\n",
+            prn,
+            year,
+            month,
+            day,
+            hours,
+            minutes,
+            seconds,
+            format_d19(frame1.af0),
+            format_d19(frame1.af1),
+            format_d19(frame1.af2),
+        );
+
+        for orbit in orbits {
+            record.push_str("    ");
+
+            for value in orbit {
+                record.push_str(&format_d19(value));
+            }
+
+            record.push('\n');
+        }
+
+        record.push_str(&format!(
+            "    {}{}\n",
+            format_d19(UNKNOWN_TRANSMISSION_TIME),
+            format_d19(fit_interval_hours),
+        ));
+
+        record
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gps::{GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3};
+
+    #[test]
+    fn d19_formatting() {
+        for (value, expected) in [
+            (0.0, " 0.000000000000D+00"),
+            (1.0, " 1.000000000000D+00"),
+            (-45.0625, "-4.506250000000D+01"),
+            (0.9999e9, " 9.999000000000D+08"),
+            (-1.1175870895385742e-08, "-1.117587089539D-08"),
+        ] {
+            assert_eq!(format_d19(value), expected);
+        }
+    }
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(GPS_EPOCH_UNIX_DAYS), (1980, 1, 6));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+    }
+
+    #[test]
+    fn rinex_nav_record() {
+        let frame1 = GpsQzssFrame1 {
+            week: 2087 % 1024,
+            ca_or_p_l2: 1,
+            ura: 0,
+            health: 0,
+            iodc: 33,
+            toc: 266_400,
+            tgd: -1.1175870895385742e-08,
+            af2: 0.0,
+            af1: -5.002220859751105e-12,
+            af0: -0.00012345239520072937,
+            ..Default::default()
+        };
+
+        let frame2 = GpsQzssFrame2 {
+            toe: 266_400,
+            iode: 33,
+            m0: 0.057489047292619944,
+            dn: 1.7053025658242404e-10,
+            cuc: -4.367902874946594e-06,
+            cus: 8.50670039653778e-06,
+            crs: -45.0625,
+            e: 0.001437226077541709,
+            sqrt_a: 5153.656005859375,
+            fit_int_flag: false,
+            aodo: 0,
+        };
+
+        let frame3 = GpsQzssFrame3 {
+            cic: 2.2351741790771484e-08,
+            cis: -6.332993507385254e-08,
+            crc: 250.15625,
+            i0: 0.3046920048072934,
+            iode: 33,
+            idot: 1.7053025658242404e-11,
+            omega0: -0.408172239549458,
+            omega: 0.21270895935595036,
+            omega_dot: -3.069544618483633e-10,
+        };
+
+        let eph = GpsQzssEphemeris::new(frame1, frame2, frame3);
+
+        assert_eq!(
+            eph.to_rinex_nav_record(6, 2087),
+            "G06 2020 01 08 02 00 00-1.234523952007D-04-5.002220859751D-12 0.000000000000D+00
+     3.300000000000D+01-4.506250000000D+01 5.357366012941D-10 1.806071686364D-01
+    -4.367902874947D-06 1.437226077542D-03 8.506700396538D-06 5.153656005859D+03
+     2.664000000000D+05 2.235174179077D-08-1.282310909168D+00-6.332993507385D-08
+     9.572181639101D-01 2.501562500000D+02 6.682449040654D-01-9.643258823294D-10
+     5.357366012941D-11 1.000000000000D+00 2.087000000000D+03 0.000000000000D+00
+     2.000000000000D+00 0.000000000000D+00-1.117587089539D-08 3.300000000000D+01
+     9.999000000000D+08 4.000000000000D+00
+"
+        );
+    }
+}
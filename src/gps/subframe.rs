@@ -1,9 +1,14 @@
 use crate::gps::{
-    GpsDataWord, GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3, GpsQzssFrameId, GPS_WORDS_PER_FRAME,
+    GpsDataWord, GpsQzssFrame1, GpsQzssFrame2, GpsQzssFrame3, GpsQzssFrame4, GpsQzssFrame5,
+    GpsQzssFrameId, GPS_WORDS_PER_FRAME,
 };
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// GPS / QZSS Interpreted subframes
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GpsQzssSubframe {
     /// GPS Ephemeris Frame #1
     Ephemeris1(GpsQzssFrame1),
@@ -13,6 +18,41 @@ pub enum GpsQzssSubframe {
 
     /// GPS Ephemeris Frame #3
     Ephemeris3(GpsQzssFrame3),
+
+    /// GPS Frame #4 (paginated)
+    Frame4(GpsQzssFrame4),
+
+    /// GPS Frame #5 (paginated)
+    Frame5(GpsQzssFrame5),
+
+    /// 8 [GpsDataWord]s (descrambled, parity bits as received) that could not be interpreted:
+    /// unknown subframe ID or unsupported page.
+    Raw([GpsDataWord; GPS_WORDS_PER_FRAME - 2]),
+}
+
+/// [GpsQzssSubframeKind] is the general nature of a [GpsQzssSubframe]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GpsQzssSubframeKind {
+    /// Clock and ephemeris data (subframes #1 to #3)
+    Ephemeris,
+
+    /// Almanach and system data (paginated subframes #4 and #5)
+    Almanach,
+
+    /// Data that could not be interpreted
+    Raw,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsQzssSubframeKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Ephemeris => write!(f, "Ephemeris"),
+            Self::Almanach => write!(f, "Almanach"),
+            Self::Raw => write!(f, "Raw"),
+        }
+    }
 }
 
 impl Default for GpsQzssSubframe {
@@ -30,6 +70,29 @@ impl GpsQzssSubframe {
             GpsQzssFrameId::Ephemeris1 => Self::Ephemeris1(GpsQzssFrame1::model()),
             GpsQzssFrameId::Ephemeris2 => Self::Ephemeris2(GpsQzssFrame2::model()),
             GpsQzssFrameId::Ephemeris3 => Self::Ephemeris3(GpsQzssFrame3::model()),
+            GpsQzssFrameId::Ephemeris4 => Self::Frame4(GpsQzssFrame4::Page2(
+                crate::gps::GpsQzssAlmanach::model().with_sv_id(25),
+            )),
+            GpsQzssFrameId::Ephemeris5 => {
+                Self::Frame5(GpsQzssFrame5::Page1(crate::gps::GpsQzssAlmanach::model()))
+            },
+        }
+    }
+
+    /// Returns the [GpsQzssFrameId] of this [GpsQzssSubframe].
+    /// [None] for [GpsQzssSubframe::Raw] data, whose subframe ID was not recognized.
+    pub fn frame_id(&self) -> Option<GpsQzssFrameId> {
+        GpsQzssFrameId::from_subframe(self)
+    }
+
+    /// Returns the [GpsQzssSubframeKind] of this [GpsQzssSubframe]
+    pub fn kind(&self) -> GpsQzssSubframeKind {
+        match self {
+            Self::Ephemeris1(_) | Self::Ephemeris2(_) | Self::Ephemeris3(_) => {
+                GpsQzssSubframeKind::Ephemeris
+            },
+            Self::Frame4(_) | Self::Frame5(_) => GpsQzssSubframeKind::Almanach,
+            Self::Raw(_) => GpsQzssSubframeKind::Raw,
         }
     }
 
@@ -81,14 +144,91 @@ impl GpsQzssSubframe {
         }
     }
 
-    /// Decodes [Self] from 8 [GpsDataWord]s.
+    /// Unwraps self as [GpsQzssFrame4] reference (if feasible)
+    pub fn as_frame4(&self) -> Option<GpsQzssFrame4> {
+        match self {
+            Self::Frame4(frame) => Some(*frame),
+            _ => None,
+        }
+    }
+
+    /// Unwraps self as mutable [GpsQzssFrame4] reference (if feasible)
+    pub fn as_mut_frame4(&mut self) -> Option<&mut GpsQzssFrame4> {
+        match self {
+            Self::Frame4(frame) => Some(frame),
+            _ => None,
+        }
+    }
+
+    /// Unwraps self as [GpsQzssFrame5] reference (if feasible)
+    pub fn as_frame5(&self) -> Option<GpsQzssFrame5> {
+        match self {
+            Self::Frame5(frame) => Some(*frame),
+            _ => None,
+        }
+    }
+
+    /// Unwraps self as mutable [GpsQzssFrame5] reference (if feasible)
+    pub fn as_mut_frame5(&mut self) -> Option<&mut GpsQzssFrame5> {
+        match self {
+            Self::Frame5(frame) => Some(frame),
+            _ => None,
+        }
+    }
+
+    /// Unwraps self as raw [GpsDataWord]s (if feasible)
+    pub fn as_raw(&self) -> Option<[GpsDataWord; GPS_WORDS_PER_FRAME - 2]> {
+        match self {
+            Self::Raw(words) => Some(*words),
+            _ => None,
+        }
+    }
+
+    /// Decodes [Self] from 8 [GpsDataWord]s, which must already be descrambled,
+    /// following a HOW with this 3-bit subframe ID. This method does not care for frames parity.
+    /// Frame #4 and #5 are dispatched on their page ID. Falls back to [GpsQzssSubframe::Raw]
+    /// when the subframe ID is unknown or the page is not supported, so no data is lost.
+    pub(crate) fn decode(frame_id: u8, words: &[GpsDataWord]) -> Self {
+        let interpreted = match GpsQzssFrameId::decode(frame_id) {
+            Ok(GpsQzssFrameId::Ephemeris1) => {
+                Ok(Self::Ephemeris1(GpsQzssFrame1::from_words(words)))
+            },
+            Ok(GpsQzssFrameId::Ephemeris2) => {
+                Ok(Self::Ephemeris2(GpsQzssFrame2::from_words(words)))
+            },
+            Ok(GpsQzssFrameId::Ephemeris3) => {
+                Ok(Self::Ephemeris3(GpsQzssFrame3::from_words(words)))
+            },
+            Ok(GpsQzssFrameId::Ephemeris4) => GpsQzssFrame4::from_words(words).map(Self::Frame4),
+            Ok(GpsQzssFrameId::Ephemeris5) => GpsQzssFrame5::from_words(words).map(Self::Frame5),
+            Err(e) => Err(e),
+        };
+
+        interpreted.unwrap_or_else(|_| {
+            let mut raw = [GpsDataWord::default(); GPS_WORDS_PER_FRAME - 2];
+
+            for (raw, word) in raw.iter_mut().zip(words.iter()) {
+                *raw = *word;
+            }
+
+            Self::Raw(raw)
+        })
+    }
+
+    /// Decodes [Self] from 8 [GpsDataWord]s, as transmitted.
+    /// The data bits of each word are un-inverted (descrambled) first, using the last bit (D30*)
+    /// of the previous word. For the first word, D30* of the HOW word must be provided.
     /// This method does not care for frames parity.
-    pub(crate) fn decode(frame_id: GpsQzssFrameId, words: &[GpsDataWord]) -> Self {
-        match frame_id {
-            GpsQzssFrameId::Ephemeris1 => Self::Ephemeris1(GpsQzssFrame1::from_words(words)),
-            GpsQzssFrameId::Ephemeris2 => Self::Ephemeris2(GpsQzssFrame2::from_words(words)),
-            GpsQzssFrameId::Ephemeris3 => Self::Ephemeris3(GpsQzssFrame3::from_words(words)),
+    pub(crate) fn decode_raw(frame_id: u8, words: &[GpsDataWord], prev_d30: bool) -> Self {
+        let mut prev_d30 = prev_d30;
+        let mut descrambled = [GpsDataWord::default(); GPS_WORDS_PER_FRAME - 2];
+
+        for (word, raw) in descrambled.iter_mut().zip(words.iter()) {
+            *word = raw.descramble(prev_d30);
+            prev_d30 = raw.d30();
         }
+
+        Self::decode(frame_id, &descrambled)
     }
 
     /// Encodes this [GpsQzssSubframe] as a burst of 8 [GpsDataWord]s.
@@ -97,6 +237,116 @@ impl GpsQzssSubframe {
             Self::Ephemeris1(subframe) => subframe.to_words(),
             Self::Ephemeris2(subframe) => subframe.to_words(),
             Self::Ephemeris3(subframe) => subframe.to_words(),
+            Self::Frame4(subframe) => subframe.to_words(),
+            Self::Frame5(subframe) => subframe.to_words(),
+            Self::Raw(words) => *words,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gps::{
+        GpsDataWord, GpsQzssAlmanachStatus, GpsQzssFrame4, GpsQzssFrame5, GpsQzssFrameId,
+        GpsQzssSubframe, GpsQzssSubframeKind, GPS_WORDS_PER_FRAME,
+    };
+
+    #[test]
+    fn frame_id() {
+        for (frame_id, kind) in [
+            (GpsQzssFrameId::Ephemeris1, GpsQzssSubframeKind::Ephemeris),
+            (GpsQzssFrameId::Ephemeris2, GpsQzssSubframeKind::Ephemeris),
+            (GpsQzssFrameId::Ephemeris3, GpsQzssSubframeKind::Ephemeris),
+            (GpsQzssFrameId::Ephemeris4, GpsQzssSubframeKind::Almanach),
+            (GpsQzssFrameId::Ephemeris5, GpsQzssSubframeKind::Almanach),
+        ] {
+            let subframe = GpsQzssSubframe::model(frame_id);
+            assert_eq!(subframe.frame_id(), Some(frame_id));
+            assert_eq!(subframe.kind(), kind);
         }
+
+        let raw = GpsQzssSubframe::Raw([GpsDataWord::default(); GPS_WORDS_PER_FRAME - 2]);
+        assert_eq!(raw.frame_id(), None);
+        assert_eq!(raw.kind(), GpsQzssSubframeKind::Raw);
+        assert_eq!(raw.kind().to_string(), "Raw");
+    }
+
+    #[test]
+    fn decode_raw() {
+        for frame_id in [
+            GpsQzssFrameId::Ephemeris1,
+            GpsQzssFrameId::Ephemeris2,
+            GpsQzssFrameId::Ephemeris3,
+            GpsQzssFrameId::Ephemeris4,
+            GpsQzssFrameId::Ephemeris5,
+        ] {
+            let subframe = GpsQzssSubframe::model(frame_id);
+            let expected = GpsQzssSubframe::decode(frame_id.encode(), &subframe.to_words());
+
+            for how_d30 in [false, true] {
+                // transmit: parity and inversion driven by the previous D30
+                let mut prev = GpsDataWord::from((how_d30 as u32) << 2);
+                let mut transmitted = subframe.to_words();
+
+                for word in transmitted.iter_mut() {
+                    *word = word.transmitted(&prev, false);
+                    prev = *word;
+                }
+
+                let decoded = GpsQzssSubframe::decode_raw(frame_id.encode(), &transmitted, how_d30);
+                assert_eq!(
+                    decoded, expected,
+                    "failed for {:?} (D30*={})",
+                    frame_id, how_d30
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn frame5_dispatch() {
+        let status = GpsQzssAlmanachStatus::default()
+            .with_toa_seconds(319_488)
+            .with_week(12)
+            .with_sv_health(3, 0x3f)
+            .with_sv_health(24, 0x2a);
+
+        let subframe = GpsQzssSubframe::Frame5(GpsQzssFrame5::Page25(status));
+
+        let decoded = GpsQzssSubframe::decode(5, &subframe.to_words());
+
+        assert_eq!(decoded, subframe);
+        assert_eq!(decoded.as_frame5().unwrap().page_id(), 25);
+        assert!(decoded.as_frame4().is_none());
+
+        // page 25 of frame 4 (SV ID #63) is not a frame 5 page: passed through
+        let words = GpsQzssFrame4::Page25(Default::default()).to_words();
+        let decoded = GpsQzssSubframe::decode(5, &words);
+
+        assert_eq!(decoded, GpsQzssSubframe::Raw(words));
+        assert_eq!(decoded.as_raw(), Some(words));
+        assert!(decoded.as_frame5().is_none());
+    }
+
+    #[test]
+    fn raw_passthrough() {
+        // synthetic frame whose HOW announces an unknown subframe ID (#0, #6 or #7)
+        let words = GpsQzssSubframe::model(GpsQzssFrameId::Ephemeris2).to_words();
+
+        for frame_id in [0, 6, 7] {
+            let decoded = GpsQzssSubframe::decode(frame_id, &words);
+
+            assert_eq!(decoded, GpsQzssSubframe::Raw(words));
+            assert_eq!(decoded.as_raw(), Some(words));
+            assert!(decoded.as_eph2().is_none());
+
+            // re-encoded as is
+            assert_eq!(decoded.to_words(), words);
+        }
+
+        // interpreted subframes are not raw
+        let decoded = GpsQzssSubframe::decode(2, &words);
+        assert!(decoded.as_raw().is_none());
+        assert!(decoded.as_eph2().is_some());
     }
 }
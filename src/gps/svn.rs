@@ -0,0 +1,163 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// GPS satellite generations (blocks)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GpsSatelliteBlock {
+    /// Block IIR (replenishment)
+    BlockIIR,
+
+    /// Block IIR-M (modernized), first to transmit L2C
+    BlockIIRM,
+
+    /// Block IIF (follow-on), first to transmit L5
+    BlockIIF,
+
+    /// Block III, first to transmit L1C
+    BlockIII,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsSatelliteBlock {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::BlockIIR => write!(f, "IIR"),
+            Self::BlockIIRM => write!(f, "IIR-M"),
+            Self::BlockIIF => write!(f, "IIF"),
+            Self::BlockIII => write!(f, "III"),
+        }
+    }
+}
+
+/// PRN assignments: PRN, GPS week from which the vehicle was assigned this PRN
+/// (launch week), SVN and [GpsSatelliteBlock]. Sorted by PRN, then by week.
+const ASSIGNMENTS: [(u8, u32, u16, GpsSatelliteBlock); 33] = [
+    (1, 1524, 49, GpsSatelliteBlock::BlockIIRM),
+    (1, 1644, 63, GpsSatelliteBlock::BlockIIF),
+    (2, 1295, 61, GpsSatelliteBlock::BlockIIR),
+    (3, 1816, 69, GpsSatelliteBlock::BlockIIF),
+    (4, 2033, 74, GpsSatelliteBlock::BlockIII),
+    (5, 1545, 50, GpsSatelliteBlock::BlockIIRM),
+    (6, 1792, 67, GpsSatelliteBlock::BlockIIF),
+    (7, 1470, 48, GpsSatelliteBlock::BlockIIRM),
+    (8, 1853, 72, GpsSatelliteBlock::BlockIIF),
+    (9, 1803, 68, GpsSatelliteBlock::BlockIIF),
+    (10, 1868, 73, GpsSatelliteBlock::BlockIIF),
+    (11, 2162, 78, GpsSatelliteBlock::BlockIII),
+    (12, 1401, 58, GpsSatelliteBlock::BlockIIRM),
+    (13, 915, 43, GpsSatelliteBlock::BlockIIR),
+    (14, 2130, 77, GpsSatelliteBlock::BlockIII),
+    (15, 1449, 55, GpsSatelliteBlock::BlockIIRM),
+    (16, 1203, 56, GpsSatelliteBlock::BlockIIR),
+    (17, 1342, 53, GpsSatelliteBlock::BlockIIRM),
+    (18, 2067, 75, GpsSatelliteBlock::BlockIII),
+    (19, 1262, 59, GpsSatelliteBlock::BlockIIR),
+    (20, 1061, 51, GpsSatelliteBlock::BlockIIR),
+    (21, 1212, 45, GpsSatelliteBlock::BlockIIR),
+    (22, 1250, 47, GpsSatelliteBlock::BlockIIR),
+    (23, 2112, 76, GpsSatelliteBlock::BlockIII),
+    (24, 1708, 65, GpsSatelliteBlock::BlockIIF),
+    (25, 1585, 62, GpsSatelliteBlock::BlockIIF),
+    (26, 1837, 71, GpsSatelliteBlock::BlockIIF),
+    (27, 1740, 66, GpsSatelliteBlock::BlockIIF),
+    (28, 2245, 79, GpsSatelliteBlock::BlockIII),
+    (29, 1458, 57, GpsSatelliteBlock::BlockIIRM),
+    (30, 1780, 64, GpsSatelliteBlock::BlockIIF),
+    (31, 1394, 52, GpsSatelliteBlock::BlockIIRM),
+    (32, 1882, 70, GpsSatelliteBlock::BlockIIF),
+];
+
+/// [GpsSatelliteVehicle] identifies the physical GPS satellite (SVN) that transmits
+/// a given PRN, so older logs can be cross-referenced.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GpsSatelliteVehicle {
+    /// Space Vehicle Number
+    pub svn: u16,
+
+    /// [GpsSatelliteBlock] (generation)
+    pub block: GpsSatelliteBlock,
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsSatelliteVehicle {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "SVN{:02} ({})", self.svn, self.block)
+    }
+}
+
+impl GpsSatelliteVehicle {
+    /// Returns the [GpsSatelliteVehicle] that was assigned this PRN (1 to 32)
+    /// during this GPS week number. The PRN assignments change over time:
+    /// when no week is specified, the latest known assignment is returned.
+    ///
+    /// Our table only covers the vehicles of the operational constellation
+    /// (as of 2023), and the vehicles they replaced when known.
+    /// [None] is returned for PRNs or periods that are not covered.
+    pub fn from_prn(prn: u8, gps_week: Option<u32>) -> Option<Self> {
+        ASSIGNMENTS
+            .iter()
+            .rev()
+            .find(|(assigned_prn, since, _, _)| {
+                *assigned_prn == prn && gps_week.map_or(true, |week| *since <= week)
+            })
+            .map(|(_, _, svn, block)| Self {
+                svn: *svn,
+                block: *block,
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn assignments() {
+        for (i, (prn, week, _, _)) in ASSIGNMENTS.iter().enumerate().skip(1) {
+            let (prev_prn, prev_week, _, _) = ASSIGNMENTS[i - 1];
+            assert!((prev_prn, prev_week) < (*prn, *week), "table is not sorted");
+        }
+    }
+
+    #[test]
+    fn from_prn() {
+        for (prn, svn, block) in [
+            (1, 63, GpsSatelliteBlock::BlockIIF),
+            (4, 74, GpsSatelliteBlock::BlockIII),
+            (13, 43, GpsSatelliteBlock::BlockIIR),
+            (30, 64, GpsSatelliteBlock::BlockIIF),
+        ] {
+            assert_eq!(
+                GpsSatelliteVehicle::from_prn(prn, None),
+                Some(GpsSatelliteVehicle { svn, block })
+            );
+        }
+
+        // PRN01 was transmitted by SVN49 until SVN63 was launched
+        assert_eq!(
+            GpsSatelliteVehicle::from_prn(1, Some(1600)),
+            Some(GpsSatelliteVehicle {
+                svn: 49,
+                block: GpsSatelliteBlock::BlockIIRM,
+            })
+        );
+
+        assert_eq!(
+            GpsSatelliteVehicle::from_prn(1, Some(2300)).map(|sv| sv.svn),
+            Some(63)
+        );
+
+        // not covered
+        assert!(GpsSatelliteVehicle::from_prn(0, None).is_none());
+        assert!(GpsSatelliteVehicle::from_prn(1, Some(1000)).is_none());
+        assert!(GpsSatelliteVehicle::from_prn(33, None).is_none());
+    }
+
+    #[test]
+    fn display() {
+        let sv = GpsSatelliteVehicle::from_prn(5, None).unwrap();
+        assert_eq!(sv.to_string(), "SVN50 (IIR-M)");
+    }
+}
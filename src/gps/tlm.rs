@@ -1,4 +1,7 @@
-use crate::gps::{GpsDataWord, GpsError};
+use crate::gps::{GpsDataWord, GpsError, GPS_PREAMBLE_BYTE};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 const PREAMBLE_MASK: u32 = 0x22C0_0000;
 const MESSAGE_SHIFT: u32 = 8;
@@ -8,6 +11,7 @@ const RESERVED_BIT_MASK: u32 = 0x0000_0040;
 
 /// [GpsQzssTelemetry] marks the beginning of each frame
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsQzssTelemetry {
     /// 14-bit TLM Message
     pub message: u16,
@@ -21,8 +25,8 @@ pub struct GpsQzssTelemetry {
 }
 
 #[cfg(feature = "std")]
-impl std::fmt::Display for GpsQzssTelemetry {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GpsQzssTelemetry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(
             f,
             "INTEGRITY={} - MSG=0x{:08X} - reserved={}",
@@ -47,6 +51,11 @@ impl GpsQzssTelemetry {
         self
     }
 
+    /// Returns the 14-bit TLM message. Unlike the public field, this is always within range.
+    pub fn message_u14(&self) -> u16 {
+        self.message & 0x3fff
+    }
+
     /// Copies and returns new [GpsQzssTelemetry] with message integrity asserted
     pub fn with_integrity(mut self) -> Self {
         self.integrity = true;
@@ -76,7 +85,8 @@ impl GpsQzssTelemetry {
     pub(crate) fn from_word(word: GpsDataWord) -> Result<Self, GpsError> {
         let value = word.value();
 
-        if value & PREAMBLE_MASK != PREAMBLE_MASK {
+        // all 8 bits must match, not only the asserted ones
+        if (value >> 22) as u8 != GPS_PREAMBLE_BYTE {
             return Err(GpsError::InvalidPreamble);
         };
 
@@ -95,7 +105,7 @@ impl GpsQzssTelemetry {
     pub(crate) fn to_word(&self) -> GpsDataWord {
         let mut value = PREAMBLE_MASK;
 
-        value |= (self.message as u32) << MESSAGE_SHIFT;
+        value |= (self.message_u14() as u32) << MESSAGE_SHIFT;
 
         if self.integrity {
             value |= INTEGRITY_BIT_MASK;
@@ -115,7 +125,7 @@ impl GpsQzssTelemetry {
 
 #[cfg(test)]
 mod tlm {
-    use crate::gps::{GpsDataWord, GpsQzssTelemetry};
+    use crate::gps::{GpsDataWord, GpsError, GpsQzssTelemetry};
 
     #[test]
     fn encoding() {
@@ -138,4 +148,48 @@ mod tlm {
             assert_eq!(tlm.to_word(), gps_word, "Reciprocal issue");
         }
     }
+
+    #[test]
+    fn invalid_preamble() {
+        for dword in [
+            0x00000000, 0x8A000000, 0x8F000000, 0xCB000000, 0xFF000000, 0x74000000,
+        ] {
+            assert_eq!(
+                GpsQzssTelemetry::from_word(GpsDataWord::from(dword)),
+                Err(GpsError::InvalidPreamble),
+                "0x{:08X} should not be accepted",
+                dword
+            );
+        }
+    }
+
+    #[test]
+    fn message_range() {
+        let tlm = GpsQzssTelemetry::default().with_message(0xFFFF);
+
+        assert_eq!(tlm.message, 0x3FFF);
+        assert_eq!(tlm.message_u14(), 0x3FFF);
+
+        // out of range field does not corrupt the preamble
+        let tlm = GpsQzssTelemetry {
+            message: 0xC000,
+            integrity: true,
+            reserved_bit: false,
+        };
+
+        assert_eq!(tlm.message_u14(), 0);
+
+        let decoded = GpsQzssTelemetry::from_word(tlm.to_word()).unwrap();
+        assert_eq!(decoded, GpsQzssTelemetry::default().with_integrity());
+
+        // builder symmetry
+        let tlm = GpsQzssTelemetry::default()
+            .with_integrity()
+            .with_reserved_bit();
+
+        assert!(tlm.integrity && tlm.reserved_bit);
+
+        let tlm = tlm.without_integrity().without_reserved_bit();
+        assert!(!tlm.integrity && !tlm.reserved_bit);
+    }
 }
@@ -1,4 +1,35 @@
-use crate::gps::{GpsDataByte, GpsError, GPS_PARITY_MASK, GPS_PARITY_SIZE, GPS_PAYLOAD_MASK};
+use crate::{
+    bits::peek_bits,
+    gps::{
+        GpsDataByte, GpsError, GPS_PARITY_MASK, GPS_PARITY_SIZE, GPS_PAYLOAD_MASK,
+        GPS_WORDS_PER_FRAME, GPS_WORD_BITS,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// 24 data bits of a 30-bit [GpsDataWord]
+const GPS_DATA_MASK: u32 = 0x3fffffc0;
+
+/// Parity equations, applied on (D29*, D30*, d1..d24, D25..D30)
+const PARITY_BITMASKS: [u32; GPS_PARITY_SIZE] = [
+    0xBB1F3480, 0x5D8F9A40, 0xAEC7CD00, 0x5763E680, 0x6BB1F340, 0x8B7A89C0,
+];
+
+/// Solves the two non-information bearing bits (23 and 24)
+/// of this (D29*, D30*, d1..d24) source word, so that D29 = D30 = 0.
+fn solve_nib(mut word: u32) -> u32 {
+    if count_bits(word & PARITY_BITMASKS[4]) % 2 > 0 {
+        word ^= 1 << 6;
+    }
+
+    if count_bits(word & PARITY_BITMASKS[5]) % 2 > 0 {
+        word ^= 1 << 7;
+    }
+
+    word
+}
 
 /// Counters number of bits set to '1'
 fn count_bits(value: u32) -> u32 {
@@ -17,7 +48,8 @@ fn count_bits(value: u32) -> u32 {
 }
 
 /// [GpsDataWord] is used to pack [GpsDataByte]s.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GpsDataWord {
     /// [GpsDataByte]s stored with "big endianness"
     bytes: [GpsDataByte; 4],
@@ -32,6 +64,35 @@ impl Default for GpsDataWord {
     }
 }
 
+impl core::fmt::Debug for GpsDataWord {
+    /// Formats the 30-bit value (hex) and whether its parity is correct,
+    /// assuming the previous word ended with D29* = D30* = 0, like the first word of a subframe.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let parity = if self.parity_check(&Self::default(), false).is_ok() {
+            "ok"
+        } else {
+            "nok"
+        };
+
+        write!(f, "GpsDataWord(0x{:08X}, parity={})", self.value(), parity)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for GpsDataWord {
+    /// Formats the 24 data bits, grouped by bytes, and the 6 parity bits (binary, MSB first).
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{:08b} {:08b} {:08b} | {:06b}",
+            self.bits(1, 8),
+            self.bits(9, 8),
+            self.bits(17, 8),
+            self.bits(25, 6),
+        )
+    }
+}
+
 impl core::ops::BitOrAssign<u8> for GpsDataWord {
     fn bitor_assign(&mut self, rhs: u8) {
         let mut value = self.value();
@@ -85,6 +146,49 @@ impl GpsDataWord {
         }
     }
 
+    /// Extracts the 30-bit [GpsDataWord] starting at any bit position of this buffer (MSB first).
+    /// Returns [None] when the buffer is too short.
+    pub fn from_bit_offset(buffer: &[u8], bit_offset: usize) -> Option<Self> {
+        peek_bits(buffer, bit_offset, GPS_WORD_BITS).map(|value| Self::from(value << 2))
+    }
+
+    /// Converts the data words of a u-blox RXM-SFRBX message to [GpsDataWord]s,
+    /// ready to be decoded with [GpsQzssFrame::decode](crate::gps::GpsQzssFrame::decode).
+    ///
+    /// Each SFRBX `dwrd` carries one 30-bit word, right aligned (bits 29..0),
+    /// the two MSBs being padding. The receiver already removed the D30* inversion
+    /// from the whole word (parity bits included): it is applied again here, so the
+    /// words are returned as transmitted. Missing words are left null and
+    /// words in excess are ignored.
+    pub fn from_ublox_sfrbx(dwords: &[u32]) -> [GpsDataWord; GPS_WORDS_PER_FRAME] {
+        let mut ret: [GpsDataWord; GPS_WORDS_PER_FRAME] = Default::default();
+        let mut prev_d30 = false;
+
+        for (word, dword) in ret.iter_mut().zip(dwords.iter()) {
+            let mut value = dword & 0x3fffffff;
+
+            if prev_d30 {
+                value ^= 0x3fffffff;
+            }
+
+            *word = GpsDataWord::from(value << 2);
+            prev_d30 = word.d30();
+        }
+
+        ret
+    }
+
+    /// Converts the `NAVBits` of a Septentrio SBF GPSRawCA block to [GpsDataWord]s,
+    /// ready to be decoded with [GpsQzssFrame::decode](crate::gps::GpsQzssFrame::decode).
+    ///
+    /// Each of the 10 [u32]s carries one 30-bit word (parity bits included), LSB justified:
+    /// bit 29 is the first transmitted bit, bit 0 is D30 and the two MSBs are padding.
+    pub fn from_sbf_rawca(
+        words: &[u32; GPS_WORDS_PER_FRAME],
+    ) -> [GpsDataWord; GPS_WORDS_PER_FRAME] {
+        words.map(|word| GpsDataWord::from(word << 2))
+    }
+
     /// Converts this [GpsDataWord] to [u32]
     pub fn value(&self) -> u32 {
         let mut value = self.bytes[3].as_u32();
@@ -94,46 +198,88 @@ impl GpsDataWord {
         value
     }
 
-    /// Evaluates the parity of this data word,
-    /// using provided NIB which must be set to zero on initial cycle.
+    /// Extracts `len` bits of this [GpsDataWord], starting at bit position `msb`.
+    /// Positions follow IS-GPS-200: bit 1 is the first transmitted bit (MSB)
+    /// and bit 30 is the last one (D30). Returns 0 when the range does not fit in the word.
+    pub fn bits(&self, msb: u32, len: u32) -> u32 {
+        if msb == 0 || len == 0 || msb + len > GPS_WORD_BITS as u32 + 1 {
+            return 0;
+        }
+
+        let shift = GPS_WORD_BITS as u32 + 1 - msb - len;
+        (self.value() >> shift) & (u32::MAX >> (32 - len))
+    }
+
+    /// Returns the last bit (D30) of this [GpsDataWord], which is
+    /// the D30* bit of the following word.
+    pub(crate) fn d30(&self) -> bool {
+        self.value() & 0x1 > 0
+    }
+
+    /// Returns the corrected [GpsDataWord], once the inversion of the 24 data bits
+    /// is removed. The data bits are transmitted inverted when the last bit (D30*)
+    /// of the previous word is set. Parity bits are not modified.
+    pub fn descramble(&self, prev_d30: bool) -> GpsDataWord {
+        if prev_d30 {
+            Self::from((self.value() ^ GPS_DATA_MASK) << 2)
+        } else {
+            *self
+        }
+    }
+
+    /// Evaluates the parity of this data word, using the 6 Hamming equations of IS-GPS-200.
+    /// The two last bits (D29*, D30*) of the previous [GpsDataWord] are required:
+    /// simply use [Default] on the first word, since each subframe ends with D29=D30=0.
+    /// When D30* is set, the transmitted data bits are inverted: this is taken into account.
+    /// When `nib` is set, bits 23 and 24 of this word are considered non-information bearing,
+    /// and the parity is evaluated once these are solved so that D29 = D30 = 0.
     /// 6-bit parity is encoded as [u8].
     pub fn parity(&self, rhs: &Self, nib: bool) -> u8 {
-        const BITMASKS: [u32; 6] = [
-            0x3B1F3480, 0x1D8F9A40, 0x2EC7CD00, 0x1763E680, 0x2BB1F340, 0x0B7A89C0,
-        ];
-
-        let prev = rhs.value();
+        let prev = rhs.value() & 0x3;
 
-        let (b29, b30) = ((prev & 0x2) >> 1, prev & 0x01);
+        let mut word = (prev << 30) | (self.value() & GPS_PAYLOAD_MASK);
 
-        let mut d = (self.value() & GPS_PAYLOAD_MASK) >> GPS_PARITY_SIZE;
+        // source data
+        if prev & 0x1 > 0 {
+            word ^= GPS_DATA_MASK;
+        }
 
         if nib {
-            if ((b30 + count_bits(BITMASKS[4] & d)) % 2) > 0 {
-                d ^= 1 << 6;
-            }
+            word = solve_nib(word);
+        }
 
-            if (((b29 + count_bits(BITMASKS[5])) & d) % 2) > 0 {
-                d ^= 1 << 7;
-            }
+        let mut parity = 0;
+
+        for mask in PARITY_BITMASKS {
+            parity <<= 1;
+            parity |= (count_bits(word & mask) % 2) as u8;
         }
 
-        let mut b = d;
+        parity
+    }
+
+    /// Returns this [GpsDataWord] (source data) as it should be transmitted after
+    /// the previous word: data bits are inverted when D30* is set and parity bits are inserted.
+    /// When `nib` is set, bits 23 and 24 are solved so that D29 = D30 = 0.
+    pub(crate) fn transmitted(&self, prev: &Self, nib: bool) -> Self {
+        let mut word = ((prev.value() & 0x3) << 30) | (self.value() & GPS_DATA_MASK);
 
-        if b30 > 0 {
-            b ^= 0x3fffffc0;
+        if nib {
+            word = solve_nib(word);
         }
 
-        b |= ((b29 + count_bits(BITMASKS[0])) & (d % 2)) << 5;
-        b |= ((b30 + count_bits(BITMASKS[1])) & (d % 2)) << 4;
-        b |= ((b29 + count_bits(BITMASKS[2])) & (d % 2)) << 3;
-        b |= ((b30 + count_bits(BITMASKS[3])) & (d % 2)) << 2;
-        b |= ((b30 + count_bits(BITMASKS[4])) & (d % 2)) << 1;
-        b |= (b29 + count_bits(BITMASKS[5])) & (d % 2);
+        let word = Self::from((word & GPS_DATA_MASK) << 2).descramble(prev.d30());
+        let parity = word.parity(prev, false);
 
-        b &= 0x3fffffff;
+        Self::from((word.value() | parity as u32) << 2)
+    }
 
-        (b & GPS_PARITY_MASK) as u8
+    /// Returns this [GpsDataWord] (source data) ready to transmit after a word that ended
+    /// with bits (D29*, D30*): the 6 parity bits are computed and inserted (IS-GPS-200)
+    /// and the 24 data bits are inverted when D30* is set.
+    pub fn with_parity(self, prev_d29: bool, prev_d30: bool) -> Self {
+        let prev = ((prev_d29 as u32) << 1) | prev_d30 as u32;
+        self.transmitted(&Self::from(prev << 2), false)
     }
 
     /// Verifies the parity of this [GpsDataWord], possibly invalidating this word.
@@ -151,6 +297,11 @@ impl GpsDataWord {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::{
+        bits::set_bits,
+        gps::{GpsQzssFrame, GpsQzssFrameId, GPS_FRAME_BITS, GPS_FRAME_BYTES},
+        tests::insert_zeros,
+    };
 
     #[test]
     fn from_be_bytes() {
@@ -170,6 +321,23 @@ mod test {
         }
     }
 
+    #[test]
+    fn formatting() {
+        let word = GpsDataWord::from_be_bytes(&[0x8B, 0x12, 0x48, 0xCA]);
+
+        assert_eq!(word.to_string(), "10001011 00010010 01001000 | 110010");
+        assert_eq!(format!("{:?}", word), "GpsDataWord(0x22C49232, parity=nok)");
+
+        let word = GpsDataWord::from(0x22C4_9200 << 2).with_parity(false, false);
+        assert_eq!(
+            format!("{:?}", word),
+            format!("GpsDataWord(0x{:08X}, parity=ok)", word.value())
+        );
+        assert!(word
+            .to_string()
+            .starts_with("10001011 00010010 01001000 | "));
+    }
+
     #[test]
     fn from_le_bytes() {
         for (bytes, value) in [
@@ -222,52 +390,270 @@ mod test {
         }
     }
 
-    // #[test]
-    // fn parity_calc() {
-    //     for (bin, b29, b30, expected) in [
-    //         (
-    //             0b101101001001011010010101110110u32,
-    //             0,
-    //             0,
-    //             0b000000u8,
-    //         ),
-    //         ] {
-
-    //         let word = GpsDataWord::from(bin << 6);
-    //         let parity = word.parity(&Default::default(), false);
-
-    //         assert_eq!(
-    //             parity, expected,
-    //             "got 0x{:02X}, expecting 0x{:02X}",
-    //             parity, expected
-    //         );
-    //     }
-    // }
-
-    // #[test]
-    // fn parity_ok_checker() {
-    //     for (b29, b30, dword) in [(0, 0, 0x8B1248CA)] {
-    //         let word = GpsDataWord::from(dword);
-
-    //         assert!(
-    //             word.parity_check(&Default::default(), false).is_ok(),
-    //             "failed for 0x{:08X}",
-    //             dword
-    //         );
-    //     }
-    // }
-
-    // #[test]
-    // fn parity_nok_checker() {
-    //     for dword in [0x8B1248CA] {
-    //         let word = GpsDataWord::from(dword);
-    //         assert!(
-    //             word.parity_check(&Default::default(), false).is_err(),
-    //             "failed for 0x{:08X}",
-    //             dword
-    //         );
-    //     }
-    // }
+    /// u-blox (previous D29* D30*, 30-bit word) pairs
+    const UBX_WORDS: [(u32, u32); 10] = [
+        (0, 0x22C13E1B),
+        (0, 0x1527C973),
+        (0, 0x13E40004),
+        (0, 0x104F5D31),
+        (2, 0x1744E6D7),
+        (0, 0x07755783),
+        (0, 0x330C80B5),
+        (2, 0x125042A1),
+        (2, 0x00001684),
+        (0, 0x312C3033),
+    ];
+
+    #[test]
+    fn ublox_sfrbx() {
+        let dwords = UBX_WORDS.map(|(_, dword)| dword);
+        let words = GpsDataWord::from_ublox_sfrbx(&dwords);
+
+        // D30* inversion is restored: words are chained as transmitted
+        let mut prev = GpsDataWord::default();
+
+        for (word, dword) in words.iter().zip(dwords.iter()) {
+            if prev.d30() {
+                assert_eq!(word.value(), !dword & 0x3fffffff);
+            } else {
+                assert_eq!(word.value(), dword & 0x3fffffff);
+            }
+
+            assert!(word.parity_check(&prev, false).is_ok());
+            prev = *word;
+        }
+
+        let frame = GpsQzssFrame::decode(&words, true).unwrap();
+
+        assert_eq!(frame.telemetry.message, 0x13E);
+        assert_eq!(frame.how.frame_id, GpsQzssFrameId::Ephemeris1);
+
+        // padding bits are discarded
+        let padded = dwords.map(|dword| dword | 0xc0000000);
+        assert_eq!(GpsDataWord::from_ublox_sfrbx(&padded), words);
+
+        // incomplete message
+        let words = GpsDataWord::from_ublox_sfrbx(&dwords[..2]);
+        assert_eq!(words[1].value(), !dwords[1] & 0x3fffffff);
+        assert_eq!(words[2], GpsDataWord::default());
+    }
+
+    #[test]
+    fn sbf_rawca() {
+        // synthetic EPH-2 frame: TLM message 0x1234 (integrity), HOW TOW=259200s (alert, A-S)
+        let nav_bits = [
+            0x22D234C2, 0x15181ABC, 0x0042B822, 0x1FFFC317, 0x0CCCCC84, 0x00864CD2, 0x0CCCCCC2,
+            0x010CA9CA, 0x12000032, 0x0350F20C,
+        ];
+
+        let words = GpsDataWord::from_sbf_rawca(&nav_bits);
+
+        for check_parity in [false, true] {
+            let frame = GpsQzssFrame::decode(&words, check_parity).unwrap();
+
+            assert_eq!(frame.telemetry.message, 0x1234);
+            assert!(frame.telemetry.integrity);
+
+            assert_eq!(frame.how.frame_id, GpsQzssFrameId::Ephemeris2);
+            assert_eq!(frame.how.tow, 259_200);
+            assert!(frame.how.alert);
+            assert!(frame.how.anti_spoofing);
+        }
+
+        // padding bits are discarded
+        let padded = nav_bits.map(|word| word | 0xc0000000);
+        assert_eq!(GpsDataWord::from_sbf_rawca(&padded), words);
+    }
+
+    #[test]
+    fn bit_offset_extraction() {
+        let mut buffer = [0; GPS_FRAME_BYTES];
+
+        for (i, (_, dword)) in UBX_WORDS.iter().enumerate() {
+            set_bits(&mut buffer, i * GPS_WORD_BITS, GPS_WORD_BITS, *dword);
+        }
+
+        for delay in 0..16 {
+            let delayed = insert_zeros(&buffer, delay);
+
+            for (i, (_, dword)) in UBX_WORDS.iter().enumerate() {
+                let word = GpsDataWord::from_bit_offset(&delayed, delay + i * GPS_WORD_BITS)
+                    .unwrap_or_else(|| panic!("failed to extract word #{} (delay={})", i, delay));
+
+                assert_eq!(word.value(), *dword, "word #{} (delay={})", i, delay);
+            }
+        }
+
+        assert!(GpsDataWord::from_bit_offset(&buffer, GPS_FRAME_BITS - GPS_WORD_BITS).is_some());
+        assert!(
+            GpsDataWord::from_bit_offset(&buffer, GPS_FRAME_BITS - GPS_WORD_BITS + 5).is_none()
+        );
+    }
+
+    #[test]
+    fn bit_fields() {
+        let word = GpsDataWord::from(0x22C13E1B << 2);
+
+        // preamble, TLM message and parity
+        assert_eq!(word.bits(1, 8), 0x8B);
+        assert_eq!(word.bits(9, 14), 0x13E);
+        assert_eq!(word.bits(25, 6), 0x1B);
+        assert_eq!(word.bits(1, 30), 0x22C13E1B);
+        assert_eq!(word.bits(30, 1), 1);
+
+        // invalid ranges
+        assert_eq!(word.bits(0, 8), 0);
+        assert_eq!(word.bits(1, 0), 0);
+        assert_eq!(word.bits(25, 7), 0);
+
+        // mask based results
+        for (_, dword) in UBX_WORDS {
+            let word = GpsDataWord::from(dword << 2);
+
+            assert_eq!(word.bits(1, 16), (dword & 0x3fffc000) >> 14);
+            assert_eq!(word.bits(17, 8), (dword & 0x00003fc0) >> 6);
+            assert_eq!(word.bits(1, 24), (dword & 0x3fffffc0) >> 6);
+            assert_eq!(word.bits(9, 14), (dword & 0x003fff00) >> 8);
+        }
+    }
+
+    #[test]
+    fn with_parity() {
+        for (prev, dword) in UBX_WORDS {
+            let word = GpsDataWord::from((dword & !0x3f) << 2);
+            let prev = GpsDataWord::from(prev << 2);
+
+            let word = word.with_parity(prev.value() & 0x2 > 0, prev.d30());
+
+            assert_eq!(word.value(), dword, "failed for 0x{:08X}", dword);
+            assert!(word.parity_check(&prev, false).is_ok());
+        }
+
+        // data bits are inverted when D30* is set
+        let word = GpsDataWord::from(0x13E40000 << 2);
+        let prev = GpsDataWord::from(0x1 << 2);
+
+        let transmitted = word.with_parity(false, true);
+
+        assert_eq!(
+            transmitted.value() & !0x3f,
+            (0x13E40000 ^ GPS_DATA_MASK) & !0x3f
+        );
+        assert!(transmitted.parity_check(&prev, false).is_ok());
+        assert_eq!(transmitted.descramble(true).value() & !0x3f, 0x13E40000);
+    }
+
+    #[test]
+    fn parity_calc() {
+        for (prev, dword) in UBX_WORDS {
+            let prev = GpsDataWord::from(prev << 2);
+            let word = GpsDataWord::from(dword << 2);
+            let parity = word.parity(&prev, false);
+            let expected = (dword & 0x3f) as u8;
+
+            assert_eq!(
+                parity, expected,
+                "got 0x{:02X}, expecting 0x{:02X}",
+                parity, expected
+            );
+        }
+    }
+
+    #[test]
+    fn parity_ok_checker() {
+        for (prev, dword) in UBX_WORDS {
+            let prev = GpsDataWord::from(prev << 2);
+            let word = GpsDataWord::from(dword << 2);
+
+            assert!(
+                word.parity_check(&prev, false).is_ok(),
+                "failed for 0x{:08X}",
+                dword
+            );
+        }
+    }
+
+    #[test]
+    fn parity_nok_checker() {
+        for (prev, dword) in UBX_WORDS {
+            let prev = GpsDataWord::from(prev << 2);
+
+            for bit in 0..30 {
+                let corrupt = dword ^ (1 << bit);
+                let word = GpsDataWord::from(corrupt << 2);
+
+                assert!(
+                    word.parity_check(&prev, false).is_err(),
+                    "failed for 0x{:08X}",
+                    corrupt
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parity_d30_inversion() {
+        for (prev, dword) in UBX_WORDS {
+            // same source data, transmitted after a word that ends with D30=1
+            let prev = GpsDataWord::from((prev | 0x1) << 2);
+            let inverted = (dword ^ 0x3fffffc0) & 0xffffffc0;
+
+            let parity = GpsDataWord::from(inverted << 2).parity(&prev, false);
+            let word = GpsDataWord::from((inverted | parity as u32) << 2);
+
+            assert!(word.parity_check(&prev, false).is_ok());
+        }
+    }
+
+    #[test]
+    fn descramble() {
+        for (_, dword) in UBX_WORDS {
+            let word = GpsDataWord::from(dword << 2);
+
+            assert_eq!(word.descramble(false), word);
+
+            let inverted = word.descramble(true);
+            assert_eq!(inverted.value(), dword ^ 0x3fffffc0);
+            assert_eq!(
+                inverted.value() & 0x3f,
+                dword & 0x3f,
+                "parity bits modified"
+            );
+            assert_eq!(inverted.descramble(true), word);
+        }
+    }
+
+    #[test]
+    fn transmitted() {
+        for (prev, dword) in UBX_WORDS {
+            let source = GpsDataWord::from((dword & 0x3fffffc0) << 2);
+
+            for prev in [prev, prev | 0x1] {
+                let prev = GpsDataWord::from(prev << 2);
+
+                let word = source.transmitted(&prev, false);
+                assert!(word.parity_check(&prev, false).is_ok());
+                assert_eq!(
+                    word.descramble(prev.d30()).value() & 0x3fffffc0,
+                    dword & 0x3fffffc0
+                );
+
+                let word = source.transmitted(&prev, true);
+                assert!(word.parity_check(&prev, false).is_ok());
+                assert_eq!(word.value() & 0x03, 0, "D29/D30 not solved");
+            }
+        }
+    }
+
+    #[test]
+    fn parity_nib() {
+        for (prev, dword) in UBX_WORDS {
+            let prev = GpsDataWord::from(prev << 2);
+            let word = GpsDataWord::from(dword << 2);
+            let parity = word.parity(&prev, true);
+            assert_eq!(parity & 0x03, 0, "D29/D30 not solved for 0x{:08X}", dword);
+        }
+    }
 
     #[test]
     fn test_asserted_bits() {
@@ -1,6 +1,7 @@
 #![doc(html_logo_url = "https://raw.githubusercontent.com/rtk-rs/.github/master/logos/logo2.jpg")]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 /*
  * gnss-protos is part of the rtk-rs framework.
@@ -20,9 +21,100 @@ mod gps;
 #[cfg(feature = "gps")]
 pub use gps::*;
 
+#[cfg(feature = "glonass")]
+mod glonass;
+
+#[cfg(feature = "glonass")]
+pub use glonass::*;
+
+#[cfg(feature = "beidou")]
+mod beidou;
+
+#[cfg(feature = "beidou")]
+pub use beidou::*;
+
+#[cfg(feature = "galileo")]
+mod galileo;
+
+#[cfg(feature = "galileo")]
+pub use galileo::*;
+
+#[cfg(feature = "sbas")]
+mod sbas;
+
+#[cfg(feature = "sbas")]
+pub use sbas::*;
+
+#[cfg(feature = "qzss_l1s")]
+mod qzss_l1s;
+
+#[cfg(feature = "qzss_l1s")]
+pub use qzss_l1s::*;
+
+mod bits;
+pub use bits::{peek_bits, BitIter, BitReader, BitWriter};
+
+mod crc;
+pub use crc::crc24q;
+
+mod time;
+pub use time::*;
+
+#[cfg(any(test, feature = "test-utils"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod testing;
+
 #[cfg(test)]
 mod tests;
 
+/// Largest message (in bits) across all enabled protocols.
+/// Use this to dimension encoding buffers that may receive any message.
+pub const MAX_MESSAGE_BITS: usize = {
+    let bits = 0;
+
+    #[cfg(feature = "gps")]
+    let bits = max_bits(bits, max_bits(GPS_FRAME_BITS, GPS_CNAV_MESSAGE_BITS));
+
+    #[cfg(feature = "glonass")]
+    let bits = max_bits(bits, GLONASS_LINE_BITS);
+
+    #[cfg(feature = "beidou")]
+    let bits = max_bits(bits, BEIDOU_D1_FRAME_BITS);
+
+    #[cfg(feature = "galileo")]
+    let bits = max_bits(
+        bits,
+        max_bits(GALILEO_INAV_PAGE_BITS, GALILEO_FNAV_PAGE_BITS),
+    );
+
+    #[cfg(feature = "sbas")]
+    let bits = max_bits(bits, SBAS_MESSAGE_BITS);
+
+    bits
+};
+
+#[cfg(any(
+    feature = "gps",
+    feature = "glonass",
+    feature = "beidou",
+    feature = "galileo",
+    feature = "sbas"
+))]
+const fn max_bits(lhs: usize, rhs: usize) -> usize {
+    if lhs > rhs {
+        lhs
+    } else {
+        rhs
+    }
+}
+
+/// Returns true if a buffer of `size` bytes can accept
+/// a message of `bits` bits, once padded to [u8].
+#[cfg(any(feature = "gps", feature = "galileo"))]
+pub(crate) const fn encoding_fits(size: usize, bits: usize) -> bool {
+    size >= (bits + 7) / 8
+}
+
 /// Two's complement parsing & interpretation.
 /// ## Input
 /// - raw bytes as [u32]
@@ -52,4 +144,18 @@ mod test {
         let parsed = twos_complement(value, 0x3fff, 0x2000);
         assert_eq!(parsed, 0xffffffffu32 as i32);
     }
+
+    #[test]
+    fn encoding_fits() {
+        use crate::{encoding_fits, GPS_FRAME_BITS, MAX_MESSAGE_BITS};
+
+        // no other protocol exceeds a GPS frame
+        assert_eq!(MAX_MESSAGE_BITS, GPS_FRAME_BITS);
+
+        assert!(encoding_fits(38, 300));
+        assert!(!encoding_fits(37, 300));
+        assert!(encoding_fits(1, 8));
+        assert!(!encoding_fits(0, 1));
+        assert!(encoding_fits((MAX_MESSAGE_BITS + 7) / 8, MAX_MESSAGE_BITS));
+    }
 }
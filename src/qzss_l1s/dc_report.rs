@@ -0,0 +1,172 @@
+use crate::bits::{get_bits, set_bits};
+
+/// Disaster specific information size (in bits!)
+const INFORMATION_BITS: usize = 183;
+
+/// Disaster specific information size (in bytes!), once padded
+const INFORMATION_BYTES: usize = (INFORMATION_BITS + 7) / 8;
+
+/// Report classification of training / test messages
+const TRAINING_CLASSIFICATION: u8 = 7;
+
+/// [QzssDcReport] (message type 43) is a Disaster and Crisis Report,
+/// issued by the Japan Meteorological Agency.
+/// The header is interpreted, the disaster specific information (which depends
+/// on the disaster category) is provided as is.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct QzssDcReport {
+    /// 3-bit Report classification: 1 for maximum priority, 2 for priority,
+    /// 3 for regular reports and 7 for training or test messages.
+    pub report_classification: u8,
+
+    /// 4-bit Disaster category code (earthquake early warning, tsunami, volcano..)
+    pub disaster_category: u8,
+
+    /// Report time (JST): 4-bit month
+    pub month: u8,
+
+    /// Report time (JST): 5-bit day of month
+    pub day: u8,
+
+    /// Report time (JST): 5-bit hour
+    pub hour: u8,
+
+    /// Report time (JST): 6-bit minute
+    pub minute: u8,
+
+    /// 2-bit Information type: 0 for issue, 1 for correction and 2 for cancellation
+    pub information_type: u8,
+
+    /// 183-bit disaster specific information, left aligned
+    pub information: [u8; INFORMATION_BYTES],
+}
+
+impl QzssDcReport {
+    /// Generates a realistic report model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        let mut information = [0u8; INFORMATION_BYTES];
+        information[0] = 0xA5;
+        information[11] = 0x3C;
+        information[22] = 0xFE;
+
+        Self::default()
+            .with_report_classification(3)
+            .with_disaster_category(5)
+            .with_report_time(3, 11, 14, 46)
+            .with_information(information)
+    }
+
+    /// Copies and returns [QzssDcReport] with updated 3-bit report classification
+    pub fn with_report_classification(mut self, classification: u8) -> Self {
+        self.report_classification = classification & 0x07;
+        self
+    }
+
+    /// Copies and returns [QzssDcReport] with updated 4-bit disaster category code
+    pub fn with_disaster_category(mut self, category: u8) -> Self {
+        self.disaster_category = category & 0x0f;
+        self
+    }
+
+    /// Copies and returns [QzssDcReport] with updated report time (JST)
+    pub fn with_report_time(mut self, month: u8, day: u8, hour: u8, minute: u8) -> Self {
+        self.month = month & 0x0f;
+        self.day = day & 0x1f;
+        self.hour = hour & 0x1f;
+        self.minute = minute & 0x3f;
+        self
+    }
+
+    /// Copies and returns [QzssDcReport] with updated 2-bit information type
+    pub fn with_information_type(mut self, information_type: u8) -> Self {
+        self.information_type = information_type & 0x03;
+        self
+    }
+
+    /// Copies and returns [QzssDcReport] with updated 183-bit disaster specific information.
+    /// The final padding bits are ignored.
+    pub fn with_information(mut self, information: [u8; INFORMATION_BYTES]) -> Self {
+        self.information = information;
+        self.information[INFORMATION_BYTES - 1] &= 0xfe;
+        self
+    }
+
+    /// Returns true if this is a training or test message, that should not be relayed
+    pub fn is_training(&self) -> bool {
+        self.report_classification == TRAINING_CLASSIFICATION
+    }
+
+    /// Decodes [Self] from this 250-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        let mut report = Self {
+            report_classification: get_bits(message, 14, 3) as u8,
+            disaster_category: get_bits(message, 17, 4) as u8,
+            month: get_bits(message, 21, 4) as u8,
+            day: get_bits(message, 25, 5) as u8,
+            hour: get_bits(message, 30, 5) as u8,
+            minute: get_bits(message, 35, 6) as u8,
+            information_type: get_bits(message, 41, 2) as u8,
+            ..Default::default()
+        };
+
+        for bit in (0..INFORMATION_BITS).step_by(16) {
+            let nbits = (INFORMATION_BITS - bit).min(16);
+            set_bits(
+                &mut report.information,
+                bit,
+                nbits,
+                get_bits(message, 43 + bit, nbits),
+            );
+        }
+
+        report
+    }
+
+    /// Encodes this [QzssDcReport] into this 250-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 14, 3, (self.report_classification & 0x07) as u32);
+        set_bits(message, 17, 4, (self.disaster_category & 0x0f) as u32);
+        set_bits(message, 21, 4, (self.month & 0x0f) as u32);
+        set_bits(message, 25, 5, (self.day & 0x1f) as u32);
+        set_bits(message, 30, 5, (self.hour & 0x1f) as u32);
+        set_bits(message, 35, 6, (self.minute & 0x3f) as u32);
+        set_bits(message, 41, 2, (self.information_type & 0x03) as u32);
+
+        for bit in (0..INFORMATION_BITS).step_by(16) {
+            let nbits = (INFORMATION_BITS - bit).min(16);
+            set_bits(
+                message,
+                43 + bit,
+                nbits,
+                get_bits(&self.information, bit, nbits),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sbas::SBAS_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = QzssDcReport::model();
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(QzssDcReport::from_message(&message), model);
+    }
+
+    #[test]
+    fn classification() {
+        let report = QzssDcReport::model();
+        assert!(!report.is_training());
+
+        let report = report.with_report_classification(0x0f);
+        assert_eq!(report.report_classification, 7);
+        assert!(report.is_training());
+    }
+}
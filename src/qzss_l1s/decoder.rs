@@ -0,0 +1,116 @@
+use crate::{qzss_l1s::QzssL1sMessage, sbas::SbasDecoder};
+
+#[cfg(feature = "log")]
+use log::error;
+
+/// [QzssL1sDecoder] can decode QZSS L1S (SLAS, DC Report) messages, from a stream of
+/// 250-bit messages that were already Viterbi decoded.
+///
+/// L1S shares the SBAS framing: the stream is synchronized on any of the three preambles.
+/// By [Default], our [QzssL1sDecoder] also verifies the CRC-24Q.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct QzssL1sDecoder {
+    /// Framing is identical to SBAS
+    framing: SbasDecoder,
+}
+
+impl QzssL1sDecoder {
+    /// Copies and returns a [QzssL1sDecoder] that does not verify the CRC.
+    /// Every message is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_crc_verification(mut self) -> Self {
+        self.framing = self.framing.without_crc_verification();
+        self
+    }
+
+    /// Decodes the first valid [QzssL1sMessage] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same message twice.
+    /// - Optional [QzssL1sMessage] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When no complete message was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next message: simply append the following bytes
+    /// and call [Self::decode] again.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<QzssL1sMessage>) {
+        let (end, message) = match self.framing.extract_message(buffer, size) {
+            (end, Some(message)) => (end, message),
+            (processed, None) => return (processed, None),
+        };
+
+        match QzssL1sMessage::decode_message(&message) {
+            Ok(message) => (end, Some(message)),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(QZSS/L1S): {}", e);
+                (end, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        bits::{get_bits, set_bits},
+        qzss_l1s::{QzssDcReport, QzssL1sDecoder, QzssL1sMessage, QzssSlasMessageType},
+        sbas::{SBAS_MESSAGE_BITS, SBAS_PREAMBLES},
+        testing::insert_bit_delay,
+    };
+
+    #[test]
+    fn stream_decoding() {
+        let mut data = [0u8; 27];
+        data[0] = 0xC3;
+        data[26] = 0xF0;
+
+        let models = [
+            QzssL1sMessage::DcReport(QzssDcReport::model()),
+            QzssL1sMessage::Slas(QzssSlasMessageType::DgpsCorrections, data),
+            QzssL1sMessage::DcReport(QzssDcReport::model().with_information_type(2)),
+            QzssL1sMessage::Raw(63, [0; 27]),
+        ];
+
+        let mut stream = vec![0u8; (models.len() * SBAS_MESSAGE_BITS + 7) / 8];
+
+        for (i, model) in models.iter().enumerate() {
+            let message = model.encode_message(SBAS_PREAMBLES[i % 3]);
+
+            for bit in (0..SBAS_MESSAGE_BITS).step_by(2) {
+                let offset = i * SBAS_MESSAGE_BITS + bit;
+                set_bits(&mut stream, offset, 2, get_bits(&message, bit, 2));
+            }
+        }
+
+        for delay in [0, 3, 8, 13] {
+            let buffer = insert_bit_delay(&stream, delay);
+
+            let mut decoder = QzssL1sDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, message) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(message) = message {
+                    decoded.push(message);
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{
+    bits::get_bits,
+    sbas::{SbasError, SbasMessage, RAW_BYTES, SBAS_MESSAGE_BYTES},
+};
+
+mod dc_report;
+pub use dc_report::QzssDcReport;
+
+mod decoder;
+pub use decoder::QzssL1sDecoder;
+
+/// [QzssSlasMessageType] identifies the Sub-meter Level Augmentation Service (SLAS) messages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum QzssSlasMessageType {
+    /// Message type 47: monitoring station information
+    MonitoringStations,
+
+    /// Message type 48: PRN mask
+    PrnMask,
+
+    /// Message type 49: data issue numbers
+    DataIssueNumbers,
+
+    /// Message type 50: DGPS corrections
+    DgpsCorrections,
+
+    /// Message type 51: satellite health
+    SatelliteHealth,
+}
+
+impl QzssSlasMessageType {
+    /// Returns the [QzssSlasMessageType] for this 6-bit message type, if any
+    pub fn from_message_type(message_type: u8) -> Option<Self> {
+        match message_type {
+            47 => Some(Self::MonitoringStations),
+            48 => Some(Self::PrnMask),
+            49 => Some(Self::DataIssueNumbers),
+            50 => Some(Self::DgpsCorrections),
+            51 => Some(Self::SatelliteHealth),
+            _ => None,
+        }
+    }
+
+    /// Returns the 6-bit message type
+    pub fn message_type(&self) -> u8 {
+        match self {
+            Self::MonitoringStations => 47,
+            Self::PrnMask => 48,
+            Self::DataIssueNumbers => 49,
+            Self::DgpsCorrections => 50,
+            Self::SatelliteHealth => 51,
+        }
+    }
+}
+
+/// [QzssL1sMessage] is the content of a QZSS L1S message, identified by its 6-bit message type.
+/// L1S messages share the SBAS (L1) format: 250-bit long, rotating preambles and CRC-24Q.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum QzssL1sMessage {
+    /// Message type 43: [QzssDcReport] (Disaster and Crisis Report)
+    DcReport(QzssDcReport),
+
+    /// Message types 47 to 51: SLAS corrections, identified by [QzssSlasMessageType].
+    /// The 212-bit data field is not interpreted yet, left aligned.
+    Slas(QzssSlasMessageType, [u8; RAW_BYTES]),
+
+    /// Other message types, not interpreted: message type and the 212-bit data field,
+    /// left aligned.
+    Raw(u8, [u8; RAW_BYTES]),
+}
+
+impl Default for QzssL1sMessage {
+    fn default() -> Self {
+        Self::DcReport(Default::default())
+    }
+}
+
+impl QzssL1sMessage {
+    /// Returns the 6-bit message type
+    pub fn message_type(&self) -> u8 {
+        match self {
+            Self::DcReport(_) => 43,
+            Self::Slas(slas, _) => slas.message_type(),
+            Self::Raw(message_type, _) => *message_type,
+        }
+    }
+
+    /// Decodes a [QzssL1sMessage] from this 250-bit message (left aligned).
+    /// This method does not care for the CRC.
+    ///
+    /// ## Output
+    /// - [SbasError::WouldNotFit] when this buffer is shorter than [SBAS_MESSAGE_BYTES]
+    /// - [SbasError::InvalidPreamble] when the message does not start with one of the SBAS preambles
+    pub fn decode_message(message: &[u8]) -> Result<Self, SbasError> {
+        if message.len() < SBAS_MESSAGE_BYTES {
+            return Err(SbasError::WouldNotFit);
+        }
+
+        if !SbasMessage::is_preamble(message, 0) {
+            return Err(SbasError::InvalidPreamble);
+        }
+
+        let message_type = get_bits(message, 8, 6) as u8;
+
+        if message_type == 43 {
+            return Ok(Self::DcReport(QzssDcReport::from_message(message)));
+        }
+
+        let data = SbasMessage::data_field(message);
+
+        Ok(match QzssSlasMessageType::from_message_type(message_type) {
+            Some(slas) => Self::Slas(slas, data),
+            None => Self::Raw(message_type, data),
+        })
+    }
+
+    /// Encodes this [QzssL1sMessage] as a 250-bit message (left aligned), CRC included.
+    /// `preamble` should be one of the SBAS preambles, following the previous message's.
+    pub fn encode_message(&self, preamble: u8) -> [u8; SBAS_MESSAGE_BYTES] {
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+
+        match self {
+            Self::DcReport(report) => report.to_message(&mut message),
+            Self::Slas(_, data) | Self::Raw(_, data) => {
+                SbasMessage::set_data_field(&mut message, data)
+            },
+        }
+
+        SbasMessage::seal(&mut message, preamble, self.message_type());
+        message
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn message_reciprocal() {
+        let mut data = [0u8; RAW_BYTES];
+        data[0] = 0x81;
+        data[26] = 0x30;
+
+        for model in [
+            QzssL1sMessage::DcReport(QzssDcReport::model()),
+            QzssL1sMessage::Slas(QzssSlasMessageType::MonitoringStations, data),
+            QzssL1sMessage::Slas(QzssSlasMessageType::SatelliteHealth, data),
+            QzssL1sMessage::Raw(63, data),
+        ] {
+            let message = model.encode_message(0x9A);
+
+            assert!(SbasMessage::crc_ok(&message, 0));
+            assert_eq!(get_bits(&message, 8, 6), model.message_type() as u32);
+            assert_eq!(QzssL1sMessage::decode_message(&message).unwrap(), model);
+        }
+
+        for message_type in 47..=51 {
+            let slas = QzssSlasMessageType::from_message_type(message_type).unwrap();
+            assert_eq!(slas.message_type(), message_type);
+        }
+
+        assert!(QzssSlasMessageType::from_message_type(43).is_none());
+    }
+
+    #[test]
+    fn reference_message() {
+        // regular tsunami (category 5) report, issued on March 11th 14:46 (JST),
+        // assembled and CRC'd independently of this library
+        let message = [
+            0xC6, 0xAD, 0xA9, 0xAD, 0xD7, 0x0B, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x1F, 0xAB, 0x33, 0x00,
+        ];
+
+        assert!(SbasMessage::crc_ok(&message, 0));
+
+        let report = match QzssL1sMessage::decode_message(&message).unwrap() {
+            QzssL1sMessage::DcReport(report) => report,
+            message => panic!("decoded unexpected message: {:?}", message),
+        };
+
+        assert_eq!(report.report_classification, 3);
+        assert_eq!(report.disaster_category, 5);
+        assert_eq!((report.month, report.day), (3, 11));
+        assert_eq!((report.hour, report.minute), (14, 46));
+        assert_eq!(report.information_type, 0);
+        assert_eq!(report.information[0], 0x5A);
+        assert!(!report.is_training());
+
+        assert_eq!(
+            QzssL1sMessage::DcReport(report).encode_message(0xC6),
+            message
+        );
+    }
+}
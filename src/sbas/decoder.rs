@@ -0,0 +1,205 @@
+use crate::{
+    bits::get_bits,
+    sbas::{SbasMessage, SBAS_MESSAGE_BITS, SBAS_MESSAGE_BYTES},
+};
+
+#[cfg(feature = "log")]
+use log::{error, trace};
+
+/// [SbasDecoder] can decode SBAS (WAAS, EGNOS..) L1 messages, from a stream of
+/// 250-bit messages that were already Viterbi decoded.
+///
+/// The stream is synchronized on any of the three preambles. By [Default], our [SbasDecoder]
+/// also verifies the CRC-24Q, which makes the synchronization much more robust.
+#[derive(Debug, Copy, Clone)]
+pub struct SbasDecoder {
+    /// True when CRC verification is requested
+    crc_verification: bool,
+}
+
+impl Default for SbasDecoder {
+    /// Creates a default [SbasDecoder] that verifies the CRC.
+    fn default() -> Self {
+        Self {
+            crc_verification: true,
+        }
+    }
+}
+
+impl SbasDecoder {
+    /// Copies and returns a [SbasDecoder] that does not verify the CRC.
+    /// Every message is then interpreted as is, which is only recommended
+    /// when working with a stream that was verified already.
+    pub fn without_crc_verification(mut self) -> Self {
+        self.crc_verification = false;
+        self
+    }
+
+    /// Locates the first preamble that is followed by a message,
+    /// within this buffer, at any bit offset.
+    fn find_message(&self, buffer: &[u8], size: usize) -> Option<usize> {
+        let total_bits = size * 8;
+
+        if total_bits < SBAS_MESSAGE_BITS {
+            return None;
+        }
+
+        (0..=total_bits - SBAS_MESSAGE_BITS).find(|offset| {
+            SbasMessage::is_preamble(buffer, *offset)
+                && (!self.crc_verification || SbasMessage::crc_ok(buffer, *offset))
+        })
+    }
+
+    /// Extracts the first message found in this read-only [u8] buffer, left aligned.
+    /// Returns the total number of _bits_ that were consumed, like [Self::decode].
+    /// Other protocols that share the SBAS framing (like QZSS L1S) rely on this.
+    pub(crate) fn extract_message(
+        &self,
+        buffer: &[u8],
+        size: usize,
+    ) -> (usize, Option<[u8; SBAS_MESSAGE_BYTES]>) {
+        let size = size.min(buffer.len());
+
+        let offset_bit = match self.find_message(buffer, size) {
+            Some(offset) => offset,
+            None => {
+                return ((size * 8).saturating_sub(SBAS_MESSAGE_BITS - 1), None);
+            },
+        };
+
+        #[cfg(feature = "log")]
+        trace!("(SBAS): pos={}", offset_bit);
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+
+        for (i, byte) in message.iter_mut().enumerate() {
+            let nbits = (SBAS_MESSAGE_BITS - i * 8).min(8);
+            *byte = (get_bits(buffer, offset_bit + i * 8, nbits) << (8 - nbits)) as u8;
+        }
+
+        (offset_bit + SBAS_MESSAGE_BITS, Some(message))
+    }
+
+    /// Decodes the first valid [SbasMessage] found in this read-only [u8] buffer.
+    ///
+    /// ## Input
+    /// - buffer: read-only [u8] buffer
+    /// - size: buffer size (in bytes)
+    ///
+    /// ## Ouput
+    /// - Total number of _bits_ that were consumed (not bytes!).
+    ///   You are expected to discard all processed _bits_ not to decode the same message twice.
+    /// - Optional [SbasMessage] correctly decoded. First in order of appearance in the buffer.
+    ///
+    /// When no complete message was found, the final bits are not consumed,
+    /// because they may contain the beginning of the next message: simply append the following bytes
+    /// and call [Self::decode] again.
+    pub fn decode(&mut self, buffer: &[u8], size: usize) -> (usize, Option<SbasMessage>) {
+        let (end, message) = match self.extract_message(buffer, size) {
+            (end, Some(message)) => (end, message),
+            (processed, None) => return (processed, None),
+        };
+
+        match SbasMessage::decode_message(&message) {
+            Ok(message) => (end, Some(message)),
+            #[cfg(not(feature = "log"))]
+            Err(_) => (end, None),
+            #[cfg(feature = "log")]
+            Err(e) => {
+                error!("(SBAS): {}", e);
+                (end, None)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        bits::{get_bits, set_bits},
+        sbas::{
+            SbasDecoder, SbasFastCorrections, SbasGeoNavigation, SbasMessage, SbasPrnMask,
+            SBAS_MESSAGE_BITS, SBAS_PREAMBLES,
+        },
+        testing::insert_bit_delay,
+    };
+
+    fn models() -> Vec<SbasMessage> {
+        vec![
+            SbasMessage::PrnMask(SbasPrnMask::model()),
+            SbasMessage::FastCorrections(2, SbasFastCorrections::model()),
+            SbasMessage::FastCorrections(3, SbasFastCorrections::model().with_iodf(1)),
+            SbasMessage::GeoNavigation(SbasGeoNavigation::model()),
+        ]
+    }
+
+    /// Concatenates (bitwise) these messages, with rotating preambles,
+    /// like a synchronous stream does.
+    fn stream(models: &[SbasMessage]) -> Vec<u8> {
+        let total_bits = models.len() * SBAS_MESSAGE_BITS;
+        let mut buffer = vec![0u8; (total_bits + 7) / 8];
+
+        for (i, model) in models.iter().enumerate() {
+            let message = model.encode_message(SBAS_PREAMBLES[i % 3]);
+
+            for bit in (0..SBAS_MESSAGE_BITS).step_by(2) {
+                let offset = i * SBAS_MESSAGE_BITS + bit;
+                set_bits(&mut buffer, offset, 2, get_bits(&message, bit, 2));
+            }
+        }
+
+        buffer
+    }
+
+    #[test]
+    fn stream_decoding() {
+        let models = models();
+
+        for delay in [0, 1, 3, 7, 8, 13] {
+            let buffer = insert_bit_delay(&stream(&models), delay);
+
+            let mut decoder = SbasDecoder::default();
+            let mut ptr = 0;
+            let mut decoded = Vec::new();
+
+            while ptr < buffer.len() {
+                let (processed, message) = decoder.decode(&buffer[ptr..], buffer.len() - ptr);
+
+                if let Some(message) = message {
+                    decoded.push(message);
+                }
+
+                if processed / 8 == 0 {
+                    break;
+                }
+
+                ptr += processed / 8;
+            }
+
+            assert_eq!(decoded, models, "failed with {} bit delay", delay);
+        }
+    }
+
+    #[test]
+    fn crc_rejection() {
+        let model = models()[1];
+        let mut buffer = stream(&[model]);
+
+        let mut decoder = SbasDecoder::default();
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, SBAS_MESSAGE_BITS);
+        assert_eq!(message, Some(model));
+
+        // corrupt the first correction
+        buffer[3] ^= 0x10;
+
+        let (_, message) = decoder.decode(&buffer, buffer.len());
+        assert!(message.is_none());
+
+        let mut decoder = SbasDecoder::default().without_crc_verification();
+        let (processed, message) = decoder.decode(&buffer, buffer.len());
+        assert_eq!(processed, SBAS_MESSAGE_BITS);
+        assert!(message.is_some());
+        assert_ne!(message, Some(model));
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SbasError {
+    /// Not a valid SBAS preamble
+    #[error("invalid SBAS preamble")]
+    InvalidPreamble,
+
+    /// Buffer is too small to contain a complete message
+    #[error("buffer to small for this SBAS message")]
+    WouldNotFit,
+}
@@ -0,0 +1,128 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+/// Number of satellites corrected by one message
+const NUM_CORRECTIONS: usize = 13;
+
+/// Pseudo range correction resolution (in meters)
+const PRC_LSB: f64 = 0.125;
+
+/// [SbasFastCorrections] (message types 2 to 5) gives the fast pseudo range corrections
+/// of 13 consecutive satellites of the [SbasPrnMask](crate::sbas::SbasPrnMask):
+/// message type 2 corrects the first 13 satellites, type 3 the following 13, and so on.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct SbasFastCorrections {
+    /// 2-bit Issue of Data fast corrections
+    pub iodf: u8,
+
+    /// 2-bit Issue of Data PRN mask this message applies to
+    pub iodp: u8,
+
+    /// Fast pseudo range corrections (in meters)
+    pub prc: [f64; NUM_CORRECTIONS],
+
+    /// 4-bit User Differential Range Error indicators.
+    /// 14 means not monitored, 15 means do not use.
+    pub udrei: [u8; NUM_CORRECTIONS],
+}
+
+impl SbasFastCorrections {
+    /// Generates a realistic message model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        let mut corrections = Self::default().with_iodf(2).with_iodp(3);
+
+        for i in 0..NUM_CORRECTIONS {
+            corrections = corrections.with_correction(i, (i as f64 - 6.0) * 12.375, (i % 16) as u8);
+        }
+
+        corrections
+    }
+
+    /// Copies and returns [SbasFastCorrections] with updated 2-bit Issue of Data fast corrections
+    pub fn with_iodf(mut self, iodf: u8) -> Self {
+        self.iodf = iodf & 0x03;
+        self
+    }
+
+    /// Copies and returns [SbasFastCorrections] with updated 2-bit Issue of Data PRN mask
+    pub fn with_iodp(mut self, iodp: u8) -> Self {
+        self.iodp = iodp & 0x03;
+        self
+    }
+
+    /// Copies and returns [SbasFastCorrections] with updated pseudo range correction (in meters)
+    /// and 4-bit UDREI, for satellite #`index` (0 to 12) of this message. Other indexes are ignored.
+    pub fn with_correction(mut self, index: usize, prc: f64, udrei: u8) -> Self {
+        if index < NUM_CORRECTIONS {
+            self.prc[index] = prc;
+            self.udrei[index] = udrei & 0x0f;
+        }
+        self
+    }
+
+    /// Decodes [Self] from this 250-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        let mut corrections = Self {
+            iodf: get_bits(message, 14, 2) as u8,
+            iodp: get_bits(message, 16, 2) as u8,
+            ..Default::default()
+        };
+
+        for i in 0..NUM_CORRECTIONS {
+            corrections.prc[i] = get_signed_bits(message, 18 + i * 12, 12) as f64 * PRC_LSB;
+            corrections.udrei[i] = get_bits(message, 174 + i * 4, 4) as u8;
+        }
+
+        corrections
+    }
+
+    /// Encodes this [SbasFastCorrections] into this 250-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 14, 2, (self.iodf & 0x03) as u32);
+        set_bits(message, 16, 2, (self.iodp & 0x03) as u32);
+
+        for i in 0..NUM_CORRECTIONS {
+            set_bits(
+                message,
+                18 + i * 12,
+                12,
+                to_signed_bits(self.prc[i] / PRC_LSB, 12),
+            );
+            set_bits(message, 174 + i * 4, 4, (self.udrei[i] & 0x0f) as u32);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sbas::SBAS_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = SbasFastCorrections::model();
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(get_bits(&message, 14, 4), 0x0b);
+        assert_eq!(SbasFastCorrections::from_message(&message), model);
+    }
+
+    #[test]
+    fn saturation() {
+        let corrections = SbasFastCorrections::default()
+            .with_correction(0, 1000.0, 0x1f)
+            .with_correction(12, -1000.0, 15)
+            .with_correction(13, 1.0, 1);
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+        corrections.to_message(&mut message);
+
+        let decoded = SbasFastCorrections::from_message(&message);
+        assert_eq!(decoded.prc[0], 255.875);
+        assert_eq!(decoded.udrei[0], 15);
+        assert_eq!(decoded.prc[12], -256.0);
+        assert_eq!(decoded.udrei[12], 15);
+    }
+}
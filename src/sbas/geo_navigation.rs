@@ -0,0 +1,198 @@
+use crate::bits::{get_bits, get_signed_bits, set_bits, to_signed_bits};
+
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+/// [SbasGeoNavigation] (message type 9) gives the position, velocity and acceleration
+/// of the GEO satellite, in ECEF coordinates, along with its clock correction.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct SbasGeoNavigation {
+    /// 8-bit reserved (formerly the Issue of Data navigation)
+    pub reserved: u8,
+
+    /// Reference time of day (in seconds)
+    pub t0: u32,
+
+    /// 4-bit User Range Accuracy index
+    pub ura: u8,
+
+    /// ECEF position (in meters)
+    pub position: (f64, f64, f64),
+
+    /// ECEF velocity (in meters.s⁻¹)
+    pub velocity: (f64, f64, f64),
+
+    /// ECEF acceleration (in meters.s⁻²)
+    pub acceleration: (f64, f64, f64),
+
+    /// Clock bias (in seconds)
+    pub af0: f64,
+
+    /// Clock drift (in seconds.s⁻¹)
+    pub af1: f64,
+}
+
+impl SbasGeoNavigation {
+    /// Generates a realistic message model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        Self::default()
+            .with_t0(43_200)
+            .with_ura(2)
+            .with_position((-12_345_678.0 * 0.08, 42_123_456.0 * 0.08, 1_234.0 * 0.4))
+            .with_velocity((1_234.0 * 0.000_625, -567.0 * 0.000_625, 12_345.0 * 0.004))
+            .with_acceleration((-12.0 * 0.000_012_5, 34.0 * 0.000_012_5, -56.0 * 0.000_062_5))
+            .with_af0(-1_234.0 * 2.0_f64.powi(-31))
+            .with_af1(56.0 * 2.0_f64.powi(-40))
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated reference time of day (in seconds).
+    /// Should be a multiple of 16 seconds, to be exactly encoded.
+    pub fn with_t0(mut self, t0: u32) -> Self {
+        self.t0 = t0;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated 4-bit URA index
+    pub fn with_ura(mut self, ura: u8) -> Self {
+        self.ura = ura & 0x0f;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated ECEF position (in meters)
+    pub fn with_position(mut self, position: (f64, f64, f64)) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated ECEF velocity (in meters.s⁻¹)
+    pub fn with_velocity(mut self, velocity: (f64, f64, f64)) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated ECEF acceleration (in meters.s⁻²)
+    pub fn with_acceleration(mut self, acceleration: (f64, f64, f64)) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated clock bias (in seconds)
+    pub fn with_af0(mut self, af0: f64) -> Self {
+        self.af0 = af0;
+        self
+    }
+
+    /// Copies and returns [SbasGeoNavigation] with updated clock drift (in seconds.s⁻¹)
+    pub fn with_af1(mut self, af1: f64) -> Self {
+        self.af1 = af1;
+        self
+    }
+
+    /// Decodes [Self] from this 250-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        Self {
+            reserved: get_bits(message, 14, 8) as u8,
+            t0: get_bits(message, 22, 13) * 16,
+            ura: get_bits(message, 35, 4) as u8,
+            position: (
+                get_signed_bits(message, 39, 30) as f64 * 0.08,
+                get_signed_bits(message, 69, 30) as f64 * 0.08,
+                get_signed_bits(message, 99, 25) as f64 * 0.4,
+            ),
+            velocity: (
+                get_signed_bits(message, 124, 17) as f64 * 0.000_625,
+                get_signed_bits(message, 141, 17) as f64 * 0.000_625,
+                get_signed_bits(message, 158, 18) as f64 * 0.004,
+            ),
+            acceleration: (
+                get_signed_bits(message, 176, 10) as f64 * 0.000_012_5,
+                get_signed_bits(message, 186, 10) as f64 * 0.000_012_5,
+                get_signed_bits(message, 196, 10) as f64 * 0.000_062_5,
+            ),
+            af0: get_signed_bits(message, 206, 12) as f64 * 2.0_f64.powi(-31),
+            af1: get_signed_bits(message, 218, 8) as f64 * 2.0_f64.powi(-40),
+        }
+    }
+
+    /// Encodes this [SbasGeoNavigation] into this 250-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        set_bits(message, 14, 8, self.reserved as u32);
+        set_bits(message, 22, 13, (self.t0 / 16) & 0x1fff);
+        set_bits(message, 35, 4, (self.ura & 0x0f) as u32);
+        set_bits(message, 39, 30, to_signed_bits(self.position.0 / 0.08, 30));
+        set_bits(message, 69, 30, to_signed_bits(self.position.1 / 0.08, 30));
+        set_bits(message, 99, 25, to_signed_bits(self.position.2 / 0.4, 25));
+        set_bits(
+            message,
+            124,
+            17,
+            to_signed_bits(self.velocity.0 / 0.000_625, 17),
+        );
+        set_bits(
+            message,
+            141,
+            17,
+            to_signed_bits(self.velocity.1 / 0.000_625, 17),
+        );
+        set_bits(
+            message,
+            158,
+            18,
+            to_signed_bits(self.velocity.2 / 0.004, 18),
+        );
+        set_bits(
+            message,
+            176,
+            10,
+            to_signed_bits(self.acceleration.0 / 0.000_012_5, 10),
+        );
+        set_bits(
+            message,
+            186,
+            10,
+            to_signed_bits(self.acceleration.1 / 0.000_012_5, 10),
+        );
+        set_bits(
+            message,
+            196,
+            10,
+            to_signed_bits(self.acceleration.2 / 0.000_062_5, 10),
+        );
+        set_bits(
+            message,
+            206,
+            12,
+            to_signed_bits(self.af0 / 2.0_f64.powi(-31), 12),
+        );
+        set_bits(
+            message,
+            218,
+            8,
+            to_signed_bits(self.af1 / 2.0_f64.powi(-40), 8),
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sbas::SBAS_MESSAGE_BYTES;
+
+    #[test]
+    fn reciprocal() {
+        let model = SbasGeoNavigation::model();
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(get_bits(&message, 22, 13), 2_700);
+
+        let decoded = SbasGeoNavigation::from_message(&message);
+        assert_eq!(decoded, model);
+
+        let mut reencoded = [0u8; SBAS_MESSAGE_BYTES];
+        decoded.to_message(&mut reencoded);
+        assert_eq!(reencoded, message);
+    }
+}
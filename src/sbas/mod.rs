@@ -0,0 +1,259 @@
+use crate::{
+    bits::{get_bits, set_bits},
+    crc24q,
+};
+
+/// SBAS preambles: each message starts with one of these 8-bit preambles,
+/// which cycle through this sequence in consecutive messages.
+pub const SBAS_PREAMBLES: [u8; 3] = [0x53, 0x9A, 0xC6];
+
+/// SBAS (L1) message size (in bits!), CRC included
+pub const SBAS_MESSAGE_BITS: usize = 250;
+
+/// SBAS (L1) message size (in bytes!), once padded
+pub const SBAS_MESSAGE_BYTES: usize = (SBAS_MESSAGE_BITS + 7) / 8;
+
+/// Preamble and message type size (in bits!)
+pub(crate) const HEADER_BITS: usize = 14;
+
+/// Data field size (in bits!)
+const RAW_BITS: usize = 212;
+
+/// Data field size (in bytes!), once padded
+pub(crate) const RAW_BYTES: usize = (RAW_BITS + 7) / 8;
+
+/// Message size (in bits!), protected by the CRC
+const DATA_BITS: usize = HEADER_BITS + RAW_BITS;
+
+/// CRC size (in bits!)
+const CRC_BITS: usize = 24;
+
+mod decoder;
+pub use decoder::SbasDecoder;
+
+mod errors;
+pub use errors::SbasError;
+
+mod fast_corrections;
+pub use fast_corrections::SbasFastCorrections;
+
+mod geo_navigation;
+pub use geo_navigation::SbasGeoNavigation;
+
+mod prn_mask;
+pub use prn_mask::SbasPrnMask;
+
+/// [SbasMessage] is the content of an SBAS (L1) message, identified by its 6-bit message type.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SbasMessage {
+    /// Message type 1: [SbasPrnMask]
+    PrnMask(SbasPrnMask),
+
+    /// Message types 2 to 5: message type and [SbasFastCorrections]
+    /// of the 13 satellites of the matching block of the PRN mask.
+    FastCorrections(u8, SbasFastCorrections),
+
+    /// Message type 9: [SbasGeoNavigation]
+    GeoNavigation(SbasGeoNavigation),
+
+    /// Other message types, not interpreted yet: message type and the 212-bit data field,
+    /// left aligned.
+    Raw(u8, [u8; RAW_BYTES]),
+}
+
+impl Default for SbasMessage {
+    fn default() -> Self {
+        Self::PrnMask(Default::default())
+    }
+}
+
+impl SbasMessage {
+    /// Returns the 6-bit message type
+    pub fn message_type(&self) -> u8 {
+        match self {
+            Self::PrnMask(_) => 1,
+            Self::FastCorrections(message_type, _) => *message_type,
+            Self::GeoNavigation(_) => 9,
+            Self::Raw(message_type, _) => *message_type,
+        }
+    }
+
+    /// Returns the 2-bit Issue of Data PRN mask, for message types 1 to 5
+    pub fn iodp(&self) -> Option<u8> {
+        match self {
+            Self::PrnMask(mask) => Some(mask.iodp),
+            Self::FastCorrections(_, corrections) => Some(corrections.iodp),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this message's CRC is correct
+    pub(crate) fn crc_ok(message: &[u8], offset: usize) -> bool {
+        let mut data = [0u8; SBAS_MESSAGE_BYTES];
+
+        for bit in (0..DATA_BITS).step_by(16) {
+            let nbits = (DATA_BITS - bit).min(16);
+            set_bits(
+                &mut data,
+                bit,
+                nbits,
+                get_bits(message, offset + bit, nbits),
+            );
+        }
+
+        crc24q(&data, DATA_BITS) == get_bits(message, offset + DATA_BITS, CRC_BITS)
+    }
+
+    /// Returns true if this buffer starts with an SBAS preamble at this bit offset
+    pub(crate) fn is_preamble(buffer: &[u8], offset: usize) -> bool {
+        let preamble = get_bits(buffer, offset, 8) as u8;
+        SBAS_PREAMBLES.contains(&preamble)
+    }
+
+    /// Decodes a [SbasMessage] from this 250-bit message (left aligned).
+    /// This method does not care for the CRC.
+    ///
+    /// ## Output
+    /// - [SbasError::WouldNotFit] when this buffer is shorter than [SBAS_MESSAGE_BYTES]
+    /// - [SbasError::InvalidPreamble] when the message does not start with one of the [SBAS_PREAMBLES]
+    pub fn decode_message(message: &[u8]) -> Result<Self, SbasError> {
+        if message.len() < SBAS_MESSAGE_BYTES {
+            return Err(SbasError::WouldNotFit);
+        }
+
+        if !Self::is_preamble(message, 0) {
+            return Err(SbasError::InvalidPreamble);
+        }
+
+        Ok(match get_bits(message, 8, 6) {
+            1 => Self::PrnMask(SbasPrnMask::from_message(message)),
+            message_type @ 2..=5 => Self::FastCorrections(
+                message_type as u8,
+                SbasFastCorrections::from_message(message),
+            ),
+            9 => Self::GeoNavigation(SbasGeoNavigation::from_message(message)),
+            message_type => Self::Raw(message_type as u8, Self::data_field(message)),
+        })
+    }
+
+    /// Returns the 212-bit data field of this message, left aligned.
+    pub(crate) fn data_field(message: &[u8]) -> [u8; RAW_BYTES] {
+        let mut data = [0u8; RAW_BYTES];
+
+        for bit in (0..RAW_BITS).step_by(16) {
+            let nbits = (RAW_BITS - bit).min(16);
+            set_bits(
+                &mut data,
+                bit,
+                nbits,
+                get_bits(message, HEADER_BITS + bit, nbits),
+            );
+        }
+
+        data
+    }
+
+    /// Writes this 212-bit data field (left aligned) into this message.
+    pub(crate) fn set_data_field(message: &mut [u8], data: &[u8; RAW_BYTES]) {
+        for bit in (0..RAW_BITS).step_by(16) {
+            let nbits = (RAW_BITS - bit).min(16);
+            set_bits(
+                message,
+                HEADER_BITS + bit,
+                nbits,
+                get_bits(data, bit, nbits),
+            );
+        }
+    }
+
+    /// Writes the preamble and message type into this message, then the CRC.
+    pub(crate) fn seal(message: &mut [u8], preamble: u8, message_type: u8) {
+        set_bits(message, 0, 8, preamble as u32);
+        set_bits(message, 8, 6, (message_type & 0x3f) as u32);
+
+        let crc = crc24q(message, DATA_BITS);
+        set_bits(message, DATA_BITS, CRC_BITS, crc);
+    }
+
+    /// Encodes this [SbasMessage] as a 250-bit message (left aligned), CRC included.
+    /// `preamble` should be one of the [SBAS_PREAMBLES], following the previous message's.
+    pub fn encode_message(&self, preamble: u8) -> [u8; SBAS_MESSAGE_BYTES] {
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+
+        match self {
+            Self::PrnMask(mask) => mask.to_message(&mut message),
+            Self::FastCorrections(_, corrections) => corrections.to_message(&mut message),
+            Self::GeoNavigation(navigation) => navigation.to_message(&mut message),
+            Self::Raw(_, data) => Self::set_data_field(&mut message, data),
+        }
+
+        Self::seal(&mut message, preamble, self.message_type());
+        message
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn models() -> Vec<SbasMessage> {
+        let mut raw = [0u8; RAW_BYTES];
+        raw[0] = 0x14;
+        raw[13] = 0xAB;
+        raw[26] = 0xF0;
+
+        vec![
+            SbasMessage::PrnMask(SbasPrnMask::model()),
+            SbasMessage::FastCorrections(2, SbasFastCorrections::model()),
+            SbasMessage::FastCorrections(5, SbasFastCorrections::model()),
+            SbasMessage::GeoNavigation(SbasGeoNavigation::model()),
+            SbasMessage::Raw(0, raw),
+            SbasMessage::Raw(63, raw),
+        ]
+    }
+
+    #[test]
+    fn sbas_properties() {
+        assert_eq!(SBAS_MESSAGE_BYTES, 32);
+        assert_eq!(DATA_BITS + CRC_BITS, SBAS_MESSAGE_BITS);
+        assert_eq!(RAW_BYTES, 27);
+    }
+
+    #[test]
+    fn message_reciprocal() {
+        for (i, model) in models().iter().enumerate() {
+            let preamble = SBAS_PREAMBLES[i % 3];
+            let message = model.encode_message(preamble);
+
+            assert_eq!(message[0], preamble);
+            assert_eq!(get_bits(&message, 8, 6), model.message_type() as u32);
+            assert!(SbasMessage::crc_ok(&message, 0));
+
+            let decoded = SbasMessage::decode_message(&message).unwrap_or_else(|e| {
+                panic!("failed to decode message #{}: {}", model.message_type(), e);
+            });
+
+            assert_eq!(decoded, *model);
+            assert_eq!(decoded.encode_message(preamble), message);
+        }
+    }
+
+    #[test]
+    fn invalid_messages() {
+        let mut message = SbasMessage::default().encode_message(0x53);
+
+        assert!(matches!(
+            SbasMessage::decode_message(&message[..SBAS_MESSAGE_BYTES - 1]),
+            Err(SbasError::WouldNotFit)
+        ));
+
+        message[0] = 0x8B;
+
+        assert!(matches!(
+            SbasMessage::decode_message(&message),
+            Err(SbasError::InvalidPreamble)
+        ));
+
+        assert!(!SbasMessage::crc_ok(&message, 0));
+    }
+}
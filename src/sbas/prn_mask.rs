@@ -0,0 +1,151 @@
+use crate::bits::{get_bits, set_bits};
+
+/// Number of PRN described by the [SbasPrnMask]
+const NUM_PRN: usize = 210;
+
+/// [SbasPrnMask] (message type 1) designates the satellites that the SBAS provides
+/// corrections for. The corrections of the following messages are ordered like
+/// the satellites of this mask (see [SbasPrnMask::slot]).
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct SbasPrnMask {
+    /// 210-bit mask, MSB first: bit #`n` is asserted when PRN #`n+1` is monitored.
+    pub mask: [u8; (NUM_PRN + 7) / 8],
+
+    /// 2-bit Issue of Data PRN mask
+    pub iodp: u8,
+}
+
+impl SbasPrnMask {
+    /// Generates a realistic mask model for testing purposes
+    #[cfg(test)]
+    pub fn model() -> Self {
+        (1..=32)
+            .chain([120, 131, 133, 135, 138])
+            .fold(Self::default(), |mask, prn| mask.with_prn(prn))
+            .with_iodp(3)
+    }
+
+    /// Copies and returns [SbasPrnMask] with this PRN (1 to 210) monitored.
+    /// Other PRN are ignored.
+    pub fn with_prn(mut self, prn: u8) -> Self {
+        if (1..=NUM_PRN).contains(&(prn as usize)) {
+            set_bits(&mut self.mask, prn as usize - 1, 1, 1);
+        }
+        self
+    }
+
+    /// Copies and returns [SbasPrnMask] with this PRN (1 to 210) no longer monitored.
+    pub fn without_prn(mut self, prn: u8) -> Self {
+        if (1..=NUM_PRN).contains(&(prn as usize)) {
+            set_bits(&mut self.mask, prn as usize - 1, 1, 0);
+        }
+        self
+    }
+
+    /// Copies and returns [SbasPrnMask] with updated 2-bit Issue of Data PRN mask
+    pub fn with_iodp(mut self, iodp: u8) -> Self {
+        self.iodp = iodp & 0x03;
+        self
+    }
+
+    /// Returns true if this PRN (1 to 210) is monitored
+    pub fn contains(&self, prn: u8) -> bool {
+        (1..=NUM_PRN).contains(&(prn as usize)) && get_bits(&self.mask, prn as usize - 1, 1) > 0
+    }
+
+    /// Returns an iterator over the monitored PRN, in ascending order
+    pub fn prns(&self) -> impl Iterator<Item = u8> + '_ {
+        (1..=NUM_PRN as u8).filter(move |prn| self.contains(*prn))
+    }
+
+    /// Returns the position (starting at 0) of this monitored PRN within the mask,
+    /// which is the position of its corrections in the following messages:
+    /// message type 2 carries slots 0 to 12, type 3 slots 13 to 25, and so on.
+    pub fn slot(&self, prn: u8) -> Option<usize> {
+        self.prns().position(|monitored| monitored == prn)
+    }
+
+    /// Decodes [Self] from this 250-bit message.
+    pub(crate) fn from_message(message: &[u8]) -> Self {
+        let mut mask = Self::default();
+
+        for bit in (0..NUM_PRN).step_by(16) {
+            let nbits = (NUM_PRN - bit).min(16);
+            set_bits(
+                &mut mask.mask,
+                bit,
+                nbits,
+                get_bits(message, 14 + bit, nbits),
+            );
+        }
+
+        mask.iodp = get_bits(message, 224, 2) as u8;
+        mask
+    }
+
+    /// Encodes this [SbasPrnMask] into this 250-bit message (header excluded).
+    pub(crate) fn to_message(self, message: &mut [u8]) {
+        for bit in (0..NUM_PRN).step_by(16) {
+            let nbits = (NUM_PRN - bit).min(16);
+            set_bits(message, 14 + bit, nbits, get_bits(&self.mask, bit, nbits));
+        }
+
+        set_bits(message, 224, 2, (self.iodp & 0x03) as u32);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sbas::{SbasMessage, SBAS_MESSAGE_BYTES};
+
+    #[test]
+    fn reciprocal() {
+        let model = SbasPrnMask::model();
+
+        let mut message = [0u8; SBAS_MESSAGE_BYTES];
+        model.to_message(&mut message);
+
+        assert_eq!(SbasPrnMask::from_message(&message), model);
+    }
+
+    #[test]
+    fn slots() {
+        let mask = SbasPrnMask::model();
+
+        assert_eq!(mask.prns().count(), 37);
+        assert!(mask.contains(1));
+        assert!(mask.contains(138));
+        assert!(!mask.contains(33));
+        assert!(!mask.contains(0));
+        assert!(!mask.contains(211));
+
+        assert_eq!(mask.slot(1), Some(0));
+        assert_eq!(mask.slot(32), Some(31));
+        assert_eq!(mask.slot(120), Some(32));
+        assert_eq!(mask.slot(138), Some(36));
+        assert_eq!(mask.slot(121), None);
+
+        let mask = mask.without_prn(120).with_prn(210).with_prn(211);
+        assert_eq!(mask.slot(131), Some(32));
+        assert_eq!(mask.prns().last(), Some(210));
+    }
+
+    #[test]
+    fn reference_message() {
+        // message type 1 with a WAAS-like mask (PRN 1 to 32, 120, 131, 133, 135 and 138, IODP=3),
+        // assembled and CRC'd independently of this library
+        let message = [
+            0x53, 0x07, 0xFF, 0xFF, 0xFF, 0xFC, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x04, 0x00, 0xA9, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0xD7, 0xF3, 0x2E, 0x00,
+        ];
+
+        assert!(SbasMessage::crc_ok(&message, 0));
+
+        let decoded = SbasMessage::decode_message(&message).unwrap();
+        assert_eq!(decoded, SbasMessage::PrnMask(SbasPrnMask::model()));
+        assert_eq!(decoded.iodp(), Some(3));
+        assert_eq!(decoded.encode_message(0x53), message);
+    }
+}
@@ -0,0 +1,82 @@
+//! Testing utilities, to help you validate your own integration.
+
+/// Inserts the desired number of zeros (bitwise) at the beginning of the stream,
+/// simply "delaying" the following bits. This is useful to simulate a bit slip,
+/// or a stream that is not aligned to [u8], which is the case of most GNSS streams.
+///
+/// The returned buffer is large enough to contain every input bit: it is
+/// `nbits / 8` bytes larger, plus one extra byte if `nbits` is not a multiple of 8.
+///
+/// ```
+/// use gnss_protos::testing::insert_bit_delay;
+///
+/// let delayed = insert_bit_delay(&[0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA], 1);
+/// assert_eq!(delayed, vec![0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00]);
+///
+/// let delayed = insert_bit_delay(&[0x55, 0x55, 0x55, 0x55], 1);
+/// assert_eq!(delayed, vec![0x2A, 0xAA, 0xAA, 0xAA, 0x80]);
+///
+/// let delayed = insert_bit_delay(&[0x55, 0x55, 0x55, 0x55], 0);
+/// assert_eq!(delayed, vec![0x55, 0x55, 0x55, 0x55]);
+///
+/// let delayed = insert_bit_delay(&[0x8B, 0x12], 8);
+/// assert_eq!(delayed, vec![0x00, 0x8B, 0x12]);
+/// ```
+pub fn insert_bit_delay(slice: &[u8], nbits: usize) -> Vec<u8> {
+    let byte_delay = nbits / 8;
+    let bit_delay = nbits % 8;
+
+    let mut size = slice.len() + byte_delay;
+
+    if bit_delay > 0 {
+        size += 1;
+    }
+
+    let mut ret = vec![0u8; size];
+
+    for (i, byte) in slice.iter().enumerate() {
+        ret[i + byte_delay] |= byte >> bit_delay;
+
+        if bit_delay > 0 {
+            ret[i + byte_delay + 1] |= byte << (8 - bit_delay);
+        }
+    }
+
+    ret
+}
+
+/// Inserts `len` bytes of garbage at byte position `at` of the stream, to simulate
+/// a burst of noise (lost lock) in the middle of a stream. The garbage bytes are
+/// pseudo random but deterministic, so tests remain reproducible.
+///
+/// ```
+/// use gnss_protos::testing::insert_garbage;
+///
+/// let stream = insert_garbage(&[0x8B, 0x12, 0x34], 1, 4);
+/// assert_eq!(stream.len(), 7);
+/// assert_eq!(stream[0], 0x8B);
+/// assert_eq!(stream[5..], [0x12, 0x34]);
+///
+/// // past the end: appended
+/// let stream = insert_garbage(&[0x8B, 0x12, 0x34], 10, 2);
+/// assert_eq!(stream[..3], [0x8B, 0x12, 0x34]);
+/// ```
+pub fn insert_garbage(slice: &[u8], at: usize, len: usize) -> Vec<u8> {
+    let at = at.min(slice.len());
+
+    let mut ret = Vec::with_capacity(slice.len() + len);
+    ret.extend_from_slice(&slice[..at]);
+
+    // xorshift64
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+
+    for _ in 0..len {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        ret.push((state >> 32) as u8);
+    }
+
+    ret.extend_from_slice(&slice[at..]);
+    ret
+}
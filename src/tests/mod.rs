@@ -20,42 +20,7 @@ pub fn init_logger() {
 /// Simple method to insert the desired number of zero (bitwise)
 /// in a stream, at the begginning of the stream, simply "delaying" the following values.
 pub fn insert_zeros(slice: &[u8], num_zero_bits: usize) -> Vec<u8> {
-    let size = slice.len();
-
-    let extra_bit = num_zero_bits % 8;
-
-    let mut extra_bytes = num_zero_bits / 8;
-
-    if extra_bit > 0 {
-        extra_bytes += 1;
-    }
-
-    println!("extra bytes: {}", extra_bytes);
-
-    let mut ret = vec![0u8; size + extra_bytes];
-
-    // first copy as is
-    ret[..size].copy_from_slice(slice);
-
-    if extra_bit > 0 {
-        let shift = 8 - extra_bit;
-        let mut mask = 0x00;
-
-        for i in 0..extra_bit {
-            mask |= 1 << (8 - i - 1);
-        }
-
-        println!("MASK=0x{:02X}", mask);
-
-        for i in 0..size - 1 {
-            ret[size - i - 1] >>= extra_bit;
-            ret[size - i - 1] |= ret[size - i - 2] << (8 - extra_bit)
-        }
-
-        ret[0] >>= extra_bit;
-    }
-
-    ret
+    crate::testing::insert_bit_delay(slice, num_zero_bits)
 }
 
 #[test]
@@ -97,33 +62,16 @@ fn test_zeros_padder() {
     }
 }
 
+/// Converts captured u-blox RXM-SFRBX words, each stored as 4 big-endian bytes.
 #[cfg(feature = "gps")]
 pub fn from_ublox_bytes<const N: usize>(bytes: &[u8; N]) -> [GpsDataWord; GPS_WORDS_PER_FRAME] {
-    let mut ret: [GpsDataWord; GPS_WORDS_PER_FRAME] = Default::default();
-
-    let mut count = 0;
-    let mut value = 0u32;
-
-    for i in 0..N {
-        match i % 4 {
-            0 => value |= (bytes[i % 4 + count * 4] as u32) << 26,
-            1 => value |= (bytes[i % 4 + count * 4] as u32) << 18,
-            2 => value |= (bytes[i % 4 + count * 4] as u32) << 10,
-            3 => {
-                value |= (bytes[i % 4 + count * 4] as u32) << 2;
-                ret[count] = GpsDataWord::from(value);
-                count += 1;
-                value = 0;
-
-                if count == GPS_WORDS_PER_FRAME {
-                    return ret;
-                }
-            },
-            _ => unreachable!("compiler issue"),
-        }
+    let mut dwords = [0u32; GPS_WORDS_PER_FRAME];
+
+    for (dword, chunk) in dwords.iter_mut().zip(bytes.chunks_exact(4)) {
+        *dword = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
     }
 
-    ret
+    GpsDataWord::from_ublox_sfrbx(&dwords)
 }
 
 #[test]
@@ -152,7 +100,8 @@ fn test_from_ublox_bytes() {
     assert_eq!(tlm.integrity, false);
     assert_eq!(tlm.reserved_bit, false);
 
-    let how = GpsQzssHow::from_word(words[1]).unwrap_or_else(|e| {
+    // HOW data bits are inverted when the TLM ends with D30=1
+    let how = GpsQzssHow::from_word(words[1].descramble(words[0].d30())).unwrap_or_else(|e| {
         panic!("failed to decode how: {}", e);
     });
 
@@ -184,7 +133,8 @@ fn test_from_ublox_bytes() {
     assert_eq!(tlm.integrity, false);
     assert_eq!(tlm.reserved_bit, false);
 
-    let how = GpsQzssHow::from_word(words[1]).unwrap_or_else(|e| {
+    // HOW data bits are inverted when the TLM ends with D30=1
+    let how = GpsQzssHow::from_word(words[1].descramble(words[0].d30())).unwrap_or_else(|e| {
         panic!("failed to decode how: {}", e);
     });
 
@@ -216,7 +166,8 @@ fn test_from_ublox_bytes() {
     assert_eq!(tlm.integrity, false);
     assert_eq!(tlm.reserved_bit, false);
 
-    let how = GpsQzssHow::from_word(words[1]).unwrap_or_else(|e| {
+    // HOW data bits are inverted when the TLM ends with D30=1
+    let how = GpsQzssHow::from_word(words[1].descramble(words[0].d30())).unwrap_or_else(|e| {
         panic!("failed to decode how: {}", e);
     });
 
@@ -248,7 +199,8 @@ fn test_from_ublox_bytes() {
     assert_eq!(tlm.integrity, false);
     assert_eq!(tlm.reserved_bit, false);
 
-    let how = GpsQzssHow::from_word(words[1]).unwrap_or_else(|e| {
+    // HOW data bits are inverted when the TLM ends with D30=1
+    let how = GpsQzssHow::from_word(words[1].descramble(words[0].d30())).unwrap_or_else(|e| {
         panic!("failed to decode how: {}", e);
     });
 
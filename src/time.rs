@@ -0,0 +1,297 @@
+//! GNSS time systems conversions, using the broadcast offset parameters.
+//! Instants are expressed as (week number, time of week in seconds).
+
+/// Number of seconds in one week
+pub const SECONDS_PER_WEEK: f64 = 604_800.0;
+
+/// GST week 0 starts on GPS week 1024 (August 22nd 1999).
+pub const GST_GPS_WEEK_OFFSET: u32 = 1024;
+
+/// [GstGpsParameters] describe the broadcast GST-GPS time offset (GGTO),
+/// so a multi-constellation receiver can align Galileo and GPS timestamps.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct GstGpsParameters {
+    /// Constant term of the polynomial (in seconds)
+    pub a0g: f64,
+
+    /// Rate of change of the offset (in seconds per second)
+    pub a1g: f64,
+
+    /// Reference time of week (in seconds)
+    pub t0g_seconds: u32,
+
+    /// 6-bit reference week number (modulo 64)
+    pub wn0g: u8,
+}
+
+impl GstGpsParameters {
+    /// Copies and returns [GstGpsParameters] with updated A0G (in seconds)
+    pub fn with_a0g_seconds(mut self, a0g: f64) -> Self {
+        self.a0g = a0g;
+        self
+    }
+
+    /// Copies and returns [GstGpsParameters] with updated A1G (in seconds per second)
+    pub fn with_a1g_seconds_s(mut self, a1g: f64) -> Self {
+        self.a1g = a1g;
+        self
+    }
+
+    /// Copies and returns [GstGpsParameters] with updated reference time of week (in seconds)
+    pub fn with_t0g_seconds(mut self, t0g_seconds: u32) -> Self {
+        self.t0g_seconds = t0g_seconds;
+        self
+    }
+
+    /// Copies and returns [GstGpsParameters] with updated 6-bit reference week number
+    pub fn with_wn0g(mut self, wn0g: u8) -> Self {
+        self.wn0g = wn0g & 0x3f;
+        self
+    }
+
+    /// Returns GST - GPS (in seconds) at this GST instant.
+    pub fn offset_seconds(&self, gst_week: u32, gst_tow_seconds: f64) -> f64 {
+        let weeks = modulo_delta(gst_week, self.wn0g as u32, 64);
+        let dt = gst_tow_seconds - self.t0g_seconds as f64 + SECONDS_PER_WEEK * weeks as f64;
+        self.a0g + self.a1g * dt
+    }
+
+    /// Converts this GST instant to GPS time.
+    ///
+    /// ## Input
+    /// - gst_week: GST week number
+    /// - gst_tow_seconds: GST time of week (in seconds)
+    ///
+    /// ## Returns
+    /// - (GPS week number, GPS time of week in seconds)
+    pub fn gst_to_gps(&self, gst_week: u32, gst_tow_seconds: f64) -> (u32, f64) {
+        let offset = self.offset_seconds(gst_week, gst_tow_seconds);
+
+        normalize(gst_week + GST_GPS_WEEK_OFFSET, gst_tow_seconds - offset)
+    }
+
+    /// Converts this GPS instant to GST.
+    ///
+    /// ## Input
+    /// - gps_week: GPS week number (must be at least [GST_GPS_WEEK_OFFSET])
+    /// - gps_tow_seconds: GPS time of week (in seconds)
+    ///
+    /// ## Returns
+    /// - (GST week number, GST time of week in seconds)
+    pub fn gps_to_gst(&self, gps_week: u32, gps_tow_seconds: f64) -> (u32, f64) {
+        let gst_week = gps_week.saturating_sub(GST_GPS_WEEK_OFFSET);
+
+        // offset is evaluated in GST, GPS is a close enough approximation
+        let offset = self.offset_seconds(gst_week, gps_tow_seconds);
+
+        normalize(gst_week, gps_tow_seconds + offset)
+    }
+}
+
+/// [UtcParameters] describe the broadcast offset to UTC.
+/// GPS (IS-GPS-200) and Galileo (OS-SIS-ICD) share the same model,
+/// so [UtcParameters] apply to both GPS time and GST.
+/// Leap second events (WN_LSF, DN and ΔtLSF) are not taken into account:
+/// the current ΔtLS is always used.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UtcParameters {
+    /// Constant term of the polynomial (in seconds)
+    pub a0: f64,
+
+    /// Rate of change of the offset (in seconds per second)
+    pub a1: f64,
+
+    /// Reference time of week (in seconds)
+    pub tot_seconds: u32,
+
+    /// 8-bit reference week number (modulo 256)
+    pub wnt: u8,
+
+    /// Current number of leap seconds
+    pub delta_t_ls: i16,
+}
+
+impl UtcParameters {
+    /// Copies and returns [UtcParameters] with updated A0 (in seconds)
+    pub fn with_a0_seconds(mut self, a0: f64) -> Self {
+        self.a0 = a0;
+        self
+    }
+
+    /// Copies and returns [UtcParameters] with updated A1 (in seconds per second)
+    pub fn with_a1_seconds_s(mut self, a1: f64) -> Self {
+        self.a1 = a1;
+        self
+    }
+
+    /// Copies and returns [UtcParameters] with updated reference time of week (in seconds)
+    pub fn with_tot_seconds(mut self, tot_seconds: u32) -> Self {
+        self.tot_seconds = tot_seconds;
+        self
+    }
+
+    /// Copies and returns [UtcParameters] with updated 8-bit reference week number
+    pub fn with_wnt(mut self, wnt: u8) -> Self {
+        self.wnt = wnt;
+        self
+    }
+
+    /// Copies and returns [UtcParameters] with updated number of leap seconds
+    pub fn with_leap_seconds(mut self, delta_t_ls: i16) -> Self {
+        self.delta_t_ls = delta_t_ls;
+        self
+    }
+
+    /// Returns system time - UTC (in seconds) at this system time instant.
+    pub fn offset_seconds(&self, week: u32, tow_seconds: f64) -> f64 {
+        let weeks = modulo_delta(week, self.wnt as u32, 256);
+        let dt = tow_seconds - self.tot_seconds as f64 + SECONDS_PER_WEEK * weeks as f64;
+        self.delta_t_ls as f64 + self.a0 + self.a1 * dt
+    }
+
+    /// Converts this system time (GPS or GST) instant to UTC.
+    ///
+    /// ## Returns
+    /// - (week number, UTC time of week in seconds), the week number
+    ///   being expressed in the same system as the input.
+    pub fn to_utc(&self, week: u32, tow_seconds: f64) -> (u32, f64) {
+        let offset = self.offset_seconds(week, tow_seconds);
+        normalize(week, tow_seconds - offset)
+    }
+
+    /// Converts this UTC instant to system time (GPS or GST).
+    pub fn from_utc(&self, week: u32, utc_tow_seconds: f64) -> (u32, f64) {
+        // first guess, then refine with the system time estimate
+        let offset = self.offset_seconds(week, utc_tow_seconds);
+        let offset = self.offset_seconds(week, utc_tow_seconds + offset);
+        normalize(week, utc_tow_seconds + offset)
+    }
+}
+
+/// Signed difference between a full week number and a truncated
+/// reference week, within [-modulo/2; modulo/2[.
+pub(crate) fn modulo_delta(week: u32, truncated: u32, modulo: u32) -> i32 {
+    let delta = ((week % modulo) + modulo - (truncated % modulo)) % modulo;
+
+    if delta >= modulo / 2 {
+        delta as i32 - modulo as i32
+    } else {
+        delta as i32
+    }
+}
+
+/// Reconstructs the full GPS week number from this 10-bit week counter
+/// (as broadcast in subframe #1), which rolls over every 1024 weeks.
+/// The 1024-week epoch that is closest to this reference GPS week
+/// (for example, the current week from the system clock) is selected.
+pub fn reconstruct_full_week(week10: u16, reference_gps_week: u16) -> u16 {
+    let delta = modulo_delta(reference_gps_week as u32, (week10 & 0x3ff) as u32, 1024);
+    let week = reference_gps_week as i32 - delta;
+
+    if week < 0 {
+        (week + 1024) as u16
+    } else {
+        week as u16
+    }
+}
+
+/// Wraps time of week within [0; 604800[, updating the week number.
+fn normalize(week: u32, tow_seconds: f64) -> (u32, f64) {
+    if tow_seconds < 0.0 {
+        (week.saturating_sub(1), tow_seconds + SECONDS_PER_WEEK)
+    } else if tow_seconds >= SECONDS_PER_WEEK {
+        (week + 1, tow_seconds - SECONDS_PER_WEEK)
+    } else {
+        (week, tow_seconds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn modulo_delta() {
+        assert_eq!(super::modulo_delta(1200, 1200 % 64, 64), 0);
+        assert_eq!(super::modulo_delta(1201, 1200 % 64, 64), 1);
+        assert_eq!(super::modulo_delta(1199, 1200 % 64, 64), -1);
+        assert_eq!(super::modulo_delta(2300, 2301 % 256, 256), -1);
+    }
+
+    #[test]
+    fn full_week_reconstruction() {
+        // within the same epoch
+        assert_eq!(reconstruct_full_week(300, 2348), 2348);
+        assert_eq!(reconstruct_full_week(290, 2348), 2338);
+        assert_eq!(reconstruct_full_week(310, 2348), 2358);
+
+        // first rollover (August 1999, week 1023 to 1024)
+        assert_eq!(reconstruct_full_week(1023, 1024), 1023);
+        assert_eq!(reconstruct_full_week(0, 1023), 1024);
+        assert_eq!(reconstruct_full_week(1020, 1030), 1020);
+        assert_eq!(reconstruct_full_week(5, 1020), 1029);
+
+        // second rollover (April 2019, week 2047 to 2048)
+        assert_eq!(reconstruct_full_week(1023, 2048), 2047);
+        assert_eq!(reconstruct_full_week(0, 2047), 2048);
+        assert_eq!(reconstruct_full_week(1000, 2060), 2024);
+        assert_eq!(reconstruct_full_week(10, 2040), 2058);
+
+        // first epoch
+        assert_eq!(reconstruct_full_week(1000, 10), 1000);
+        assert_eq!(reconstruct_full_week(10, 0), 10);
+    }
+
+    #[test]
+    fn gst_to_gps() {
+        let (a0g, a1g) = (-2.91e-9, -4.441e-15);
+
+        let params = GstGpsParameters::default()
+            .with_a0g_seconds(a0g)
+            .with_a1g_seconds_s(a1g)
+            .with_t0g_seconds(345_600)
+            .with_wn0g((1200 % 64) as u8);
+
+        let (gst_week, gst_tow) = (1200, 352_800.0);
+
+        let expected = a0g + a1g * (gst_tow - 345_600.0);
+        assert!((params.offset_seconds(gst_week, gst_tow) - expected).abs() < 1e-18);
+
+        let (gps_week, gps_tow) = params.gst_to_gps(gst_week, gst_tow);
+        assert_eq!(gps_week, 2224);
+        assert!((gps_tow - (gst_tow - expected)).abs() < 1e-9);
+
+        let (week, tow) = params.gps_to_gst(gps_week, gps_tow);
+        assert_eq!(week, gst_week);
+        assert!((tow - gst_tow).abs() < 1e-9);
+
+        // week boundary
+        let params = params.with_a0g_seconds(1.0e-3);
+        let (gps_week, gps_tow) = params.gst_to_gps(gst_week, 0.0);
+        assert_eq!(gps_week, 2223);
+        assert!(gps_tow > SECONDS_PER_WEEK - 1.0e-2);
+    }
+
+    #[test]
+    fn gps_to_utc() {
+        let params = UtcParameters::default()
+            .with_a0_seconds(1.0e-9)
+            .with_a1_seconds_s(0.0)
+            .with_tot_seconds(405_504)
+            .with_wnt((2224 % 256) as u8)
+            .with_leap_seconds(18);
+
+        let (week, tow) = params.to_utc(2224, 100.0);
+        assert_eq!(week, 2224);
+        assert!((tow - (100.0 - 18.0 - 1.0e-9)).abs() < 1e-9);
+
+        let (week, tow) = params.from_utc(week, tow);
+        assert_eq!(week, 2224);
+        assert!((tow - 100.0).abs() < 1e-9);
+
+        // previous week
+        let (week, tow) = params.to_utc(2224, 5.0);
+        assert_eq!(week, 2223);
+        assert!((tow - (SECONDS_PER_WEEK - 13.0 - 1.0e-9)).abs() < 1e-9);
+    }
+}
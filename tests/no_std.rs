@@ -0,0 +1,37 @@
+//! Smoke test of the `no_std` interfaces. The library itself
+//! is built without `std` when running:
+//!
+//! cargo test --no-default-features --features gps --test no_std
+use gnss_protos::{
+    GpsQzssDecoder, GpsQzssEncoder, GpsQzssFrame, GpsQzssFrame1, GpsQzssHow, GpsQzssSubframe,
+    GpsQzssTelemetry, GPS_FRAME_BITS,
+};
+
+#[test]
+fn no_std_smoke_test() {
+    let frame = GpsQzssFrame::default()
+        .with_telemetry(GpsQzssTelemetry::default().with_message(0x1234))
+        .with_hand_over_word(GpsQzssHow::ephemeris1().with_tow_seconds(15_000))
+        .with_subframe(GpsQzssSubframe::Ephemeris1(
+            GpsQzssFrame1::default().with_week(123),
+        ));
+
+    // fill the buffer, without std::io
+    let mut buffer = [0; 64];
+
+    let mut encoder = GpsQzssEncoder::default();
+    assert!(encoder.push(&frame));
+
+    let mut size = encoder.drain(&mut buffer);
+    assert_eq!(size, 37);
+
+    buffer[size] = encoder.finish().unwrap();
+    size += 1;
+
+    let mut decoder = GpsQzssDecoder::default();
+
+    let (processed, decoded) = decoder.decode(&buffer, size);
+
+    assert_eq!(processed, GPS_FRAME_BITS);
+    assert_eq!(decoded, Some(frame));
+}